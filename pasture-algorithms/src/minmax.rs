@@ -37,7 +37,8 @@ pub fn minmax_attribute<T: PrimitiveType + MinMax + Copy, B: PointBuffer>(
             }
         }
     } else {
-        for val in buffer.iter_attribute_as::<T>(attribute) {
+        let target_attribute = attribute.with_custom_datatype(T::data_type());
+        for val in buffer.iter_attribute_as::<T>(&target_attribute) {
             match minmax {
                 None => minmax = Some((val, val)),
                 Some((old_min, old_max)) => {
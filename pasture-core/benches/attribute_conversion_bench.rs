@@ -0,0 +1,245 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use pasture_core::{
+    containers::{
+        InterleavedPointBuffer, InterleavedVecPointStorage, PerAttributeVecPointStorage,
+        PointBuffer, PointBufferExt, PointBufferWriteable,
+    },
+    layout::conversion::{AttributeConverter, RawPointConverter},
+    layout::{attributes, PointAttributeDataType, PointLayout, PointType},
+    nalgebra::Vector3,
+};
+use pasture_derive::PointType;
+use rand::{distributions::Uniform, thread_rng, Rng};
+
+#[derive(PointType, Default)]
+#[repr(C)]
+struct CustomPointTypeBig {
+    #[pasture(BUILTIN_GPS_TIME)]
+    pub gps_time: f64,
+    #[pasture(BUILTIN_COLOR_RGB)]
+    pub color: Vector3<u16>,
+    #[pasture(BUILTIN_POSITION_3D)]
+    pub position: Vector3<f64>,
+    #[pasture(BUILTIN_CLASSIFICATION)]
+    pub classification: u8,
+    #[pasture(BUILTIN_INTENSITY)]
+    pub intensity: u16,
+}
+
+fn random_custom_point_big<R: Rng + ?Sized>(rng: &mut R) -> CustomPointTypeBig {
+    CustomPointTypeBig {
+        gps_time: rng.sample(Uniform::new(0.0, 1000.0)),
+        color: Vector3::new(
+            rng.sample(Uniform::new(0u16, u16::MAX)),
+            rng.sample(Uniform::new(0u16, u16::MAX)),
+            rng.sample(Uniform::new(0u16, u16::MAX)),
+        ),
+        position: Vector3::new(
+            rng.sample(Uniform::new(-100.0, 100.0)),
+            rng.sample(Uniform::new(-100.0, 100.0)),
+            rng.sample(Uniform::new(-100.0, 100.0)),
+        ),
+        classification: rng.sample(Uniform::new(0u8, 8)),
+        intensity: rng.sample(Uniform::new(0u16, u16::MAX)),
+    }
+}
+
+fn get_dummy_points_custom_format_big_interleaved() -> InterleavedVecPointStorage {
+    const NUM_POINTS: usize = 100_000;
+    let mut buffer =
+        InterleavedVecPointStorage::with_capacity(NUM_POINTS, CustomPointTypeBig::layout());
+    let mut rng = thread_rng();
+    for _ in 0..NUM_POINTS {
+        buffer.push_point(random_custom_point_big(&mut rng));
+    }
+    buffer
+}
+
+fn get_target_layout() -> PointLayout {
+    PointLayout::from_attributes(&[
+        attributes::POSITION_3D.with_custom_datatype(PointAttributeDataType::Vec3f32),
+        attributes::COLOR_RGB.with_custom_datatype(PointAttributeDataType::Vec3u8),
+        attributes::GPS_TIME.with_custom_datatype(PointAttributeDataType::F32),
+        attributes::INTENSITY.with_custom_datatype(PointAttributeDataType::U32),
+        attributes::CLASSIFICATION.with_custom_datatype(PointAttributeDataType::U16),
+    ])
+}
+
+fn convert_points_per_point(
+    buffer: &InterleavedVecPointStorage,
+    converter: &RawPointConverter,
+    target_layout: &PointLayout,
+    target_points: &mut [u8],
+) {
+    let source_points = buffer.get_raw_points_ref(0..buffer.len());
+    let source_size = buffer.point_layout().size_of_point_entry() as usize;
+    let target_size = target_layout.size_of_point_entry() as usize;
+    for (source_point, target_point) in source_points
+        .chunks_exact(source_size)
+        .zip(target_points.chunks_exact_mut(target_size))
+    {
+        unsafe {
+            converter.convert(source_point, target_point);
+        }
+    }
+}
+
+fn convert_points_batch(
+    buffer: &InterleavedVecPointStorage,
+    converter: &RawPointConverter,
+    target_layout: &PointLayout,
+    target_points: &mut [u8],
+) {
+    let source_points = buffer.get_raw_points_ref(0..buffer.len());
+    unsafe {
+        converter.convert_batch(
+            source_points,
+            buffer.point_layout().size_of_point_entry() as usize,
+            target_points,
+            target_layout.size_of_point_entry() as usize,
+            buffer.len(),
+        );
+    }
+}
+
+fn convert_positions_per_value(
+    source_positions: &[u8],
+    converter: &AttributeConverter,
+    target_positions: &mut [u8],
+) {
+    for (source, target) in source_positions
+        .chunks_exact(24)
+        .zip(target_positions.chunks_exact_mut(12))
+    {
+        unsafe {
+            converter.convert(source, target);
+        }
+    }
+}
+
+fn iterate_positions_as_f32(buffer: &InterleavedVecPointStorage) -> Vector3<f32> {
+    let mut position = Vector3::new(0.0, 0.0, 0.0);
+    for p in buffer.iter_attribute_as::<Vector3<f32>>(
+        &attributes::POSITION_3D.with_custom_datatype(PointAttributeDataType::Vec3f32),
+    ) {
+        position += p;
+    }
+    position
+}
+
+fn bench(c: &mut Criterion) {
+    let dummy_points_big_interleaved = get_dummy_points_custom_format_big_interleaved();
+    let target_layout = get_target_layout();
+    let converter =
+        RawPointConverter::from_to(dummy_points_big_interleaved.point_layout(), &target_layout);
+    let mut target_points =
+        vec![0; dummy_points_big_interleaved.len() * target_layout.size_of_point_entry() as usize];
+
+    c.bench_function("raw_point_converter_per_point", |b| {
+        b.iter(|| {
+            convert_points_per_point(
+                &dummy_points_big_interleaved,
+                &converter,
+                &target_layout,
+                &mut target_points,
+            )
+        })
+    });
+    c.bench_function("raw_point_converter_batch", |b| {
+        b.iter(|| {
+            convert_points_batch(
+                &dummy_points_big_interleaved,
+                &converter,
+                &target_layout,
+                &mut target_points,
+            )
+        })
+    });
+
+    let mut source_positions = vec![0; dummy_points_big_interleaved.len() * 24];
+    dummy_points_big_interleaved.get_raw_attribute_range(
+        0..dummy_points_big_interleaved.len(),
+        &attributes::POSITION_3D,
+        &mut source_positions,
+    );
+    let mut target_positions = vec![0; dummy_points_big_interleaved.len() * 12];
+    let position_converter = AttributeConverter::new(
+        &attributes::POSITION_3D,
+        &attributes::POSITION_3D.with_custom_datatype(PointAttributeDataType::Vec3f32),
+    )
+    .unwrap();
+
+    c.bench_function("attribute_converter_per_value", |b| {
+        b.iter(|| {
+            convert_positions_per_value(
+                &source_positions,
+                &position_converter,
+                &mut target_positions,
+            )
+        })
+    });
+    c.bench_function("attribute_converter_batch", |b| {
+        b.iter(|| unsafe {
+            position_converter.convert_batch(
+                &source_positions,
+                24,
+                &mut target_positions,
+                12,
+                dummy_points_big_interleaved.len(),
+            )
+        })
+    });
+    c.bench_function("attribute_iterator_with_conversion", |b| {
+        b.iter(|| -> Vector3<f32> { iterate_positions_as_f32(&dummy_points_big_interleaved) })
+    });
+
+    c.bench_function("interleaved_to_per_attribute_push", |b| {
+        b.iter(|| {
+            let mut per_attribute = PerAttributeVecPointStorage::with_capacity(
+                dummy_points_big_interleaved.len(),
+                dummy_points_big_interleaved.point_layout().clone(),
+            );
+            per_attribute.push(&dummy_points_big_interleaved);
+            per_attribute
+        })
+    });
+    c.bench_function("interleaved_to_per_attribute_transpose", |b| {
+        b.iter(|| dummy_points_big_interleaved.to_per_attribute())
+    });
+    c.bench_function("interleaved_to_per_attribute_transpose_parallel", |b| {
+        b.iter(|| dummy_points_big_interleaved.par_to_per_attribute())
+    });
+    c.bench_function(
+        "interleaved_to_per_attribute_transpose_with_conversion",
+        |b| {
+            b.iter(|| {
+                dummy_points_big_interleaved.to_per_attribute_with_layout(target_layout.clone())
+            })
+        },
+    );
+
+    let dummy_points_big_per_attribute = dummy_points_big_interleaved.to_per_attribute();
+    c.bench_function("per_attribute_to_interleaved_push", |b| {
+        b.iter(|| {
+            let mut interleaved = InterleavedVecPointStorage::with_capacity(
+                dummy_points_big_per_attribute.len(),
+                dummy_points_big_per_attribute.point_layout().clone(),
+            );
+            interleaved.push(&dummy_points_big_per_attribute);
+            interleaved
+        })
+    });
+    c.bench_function("per_attribute_to_interleaved_transpose", |b| {
+        b.iter(|| dummy_points_big_per_attribute.to_interleaved())
+    });
+    c.bench_function("per_attribute_to_interleaved_transpose_parallel", |b| {
+        b.iter(|| dummy_points_big_per_attribute.par_to_interleaved())
+    });
+}
+
+criterion_group! {
+    name = attribute_conversion;
+    config = Criterion::default().sample_size(40);
+    targets = bench
+}
+criterion_main!(attribute_conversion);
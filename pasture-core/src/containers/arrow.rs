@@ -0,0 +1,696 @@
+use std::{borrow::Cow, collections::HashMap, convert::TryFrom, sync::Arc};
+
+use anyhow::{anyhow, bail, Context, Result};
+use arrow_array::{
+    cast::AsArray,
+    types::{
+        ArrowPrimitiveType, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type,
+        UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+    },
+    Array, ArrayRef, BooleanArray, FixedSizeBinaryArray, FixedSizeListArray, PrimitiveArray,
+    RecordBatch, RecordBatchOptions,
+};
+use arrow_buffer::{BooleanBuffer, Buffer, NullBuffer, ScalarBuffer};
+use arrow_schema::{DataType, Field, FieldRef, Schema};
+
+use crate::layout::{
+    PointAttributeDataType, PointAttributeDefinition, PointAttributeScalarType, PointLayout,
+};
+
+use super::{PerAttributePointBuffer, PerAttributeVecPointStorage, PointBuffer};
+
+/// Key of the `Field` metadata that stores the shape of a matrix attribute as `<rows>x<columns>`. Matrices are stored
+/// as `FixedSizeList`s in column-major order, so without this metadata they could not be told apart from vectors
+pub const ARROW_MATRIX_SHAPE_METADATA_KEY: &str = "pasture.matrix_shape";
+
+/// Converts the given `PointLayout` into an Arrow `Schema`. Each attribute becomes one `Field` with the name of the
+/// attribute. Scalar types map to the corresponding primitive Arrow types, `Bool` maps to `Boolean`, vectors and matrices
+/// map to `FixedSizeList`s of their scalar type and `ByteArray`s map to `FixedSizeBinary`. Nullable attributes become
+/// nullable fields of their value type. Offsets and bit fields of the attributes are not part of the schema
+/// ```
+/// # use pasture_core::containers::*;
+/// # use pasture_core::layout::*;
+/// # use arrow_schema::DataType;
+/// let layout = PointLayout::from_attributes(&[attributes::POSITION_3D, attributes::INTENSITY]);
+/// let schema = point_layout_to_arrow_schema(&layout).unwrap();
+/// assert!(matches!(schema.field(0).data_type(), DataType::FixedSizeList(_, 3)));
+/// assert_eq!(&DataType::UInt16, schema.field(1).data_type());
+/// ```
+///
+/// # Errors
+///
+/// If the layout contains a `ByteArray` attribute whose length exceeds the maximum length of an Arrow `FixedSizeBinary`
+pub fn point_layout_to_arrow_schema(layout: &PointLayout) -> Result<Schema> {
+    let fields = layout
+        .attributes()
+        .map(|attribute| attribute_to_arrow_field(attribute.name(), attribute.datatype()))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Schema::new(fields))
+}
+
+/// Converts the given Arrow `Schema` into a `PointLayout`. This is the inverse of [point_layout_to_arrow_schema]. Each
+/// `Field` becomes an attribute with the name of the field, nullable fields become nullable attributes
+/// ```
+/// # use pasture_core::containers::*;
+/// # use pasture_core::layout::*;
+/// let layout = PointLayout::from_attributes(&[attributes::POSITION_3D, attributes::INTENSITY]);
+/// let schema = point_layout_to_arrow_schema(&layout).unwrap();
+/// assert_eq!(layout, arrow_schema_to_point_layout(&schema).unwrap());
+/// ```
+///
+/// # Errors
+///
+/// If the schema contains a field with a data type that has no corresponding `PointAttributeDataType`, or a nullable
+/// `Boolean` field
+pub fn arrow_schema_to_point_layout(schema: &Schema) -> Result<PointLayout> {
+    let attributes = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            let datatype = arrow_field_to_datatype(field)
+                .with_context(|| format!("Can't convert Arrow field {}", field.name()))?;
+            Ok(PointAttributeDefinition::custom_owned(
+                field.name().clone(),
+                datatype,
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(PointLayout::from_attributes(&attributes))
+}
+
+/// Converts the point data of the given `PerAttributePointBuffer` into an Arrow `RecordBatch` with the schema given by
+/// [point_layout_to_arrow_schema]. Since Arrow arrays own (or share ownership of) their memory, the data of each attribute
+/// is copied. Use [PerAttributeVecPointStorage::into_record_batch] to reuse the memory of a buffer instead
+/// ```
+/// # use pasture_core::containers::*;
+/// # use pasture_core::layout::*;
+/// # use pasture_derive::PointType;
+/// #[repr(C)]
+/// #[derive(PointType)]
+/// struct MyPointType(#[pasture(BUILTIN_INTENSITY)] u16);
+///
+/// let mut buffer = PerAttributeVecPointStorage::new(MyPointType::layout());
+/// buffer.push_points(&[MyPointType(42), MyPointType(43)]);
+/// let record_batch = point_buffer_to_record_batch(&buffer).unwrap();
+/// assert_eq!(2, record_batch.num_rows());
+/// assert_eq!(1, record_batch.num_columns());
+/// ```
+///
+/// # Errors
+///
+/// If the `PointLayout` of the buffer can't be converted into an Arrow schema
+pub fn point_buffer_to_record_batch(buffer: &dyn PerAttributePointBuffer) -> Result<RecordBatch> {
+    let num_points = buffer.len();
+    let layout = buffer.point_layout();
+    let columns = layout
+        .attributes()
+        .map(|attribute| {
+            let data = buffer.get_raw_attribute_range_ref(0..num_points, &attribute.into());
+            attribute_to_arrow_array(attribute.datatype(), Cow::Borrowed(data), num_points)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    new_record_batch(layout, columns, num_points)
+}
+
+impl PerAttributeVecPointStorage {
+    /// Converts the associated `PerAttributeVecPointStorage` into an Arrow `RecordBatch` with the schema given by
+    /// [point_layout_to_arrow_schema]. Where possible, the memory of the attributes is moved into the `RecordBatch`
+    /// without copying. This is the case for all attributes except `Bool` and nullable attributes, whose memory layout
+    /// differs from Arrow, and attributes whose memory is not aligned to their scalar type
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// # use pasture_derive::PointType;
+    /// #[repr(C)]
+    /// #[derive(PointType)]
+    /// struct MyPointType(#[pasture(BUILTIN_INTENSITY)] u16);
+    ///
+    /// let mut buffer = PerAttributeVecPointStorage::new(MyPointType::layout());
+    /// buffer.push_points(&[MyPointType(42), MyPointType(43)]);
+    /// let record_batch = buffer.into_record_batch().unwrap();
+    /// assert_eq!(2, record_batch.num_rows());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the `PointLayout` of the buffer can't be converted into an Arrow schema
+    pub fn into_record_batch(self) -> Result<RecordBatch> {
+        let num_points = self.len();
+        let layout = self.point_layout().clone();
+        let mut attribute_buffers = self.into_attribute_buffers();
+        let columns = layout
+            .attributes()
+            .map(|attribute| {
+                let data = attribute_buffers.remove(attribute.name()).unwrap();
+                attribute_to_arrow_array(attribute.datatype(), Cow::Owned(data), num_points)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        new_record_batch(&layout, columns, num_points)
+    }
+}
+
+impl TryFrom<&RecordBatch> for PerAttributeVecPointStorage {
+    type Error = anyhow::Error;
+
+    /// Converts an Arrow `RecordBatch` into a `PerAttributeVecPointStorage` whose `PointLayout` is given by
+    /// [arrow_schema_to_point_layout]. The data of all columns is copied. Null values in nullable columns become absent
+    /// values of the corresponding nullable attributes
+    fn try_from(record_batch: &RecordBatch) -> Result<Self> {
+        let layout = arrow_schema_to_point_layout(&record_batch.schema())?;
+        let attribute_buffers = layout
+            .attributes()
+            .zip(record_batch.columns())
+            .map(|(attribute, column)| {
+                let data = arrow_array_to_attribute(column.as_ref(), attribute.datatype())
+                    .with_context(|| format!("Can't convert Arrow column {}", attribute.name()))?;
+                Ok((attribute.name().to_owned(), data))
+            })
+            .collect::<Result<HashMap<_, _>>>()?;
+        Ok(Self::from_attribute_buffers(layout, attribute_buffers))
+    }
+}
+
+fn new_record_batch(
+    layout: &PointLayout,
+    columns: Vec<ArrayRef>,
+    num_points: usize,
+) -> Result<RecordBatch> {
+    let schema = point_layout_to_arrow_schema(layout)?;
+    // The row count is required for layouts without attributes
+    let options = RecordBatchOptions::new().with_row_count(Some(num_points));
+    Ok(RecordBatch::try_new_with_options(
+        Arc::new(schema),
+        columns,
+        &options,
+    )?)
+}
+
+fn scalar_type_to_arrow(scalar_type: PointAttributeScalarType) -> DataType {
+    match scalar_type {
+        PointAttributeScalarType::U8 => DataType::UInt8,
+        PointAttributeScalarType::I8 => DataType::Int8,
+        PointAttributeScalarType::U16 => DataType::UInt16,
+        PointAttributeScalarType::I16 => DataType::Int16,
+        PointAttributeScalarType::U32 => DataType::UInt32,
+        PointAttributeScalarType::I32 => DataType::Int32,
+        PointAttributeScalarType::U64 => DataType::UInt64,
+        PointAttributeScalarType::I64 => DataType::Int64,
+        PointAttributeScalarType::F32 => DataType::Float32,
+        PointAttributeScalarType::F64 => DataType::Float64,
+    }
+}
+
+fn arrow_to_scalar_type(datatype: &DataType) -> Option<PointAttributeScalarType> {
+    match datatype {
+        DataType::UInt8 => Some(PointAttributeScalarType::U8),
+        DataType::Int8 => Some(PointAttributeScalarType::I8),
+        DataType::UInt16 => Some(PointAttributeScalarType::U16),
+        DataType::Int16 => Some(PointAttributeScalarType::I16),
+        DataType::UInt32 => Some(PointAttributeScalarType::U32),
+        DataType::Int32 => Some(PointAttributeScalarType::I32),
+        DataType::UInt64 => Some(PointAttributeScalarType::U64),
+        DataType::Int64 => Some(PointAttributeScalarType::I64),
+        DataType::Float32 => Some(PointAttributeScalarType::F32),
+        DataType::Float64 => Some(PointAttributeScalarType::F64),
+        _ => None,
+    }
+}
+
+/// The `Field` of the items of the `FixedSizeList`s that store vectors and matrices
+fn list_item_field(scalar_type: PointAttributeScalarType) -> FieldRef {
+    Arc::new(Field::new("item", scalar_type_to_arrow(scalar_type), false))
+}
+
+fn attribute_to_arrow_field(name: &str, datatype: PointAttributeDataType) -> Result<Field> {
+    let (value_type, nullable) = match datatype {
+        PointAttributeDataType::Nullable(value_type) => (value_type.datatype(), true),
+        _ => (datatype, false),
+    };
+    let arrow_datatype = match value_type.normalized() {
+        PointAttributeDataType::Bool => DataType::Boolean,
+        PointAttributeDataType::ByteArray(length) => DataType::FixedSizeBinary(
+            i32::try_from(length)
+                .map_err(|_| anyhow!("ByteArray length {} is too large for Arrow", length))?,
+        ),
+        PointAttributeDataType::Vector(scalar_type, components) => {
+            DataType::FixedSizeList(list_item_field(scalar_type), components as i32)
+        }
+        PointAttributeDataType::Matrix(scalar_type, rows, columns) => {
+            DataType::FixedSizeList(list_item_field(scalar_type), rows as i32 * columns as i32)
+        }
+        other => scalar_type_to_arrow(other.scalar_type().unwrap()),
+    };
+    let field = Field::new(name, arrow_datatype, nullable);
+    match value_type {
+        PointAttributeDataType::Matrix(_, rows, columns) => {
+            let mut metadata = HashMap::new();
+            metadata.insert(
+                ARROW_MATRIX_SHAPE_METADATA_KEY.to_owned(),
+                format!("{}x{}", rows, columns),
+            );
+            Ok(field.with_metadata(metadata))
+        }
+        _ => Ok(field),
+    }
+}
+
+fn arrow_field_to_datatype(field: &Field) -> Result<PointAttributeDataType> {
+    let value_type = match field.data_type() {
+        DataType::Boolean => PointAttributeDataType::Bool,
+        DataType::FixedSizeBinary(length) => PointAttributeDataType::ByteArray(*length as u32),
+        DataType::FixedSizeList(item_field, length) => {
+            let scalar_type = arrow_to_scalar_type(item_field.data_type()).ok_or_else(|| {
+                anyhow!(
+                    "FixedSizeList with items of type {} is not supported",
+                    item_field.data_type()
+                )
+            })?;
+            match field.metadata().get(ARROW_MATRIX_SHAPE_METADATA_KEY) {
+                Some(shape) => {
+                    let (rows, columns) = parse_matrix_shape(shape)?;
+                    if rows as i32 * columns as i32 != *length {
+                        bail!(
+                            "Matrix shape {} does not match FixedSizeList length {}",
+                            shape,
+                            length
+                        );
+                    }
+                    PointAttributeDataType::Matrix(scalar_type, rows, columns)
+                }
+                None => {
+                    let components = u8::try_from(*length).map_err(|_| {
+                        anyhow!("FixedSizeList length {} is too large for a vector", length)
+                    })?;
+                    PointAttributeDataType::vector(scalar_type, components)
+                }
+            }
+        }
+        other => match arrow_to_scalar_type(other) {
+            Some(scalar_type) => scalar_type.into(),
+            None => bail!("Arrow data type {} is not supported", other),
+        },
+    };
+    if !field.is_nullable() {
+        Ok(value_type)
+    } else if value_type == PointAttributeDataType::Bool {
+        bail!("Nullable Boolean fields are not supported")
+    } else {
+        Ok(PointAttributeDataType::nullable(value_type))
+    }
+}
+
+fn parse_matrix_shape(shape: &str) -> Result<(u8, u8)> {
+    let parse = || -> Option<(u8, u8)> {
+        let (rows, columns) = shape.split_once('x')?;
+        Some((rows.parse().ok()?, columns.parse().ok()?))
+    };
+    parse().ok_or_else(|| anyhow!("Invalid matrix shape {}", shape))
+}
+
+/// Returns an Arrow `Buffer` with the given `data`. Owned data is moved into the buffer if it is aligned to `alignment`,
+/// since Arrow requires aligned memory for its primitive arrays. All other data is copied
+fn to_arrow_buffer(data: Cow<'_, [u8]>, alignment: usize) -> Buffer {
+    match data {
+        Cow::Owned(data) if data.as_ptr().align_offset(alignment) == 0 => Buffer::from_vec(data),
+        data => Buffer::from_slice_ref(data.as_ref()),
+    }
+}
+
+fn scalar_arrow_array(
+    scalar_type: PointAttributeScalarType,
+    data: Cow<'_, [u8]>,
+    num_values: usize,
+    nulls: Option<NullBuffer>,
+) -> ArrayRef {
+    fn typed_array<T: ArrowPrimitiveType>(
+        data: Cow<'_, [u8]>,
+        num_values: usize,
+        nulls: Option<NullBuffer>,
+    ) -> ArrayRef {
+        let buffer = to_arrow_buffer(data, std::mem::align_of::<T::Native>());
+        Arc::new(PrimitiveArray::<T>::new(
+            ScalarBuffer::new(buffer, 0, num_values),
+            nulls,
+        ))
+    }
+
+    match scalar_type {
+        PointAttributeScalarType::U8 => typed_array::<UInt8Type>(data, num_values, nulls),
+        PointAttributeScalarType::I8 => typed_array::<Int8Type>(data, num_values, nulls),
+        PointAttributeScalarType::U16 => typed_array::<UInt16Type>(data, num_values, nulls),
+        PointAttributeScalarType::I16 => typed_array::<Int16Type>(data, num_values, nulls),
+        PointAttributeScalarType::U32 => typed_array::<UInt32Type>(data, num_values, nulls),
+        PointAttributeScalarType::I32 => typed_array::<Int32Type>(data, num_values, nulls),
+        PointAttributeScalarType::U64 => typed_array::<UInt64Type>(data, num_values, nulls),
+        PointAttributeScalarType::I64 => typed_array::<Int64Type>(data, num_values, nulls),
+        PointAttributeScalarType::F32 => typed_array::<Float32Type>(data, num_values, nulls),
+        PointAttributeScalarType::F64 => typed_array::<Float64Type>(data, num_values, nulls),
+    }
+}
+
+fn attribute_to_arrow_array(
+    datatype: PointAttributeDataType,
+    data: Cow<'_, [u8]>,
+    num_points: usize,
+) -> Result<ArrayRef> {
+    match datatype {
+        PointAttributeDataType::Nullable(value_type) => {
+            let value_type = value_type.datatype();
+            // Arrow stores the validity of the values in a separate bitmap, so the values have to be extracted from
+            // behind their validity bytes and padding (see `PointAttributeDataType::Nullable`)
+            let value_size = value_type.size() as usize;
+            let value_offset = datatype.size() as usize - value_size;
+            let mut values = Vec::with_capacity(num_points * value_size);
+            let mut validity = Vec::with_capacity(num_points);
+            for entry in data.chunks_exact(datatype.size() as usize) {
+                let is_present = entry[0] != 0;
+                if is_present {
+                    values.extend_from_slice(&entry[value_offset..]);
+                } else {
+                    values.resize(values.len() + value_size, 0);
+                }
+                validity.push(is_present);
+            }
+            values_to_arrow_array(
+                value_type,
+                Cow::Owned(values),
+                num_points,
+                Some(NullBuffer::from(validity)),
+            )
+        }
+        _ => values_to_arrow_array(datatype, data, num_points, None),
+    }
+}
+
+fn values_to_arrow_array(
+    datatype: PointAttributeDataType,
+    data: Cow<'_, [u8]>,
+    num_points: usize,
+    nulls: Option<NullBuffer>,
+) -> Result<ArrayRef> {
+    let array: ArrayRef = match datatype.normalized() {
+        PointAttributeDataType::Bool => Arc::new(BooleanArray::new(
+            data.iter()
+                .map(|&value| value != 0)
+                .collect::<BooleanBuffer>(),
+            nulls,
+        )),
+        PointAttributeDataType::ByteArray(length) => Arc::new(FixedSizeBinaryArray::try_new(
+            length as i32,
+            to_arrow_buffer(data, 1),
+            nulls,
+        )?),
+        PointAttributeDataType::Vector(scalar_type, components) => {
+            let components = components as usize;
+            Arc::new(FixedSizeListArray::try_new(
+                list_item_field(scalar_type),
+                components as i32,
+                scalar_arrow_array(scalar_type, data, num_points * components, None),
+                nulls,
+            )?)
+        }
+        PointAttributeDataType::Matrix(scalar_type, rows, columns) => {
+            let components = rows as usize * columns as usize;
+            Arc::new(FixedSizeListArray::try_new(
+                list_item_field(scalar_type),
+                components as i32,
+                scalar_arrow_array(scalar_type, data, num_points * components, None),
+                nulls,
+            )?)
+        }
+        other => {
+            let scalar_type = other
+                .scalar_type()
+                .ok_or_else(|| anyhow!("Datatype {} has no Arrow representation", other))?;
+            scalar_arrow_array(scalar_type, data, num_points, nulls)
+        }
+    };
+    Ok(array)
+}
+
+fn arrow_array_to_attribute(
+    array: &dyn Array,
+    datatype: PointAttributeDataType,
+) -> Result<Vec<u8>> {
+    match datatype {
+        PointAttributeDataType::Nullable(value_type) => {
+            let value_type = value_type.datatype();
+            let values = arrow_array_to_values(array, value_type)?;
+            let value_size = value_type.size() as usize;
+            let value_offset = datatype.size() as usize - value_size;
+            // Absent values and the padding between validity bytes and values stay zero
+            let mut data = vec![0; array.len() * datatype.size() as usize];
+            for (index, entry) in data.chunks_exact_mut(datatype.size() as usize).enumerate() {
+                if array.is_null(index) {
+                    continue;
+                }
+                entry[0] = 1;
+                entry[value_offset..]
+                    .copy_from_slice(&values[index * value_size..(index + 1) * value_size]);
+            }
+            Ok(data)
+        }
+        _ => {
+            if array.null_count() > 0 {
+                bail!("Column contains null values, but its field is not nullable");
+            }
+            arrow_array_to_values(array, datatype)
+        }
+    }
+}
+
+fn arrow_array_to_values(array: &dyn Array, datatype: PointAttributeDataType) -> Result<Vec<u8>> {
+    fn typed_values<T: ArrowPrimitiveType>(array: &dyn Array) -> Result<Vec<u8>> {
+        let array = array
+            .as_primitive_opt::<T>()
+            .ok_or_else(|| anyhow!("Expected an array of type {}", T::DATA_TYPE))?;
+        Ok(array.values().inner().as_slice().to_vec())
+    }
+
+    match datatype.normalized() {
+        PointAttributeDataType::Bool => {
+            let array = array
+                .as_boolean_opt()
+                .ok_or_else(|| anyhow!("Expected a Boolean array"))?;
+            Ok(array.values().iter().map(|value| value as u8).collect())
+        }
+        PointAttributeDataType::ByteArray(length) => {
+            let array = array
+                .as_fixed_size_binary_opt()
+                .filter(|array| array.value_length() as u32 == length)
+                .ok_or_else(|| anyhow!("Expected a FixedSizeBinary array of length {}", length))?;
+            Ok(array.value_data().to_vec())
+        }
+        PointAttributeDataType::Vector(scalar_type, _)
+        | PointAttributeDataType::Matrix(scalar_type, _, _) => {
+            let components = (datatype.size() / scalar_type.size()) as i32;
+            let array = array
+                .as_fixed_size_list_opt()
+                .filter(|array| array.value_length() == components)
+                .ok_or_else(|| {
+                    anyhow!("Expected a FixedSizeList array of length {}", components)
+                })?;
+            arrow_array_to_values(array.values().as_ref(), scalar_type.into())
+        }
+        other => match other.scalar_type() {
+            Some(PointAttributeScalarType::U8) => typed_values::<UInt8Type>(array),
+            Some(PointAttributeScalarType::I8) => typed_values::<Int8Type>(array),
+            Some(PointAttributeScalarType::U16) => typed_values::<UInt16Type>(array),
+            Some(PointAttributeScalarType::I16) => typed_values::<Int16Type>(array),
+            Some(PointAttributeScalarType::U32) => typed_values::<UInt32Type>(array),
+            Some(PointAttributeScalarType::I32) => typed_values::<Int32Type>(array),
+            Some(PointAttributeScalarType::U64) => typed_values::<UInt64Type>(array),
+            Some(PointAttributeScalarType::I64) => typed_values::<Int64Type>(array),
+            Some(PointAttributeScalarType::F32) => typed_values::<Float32Type>(array),
+            Some(PointAttributeScalarType::F64) => typed_values::<Float64Type>(array),
+            None => bail!("Datatype {} has no Arrow representation", other),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        containers::PointBufferExt,
+        layout::{attributes, Nullable, PointType},
+    };
+    use nalgebra::{Matrix2, Vector2, Vector3};
+    use pasture_derive::PointType;
+
+    #[repr(C)]
+    #[derive(PointType, Debug, Clone, Copy, PartialEq)]
+    struct ArrowTestPoint {
+        #[pasture(BUILTIN_POSITION_3D)]
+        pub position: Vector3<f64>,
+        #[pasture(BUILTIN_INTENSITY)]
+        pub intensity: u16,
+        #[pasture(BUILTIN_CLASSIFICATION)]
+        pub classification: u8,
+        #[pasture(attribute = "Flag")]
+        pub flag: bool,
+        #[pasture(attribute = "Texcoords")]
+        pub texcoords: Vector2<f32>,
+        #[pasture(attribute = "Transform")]
+        pub transform: Matrix2<f32>,
+        #[pasture(attribute = "Id")]
+        pub id: [u8; 5],
+        #[pasture(BUILTIN_GPS_TIME)]
+        pub gps_time: Nullable<f64>,
+    }
+
+    fn test_points() -> Vec<ArrowTestPoint> {
+        (0..37)
+            .map(|index| ArrowTestPoint {
+                position: Vector3::new(index as f64, index as f64 * 2.0, -(index as f64)),
+                intensity: index as u16 * 100,
+                classification: (index % 7) as u8,
+                flag: index % 3 == 0,
+                texcoords: Vector2::new(index as f32 * 0.5, 1.0),
+                transform: Matrix2::new(index as f32, 1.0, 2.0, 3.0),
+                id: [index as u8; 5],
+                gps_time: if index % 2 == 0 {
+                    Nullable::some(index as f64 * 0.25)
+                } else {
+                    Nullable::none()
+                },
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_point_layout_to_arrow_schema() {
+        let layout = ArrowTestPoint::layout();
+        let schema = point_layout_to_arrow_schema(&layout).unwrap();
+        assert_eq!(layout.attributes().count(), schema.fields().len());
+
+        let position = schema
+            .field_with_name(attributes::POSITION_3D.name())
+            .unwrap();
+        assert_eq!(
+            &DataType::FixedSizeList(list_item_field(PointAttributeScalarType::F64), 3),
+            position.data_type()
+        );
+        assert!(!position.is_nullable());
+        assert_eq!(
+            &DataType::Boolean,
+            schema.field_with_name("Flag").unwrap().data_type()
+        );
+        assert_eq!(
+            &DataType::FixedSizeBinary(5),
+            schema.field_with_name("Id").unwrap().data_type()
+        );
+        let transform = schema.field_with_name("Transform").unwrap();
+        assert_eq!(
+            Some(&"2x2".to_owned()),
+            transform.metadata().get(ARROW_MATRIX_SHAPE_METADATA_KEY)
+        );
+        let gps_time = schema.field_with_name(attributes::GPS_TIME.name()).unwrap();
+        assert_eq!(&DataType::Float64, gps_time.data_type());
+        assert!(gps_time.is_nullable());
+
+        let layout_from_schema = arrow_schema_to_point_layout(&schema).unwrap();
+        assert!(layout_from_schema.compare_without_offsets(&layout));
+    }
+
+    #[test]
+    fn test_arrow_schema_with_unsupported_types() {
+        let schema = Schema::new(vec![Field::new("Name", DataType::Utf8, false)]);
+        assert!(arrow_schema_to_point_layout(&schema).is_err());
+
+        let schema = Schema::new(vec![Field::new("Flag", DataType::Boolean, true)]);
+        assert!(arrow_schema_to_point_layout(&schema).is_err());
+    }
+
+    #[test]
+    fn test_record_batch_round_trip() {
+        let points = test_points();
+        let mut buffer = PerAttributeVecPointStorage::new(ArrowTestPoint::layout());
+        buffer.push_points(&points);
+
+        let record_batch = point_buffer_to_record_batch(&buffer).unwrap();
+        assert_eq!(points.len(), record_batch.num_rows());
+
+        let intensities = record_batch
+            .column_by_name(attributes::INTENSITY.name())
+            .unwrap()
+            .as_primitive::<UInt16Type>();
+        for (point, intensity) in points.iter().zip(intensities.values().iter()) {
+            assert_eq!(point.intensity, *intensity);
+        }
+        let gps_times = record_batch
+            .column_by_name(attributes::GPS_TIME.name())
+            .unwrap()
+            .as_primitive::<Float64Type>();
+        for (point, gps_time) in points.iter().zip(gps_times.iter()) {
+            assert_eq!(point.gps_time.get(), gps_time);
+        }
+
+        let owned_record_batch = buffer.into_record_batch().unwrap();
+        assert_eq!(record_batch, owned_record_batch);
+
+        let converted_buffer = PerAttributeVecPointStorage::try_from(&record_batch).unwrap();
+        assert!(converted_buffer
+            .point_layout()
+            .compare_without_offsets(&ArrowTestPoint::layout()));
+        let converted_points = converted_buffer
+            .iter_point::<ArrowTestPoint>()
+            .collect::<Vec<_>>();
+        assert_eq!(points, converted_points);
+
+        let sliced_buffer =
+            PerAttributeVecPointStorage::try_from(&record_batch.slice(5, 10)).unwrap();
+        let sliced_points = sliced_buffer
+            .iter_point::<ArrowTestPoint>()
+            .collect::<Vec<_>>();
+        assert_eq!(&points[5..15], sliced_points.as_slice());
+    }
+
+    #[test]
+    fn test_empty_record_batch() {
+        let buffer = PerAttributeVecPointStorage::new(ArrowTestPoint::layout());
+        let record_batch = buffer.into_record_batch().unwrap();
+        assert_eq!(0, record_batch.num_rows());
+
+        let converted_buffer = PerAttributeVecPointStorage::try_from(&record_batch).unwrap();
+        assert_eq!(0, converted_buffer.len());
+    }
+
+    #[test]
+    fn test_record_batch_with_nulls_in_non_nullable_column() {
+        let schema = Schema::new(vec![Field::new("Intensity", DataType::UInt16, true)]);
+        let array = PrimitiveArray::<UInt16Type>::from(vec![Some(1), None, Some(3)]);
+        let record_batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(array)]).unwrap();
+        let buffer = PerAttributeVecPointStorage::try_from(&record_batch).unwrap();
+        let values = buffer
+            .iter_attribute::<Nullable<u16>>(&PointAttributeDefinition::custom_owned(
+                "Intensity",
+                PointAttributeDataType::nullable(PointAttributeDataType::U16),
+            ))
+            .map(|intensity| intensity.get())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![Some(1), None, Some(3)], values);
+        let raw_values = buffer.get_raw_attribute_range_ref(
+            0..3,
+            &PointAttributeDefinition::custom_owned(
+                "Intensity",
+                PointAttributeDataType::nullable(PointAttributeDataType::U16),
+            ),
+        );
+        let expected_raw_values = [
+            &[1, 0][..],
+            &1_u16.to_ne_bytes(),
+            &[0; 4],
+            &[1, 0],
+            &3_u16.to_ne_bytes(),
+        ]
+        .concat();
+        assert_eq!(expected_raw_values.as_slice(), raw_values);
+
+        let schema = Schema::new(vec![Field::new("Intensity", DataType::UInt16, false)]);
+        let layout = arrow_schema_to_point_layout(&schema).unwrap();
+        let array = PrimitiveArray::<UInt16Type>::from(vec![Some(1), None]);
+        let intensity = layout.get_attribute_by_name("Intensity").unwrap();
+        assert!(arrow_array_to_attribute(&array, intensity.datatype()).is_err());
+    }
+}
@@ -1,11 +1,108 @@
 use crate::containers::{PerAttributePointBuffer, PerAttributePointBufferMut, PointBuffer};
-use crate::layout::conversion::{get_converter_for_attributes, AttributeConversionFn};
+use crate::layout::conversion::AttributeConverter;
 use crate::layout::PointAttributeDefinition;
 use crate::layout::PrimitiveType;
 use crate::util::view_raw_bytes_mut;
 
+use rayon::prelude::*;
 use std::marker::PhantomData;
-use std::mem::MaybeUninit;
+
+/// Converts the values of a single attribute in chunks of many points at once, using [`AttributeConverter::convert_batch`]
+struct ConvertedAttributeChunks {
+    source_attribute: PointAttributeDefinition,
+    converter: AttributeConverter,
+    target_size: usize,
+    source_chunk: Vec<u8>,
+    target_chunk: Vec<u8>,
+    chunk_start: usize,
+    chunk_length: usize,
+}
+
+impl ConvertedAttributeChunks {
+    const CHUNK_SIZE: usize = 50_000;
+
+    /// Creates new `ConvertedAttributeChunks` that convert from `source_attribute` into `target_attribute`, whose values
+    /// are then read as values of type `T`
+    ///
+    /// # Panics
+    ///
+    /// If the datatype of `target_attribute` does not match `T`, or if no conversion from `source_attribute` into
+    /// `target_attribute` exists
+    fn new<T: PrimitiveType>(
+        source_attribute: PointAttributeDefinition,
+        target_attribute: &PointAttributeDefinition,
+    ) -> Self {
+        if target_attribute.datatype() != T::data_type() {
+            panic!(
+                "Type T does not match datatype of attribute {}",
+                target_attribute
+            );
+        }
+        let converter = match AttributeConverter::new(&source_attribute, target_attribute) {
+            Some(c) => c,
+            None => panic!(
+                "Can't convert from attribute {} to attribute {} because no valid conversion exists",
+                source_attribute, target_attribute
+            ),
+        };
+        Self {
+            source_attribute,
+            converter,
+            target_size: target_attribute.size() as usize,
+            source_chunk: vec![],
+            target_chunk: vec![],
+            chunk_start: 0,
+            chunk_length: 0,
+        }
+    }
+
+    /// Returns the converted attribute value of the point at `point_index` in `buffer`. Converts the next chunk of points
+    /// starting at `point_index` if the point is not part of the current chunk. `T` must be the type that these
+    /// `ConvertedAttributeChunks` were created with
+    fn get<T: PrimitiveType, B: PointBuffer + ?Sized>(
+        &mut self,
+        buffer: &B,
+        point_index: usize,
+    ) -> T {
+        assert_eq!(
+            std::mem::size_of::<T>(),
+            self.target_size,
+            "Size of type T does not match the size of the target attribute"
+        );
+        if point_index < self.chunk_start || point_index >= self.chunk_start + self.chunk_length {
+            self.convert_chunk(buffer, point_index);
+        }
+
+        let offset = (point_index - self.chunk_start) * self.target_size;
+        let value_bytes = &self.target_chunk[offset..offset + std::mem::size_of::<T>()];
+        unsafe { (value_bytes.as_ptr() as *const T).read_unaligned() }
+    }
+
+    fn convert_chunk<B: PointBuffer + ?Sized>(&mut self, buffer: &B, chunk_start: usize) {
+        let chunk_length = std::cmp::min(Self::CHUNK_SIZE, buffer.len() - chunk_start);
+        let source_size = self.source_attribute.size() as usize;
+        self.source_chunk.resize(chunk_length * source_size, 0);
+        self.target_chunk.resize(chunk_length * self.target_size, 0);
+
+        buffer.get_raw_attribute_range(
+            chunk_start..chunk_start + chunk_length,
+            &self.source_attribute,
+            &mut self.source_chunk,
+        );
+        unsafe {
+            self.converter.convert_batch(
+                &self.source_chunk,
+                source_size,
+                &mut self.target_chunk,
+                self.target_size,
+                chunk_length,
+            );
+        }
+
+        self.chunk_start = chunk_start;
+        self.chunk_length = chunk_length;
+    }
+}
 
 // The iterators for a single point attribute are implemented without macros, because we want them to return just T instead of a tuple (T)
 
@@ -105,10 +202,8 @@ pub mod attr1 {
 
     pub struct AttributeIteratorByValueWithConversion<'a, T: PrimitiveType, B: PointBuffer + ?Sized> {
         buffer: &'a B,
-        source_attribute: PointAttributeDefinition,
         current_index: usize,
-        converter: AttributeConversionFn,
-        source_attribute_buffer: Vec<u8>,
+        converted_chunks: ConvertedAttributeChunks,
         _unused: PhantomData<T>,
     }
 
@@ -128,17 +223,13 @@ pub mod attr1 {
                 ),
             };
 
-            let converter = match get_converter_for_attributes(&source_attribute.into(), target_attribute) {
-                Some(c) => c,
-                None => panic!("Can't convert from attribute {} to attribute {} because no valid conversion exists", source_attribute, target_attribute),
-            };
-
             Self {
                 buffer,
-                source_attribute: source_attribute.into(),
                 current_index: 0,
-                converter,
-                source_attribute_buffer: vec![0; source_attribute.size() as usize],
+                converted_chunks: ConvertedAttributeChunks::new::<T>(
+                    source_attribute.into(),
+                    target_attribute,
+                ),
                 _unused: Default::default(),
             }
         }
@@ -154,27 +245,12 @@ pub mod attr1 {
                 return None;
             }
 
-            let mut target_attribute = MaybeUninit::<T>::uninit();
-            unsafe {
-                let target_attribute_byte_slice = std::slice::from_raw_parts_mut(
-                    target_attribute.as_mut_ptr() as *mut u8,
-                    std::mem::size_of::<T>(),
-                );
-                self.buffer.get_raw_attribute(
-                    self.current_index,
-                    &self.source_attribute,
-                    self.source_attribute_buffer.as_mut_slice(),
-                );
-
-                (self.converter)(
-                    self.source_attribute_buffer.as_slice(),
-                    target_attribute_byte_slice,
-                );
-            }
-
+            let target_attribute = self
+                .converted_chunks
+                .get::<T, _>(self.buffer, self.current_index);
             self.current_index += 1;
 
-            unsafe { Some(target_attribute.assume_init()) }
+            Some(target_attribute)
         }
     }
 
@@ -299,18 +375,6 @@ macro_rules! extract_attributes {
     }};
 }
 
-macro_rules! extract_and_convert_attributes {
-    ($attributes:ident, $buffer:expr, $current_index:expr, $self_attributes:expr, $($idx:tt),+ ) => {{
-        $(unsafe {
-            let cur_attribute = &mut $self_attributes[$idx];
-            let target_attribute_bytes = view_raw_bytes_mut(&mut $attributes.$idx);
-            $buffer.get_raw_attribute($current_index, &cur_attribute.0, cur_attribute.2.as_mut_slice());
-            let converter = cur_attribute.1;
-            converter(cur_attribute.2.as_slice(), target_attribute_bytes);
-        })+
-    }};
-}
-
 macro_rules! attributes_iter {
     ($name:ident, $num_attributes:expr, $($t:ident), + and $($idx:tt),+ ) => {
         pub mod $name {
@@ -363,29 +427,22 @@ macro_rules! attributes_iter {
 
             pub struct AttributeIteratorByValueWithConversion<'a, $($t: PrimitiveType, )+> {
                 buffer: &'a dyn PointBuffer,
-                attributes_and_converters_and_buffers: Vec<(PointAttributeDefinition, AttributeConversionFn, Vec<u8>)>,
+                converted_chunks: Vec<ConvertedAttributeChunks>,
                 current_index: usize,
                 _unused: PhantomData<( $($t),+ )>,
             }
 
             impl<'a, $($t: PrimitiveType + Default, )+> AttributeIteratorByValueWithConversion<'a, $($t, )+> {
                 pub fn new(buffer: &'a dyn PointBuffer, attributes: [&'a PointAttributeDefinition; $num_attributes]) -> Self {
-                    let attributes_and_converters_and_buffers = attributes.iter().map(|target_attribute| { match buffer.point_layout().get_attribute_by_name(target_attribute.name()) {
-                        Some(a) => {
-                            let source_attribute : PointAttributeDefinition = a.into();
-                            let converter = match get_converter_for_attributes(&source_attribute, target_attribute) {
-                                Some(c) => c,
-                                None => panic!("Can't convert from attribute {} to attribute {} because no valid conversion exists", source_attribute, target_attribute),
-                            };
-                            let buffer : Vec<u8> = vec![0; source_attribute.size() as usize];
-                            (source_attribute, converter, buffer)
-                        },
+                    let source_attribute = |target_attribute: &PointAttributeDefinition| match buffer.point_layout().get_attribute_by_name(target_attribute.name()) {
+                        Some(a) => a.into(),
                         None => panic!("Attribute {} not contained in PointLayout of buffer ({})", target_attribute, buffer.point_layout()),
-                    }}).collect();
+                    };
+                    let converted_chunks = vec![$(ConvertedAttributeChunks::new::<$t>(source_attribute(attributes[$idx]), attributes[$idx]),)+];
 
                     Self {
                         buffer,
-                        attributes_and_converters_and_buffers,
+                        converted_chunks,
                         current_index: 0,
                         _unused: Default::default(),
                     }
@@ -400,9 +457,7 @@ macro_rules! attributes_iter {
                         return None;
                     }
 
-                    let mut attributes: Self::Item = Default::default();
-
-                    extract_and_convert_attributes!{attributes, self.buffer, self.current_index, self.attributes_and_converters_and_buffers, $($idx),+ }
+                    let attributes: Self::Item = ($(self.converted_chunks[$idx].get::<$t, _>(self.buffer, self.current_index),)+);
 
                     self.current_index += 1;
 
@@ -507,6 +562,78 @@ macro_rules! attributes_iter {
                 }
             }
 
+            /// Parallel version of `AttributeIteratorByValue`, using the `rayon` crate. Requires that `buffer` can be shared
+            /// between threads
+            pub fn par_iter_by_value<'a, $($t: PrimitiveType + Default, )+ B: PointBuffer + Sync + ?Sized>(
+                buffer: &'a B,
+                attributes: [&'a PointAttributeDefinition; $num_attributes],
+            ) -> impl IndexedParallelIterator<Item = ($($t,)+)> + 'a {
+                $(if attributes[$idx].datatype() != $t::data_type() {
+                    panic!("Type T does not match datatype of attribute {}", attributes[$idx]);
+                })+
+                for attribute in attributes.iter() {
+                    if !buffer.point_layout().has_attribute(attribute) {
+                        panic!("Attribute {} not contained in PointLayout of buffer ({})", attribute, buffer.point_layout());
+                    }
+                }
+
+                (0..buffer.len()).into_par_iter().map(move |current_index| {
+                    let mut attribute_values: ($($t,)+) = Default::default();
+                    extract_attributes!{attribute_values, buffer, current_index, attributes, $($idx),+ }
+                    attribute_values
+                })
+            }
+
+            /// Parallel version of `AttributeIteratorByRef`, using the `rayon` crate
+            pub fn par_iter_by_ref<'a, $($t: PrimitiveType + 'a, )+ B: PerAttributePointBuffer + ?Sized>(
+                buffer: &'a B,
+                attributes: [&'a PointAttributeDefinition; $num_attributes],
+            ) -> impl IndexedParallelIterator<Item = ($(&'a $t,)+)> {
+                $(if attributes[$idx].datatype() != $t::data_type() {
+                    panic!("Type T does not match datatype of attribute {}", attributes[$idx]);
+                })+
+                let buffer_len = buffer.len();
+                (
+                    $(unsafe {
+                        std::slice::from_raw_parts(
+                            buffer
+                                .get_raw_attribute_range_ref(0..buffer_len, attributes[$idx])
+                                .as_ptr() as *const $t,
+                            buffer_len,
+                        )
+                    }.par_iter(),)+
+                ).into_par_iter()
+            }
+
+            /// Parallel version of `AttributeIteratorByMut`, using the `rayon` crate
+            ///
+            /// # Panics
+            ///
+            /// Panics if any attribute is contained more than once in `attributes`, as it would be borrowed mutably more than once
+            pub fn par_iter_by_mut<'a, 'b, $($t: PrimitiveType + 'a, )+ B: PerAttributePointBufferMut<'b> + ?Sized>(
+                buffer: &'a mut B,
+                attributes: [&'a PointAttributeDefinition; $num_attributes],
+            ) -> impl IndexedParallelIterator<Item = ($(&'a mut $t,)+)> {
+                $(if attributes[$idx].datatype() != $t::data_type() {
+                    panic!("Type T does not match datatype of attribute {}", attributes[$idx]);
+                })+
+                for (index, attribute) in attributes.iter().enumerate() {
+                    if attributes[..index].iter().any(|other| other.name() == attribute.name()) {
+                        panic!("Attribute {} can't be borrowed mutably more than once", attribute);
+                    }
+                }
+                let buffer_len = buffer.len();
+                (
+                    $(unsafe {
+                        std::slice::from_raw_parts_mut(
+                            buffer
+                                .get_raw_attribute_range_mut(0..buffer_len, attributes[$idx])
+                                .as_mut_ptr() as *mut $t,
+                            buffer_len,
+                        )
+                    }.par_iter_mut(),)+
+                ).into_par_iter()
+            }
         }
     };
 }
@@ -518,6 +645,45 @@ macro_rules! attributes_iter {
 attributes_iter!(attr2, 2, T1, T2 and 0, 1);
 attributes_iter!(attr3, 3, T1, T2, T3 and 0, 1, 2);
 attributes_iter!(attr4, 4, T1, T2, T3, T4 and 0, 1, 2, 3);
+attributes_iter!(attr5, 5, T1, T2, T3, T4, T5 and 0, 1, 2, 3, 4);
+attributes_iter!(attr6, 6, T1, T2, T3, T4, T5, T6 and 0, 1, 2, 3, 4, 5);
+attributes_iter!(attr7, 7, T1, T2, T3, T4, T5, T6, T7 and 0, 1, 2, 3, 4, 5, 6);
+attributes_iter!(attr8, 8, T1, T2, T3, T4, T5, T6, T7, T8 and 0, 1, 2, 3, 4, 5, 6, 7);
+attributes_iter!(attr9, 9, T1, T2, T3, T4, T5, T6, T7, T8, T9 and 0, 1, 2, 3, 4, 5, 6, 7, 8);
+attributes_iter!(attr10, 10, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10 and 0, 1, 2, 3, 4, 5, 6, 7, 8, 9);
+attributes_iter!(attr11, 11, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11 and 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
+attributes_iter!(attr12, 12, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12 and 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
+
+/// Implementation detail of the multi-attribute iterator macros such as [attributes!]. Splits the
+/// `ATTRIBUTE_1_EXPR => ATTRIBUTE_1_TYPE, ..., buffer` syntax into the attributes, their types and the buffer and calls `$item`
+/// from the `attrN` module that matches the number of attributes. Since we can't count in Rust macros, every parsed attribute
+/// advances `$module` by one entry of the list of `attrN` modules
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __multi_attribute_iterator {
+    ($item:ident [$($generics:tt)*] [$($ctor:tt)*] $($attributes_and_buffer:tt)+) => {
+        $crate::__multi_attribute_iterator!(
+            @parse $item [$($generics)*] [$($ctor)*] attr0
+            [attr1 attr2 attr3 attr4 attr5 attr6 attr7 attr8 attr9 attr10 attr11 attr12]
+            [] [] $($attributes_and_buffer)+
+        )
+    };
+    (@parse $item:ident [$($generics:tt)*] [$($ctor:tt)*] attr1 [$($modules:ident)*] [$($attr:expr),+] [$($t:ty),+] $buffer:expr) => {
+        compile_error!("At least two attributes are required, use the iterators of the PointBuffer for a single attribute")
+    };
+    (@parse $item:ident [$($generics:tt)*] [$($ctor:tt)*] $module:ident [$($modules:ident)*] [$($attr:expr),+] [$($t:ty),+] $buffer:expr) => {
+        $crate::containers::$module::$item::<$($t),+ $($generics)*>$($ctor)*($buffer, [$($attr),+])
+    };
+    (@parse $item:ident [$($generics:tt)*] [$($ctor:tt)*] $module:ident [] [$($attr:expr),*] [$($t:ty),*] $next_attr:expr => $next_t:ty, $($rest:tt)+) => {
+        compile_error!("A maximum of 12 attributes at the same time is supported")
+    };
+    (@parse $item:ident [$($generics:tt)*] [$($ctor:tt)*] $module:ident [$next_module:ident $($modules:ident)*] [$($attr:expr),*] [$($t:ty),*] $next_attr:expr => $next_t:ty, $($rest:tt)+) => {
+        $crate::__multi_attribute_iterator!(
+            @parse $item [$($generics)*] [$($ctor)*] $next_module [$($modules)*]
+            [$($attr,)* $next_attr] [$($t,)* $next_t] $($rest)+
+        )
+    };
+}
 
 /// Create an iterator over multiple attributes within a `PointBuffer`. This macro uses some special syntax  to determine the attributes
 /// and their types:
@@ -530,7 +696,7 @@ attributes_iter!(attr4, 4, T1, T2, T3, T4 and 0, 1, 2, 3);
 ///
 /// `(ATTRIBUTE_1_TYPE, ATTRIBUTE_2_TYPE, ...)`
 ///
-/// *Note:* Currently, a maximum of 4 attributes at the same time are supported.
+/// *Note:* Currently, a maximum of 12 attributes at the same time are supported.
 ///
 /// # Panics
 ///
@@ -538,23 +704,8 @@ attributes_iter!(attr4, 4, T1, T2, T3, T4 and 0, 1, 2, 3);
 /// Panics if, for any attribute, the desired type does not match the `PointAttributeDataType` of that attribute.
 #[macro_export]
 macro_rules! attributes {
-    ($attr1:expr => $t1:ty, $attr2:expr => $t2:ty, $buffer:expr) => {
-        $crate::containers::attr2::AttributeIteratorByValue::<$t1, $t2>::new(
-            $buffer,
-            [$attr1, $attr2],
-        )
-    };
-    ($attr1:expr => $t1:ty, $attr2:expr => $t2:ty, $attr3:expr => $t3:ty, $buffer:expr) => {
-        $crate::containers::attr3::AttributeIteratorByValue::<$t1, $t2, $t3>::new(
-            $buffer,
-            [$attr1, $attr2, $attr3],
-        )
-    };
-    ($attr1:expr => $t1:ty, $attr2:expr => $t2:ty, $attr3:expr => $t3:ty, $attr4:expr => $t4:ty, $buffer:expr) => {
-        $crate::containers::attr3::AttributeIteratorByValue::<$t1, $t2, $t3, $t4>::new(
-            $buffer,
-            [$attr1, $attr2, $attr3, $attr4],
-        )
+    ($($attributes_and_buffer:tt)+) => {
+        $crate::__multi_attribute_iterator!(AttributeIteratorByValue [] [::new] $($attributes_and_buffer)+)
     };
 }
 
@@ -569,7 +720,7 @@ macro_rules! attributes {
 ///
 /// `(ATTRIBUTE_1_TYPE, ATTRIBUTE_2_TYPE, ...)`
 ///
-/// *Note:* Currently, a maximum of 4 attributes at the same time are supported.
+/// *Note:* Currently, a maximum of 12 attributes at the same time are supported.
 ///
 /// # Panics
 ///
@@ -577,23 +728,8 @@ macro_rules! attributes {
 /// Panics if, for any attribute, no conversion exists between this attributes `PointAttributeDataType` and the desired type for this attribute.
 #[macro_export]
 macro_rules! attributes_as {
-    ($attr1:expr => $t1:ty, $attr2:expr => $t2:ty, $buffer:expr) => {
-        $crate::containers::attr2::AttributeIteratorByValueWithConversion::<$t1, $t2>::new(
-            $buffer,
-            [$attr1, $attr2],
-        )
-    };
-    ($attr1:expr => $t1:ty, $attr2:expr => $t2:ty, $attr3:expr => $t3:ty, $buffer:expr) => {
-        $crate::containers::attr3::AttributeIteratorByValueWithConversion::<$t1, $t2, $t3>::new(
-            $buffer,
-            [$attr1, $attr2, $attr3],
-        )
-    };
-    ($attr1:expr => $t1:ty, $attr2:expr => $t2:ty, $attr3:expr => $t3:ty, $attr4:expr => $t4:ty, $buffer:expr) => {
-        $crate::containers::attr3::AttributeIteratorByValueWithConversion::<$t1, $t2, $t3, $t4>::new(
-            $buffer,
-            [$attr1, $attr2, $attr3, $attr4],
-        )
+    ($($attributes_and_buffer:tt)+) => {
+        $crate::__multi_attribute_iterator!(AttributeIteratorByValueWithConversion [] [::new] $($attributes_and_buffer)+)
     };
 }
 
@@ -608,7 +744,7 @@ macro_rules! attributes_as {
 ///
 /// `(&ATTRIBUTE_1_TYPE, &ATTRIBUTE_2_TYPE, ...)`
 ///
-/// *Note:* Currently, a maximum of 4 attributes at the same time are supported.
+/// *Note:* Currently, a maximum of 12 attributes at the same time are supported.
 ///
 /// # Panics
 ///
@@ -616,23 +752,8 @@ macro_rules! attributes_as {
 /// Panics if, for any attribute, the desired type does not match the `PointAttributeDataType` of that attribute.
 #[macro_export]
 macro_rules! attributes_ref {
-    ($attr1:expr => $t1:ty, $attr2:expr => $t2:ty, $buffer:expr) => {
-        $crate::containers::attr2::AttributeIteratorByRef::<$t1, $t2>::new(
-            $buffer,
-            [$attr1, $attr2],
-        )
-    };
-    ($attr1:expr => $t1:ty, $attr2:expr => $t2:ty, $attr3:expr => $t3:ty, $buffer:expr) => {
-        $crate::containers::attr3::AttributeIteratorByRef::<$t1, $t2, $t3>::new(
-            $buffer,
-            [$attr1, $attr2, $attr3],
-        )
-    };
-    ($attr1:expr => $t1:ty, $attr2:expr => $t2:ty, $attr3:expr => $t3:ty, $attr4:expr => $t4:ty, $buffer:expr) => {
-        $crate::containers::attr3::AttributeIteratorByRef::<$t1, $t2, $t3, $t4>::new(
-            $buffer,
-            [$attr1, $attr2, $attr3, $attr4],
-        )
+    ($($attributes_and_buffer:tt)+) => {
+        $crate::__multi_attribute_iterator!(AttributeIteratorByRef [] [::new] $($attributes_and_buffer)+)
     };
 }
 
@@ -647,7 +768,7 @@ macro_rules! attributes_ref {
 ///
 /// `(&mut ATTRIBUTE_1_TYPE, &mut ATTRIBUTE_2_TYPE, ...)`
 ///
-/// *Note:* Currently, a maximum of 4 attributes at the same time are supported.
+/// *Note:* Currently, a maximum of 12 attributes at the same time are supported.
 ///
 /// # Panics
 ///
@@ -655,40 +776,122 @@ macro_rules! attributes_ref {
 /// Panics if, for any attribute, the desired type does not match the `PointAttributeDataType` of that attribute.
 #[macro_export]
 macro_rules! attributes_mut {
-    ($attr1:expr => $t1:ty, $attr2:expr => $t2:ty, $buffer:expr) => {
-        $crate::containers::attr2::AttributeIteratorByMut::<$t1, $t2>::new(
-            $buffer,
-            [$attr1, $attr2],
-        )
+    ($($attributes_and_buffer:tt)+) => {
+        $crate::__multi_attribute_iterator!(AttributeIteratorByMut [] [::new] $($attributes_and_buffer)+)
     };
-    ($attr1:expr => $t1:ty, $attr2:expr => $t2:ty, $attr3:expr => $t3:ty, $buffer:expr) => {
-        $crate::containers::attr3::AttributeIteratorByMut::<$t1, $t2, $t3>::new(
-            $buffer,
-            [$attr1, $attr2, $attr3],
-        )
+}
+
+/// Parallel version of the [attributes!] macro, using the [`rayon`](https://docs.rs/rayon) crate. Uses the same syntax as
+/// [attributes!] and returns an `IndexedParallelIterator` over tuples of the form:
+///
+/// `(ATTRIBUTE_1_TYPE, ATTRIBUTE_2_TYPE, ...)`
+///
+/// In contrast to [attributes!], the `buffer` must be a concrete buffer type that can be shared between threads.
+///
+/// ```
+/// # use pasture_core::containers::*;
+/// # use pasture_core::layout::*;
+/// # use pasture_core::par_attributes;
+/// use rayon::prelude::*;
+///
+/// let mut buffer = InterleavedVecPointStorage::new(PointLayout::from_attributes(&[attributes::INTENSITY, attributes::CLASSIFICATION]));
+/// buffer.resize(100);
+///
+/// let count = par_attributes!(&attributes::INTENSITY => u16, &attributes::CLASSIFICATION => u8, &buffer)
+///     .filter(|(intensity, classification)| *intensity == 0 && *classification == 0)
+///     .count();
+/// assert_eq!(100, count);
+/// ```
+///
+/// *Note:* Currently, a maximum of 12 attributes at the same time are supported.
+///
+/// # Panics
+///
+/// Panics if any of the attributes are not contained within the `buffer`.
+/// Panics if, for any attribute, the desired type does not match the `PointAttributeDataType` of that attribute.
+#[macro_export]
+macro_rules! par_attributes {
+    ($($attributes_and_buffer:tt)+) => {
+        $crate::__multi_attribute_iterator!(par_iter_by_value [, _] [] $($attributes_and_buffer)+)
     };
-    ($attr1:expr => $t1:ty, $attr2:expr => $t2:ty, $attr3:expr => $t3:ty, $attr4:expr => $t4:ty, $buffer:expr) => {
-        $crate::containers::attr3::AttributeIteratorByMut::<$t1, $t2, $t3, $t4>::new(
-            $buffer,
-            [$attr1, $attr2, $attr3, $attr4],
-        )
+}
+
+/// Parallel version of the [attributes_ref!] macro, using the [`rayon`](https://docs.rs/rayon) crate. Uses the same syntax as
+/// [attributes_ref!] and returns an `IndexedParallelIterator` over tuples of the form:
+///
+/// `(&ATTRIBUTE_1_TYPE, &ATTRIBUTE_2_TYPE, ...)`
+///
+/// *Note:* Currently, a maximum of 12 attributes at the same time are supported.
+///
+/// # Panics
+///
+/// Panics if any of the attributes are not contained within the `buffer`.
+/// Panics if, for any attribute, the desired type does not match the `PointAttributeDataType` of that attribute.
+#[macro_export]
+macro_rules! par_attributes_ref {
+    ($($attributes_and_buffer:tt)+) => {
+        $crate::__multi_attribute_iterator!(par_iter_by_ref [, _] [] $($attributes_and_buffer)+)
+    };
+}
+
+/// Parallel version of the [attributes_mut!] macro, using the [`rayon`](https://docs.rs/rayon) crate. Uses the same syntax as
+/// [attributes_mut!] and returns an `IndexedParallelIterator` over tuples of the form:
+///
+/// `(&mut ATTRIBUTE_1_TYPE, &mut ATTRIBUTE_2_TYPE, ...)`
+///
+/// ```
+/// # use pasture_core::containers::*;
+/// # use pasture_core::layout::*;
+/// # use pasture_core::par_attributes_mut;
+/// use rayon::prelude::*;
+///
+/// let mut buffer = PerAttributeVecPointStorage::new(PointLayout::from_attributes(&[attributes::INTENSITY, attributes::CLASSIFICATION]));
+/// buffer.resize(100);
+///
+/// par_attributes_mut!(&attributes::INTENSITY => u16, &attributes::CLASSIFICATION => u8, &mut buffer)
+///     .enumerate()
+///     .for_each(|(index, (intensity, classification))| {
+///         *intensity = index as u16;
+///         *classification = (index % 2) as u8;
+///     });
+/// assert_eq!(99, buffer.get_attribute::<u16>(&attributes::INTENSITY, 99));
+/// assert_eq!(1, buffer.get_attribute::<u8>(&attributes::CLASSIFICATION, 99));
+/// ```
+///
+/// *Note:* Currently, a maximum of 12 attributes at the same time are supported.
+///
+/// # Panics
+///
+/// Panics if any of the attributes are not contained within the `buffer`.
+/// Panics if, for any attribute, the desired type does not match the `PointAttributeDataType` of that attribute.
+/// Panics if any attribute is passed more than once.
+#[macro_export]
+macro_rules! par_attributes_mut {
+    ($($attributes_and_buffer:tt)+) => {
+        $crate::__multi_attribute_iterator!(par_iter_by_mut [, _] [] $($attributes_and_buffer)+)
     };
 }
 
 #[cfg(test)]
 mod tests {
 
+    use crate::containers::{InterleavedPointBufferExt, InterleavedPointBufferMutExt};
     use crate::{containers::PointBufferExt, layout::attributes};
     use crate::{
         containers::{
             InterleavedVecPointStorage, PerAttributePointBufferExt, PerAttributePointBufferMutExt,
             PerAttributeVecPointStorage,
         },
+        containers::{PointBufferWriteable, PointBufferWriteableExt},
         layout::attributes::POSITION_3D,
-        layout::PointType,
+        layout::{
+            PointAttributeDataType, PointAttributeQuantization, PointAttributeScalarType,
+            PointLayout, PointType,
+        },
     };
     use nalgebra::Vector3;
     use pasture_derive::PointType;
+    use rayon::prelude::*;
 
     // We need this, otherwise we can't use the derive(PointType) macro from within pasture_core because the macro
     // doesn't recognize the name 'pasture_core' :/
@@ -796,6 +999,318 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_attributes_with_many_attributes() {
+        let layout = PointLayout::from_attributes(&[
+            attributes::INTENSITY,
+            attributes::RETURN_NUMBER,
+            attributes::NUMBER_OF_RETURNS,
+            attributes::CLASSIFICATION,
+            attributes::USER_DATA,
+            attributes::POINT_SOURCE_ID,
+            attributes::GPS_TIME,
+        ]);
+        let mut storage = PerAttributeVecPointStorage::new(layout);
+        storage.resize(10);
+
+        attributes_mut!(
+            &attributes::INTENSITY => u16,
+            &attributes::RETURN_NUMBER => u8,
+            &attributes::NUMBER_OF_RETURNS => u8,
+            &attributes::CLASSIFICATION => u8,
+            &attributes::USER_DATA => u8,
+            &attributes::POINT_SOURCE_ID => u16,
+            &attributes::GPS_TIME => f64,
+            &mut storage
+        )
+        .enumerate()
+        .for_each(|(index, point)| {
+            *point.0 = index as u16;
+            *point.1 = 1;
+            *point.2 = 2;
+            *point.3 = 3;
+            *point.4 = 4;
+            *point.5 = 5;
+            *point.6 = index as f64;
+        });
+
+        let expected_points = (0..10)
+            .map(|index| (index as u16, 1_u8, 2_u8, 3_u8, 4_u8, 5_u16, index as f64))
+            .collect::<Vec<_>>();
+        let points_by_value = attributes!(
+            &attributes::INTENSITY => u16,
+            &attributes::RETURN_NUMBER => u8,
+            &attributes::NUMBER_OF_RETURNS => u8,
+            &attributes::CLASSIFICATION => u8,
+            &attributes::USER_DATA => u8,
+            &attributes::POINT_SOURCE_ID => u16,
+            &attributes::GPS_TIME => f64,
+            &storage
+        )
+        .collect::<Vec<_>>();
+        assert_eq!(expected_points, points_by_value);
+
+        let points_by_ref = attributes_ref!(
+            &attributes::INTENSITY => u16,
+            &attributes::RETURN_NUMBER => u8,
+            &attributes::NUMBER_OF_RETURNS => u8,
+            &attributes::CLASSIFICATION => u8,
+            &attributes::USER_DATA => u8,
+            &attributes::POINT_SOURCE_ID => u16,
+            &attributes::GPS_TIME => f64,
+            &storage
+        )
+        .map(|(a, b, c, d, e, f, g)| (*a, *b, *c, *d, *e, *f, *g))
+        .collect::<Vec<_>>();
+        assert_eq!(expected_points, points_by_ref);
+
+        let par_points_by_value = par_attributes!(
+            &attributes::INTENSITY => u16,
+            &attributes::RETURN_NUMBER => u8,
+            &attributes::NUMBER_OF_RETURNS => u8,
+            &attributes::CLASSIFICATION => u8,
+            &attributes::USER_DATA => u8,
+            &attributes::POINT_SOURCE_ID => u16,
+            &attributes::GPS_TIME => f64,
+            &storage
+        )
+        .collect::<Vec<_>>();
+        assert_eq!(expected_points, par_points_by_value);
+
+        let intensity_u32 = attributes::INTENSITY.with_custom_datatype(PointAttributeDataType::U32);
+        let return_number_u16 =
+            attributes::RETURN_NUMBER.with_custom_datatype(PointAttributeDataType::U16);
+        let number_of_returns_u16 =
+            attributes::NUMBER_OF_RETURNS.with_custom_datatype(PointAttributeDataType::U16);
+        let classification_u16 =
+            attributes::CLASSIFICATION.with_custom_datatype(PointAttributeDataType::U16);
+        let point_source_id_u32 =
+            attributes::POINT_SOURCE_ID.with_custom_datatype(PointAttributeDataType::U32);
+        let converted_points = attributes_as!(
+            &intensity_u32 => u32,
+            &return_number_u16 => u16,
+            &number_of_returns_u16 => u16,
+            &classification_u16 => u16,
+            &point_source_id_u32 => u32,
+            &storage
+        )
+        .collect::<Vec<_>>();
+        let expected_converted_points = (0..10)
+            .map(|index| (index as u32, 1_u16, 2_u16, 3_u16, 5_u32))
+            .collect::<Vec<_>>();
+        assert_eq!(expected_converted_points, converted_points);
+
+        // Four attributes used to be dispatched to the iterators for three attributes
+        let last_point = attributes!(
+            &attributes::INTENSITY => u16,
+            &attributes::RETURN_NUMBER => u8,
+            &attributes::NUMBER_OF_RETURNS => u8,
+            &attributes::CLASSIFICATION => u8,
+            &storage
+        )
+        .last();
+        assert_eq!(Some((9, 1, 2, 3)), last_point);
+    }
+
+    #[test]
+    fn test_attributes_with_twelve_attributes() {
+        let layout = PointLayout::from_attributes(&[
+            attributes::INTENSITY,
+            attributes::RETURN_NUMBER,
+            attributes::NUMBER_OF_RETURNS,
+            attributes::CLASSIFICATION_FLAGS,
+            attributes::SCANNER_CHANNEL,
+            attributes::CLASSIFICATION,
+            attributes::SCAN_ANGLE_RANK,
+            attributes::SCAN_ANGLE,
+            attributes::USER_DATA,
+            attributes::POINT_SOURCE_ID,
+            attributes::GPS_TIME,
+            attributes::NIR,
+        ]);
+        let mut storage = PerAttributeVecPointStorage::new(layout);
+        storage.resize(10);
+
+        par_attributes_mut!(
+            &attributes::INTENSITY => u16,
+            &attributes::RETURN_NUMBER => u8,
+            &attributes::NUMBER_OF_RETURNS => u8,
+            &attributes::CLASSIFICATION_FLAGS => u8,
+            &attributes::SCANNER_CHANNEL => u8,
+            &attributes::CLASSIFICATION => u8,
+            &attributes::SCAN_ANGLE_RANK => i8,
+            &attributes::SCAN_ANGLE => i16,
+            &attributes::USER_DATA => u8,
+            &attributes::POINT_SOURCE_ID => u16,
+            &attributes::GPS_TIME => f64,
+            &attributes::NIR => u16,
+            &mut storage
+        )
+        .enumerate()
+        .for_each(|(index, point)| {
+            *point.0 = index as u16;
+            *point.1 = 1;
+            *point.2 = 2;
+            *point.3 = 3;
+            *point.4 = 4;
+            *point.5 = 5;
+            *point.6 = -6;
+            *point.7 = -7;
+            *point.8 = 8;
+            *point.9 = 9;
+            *point.10 = index as f64;
+            *point.11 = 11;
+        });
+        attributes_mut!(
+            &attributes::INTENSITY => u16,
+            &attributes::RETURN_NUMBER => u8,
+            &attributes::NUMBER_OF_RETURNS => u8,
+            &attributes::CLASSIFICATION_FLAGS => u8,
+            &attributes::SCANNER_CHANNEL => u8,
+            &attributes::CLASSIFICATION => u8,
+            &attributes::SCAN_ANGLE_RANK => i8,
+            &attributes::SCAN_ANGLE => i16,
+            &attributes::USER_DATA => u8,
+            &attributes::POINT_SOURCE_ID => u16,
+            &attributes::GPS_TIME => f64,
+            &attributes::NIR => u16,
+            &mut storage
+        )
+        .for_each(|point| *point.11 += 1);
+
+        let expected_points = (0..10)
+            .map(|index| {
+                (
+                    index as u16,
+                    1_u8,
+                    2_u8,
+                    3_u8,
+                    4_u8,
+                    5_u8,
+                    -6_i8,
+                    -7_i16,
+                    8_u8,
+                    9_u16,
+                    index as f64,
+                    12_u16,
+                )
+            })
+            .collect::<Vec<_>>();
+        let points_by_value = attributes!(
+            &attributes::INTENSITY => u16,
+            &attributes::RETURN_NUMBER => u8,
+            &attributes::NUMBER_OF_RETURNS => u8,
+            &attributes::CLASSIFICATION_FLAGS => u8,
+            &attributes::SCANNER_CHANNEL => u8,
+            &attributes::CLASSIFICATION => u8,
+            &attributes::SCAN_ANGLE_RANK => i8,
+            &attributes::SCAN_ANGLE => i16,
+            &attributes::USER_DATA => u8,
+            &attributes::POINT_SOURCE_ID => u16,
+            &attributes::GPS_TIME => f64,
+            &attributes::NIR => u16,
+            &storage
+        )
+        .collect::<Vec<_>>();
+        assert_eq!(expected_points, points_by_value);
+
+        let par_points_by_value = par_attributes!(
+            &attributes::INTENSITY => u16,
+            &attributes::RETURN_NUMBER => u8,
+            &attributes::NUMBER_OF_RETURNS => u8,
+            &attributes::CLASSIFICATION_FLAGS => u8,
+            &attributes::SCANNER_CHANNEL => u8,
+            &attributes::CLASSIFICATION => u8,
+            &attributes::SCAN_ANGLE_RANK => i8,
+            &attributes::SCAN_ANGLE => i16,
+            &attributes::USER_DATA => u8,
+            &attributes::POINT_SOURCE_ID => u16,
+            &attributes::GPS_TIME => f64,
+            &attributes::NIR => u16,
+            &storage
+        )
+        .collect::<Vec<_>>();
+        assert_eq!(expected_points, par_points_by_value);
+
+        let points_by_ref = attributes_ref!(
+            &attributes::INTENSITY => u16,
+            &attributes::RETURN_NUMBER => u8,
+            &attributes::NUMBER_OF_RETURNS => u8,
+            &attributes::CLASSIFICATION_FLAGS => u8,
+            &attributes::SCANNER_CHANNEL => u8,
+            &attributes::CLASSIFICATION => u8,
+            &attributes::SCAN_ANGLE_RANK => i8,
+            &attributes::SCAN_ANGLE => i16,
+            &attributes::USER_DATA => u8,
+            &attributes::POINT_SOURCE_ID => u16,
+            &attributes::GPS_TIME => f64,
+            &attributes::NIR => u16,
+            &storage
+        )
+        .map(|(a, b, c, d, e, f, g, h, i, j, k, l)| {
+            (*a, *b, *c, *d, *e, *f, *g, *h, *i, *j, *k, *l)
+        })
+        .collect::<Vec<_>>();
+        assert_eq!(expected_points, points_by_ref);
+
+        let par_points_by_ref = par_attributes_ref!(
+            &attributes::INTENSITY => u16,
+            &attributes::RETURN_NUMBER => u8,
+            &attributes::NUMBER_OF_RETURNS => u8,
+            &attributes::CLASSIFICATION_FLAGS => u8,
+            &attributes::SCANNER_CHANNEL => u8,
+            &attributes::CLASSIFICATION => u8,
+            &attributes::SCAN_ANGLE_RANK => i8,
+            &attributes::SCAN_ANGLE => i16,
+            &attributes::USER_DATA => u8,
+            &attributes::POINT_SOURCE_ID => u16,
+            &attributes::GPS_TIME => f64,
+            &attributes::NIR => u16,
+            &storage
+        )
+        .map(|(a, b, c, d, e, f, g, h, i, j, k, l)| {
+            (*a, *b, *c, *d, *e, *f, *g, *h, *i, *j, *k, *l)
+        })
+        .collect::<Vec<_>>();
+        assert_eq!(expected_points, par_points_by_ref);
+
+        let converted_points = attributes_as!(
+            &attributes::INTENSITY.with_custom_datatype(PointAttributeDataType::U32) => u32,
+            &attributes::RETURN_NUMBER.with_custom_datatype(PointAttributeDataType::U16) => u16,
+            &attributes::NUMBER_OF_RETURNS.with_custom_datatype(PointAttributeDataType::U16) => u16,
+            &attributes::CLASSIFICATION_FLAGS.with_custom_datatype(PointAttributeDataType::U16) => u16,
+            &attributes::SCANNER_CHANNEL.with_custom_datatype(PointAttributeDataType::U16) => u16,
+            &attributes::CLASSIFICATION.with_custom_datatype(PointAttributeDataType::U16) => u16,
+            &attributes::SCAN_ANGLE_RANK.with_custom_datatype(PointAttributeDataType::I16) => i16,
+            &attributes::SCAN_ANGLE.with_custom_datatype(PointAttributeDataType::I32) => i32,
+            &attributes::USER_DATA.with_custom_datatype(PointAttributeDataType::U16) => u16,
+            &attributes::POINT_SOURCE_ID.with_custom_datatype(PointAttributeDataType::U32) => u32,
+            &attributes::GPS_TIME.with_custom_datatype(PointAttributeDataType::F32) => f32,
+            &attributes::NIR.with_custom_datatype(PointAttributeDataType::U32) => u32,
+            &storage
+        )
+        .collect::<Vec<_>>();
+        let expected_converted_points = (0..10)
+            .map(|index| {
+                (
+                    index as u32,
+                    1_u16,
+                    2_u16,
+                    3_u16,
+                    4_u16,
+                    5_u16,
+                    -6_i16,
+                    -7_i32,
+                    8_u16,
+                    9_u32,
+                    index as f32,
+                    12_u32,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(expected_converted_points, converted_points);
+    }
+
     #[test]
     #[should_panic(expected = "not contained in PointLayout of buffer")]
     fn test_attributes_with_different_datatype_fails() {
@@ -896,4 +1411,187 @@ mod tests {
             &mut storage
         );
     }
+
+    #[test]
+    #[should_panic(expected = "Type T does not match datatype of attribute")]
+    fn test_attribute_as_with_wrong_type_fails() {
+        let storage = InterleavedVecPointStorage::new(TestPointType::layout());
+        storage
+            .iter_attribute_as::<u64>(
+                &attributes::INTENSITY.with_custom_datatype(PointAttributeDataType::U32),
+            )
+            .for_each(drop);
+    }
+
+    #[test]
+    #[should_panic(expected = "Type T does not match datatype of attribute")]
+    fn test_attributes_as_with_wrong_type_fails() {
+        let storage = PerAttributeVecPointStorage::new(TestPointType::layout());
+        attributes_as!(
+            &attributes::INTENSITY.with_custom_datatype(PointAttributeDataType::U32) => u64,
+            &attributes::GPS_TIME.with_custom_datatype(PointAttributeDataType::F32) => f32,
+            &storage
+        );
+    }
+
+    #[test]
+    fn test_iter_quantized_attribute_as() {
+        let raw_positions = POSITION_3D
+            .with_custom_datatype(PointAttributeDataType::vector(
+                PointAttributeScalarType::I32,
+                3,
+            ))
+            .with_quantization(PointAttributeQuantization::per_component(
+                &[0.5, 0.25, 2.0],
+                &[100.0, 200.0, 300.0],
+            ));
+        let layout = PointLayout::from_attributes(&[raw_positions.clone(), attributes::INTENSITY]);
+        let mut storage = InterleavedVecPointStorage::new(layout);
+        storage.resize(2);
+        storage.set_attribute(&raw_positions, 0, Vector3::<i32>::new(0, 0, 0));
+        storage.set_attribute(&raw_positions, 1, Vector3::<i32>::new(2, -4, 8));
+        storage.set_attribute(&attributes::INTENSITY, 1, 42_u16);
+
+        let positions = storage
+            .iter_attribute_as::<Vector3<f64>>(&POSITION_3D)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                Vector3::new(100.0, 200.0, 300.0),
+                Vector3::new(101.0, 199.0, 316.0)
+            ],
+            positions
+        );
+
+        let intensity_u32 = attributes::INTENSITY.with_custom_datatype(PointAttributeDataType::U32);
+        let positions_and_intensities = attributes_as!(
+            &POSITION_3D => Vector3<f64>,
+            &intensity_u32 => u32,
+            &storage
+        )
+        .collect::<Vec<_>>();
+        assert_eq!(
+            (Vector3::new(101.0, 199.0, 316.0), 42),
+            positions_and_intensities[1]
+        );
+
+        // Requantize the positions with a different quantization
+        let requantized_positions =
+            raw_positions.with_quantization(PointAttributeQuantization::new(1.0, 100.0));
+        let positions = storage
+            .iter_attribute_as::<Vector3<i32>>(&requantized_positions)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![Vector3::new(0, 100, 200), Vector3::new(1, 99, 216)],
+            positions
+        );
+    }
+
+    fn get_test_points(count: usize) -> Vec<TestPointType> {
+        (0..count)
+            .map(|index| TestPointType {
+                intensity: index as u16,
+                gps_time: index as f64 * 0.5,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parallel_iterators_match_sequential_iterators() {
+        let reference_points = get_test_points(1000);
+        let mut interleaved = InterleavedVecPointStorage::new(TestPointType::layout());
+        interleaved.push_points(&reference_points);
+        let mut per_attribute = PerAttributeVecPointStorage::new(TestPointType::layout());
+        per_attribute.push_points(&reference_points);
+
+        let points = interleaved
+            .par_iter_point::<TestPointType>()
+            .collect::<Vec<_>>();
+        assert_eq!(reference_points, points);
+        let points = interleaved
+            .par_iter_point_ref::<TestPointType>()
+            .copied()
+            .collect::<Vec<_>>();
+        assert_eq!(reference_points, points);
+
+        let intensities = per_attribute
+            .iter_attribute::<u16>(&attributes::INTENSITY)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            intensities,
+            interleaved
+                .par_iter_attribute::<u16>(&attributes::INTENSITY)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            intensities,
+            per_attribute
+                .par_iter_attribute_ref::<u16>(&attributes::INTENSITY)
+                .copied()
+                .collect::<Vec<_>>()
+        );
+
+        let pairs =
+            attributes!(&attributes::INTENSITY => u16, &attributes::GPS_TIME => f64, &interleaved)
+                .collect::<Vec<_>>();
+        assert_eq!(
+            pairs,
+            par_attributes!(&attributes::INTENSITY => u16, &attributes::GPS_TIME => f64, &interleaved)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            pairs,
+            par_attributes_ref!(&attributes::INTENSITY => u16, &attributes::GPS_TIME => f64, &per_attribute)
+                .map(|(intensity, gps_time)| (*intensity, *gps_time))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_parallel_mutable_iterators() {
+        let mut interleaved = InterleavedVecPointStorage::new(TestPointType::layout());
+        interleaved.resize(1000);
+        interleaved
+            .par_iter_point_mut::<TestPointType>()
+            .enumerate()
+            .for_each(|(index, point)| point.intensity = index as u16);
+
+        let mut per_attribute = PerAttributeVecPointStorage::new(TestPointType::layout());
+        per_attribute.resize(1000);
+        per_attribute
+            .par_iter_attribute_mut::<u16>(&attributes::INTENSITY)
+            .enumerate()
+            .for_each(|(index, intensity)| *intensity = index as u16);
+        par_attributes_mut!(&attributes::GPS_TIME => f64, &attributes::INTENSITY => u16, &mut per_attribute)
+            .for_each(|(gps_time, intensity)| *gps_time = *intensity as f64 * 0.5);
+
+        let reference_points = get_test_points(1000);
+        assert_eq!(
+            reference_points
+                .iter()
+                .map(|point| point.intensity)
+                .collect::<Vec<_>>(),
+            interleaved
+                .iter_attribute::<u16>(&attributes::INTENSITY)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            reference_points,
+            per_attribute
+                .iter_point::<TestPointType>()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_par_attributes_mut_with_duplicate_attribute_fails() {
+        let mut storage = PerAttributeVecPointStorage::new(TestPointType::layout());
+        storage.resize(10);
+        par_attributes_mut!(
+            &attributes::INTENSITY => u16,
+            &attributes::INTENSITY => u16,
+            &mut storage
+        );
+    }
 }
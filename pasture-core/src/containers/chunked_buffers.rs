@@ -0,0 +1,711 @@
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    fs,
+    ops::Range,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use anyhow::{bail, Result};
+
+use crate::layout::{PointAttributeDefinition, PointLayout};
+
+use super::{InterleavedPointView, PointBuffer, PointBufferWriteable};
+
+/// Counter for creating unique scratch directories for all `ChunkedPointStorage`s within this process
+static SCRATCH_DIRECTORY_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// A single chunk of a `ChunkedPointStorage`. If `points` is `None`, the chunk is not resident in memory and its
+/// data is stored in the scratch directory
+struct Chunk {
+    points: Option<Vec<u8>>,
+    modified: bool,
+}
+
+/// The chunks of a `ChunkedPointStorage`, together with the bookkeeping for keeping at most `max_resident_chunks`
+/// chunks in memory
+struct ChunkCache {
+    chunks: Vec<Chunk>,
+    /// Indices of all chunks that are resident in memory, ordered from least recently to most recently used
+    resident_chunks: VecDeque<usize>,
+    max_resident_chunks: usize,
+    scratch_directory: PathBuf,
+}
+
+impl ChunkCache {
+    fn chunk_path(&self, chunk_index: usize) -> PathBuf {
+        self.scratch_directory
+            .join(format!("chunk_{}.bin", chunk_index))
+    }
+
+    /// Loads the chunk with the given index into memory, evicting the least recently used chunk if necessary, and
+    /// marks it as most recently used
+    fn make_resident(&mut self, chunk_index: usize) {
+        if let Some(position) = self
+            .resident_chunks
+            .iter()
+            .position(|resident_index| *resident_index == chunk_index)
+        {
+            self.resident_chunks.remove(position);
+            self.resident_chunks.push_back(chunk_index);
+            return;
+        }
+
+        self.make_room_for_chunk();
+        let path = self.chunk_path(chunk_index);
+        let points = fs::read(&path).unwrap_or_else(|e| {
+            panic!(
+                "ChunkedPointStorage: Could not load chunk from scratch file {}: {}",
+                path.display(),
+                e
+            )
+        });
+        self.chunks[chunk_index].points = Some(points);
+        self.resident_chunks.push_back(chunk_index);
+    }
+
+    /// Evicts the least recently used chunk if the maximum number of resident chunks is reached
+    fn make_room_for_chunk(&mut self) {
+        if self.resident_chunks.len() < self.max_resident_chunks {
+            return;
+        }
+        let chunk_index = self.resident_chunks.pop_front().unwrap();
+        let path = self.chunk_path(chunk_index);
+        let chunk = &mut self.chunks[chunk_index];
+        let points = chunk.points.take().unwrap();
+        // Unmodified chunks are already up to date in the scratch directory, so they can simply be dropped
+        if chunk.modified {
+            fs::write(&path, &points).unwrap_or_else(|e| {
+                panic!(
+                    "ChunkedPointStorage: Could not spill chunk to scratch file {}: {}",
+                    path.display(),
+                    e
+                )
+            });
+            chunk.modified = false;
+        }
+    }
+
+    fn get(&mut self, chunk_index: usize) -> &[u8] {
+        self.make_resident(chunk_index);
+        self.chunks[chunk_index].points.as_ref().unwrap()
+    }
+
+    fn get_mut(&mut self, chunk_index: usize) -> &mut Vec<u8> {
+        self.make_resident(chunk_index);
+        let chunk = &mut self.chunks[chunk_index];
+        chunk.modified = true;
+        chunk.points.as_mut().unwrap()
+    }
+
+    fn push(&mut self, points: Vec<u8>) {
+        self.make_room_for_chunk();
+        self.chunks.push(Chunk {
+            points: Some(points),
+            modified: true,
+        });
+        self.resident_chunks.push_back(self.chunks.len() - 1);
+    }
+
+    /// Removes all chunks starting from `chunk_count`, together with their scratch files
+    fn truncate(&mut self, chunk_count: usize) {
+        for chunk_index in chunk_count..self.chunks.len() {
+            // Chunks that were never spilled have no scratch file, so errors can be ignored here
+            let _ = fs::remove_file(self.chunk_path(chunk_index));
+        }
+        self.resident_chunks
+            .retain(|chunk_index| *chunk_index < chunk_count);
+        self.chunks.truncate(chunk_count);
+    }
+}
+
+/// `PointBuffer` type for point clouds that are too large to fit into memory. The points are stored in Interleaved
+/// memory layout in chunks of a fixed number of points. At most `max_chunks_in_memory` chunks are kept in memory at
+/// any time, all other chunks are spilled to files in a scratch directory and are loaded again once they are accessed.
+/// Chunks are evicted in least-recently-used order.
+///
+/// Paging is hidden behind the `PointBuffer` and `PointBufferWriteable` traits, so all iterators work with a
+/// `ChunkedPointStorage` as well. Iterating sequentially loads every chunk only once. For processing the points
+/// chunk by chunk without copying, use [`ChunkedPointStorage::for_each_chunk`].
+///
+/// ```
+/// # use pasture_core::containers::*;
+/// # use pasture_core::layout::*;
+/// let layout = PointLayout::from_attributes(&[attributes::INTENSITY]);
+/// let mut storage = ChunkedPointStorage::new(layout, 1024, 4, std::env::temp_dir()).unwrap();
+///
+/// storage.resize(10_000);
+/// for index in 0..10_000 {
+///     storage.set_attribute(&attributes::INTENSITY, index, index as u16);
+/// }
+///
+/// // Only 4 of the 10 chunks are in memory at the same time
+/// for (index, intensity) in storage.iter_attribute::<u16>(&attributes::INTENSITY).enumerate() {
+///     assert_eq!(index as u16, intensity);
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Since the `PointBuffer` traits can't report errors, all methods that access point data panic if a chunk can't be
+/// written to or read from the scratch directory
+pub struct ChunkedPointStorage {
+    layout: PointLayout,
+    size_of_point_entry: usize,
+    points_per_chunk: usize,
+    length: usize,
+    cache: RefCell<ChunkCache>,
+}
+
+impl ChunkedPointStorage {
+    /// Creates a new empty `ChunkedPointStorage` with the given `PointLayout`. Points are stored in chunks of
+    /// `points_per_chunk` points, of which at most `max_chunks_in_memory` are kept in memory. Spilled chunks are
+    /// written into a new subdirectory of `scratch_directory`, which is removed when the `ChunkedPointStorage` is
+    /// dropped
+    ///
+    /// # Errors
+    ///
+    /// If `points_per_chunk` or `max_chunks_in_memory` is zero, or if the scratch directory can't be created
+    pub fn new<P: AsRef<Path>>(
+        layout: PointLayout,
+        points_per_chunk: usize,
+        max_chunks_in_memory: usize,
+        scratch_directory: P,
+    ) -> Result<Self> {
+        if points_per_chunk == 0 {
+            bail!("Number of points per chunk must be greater than zero");
+        }
+        if max_chunks_in_memory == 0 {
+            bail!("Maximum number of chunks in memory must be greater than zero");
+        }
+
+        let scratch_directory = scratch_directory.as_ref().join(format!(
+            "pasture-chunks-{}-{}",
+            std::process::id(),
+            SCRATCH_DIRECTORY_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir_all(&scratch_directory)?;
+
+        Ok(Self {
+            size_of_point_entry: layout.size_of_point_entry() as usize,
+            layout,
+            points_per_chunk,
+            length: 0,
+            cache: RefCell::new(ChunkCache {
+                chunks: vec![],
+                resident_chunks: VecDeque::with_capacity(max_chunks_in_memory),
+                max_resident_chunks: max_chunks_in_memory,
+                scratch_directory,
+            }),
+        })
+    }
+
+    /// Returns the number of points per chunk of the associated `ChunkedPointStorage`
+    pub fn points_per_chunk(&self) -> usize {
+        self.points_per_chunk
+    }
+
+    /// Returns the number of chunks of the associated `ChunkedPointStorage`, including the chunks that are not
+    /// resident in memory
+    pub fn chunk_count(&self) -> usize {
+        self.cache.borrow().chunks.len()
+    }
+
+    /// Returns the path of the scratch directory that the chunks of the associated `ChunkedPointStorage` are
+    /// spilled to
+    pub fn scratch_directory(&self) -> PathBuf {
+        self.cache.borrow().scratch_directory.clone()
+    }
+
+    /// Calls `func` with an `InterleavedPointView` of every chunk of the associated `ChunkedPointStorage`, in order.
+    /// Only one chunk is loaded at a time, which makes this the most efficient way to process all points
+    ///
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// let layout = PointLayout::from_attributes(&[attributes::INTENSITY]);
+    /// let mut storage = ChunkedPointStorage::new(layout, 64, 2, std::env::temp_dir()).unwrap();
+    /// storage.resize(1000);
+    ///
+    /// let mut chunk_sizes = vec![];
+    /// storage.for_each_chunk(|chunk| chunk_sizes.push(chunk.len()));
+    /// assert_eq!(16, chunk_sizes.len());
+    /// assert_eq!(1000 - 15 * 64, chunk_sizes[15]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `func` accesses the point data of the associated `ChunkedPointStorage`
+    pub fn for_each_chunk<F: FnMut(InterleavedPointView<'_>)>(&self, mut func: F) {
+        let chunk_count = self.chunk_count();
+        for chunk_index in 0..chunk_count {
+            let mut cache = self.cache.borrow_mut();
+            let chunk = cache.get(chunk_index);
+            func(InterleavedPointView::from_raw_slice(
+                chunk,
+                self.layout.clone(),
+            ));
+        }
+    }
+
+    /// Splits `point_indices` into the parts that fall into the individual chunks. Yields the chunk index, the range
+    /// of points within the chunk and the offset of the first of these points relative to `point_indices.start`
+    fn split_into_chunks(
+        &self,
+        point_indices: Range<usize>,
+    ) -> impl Iterator<Item = (usize, Range<usize>, usize)> {
+        let points_per_chunk = self.points_per_chunk;
+        let first_chunk = point_indices.start / points_per_chunk;
+        let end_chunk = point_indices.end.div_ceil(points_per_chunk);
+        (first_chunk..end_chunk).map(move |chunk_index| {
+            let chunk_start = chunk_index * points_per_chunk;
+            let start = point_indices.start.max(chunk_start);
+            let end = point_indices.end.min(chunk_start + points_per_chunk);
+            (
+                chunk_index,
+                (start - chunk_start)..(end - chunk_start),
+                start - point_indices.start,
+            )
+        })
+    }
+
+    /// Appends `count` points to the end of the associated `ChunkedPointStorage`. The new points are
+    /// default-initialized and then passed to `fill` together with their indices relative to the first new point
+    fn append_with<F: FnMut(Range<usize>, &mut [u8])>(&mut self, count: usize, mut fill: F) {
+        let cache = self.cache.get_mut();
+        let mut appended = 0;
+        while appended < count {
+            let index_in_chunk = self.length % self.points_per_chunk;
+            if index_in_chunk == 0 {
+                cache.push(Vec::with_capacity(
+                    self.points_per_chunk * self.size_of_point_entry,
+                ));
+            }
+            let points_to_append = (self.points_per_chunk - index_in_chunk).min(count - appended);
+            let chunk = cache.get_mut(self.length / self.points_per_chunk);
+            let old_size = chunk.len();
+            chunk.resize(old_size + points_to_append * self.size_of_point_entry, 0);
+            fill(
+                appended..(appended + points_to_append),
+                &mut chunk[old_size..],
+            );
+
+            appended += points_to_append;
+            self.length += points_to_append;
+        }
+    }
+}
+
+impl Drop for ChunkedPointStorage {
+    fn drop(&mut self) {
+        // Cleaning up the scratch directory is best effort, there is no way to report errors from here
+        let _ = fs::remove_dir_all(&self.cache.get_mut().scratch_directory);
+    }
+}
+
+impl PointBuffer for ChunkedPointStorage {
+    fn get_raw_point(&self, point_index: usize, buf: &mut [u8]) {
+        if point_index >= self.len() {
+            panic!(
+                "ChunkedPointStorage::get_raw_point: Point index {} out of bounds!",
+                point_index
+            );
+        }
+
+        let mut cache = self.cache.borrow_mut();
+        let chunk = cache.get(point_index / self.points_per_chunk);
+        let offset_to_point_bytes =
+            (point_index % self.points_per_chunk) * self.size_of_point_entry;
+        buf.copy_from_slice(
+            &chunk[offset_to_point_bytes..offset_to_point_bytes + self.size_of_point_entry],
+        );
+    }
+
+    fn get_raw_attribute(
+        &self,
+        point_index: usize,
+        attribute: &PointAttributeDefinition,
+        buf: &mut [u8],
+    ) {
+        if point_index >= self.len() {
+            panic!(
+                "ChunkedPointStorage::get_raw_attribute: Point index {} out of bounds!",
+                point_index
+            );
+        }
+
+        if let Some(attribute_in_buffer) = self.layout.get_attribute(attribute) {
+            let mut cache = self.cache.borrow_mut();
+            let chunk = cache.get(point_index / self.points_per_chunk);
+            let offset_to_point_bytes =
+                (point_index % self.points_per_chunk) * self.size_of_point_entry;
+            let point_record =
+                &chunk[offset_to_point_bytes..offset_to_point_bytes + self.size_of_point_entry];
+            attribute_in_buffer.read_from_point_record(point_record, buf);
+        } else {
+            panic!("ChunkedPointStorage::get_raw_attribute: Attribute {:?} is not part of this PointBuffer's PointLayout!", attribute);
+        }
+    }
+
+    fn get_raw_points(&self, point_indices: Range<usize>, buf: &mut [u8]) {
+        if point_indices.start > point_indices.end || point_indices.end > self.len() {
+            panic!(
+                "ChunkedPointStorage::get_raw_points: Point indices {:?} out of bounds!",
+                point_indices
+            );
+        }
+
+        let mut cache = self.cache.borrow_mut();
+        for (chunk_index, range_in_chunk, offset_in_range) in self.split_into_chunks(point_indices)
+        {
+            let chunk = cache.get(chunk_index);
+            let source_start = range_in_chunk.start * self.size_of_point_entry;
+            let byte_count = range_in_chunk.len() * self.size_of_point_entry;
+            let target_start = offset_in_range * self.size_of_point_entry;
+            buf[target_start..target_start + byte_count]
+                .copy_from_slice(&chunk[source_start..source_start + byte_count]);
+        }
+    }
+
+    fn get_raw_attribute_range(
+        &self,
+        point_indices: Range<usize>,
+        attribute: &PointAttributeDefinition,
+        buf: &mut [u8],
+    ) {
+        if point_indices.start > point_indices.end || point_indices.end > self.len() {
+            panic!(
+                "ChunkedPointStorage::get_raw_attribute_range: Point indices {:?} out of bounds!",
+                point_indices
+            );
+        }
+
+        if let Some(attribute_in_buffer) = self.layout.get_attribute(attribute) {
+            let attribute_size = attribute.size() as usize;
+            let mut cache = self.cache.borrow_mut();
+            for (chunk_index, range_in_chunk, offset_in_range) in
+                self.split_into_chunks(point_indices)
+            {
+                let chunk = cache.get(chunk_index);
+                let point_records = &chunk[range_in_chunk.start * self.size_of_point_entry
+                    ..range_in_chunk.end * self.size_of_point_entry];
+                let target_start = offset_in_range * attribute_size;
+                let target_slice =
+                    &mut buf[target_start..target_start + range_in_chunk.len() * attribute_size];
+                for (point_record, target_buf_slice) in point_records
+                    .chunks_exact(self.size_of_point_entry)
+                    .zip(target_slice.chunks_exact_mut(attribute_size))
+                {
+                    attribute_in_buffer.read_from_point_record(point_record, target_buf_slice);
+                }
+            }
+        } else {
+            panic!("ChunkedPointStorage::get_raw_attribute_range: Attribute {:?} is not part of this PointBuffer's PointLayout!", attribute);
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn point_layout(&self) -> &PointLayout {
+        &self.layout
+    }
+}
+
+impl PointBufferWriteable for ChunkedPointStorage {
+    fn set_raw_point(&mut self, point_index: usize, buf: &[u8]) {
+        if point_index >= self.len() {
+            panic!("Point index is out of bounds")
+        }
+        if buf.len() != self.size_of_point_entry {
+            panic!("Size of buffer does not match the point size in this buffers PointLayout")
+        }
+
+        let chunk = self
+            .cache
+            .get_mut()
+            .get_mut(point_index / self.points_per_chunk);
+        let offset_to_point_bytes =
+            (point_index % self.points_per_chunk) * self.size_of_point_entry;
+        chunk[offset_to_point_bytes..offset_to_point_bytes + self.size_of_point_entry]
+            .copy_from_slice(buf);
+    }
+
+    fn set_raw_attribute(
+        &mut self,
+        point_index: usize,
+        attribute: &PointAttributeDefinition,
+        buf: &[u8],
+    ) {
+        if point_index >= self.len() {
+            panic!("Point index is out of bounds")
+        }
+        let attribute_member = self
+            .layout
+            .get_attribute(attribute)
+            .expect("Attribute not found in this PointBuffer's PointLayout");
+        if buf.len() != attribute_member.size() as usize {
+            panic!("Size of buffer does not match the size of the point attribute")
+        }
+
+        let chunk = self
+            .cache
+            .get_mut()
+            .get_mut(point_index / self.points_per_chunk);
+        let offset_to_point_bytes =
+            (point_index % self.points_per_chunk) * self.size_of_point_entry;
+        let point_record =
+            &mut chunk[offset_to_point_bytes..offset_to_point_bytes + self.size_of_point_entry];
+        attribute_member.write_to_point_record(buf, point_record);
+    }
+
+    fn push(&mut self, points: &dyn PointBuffer) {
+        if *points.point_layout() != self.layout {
+            panic!("ChunkedPointStorage::push: points layout does not match this PointLayout!");
+        }
+
+        self.append_with(points.len(), |point_indices, chunk_slice| {
+            points.get_raw_points(point_indices, chunk_slice);
+        });
+    }
+
+    fn splice(&mut self, range: Range<usize>, replace_with: &dyn PointBuffer) {
+        if *replace_with.point_layout() != self.layout {
+            panic!(
+                "ChunkedPointStorage::splice: replace_with layout does not match this PointLayout!"
+            );
+        }
+        if range.start > range.end {
+            panic!("Range start is greater than range end");
+        }
+        if range.end > self.len() {
+            panic!("Range is out of bounds");
+        }
+        if replace_with.len() < range.len() {
+            panic!("replace_with buffer contains less points than the range to replace");
+        }
+
+        let chunk_ranges = self.split_into_chunks(range).collect::<Vec<_>>();
+        let cache = self.cache.get_mut();
+        for (chunk_index, range_in_chunk, offset_in_range) in chunk_ranges {
+            let chunk = cache.get_mut(chunk_index);
+            replace_with.get_raw_points(
+                offset_in_range..(offset_in_range + range_in_chunk.len()),
+                &mut chunk[range_in_chunk.start * self.size_of_point_entry
+                    ..range_in_chunk.end * self.size_of_point_entry],
+            );
+        }
+    }
+
+    fn clear(&mut self) {
+        self.cache.get_mut().truncate(0);
+        self.length = 0;
+    }
+
+    fn resize(&mut self, new_points: usize) {
+        if new_points >= self.len() {
+            self.append_with(new_points - self.len(), |_, _| {});
+            return;
+        }
+
+        let chunk_count = new_points.div_ceil(self.points_per_chunk);
+        let cache = self.cache.get_mut();
+        cache.truncate(chunk_count);
+        if chunk_count > 0 {
+            let points_in_last_chunk = new_points - (chunk_count - 1) * self.points_per_chunk;
+            cache
+                .get_mut(chunk_count - 1)
+                .truncate(points_in_last_chunk * self.size_of_point_entry);
+        }
+        self.length = new_points;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::containers::{InterleavedVecPointStorage, PointBufferExt, PointBufferWriteableExt};
+    use crate::layout::{attributes, PointType};
+    use nalgebra::Vector3;
+    use pasture_derive::PointType;
+
+    // We need this, otherwise we can't use the derive(PointType) macro from within pasture_core because the macro
+    // doesn't recognize the name 'pasture_core' :/
+    use crate as pasture_core;
+
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone, PartialEq, PointType)]
+    struct TestPointType(
+        #[pasture(BUILTIN_POSITION_3D)] Vector3<f64>,
+        #[pasture(BUILTIN_INTENSITY)] u16,
+    );
+
+    fn test_points(count: usize) -> InterleavedVecPointStorage {
+        let mut points = InterleavedVecPointStorage::new(TestPointType::layout());
+        for index in 0..count {
+            points.push_point(TestPointType(
+                Vector3::new(index as f64, 1.0, 2.0),
+                index as u16,
+            ));
+        }
+        points
+    }
+
+    fn test_storage(points_per_chunk: usize, max_chunks_in_memory: usize) -> ChunkedPointStorage {
+        ChunkedPointStorage::new(
+            TestPointType::layout(),
+            points_per_chunk,
+            max_chunks_in_memory,
+            std::env::temp_dir(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_chunked_storage_push_and_read() {
+        let points = test_points(50);
+        let mut storage = test_storage(8, 2);
+        storage.push(&points.slice(0..5));
+        storage.push(&points.slice(5..50));
+
+        assert_eq!(50, storage.len());
+        assert_eq!(7, storage.chunk_count());
+        assert_eq!(2, storage.cache.borrow().resident_chunks.len());
+
+        let expected = points.iter_point::<TestPointType>().collect::<Vec<_>>();
+        let actual = storage.iter_point::<TestPointType>().collect::<Vec<_>>();
+        assert_eq!(expected, actual);
+
+        let expected_intensities = points
+            .iter_attribute::<u16>(&attributes::INTENSITY)
+            .collect::<Vec<_>>();
+        let actual_intensities = storage
+            .iter_attribute::<u16>(&attributes::INTENSITY)
+            .collect::<Vec<_>>();
+        assert_eq!(expected_intensities, actual_intensities);
+
+        // Random access in reverse order requires reloading the spilled chunks
+        for index in (0..50).rev() {
+            assert_eq!(
+                expected[index],
+                storage.get_point::<TestPointType>(index),
+                "Point {} does not match",
+                index
+            );
+        }
+
+        let mut chunk_lengths = vec![];
+        storage.for_each_chunk(|chunk| chunk_lengths.push(chunk.len()));
+        assert_eq!(vec![8, 8, 8, 8, 8, 8, 2], chunk_lengths);
+    }
+
+    #[test]
+    fn test_chunked_storage_modify_spilled_chunks() {
+        let points = test_points(30);
+        let mut storage = test_storage(4, 1);
+        storage.push(&points);
+
+        storage.set_point(1, TestPointType(Vector3::new(-1.0, -1.0, -1.0), 42));
+        storage.set_attribute(&attributes::INTENSITY, 29, 1234_u16);
+        // Overwrite points 10 to 20, which span three chunks, with the first ten points
+        storage.splice(10..20, &points.slice(0..10));
+
+        // Modifications have to survive spilling and reloading of the chunks
+        assert_eq!(
+            TestPointType(Vector3::new(-1.0, -1.0, -1.0), 42),
+            storage.get_point::<TestPointType>(1)
+        );
+        assert_eq!(
+            1234,
+            storage.get_attribute::<u16>(&attributes::INTENSITY, 29)
+        );
+        for index in 10..20 {
+            assert_eq!(
+                points.get_point::<TestPointType>(index - 10),
+                storage.get_point::<TestPointType>(index)
+            );
+        }
+        assert_eq!(
+            points.get_point::<TestPointType>(20),
+            storage.get_point::<TestPointType>(20)
+        );
+    }
+
+    #[test]
+    fn test_chunked_storage_resize_and_clear() {
+        let points = test_points(20);
+        let mut storage = test_storage(8, 2);
+        storage.push(&points);
+
+        storage.resize(10);
+        assert_eq!(10, storage.len());
+        assert_eq!(2, storage.chunk_count());
+        assert_eq!(
+            points.get_point::<TestPointType>(9),
+            storage.get_point::<TestPointType>(9)
+        );
+
+        storage.resize(25);
+        assert_eq!(25, storage.len());
+        assert_eq!(4, storage.chunk_count());
+        for index in 10..25 {
+            assert_eq!(
+                TestPointType(Vector3::new(0.0, 0.0, 0.0), 0),
+                storage.get_point::<TestPointType>(index)
+            );
+        }
+
+        storage.clear();
+        assert_eq!(0, storage.len());
+        assert_eq!(0, storage.chunk_count());
+
+        storage.push(&points);
+        assert_eq!(
+            points.iter_point::<TestPointType>().collect::<Vec<_>>(),
+            storage.iter_point::<TestPointType>().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_chunked_storage_remove_points() {
+        let points = test_points(20);
+        let mut storage = test_storage(8, 2);
+        storage.push(&points);
+
+        storage.retain(|point: &TestPointType| point.1 % 2 == 0);
+        assert_eq!(10, storage.len());
+        assert_eq!(2, storage.chunk_count());
+        storage.swap_remove(0);
+        storage.remove_indices(&[1, 2]);
+        assert_eq!(
+            vec![18, 6, 8, 10, 12, 14, 16],
+            storage
+                .iter_attribute::<u16>(&attributes::INTENSITY)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_chunked_storage_removes_scratch_directory() {
+        let scratch_directory = {
+            let mut storage = test_storage(4, 1);
+            storage.push(&test_points(20));
+            let scratch_directory = storage.scratch_directory();
+            assert!(scratch_directory.join("chunk_0.bin").exists());
+            scratch_directory
+        };
+        assert!(!scratch_directory.exists());
+    }
+
+    #[test]
+    fn test_chunked_storage_invalid_parameters() {
+        assert!(
+            ChunkedPointStorage::new(TestPointType::layout(), 0, 1, std::env::temp_dir()).is_err()
+        );
+        assert!(
+            ChunkedPointStorage::new(TestPointType::layout(), 1, 0, std::env::temp_dir()).is_err()
+        );
+    }
+}
@@ -0,0 +1,772 @@
+use std::{borrow::Cow, ops::Range};
+
+use crate::layout::{PointAttributeDefinition, PointLayout};
+
+use super::{PointBuffer, PointBufferWriteable};
+
+/// Checks that all `indices` are valid point indices for a buffer with `buffer_len` points
+fn check_indices(indices: &[usize], buffer_len: usize, type_name: &str) {
+    if let Some(index) = indices.iter().find(|index| **index >= buffer_len) {
+        panic!(
+            "{}::new: Point index {} is out of bounds for buffer with {} points!",
+            type_name, index, buffer_len
+        );
+    }
+}
+
+/// Converts a bitmask with one entry per point into the indices of all selected points
+fn indices_from_mask(mask: &[bool], buffer_len: usize, type_name: &str) -> Vec<usize> {
+    if mask.len() != buffer_len {
+        panic!(
+            "{}::from_mask: Length of mask ({}) does not match the number of points in the buffer ({})!",
+            type_name,
+            mask.len(),
+            buffer_len
+        );
+    }
+    mask.iter()
+        .enumerate()
+        .filter(|(_, selected)| **selected)
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// A selection of points within a buffer, either as a list of point indices or as a bitmask
+trait PointSelection {
+    /// Returns the number of selected points
+    fn selected_count(&self) -> usize;
+
+    /// Returns the index within the underlying buffer of the `selected_index`-th selected point
+    fn point_index(&self, selected_index: usize) -> usize;
+
+    /// Calls `func` for every run of consecutive point indices among the selected points in `index_range`. `func` receives
+    /// the range of point indices in the underlying buffer and the offset of the first point of the run relative to
+    /// `index_range.start`. This way, selections that contain contiguous ranges of points can be read using the
+    /// range-based methods of `PointBuffer`
+    fn for_each_contiguous_run<F: FnMut(Range<usize>, usize)>(
+        &self,
+        index_range: Range<usize>,
+        func: F,
+    );
+}
+
+impl PointSelection for [usize] {
+    fn selected_count(&self) -> usize {
+        self.len()
+    }
+
+    fn point_index(&self, selected_index: usize) -> usize {
+        self[selected_index]
+    }
+
+    fn for_each_contiguous_run<F: FnMut(Range<usize>, usize)>(
+        &self,
+        index_range: Range<usize>,
+        mut func: F,
+    ) {
+        let selected_indices = &self[index_range];
+        let mut run_start = 0;
+        while run_start < selected_indices.len() {
+            let first_index = selected_indices[run_start];
+            let run_length = selected_indices[run_start..]
+                .iter()
+                .enumerate()
+                .take_while(|(offset, index)| **index == first_index + offset)
+                .count();
+            func(first_index..(first_index + run_length), run_start);
+            run_start += run_length;
+        }
+    }
+}
+
+/// Number of mask entries per block of a `MaskSelection`
+const MASK_BLOCK_SIZE: usize = 512;
+
+/// Selection of points through a bitmask with one entry per point. To find the `i`-th selected point without scanning the
+/// whole mask, the number of selected points in front of each block of `MASK_BLOCK_SIZE` mask entries is stored, which
+/// takes one `usize` per block instead of one per selected point
+struct MaskSelection<'p> {
+    mask: &'p [bool],
+    selected_before_block: Vec<usize>,
+    selected_count: usize,
+}
+
+impl<'p> MaskSelection<'p> {
+    fn new(mask: &'p [bool], buffer_len: usize, type_name: &str) -> Self {
+        if mask.len() != buffer_len {
+            panic!(
+                "{}::new: Length of mask ({}) does not match the number of points in the buffer ({})!",
+                type_name,
+                mask.len(),
+                buffer_len
+            );
+        }
+        let mut selected_before_block = Vec::with_capacity(mask.len() / MASK_BLOCK_SIZE + 1);
+        let mut selected_count = 0;
+        for block in mask.chunks(MASK_BLOCK_SIZE) {
+            selected_before_block.push(selected_count);
+            selected_count += block.iter().filter(|selected| **selected).count();
+        }
+        Self {
+            mask,
+            selected_before_block,
+            selected_count,
+        }
+    }
+}
+
+impl PointSelection for MaskSelection<'_> {
+    fn selected_count(&self) -> usize {
+        self.selected_count
+    }
+
+    fn point_index(&self, selected_index: usize) -> usize {
+        if selected_index >= self.selected_count {
+            panic!(
+                "Index {} is out of bounds for a selection of {} points",
+                selected_index, self.selected_count
+            );
+        }
+        // The last block with at most `selected_index` selected points in front of it contains the point
+        let block = self
+            .selected_before_block
+            .partition_point(|selected_before| *selected_before <= selected_index)
+            - 1;
+        let block_start = block * MASK_BLOCK_SIZE;
+        let offset_in_block = self.mask[block_start..]
+            .iter()
+            .enumerate()
+            .filter(|(_, selected)| **selected)
+            .nth(selected_index - self.selected_before_block[block])
+            .map(|(offset, _)| offset)
+            .expect("The selected point is within the block or one of the following blocks");
+        block_start + offset_in_block
+    }
+
+    fn for_each_contiguous_run<F: FnMut(Range<usize>, usize)>(
+        &self,
+        index_range: Range<usize>,
+        mut func: F,
+    ) {
+        if index_range.end > self.selected_count {
+            panic!(
+                "Range {:?} is out of bounds for a selection of {} points",
+                index_range, self.selected_count
+            );
+        }
+        if index_range.is_empty() {
+            return;
+        }
+        let mut point_index = self.point_index(index_range.start);
+        let mut offset = 0;
+        while offset < index_range.len() {
+            let run_length = self.mask[point_index..]
+                .iter()
+                .take(index_range.len() - offset)
+                .take_while(|selected| **selected)
+                .count();
+            func(point_index..(point_index + run_length), offset);
+            offset += run_length;
+            point_index += run_length;
+            point_index += self.mask[point_index..]
+                .iter()
+                .take_while(|selected| !**selected)
+                .count();
+        }
+    }
+}
+
+macro_rules! impl_point_buffer_for_indexed_view {
+    ($view:ident, $selection:ident) => {
+        impl<'p> PointBuffer for $view<'p> {
+            fn get_raw_point(&self, point_index: usize, buf: &mut [u8]) {
+                self.buffer
+                    .get_raw_point(self.$selection.point_index(point_index), buf);
+            }
+
+            fn get_raw_attribute(
+                &self,
+                point_index: usize,
+                attribute: &PointAttributeDefinition,
+                buf: &mut [u8],
+            ) {
+                self.buffer.get_raw_attribute(
+                    self.$selection.point_index(point_index),
+                    attribute,
+                    buf,
+                );
+            }
+
+            fn get_raw_points(&self, index_range: Range<usize>, buf: &mut [u8]) {
+                let size_of_point_entry = self.buffer.point_layout().size_of_point_entry() as usize;
+                self.$selection
+                    .for_each_contiguous_run(index_range, |range_in_buffer, offset| {
+                        let start = offset * size_of_point_entry;
+                        let end = start + range_in_buffer.len() * size_of_point_entry;
+                        self.buffer
+                            .get_raw_points(range_in_buffer, &mut buf[start..end]);
+                    });
+            }
+
+            fn get_raw_attribute_range(
+                &self,
+                index_range: Range<usize>,
+                attribute: &PointAttributeDefinition,
+                buf: &mut [u8],
+            ) {
+                let attribute_size = attribute.size() as usize;
+                self.$selection
+                    .for_each_contiguous_run(index_range, |range_in_buffer, offset| {
+                        let start = offset * attribute_size;
+                        let end = start + range_in_buffer.len() * attribute_size;
+                        self.buffer.get_raw_attribute_range(
+                            range_in_buffer,
+                            attribute,
+                            &mut buf[start..end],
+                        );
+                    });
+            }
+
+            fn len(&self) -> usize {
+                self.$selection.selected_count()
+            }
+
+            fn point_layout(&self) -> &PointLayout {
+                self.buffer.point_layout()
+            }
+        }
+    };
+}
+
+/// Non-owning, read-only view of a selection of points within any `PointBuffer`. The selection is given as a list of point
+/// indices, as returned by many algorithms and filters. The `i`-th point of the `IndexedPointView` is the point at
+/// `indices[i]` in the underlying buffer. Since `IndexedPointView` implements `PointBuffer`, a selection can be passed to
+/// writers and algorithms without copying the selected points first
+///
+/// ```
+/// # use pasture_core::containers::*;
+/// # use pasture_core::layout::*;
+/// let mut buffer = InterleavedVecPointStorage::new(PointLayout::from_attributes(&[attributes::INTENSITY]));
+/// buffer.resize(10);
+/// for index in 0..10 {
+///     buffer.set_attribute(&attributes::INTENSITY, index, (index * 10) as u16);
+/// }
+///
+/// let indices = vec![1, 2, 7];
+/// let view = IndexedPointView::new(&buffer, &indices);
+/// let intensities = view.iter_attribute::<u16>(&attributes::INTENSITY).collect::<Vec<_>>();
+/// assert_eq!(vec![10, 20, 70], intensities);
+/// ```
+pub struct IndexedPointView<'p> {
+    buffer: &'p dyn PointBuffer,
+    indices: Cow<'p, [usize]>,
+}
+
+impl<'p> IndexedPointView<'p> {
+    /// Creates a new `IndexedPointView` over the points at the given `indices` within `buffer`
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `indices` is out of bounds for `buffer`
+    pub fn new(buffer: &'p dyn PointBuffer, indices: &'p [usize]) -> Self {
+        check_indices(indices, buffer.len(), "IndexedPointView");
+        Self {
+            buffer,
+            indices: Cow::Borrowed(indices),
+        }
+    }
+
+    /// Creates a new `IndexedPointView` over all points within `buffer` for which the corresponding entry in `mask`
+    /// is `true`. The view does not keep the mask. Instead, the mask is converted into the indices of the selected points
+    /// when the view is created, so the view stores one `usize` per selected point. Use [`MaskedPointView`] to access
+    /// the selected points through the mask itself
+    ///
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// let mut buffer = InterleavedVecPointStorage::new(PointLayout::from_attributes(&[attributes::INTENSITY]));
+    /// buffer.resize(4);
+    ///
+    /// let view = IndexedPointView::from_mask(&buffer, &[true, false, false, true]);
+    /// assert_eq!(&[0, 3], view.indices());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `mask` does not match the length of `buffer`
+    pub fn from_mask(buffer: &'p dyn PointBuffer, mask: &[bool]) -> Self {
+        Self {
+            indices: Cow::Owned(indices_from_mask(mask, buffer.len(), "IndexedPointView")),
+            buffer,
+        }
+    }
+
+    /// Returns the indices of the selected points within the underlying buffer
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+}
+
+impl_point_buffer_for_indexed_view!(IndexedPointView, indices);
+
+/// Like [`IndexedPointView`], but allows modifying the selected points through the `PointBufferWriteable` trait. Since the
+/// selection can't grow or shrink, only `set_raw_point`, `set_raw_attribute` and `splice` are supported
+///
+/// ```
+/// # use pasture_core::containers::*;
+/// # use pasture_core::layout::*;
+/// let mut buffer = InterleavedVecPointStorage::new(PointLayout::from_attributes(&[attributes::INTENSITY]));
+/// buffer.resize(10);
+///
+/// let indices = vec![1, 2, 7];
+/// {
+///     let mut view = IndexedPointViewMut::new(&mut buffer, &indices);
+///     for index in 0..view.len() {
+///         view.set_attribute(&attributes::INTENSITY, index, 42_u16);
+///     }
+/// }
+/// assert_eq!(42, buffer.get_attribute::<u16>(&attributes::INTENSITY, 7));
+/// assert_eq!(0, buffer.get_attribute::<u16>(&attributes::INTENSITY, 8));
+/// ```
+pub struct IndexedPointViewMut<'p> {
+    buffer: &'p mut dyn PointBufferWriteable,
+    indices: Cow<'p, [usize]>,
+}
+
+impl<'p> IndexedPointViewMut<'p> {
+    /// Creates a new `IndexedPointViewMut` over the points at the given `indices` within `buffer`
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `indices` is out of bounds for `buffer`
+    pub fn new(buffer: &'p mut dyn PointBufferWriteable, indices: &'p [usize]) -> Self {
+        check_indices(indices, buffer.len(), "IndexedPointViewMut");
+        Self {
+            buffer,
+            indices: Cow::Borrowed(indices),
+        }
+    }
+
+    /// Creates a new `IndexedPointViewMut` over all points within `buffer` for which the corresponding entry in `mask`
+    /// is `true`. Like [`IndexedPointView::from_mask`], this converts the mask into the indices of the selected points.
+    /// Use [`MaskedPointViewMut`] to access the selected points through the mask itself
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `mask` does not match the length of `buffer`
+    pub fn from_mask(buffer: &'p mut dyn PointBufferWriteable, mask: &[bool]) -> Self {
+        Self {
+            indices: Cow::Owned(indices_from_mask(mask, buffer.len(), "IndexedPointViewMut")),
+            buffer,
+        }
+    }
+
+    /// Returns the indices of the selected points within the underlying buffer
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+}
+
+impl_point_buffer_for_indexed_view!(IndexedPointViewMut, indices);
+
+macro_rules! impl_point_buffer_writeable_for_indexed_view {
+    ($view:ident, $selection:ident) => {
+        impl<'p> PointBufferWriteable for $view<'p> {
+            fn set_raw_point(&mut self, point_index: usize, buf: &[u8]) {
+                self.buffer
+                    .set_raw_point(self.$selection.point_index(point_index), buf);
+            }
+
+            fn set_raw_attribute(
+                &mut self,
+                point_index: usize,
+                attribute: &PointAttributeDefinition,
+                buf: &[u8],
+            ) {
+                self.buffer.set_raw_attribute(
+                    self.$selection.point_index(point_index),
+                    attribute,
+                    buf,
+                );
+            }
+
+            /// Not supported, since a view of selected points can't change the number of points
+            ///
+            /// # Panics
+            ///
+            /// Always panics
+            fn push(&mut self, _points: &dyn PointBuffer) {
+                panic!(concat!(
+                    stringify!($view),
+                    "::push: Can't push points into a view of selected points"
+                ));
+            }
+
+            fn splice(&mut self, range: Range<usize>, replace_with: &dyn PointBuffer) {
+                if range.start > range.end {
+                    panic!("Range start is greater than range end");
+                }
+                if range.end > self.len() {
+                    panic!("Range is out of bounds");
+                }
+                if replace_with.len() < range.len() {
+                    panic!("replace_with buffer contains less points than the range to replace");
+                }
+                if replace_with.point_layout() != self.point_layout() {
+                    panic!(concat!(
+                        stringify!($view),
+                        "::splice: replace_with layout does not match this PointLayout!"
+                    ));
+                }
+
+                let mut point_buffer = vec![0; self.point_layout().size_of_point_entry() as usize];
+                for (offset, point_index) in range.enumerate() {
+                    replace_with.get_raw_point(offset, &mut point_buffer);
+                    self.buffer
+                        .set_raw_point(self.$selection.point_index(point_index), &point_buffer);
+                }
+            }
+
+            /// Not supported, since a view of selected points can't change the number of points
+            ///
+            /// # Panics
+            ///
+            /// Always panics
+            fn clear(&mut self) {
+                panic!(concat!(
+                    stringify!($view),
+                    "::clear: Can't clear a view of selected points"
+                ));
+            }
+
+            /// Not supported, since a view of selected points can't change the number of points
+            ///
+            /// # Panics
+            ///
+            /// Always panics
+            fn resize(&mut self, _new_points: usize) {
+                panic!(concat!(
+                    stringify!($view),
+                    "::resize: Can't resize a view of selected points"
+                ));
+            }
+
+            /// Not supported, since a view of selected points can't change the number of points
+            ///
+            /// # Panics
+            ///
+            /// Always panics
+            fn retain_by_mask(&mut self, _keep: &[bool]) {
+                panic!(concat!(
+                    stringify!($view),
+                    "::retain_by_mask: Can't remove points from a view of selected points"
+                ));
+            }
+
+            /// Not supported, since a view of selected points can't change the number of points
+            ///
+            /// # Panics
+            ///
+            /// Always panics
+            fn swap_remove(&mut self, _point_index: usize) {
+                panic!(concat!(
+                    stringify!($view),
+                    "::swap_remove: Can't remove points from a view of selected points"
+                ));
+            }
+        }
+    };
+}
+
+impl_point_buffer_writeable_for_indexed_view!(IndexedPointViewMut, indices);
+
+/// Non-owning, read-only view of a selection of points within any `PointBuffer`, where the selection is given as a bitmask
+/// with one entry per point of the underlying buffer. The `i`-th point of the `MaskedPointView` is the `i`-th point for
+/// which the mask is `true`. In contrast to [`IndexedPointView::from_mask`], the view keeps the mask instead of converting
+/// it into point indices, so it only needs a few bytes of additional memory per 512 points of the underlying buffer.
+/// Accessing a single point by its index requires scanning up to 512 entries of the mask, iterating over consecutive
+/// points does not
+///
+/// ```
+/// # use pasture_core::containers::*;
+/// # use pasture_core::layout::*;
+/// let mut buffer = InterleavedVecPointStorage::new(PointLayout::from_attributes(&[attributes::INTENSITY]));
+/// buffer.resize(4);
+/// for index in 0..4 {
+///     buffer.set_attribute(&attributes::INTENSITY, index, (index * 10) as u16);
+/// }
+///
+/// let mask = vec![true, false, false, true];
+/// let view = MaskedPointView::new(&buffer, &mask);
+/// let intensities = view.iter_attribute::<u16>(&attributes::INTENSITY).collect::<Vec<_>>();
+/// assert_eq!(vec![0, 30], intensities);
+/// ```
+pub struct MaskedPointView<'p> {
+    buffer: &'p dyn PointBuffer,
+    selection: MaskSelection<'p>,
+}
+
+impl<'p> MaskedPointView<'p> {
+    /// Creates a new `MaskedPointView` over all points within `buffer` for which the corresponding entry in `mask` is `true`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `mask` does not match the length of `buffer`
+    pub fn new(buffer: &'p dyn PointBuffer, mask: &'p [bool]) -> Self {
+        Self {
+            selection: MaskSelection::new(mask, buffer.len(), "MaskedPointView"),
+            buffer,
+        }
+    }
+
+    /// Returns the mask that selects the points of the underlying buffer
+    pub fn mask(&self) -> &[bool] {
+        self.selection.mask
+    }
+}
+
+impl_point_buffer_for_indexed_view!(MaskedPointView, selection);
+
+/// Like [`MaskedPointView`], but allows modifying the selected points through the `PointBufferWriteable` trait. Since the
+/// selection can't grow or shrink, only `set_raw_point`, `set_raw_attribute` and `splice` are supported
+///
+/// ```
+/// # use pasture_core::containers::*;
+/// # use pasture_core::layout::*;
+/// let mut buffer = InterleavedVecPointStorage::new(PointLayout::from_attributes(&[attributes::INTENSITY]));
+/// buffer.resize(4);
+///
+/// let mask = vec![false, true, false, true];
+/// {
+///     let mut view = MaskedPointViewMut::new(&mut buffer, &mask);
+///     for index in 0..view.len() {
+///         view.set_attribute(&attributes::INTENSITY, index, 42_u16);
+///     }
+/// }
+/// assert_eq!(vec![0, 42, 0, 42], buffer.iter_attribute::<u16>(&attributes::INTENSITY).collect::<Vec<_>>());
+/// ```
+pub struct MaskedPointViewMut<'p> {
+    buffer: &'p mut dyn PointBufferWriteable,
+    selection: MaskSelection<'p>,
+}
+
+impl<'p> MaskedPointViewMut<'p> {
+    /// Creates a new `MaskedPointViewMut` over all points within `buffer` for which the corresponding entry in `mask` is
+    /// `true`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `mask` does not match the length of `buffer`
+    pub fn new(buffer: &'p mut dyn PointBufferWriteable, mask: &'p [bool]) -> Self {
+        Self {
+            selection: MaskSelection::new(mask, buffer.len(), "MaskedPointViewMut"),
+            buffer,
+        }
+    }
+
+    /// Returns the mask that selects the points of the underlying buffer
+    pub fn mask(&self) -> &[bool] {
+        self.selection.mask
+    }
+}
+
+impl_point_buffer_for_indexed_view!(MaskedPointViewMut, selection);
+impl_point_buffer_writeable_for_indexed_view!(MaskedPointViewMut, selection);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::containers::{
+        InterleavedVecPointStorage, PerAttributeVecPointStorage, PointBufferExt,
+        PointBufferWriteableExt,
+    };
+    use crate::layout::{attributes, PointType};
+    use nalgebra::Vector3;
+    use pasture_derive::PointType;
+
+    // We need this, otherwise we can't use the derive(PointType) macro from within pasture_core because the macro
+    // doesn't recognize the name 'pasture_core' :/
+    use crate as pasture_core;
+
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone, PartialEq, PointType)]
+    struct TestPointType(
+        #[pasture(BUILTIN_POSITION_3D)] Vector3<f64>,
+        #[pasture(BUILTIN_INTENSITY)] u16,
+    );
+
+    fn test_point(index: usize) -> TestPointType {
+        TestPointType(Vector3::new(index as f64, 1.0, 2.0), index as u16)
+    }
+
+    fn test_points(count: usize) -> PerAttributeVecPointStorage {
+        let mut points = PerAttributeVecPointStorage::new(TestPointType::layout());
+        for index in 0..count {
+            points.push_point(test_point(index));
+        }
+        points
+    }
+
+    #[test]
+    fn test_indexed_point_view() {
+        let points = test_points(10);
+        let indices = vec![9, 2, 3, 4, 0, 1];
+        let view = IndexedPointView::new(&points, &indices);
+
+        assert_eq!(6, view.len());
+        let expected = indices
+            .iter()
+            .map(|index| test_point(*index))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            expected,
+            view.iter_point::<TestPointType>().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            expected.iter().map(|point| point.1).collect::<Vec<_>>(),
+            view.iter_attribute::<u16>(&attributes::INTENSITY)
+                .collect::<Vec<_>>()
+        );
+
+        // Copying the selection into another buffer has to work for every kind of buffer
+        let mut copy = InterleavedVecPointStorage::new(TestPointType::layout());
+        copy.push(&view);
+        assert_eq!(
+            expected,
+            copy.iter_point::<TestPointType>().collect::<Vec<_>>()
+        );
+
+        let mut per_attribute_copy = PerAttributeVecPointStorage::new(TestPointType::layout());
+        per_attribute_copy.push(&view);
+        assert_eq!(
+            expected,
+            per_attribute_copy
+                .iter_point::<TestPointType>()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_indexed_point_view_from_mask() {
+        let points = test_points(5);
+        let view = IndexedPointView::from_mask(&points, &[false, true, true, false, true]);
+
+        assert_eq!(&[1, 2, 4], view.indices());
+        assert_eq!(
+            vec![test_point(1), test_point(2), test_point(4)],
+            view.iter_point::<TestPointType>().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_indexed_point_view_mut() {
+        let mut points = test_points(10);
+        let replacement = test_points(2);
+        {
+            let mut view = IndexedPointViewMut::from_mask(
+                &mut points,
+                &[
+                    true, false, false, false, false, true, false, false, true, false,
+                ],
+            );
+            view.set_attribute(&attributes::INTENSITY, 0, 42_u16);
+            view.splice(1..3, &replacement);
+        }
+
+        assert_eq!(42, points.get_attribute::<u16>(&attributes::INTENSITY, 0));
+        assert_eq!(test_point(0), points.get_point::<TestPointType>(5));
+        assert_eq!(test_point(1), points.get_point::<TestPointType>(8));
+        assert_eq!(test_point(1), points.get_point::<TestPointType>(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_indexed_point_view_with_invalid_index() {
+        let points = test_points(10);
+        let indices = vec![0, 10];
+        IndexedPointView::new(&points, &indices);
+    }
+
+    #[test]
+    fn test_masked_point_view() {
+        // Spans multiple blocks of the mask, including a block without any selected points
+        let mask = (0..2000)
+            .map(|index| index % 3 != 0 && !(600..1200).contains(&index))
+            .collect::<Vec<_>>();
+        let points = test_points(mask.len());
+        let masked_view = MaskedPointView::new(&points, &mask);
+        let indexed_view = IndexedPointView::from_mask(&points, &mask);
+
+        assert_eq!(&mask[..], masked_view.mask());
+        assert_eq!(indexed_view.len(), masked_view.len());
+        assert_eq!(
+            indexed_view
+                .iter_point::<TestPointType>()
+                .collect::<Vec<_>>(),
+            masked_view
+                .iter_point::<TestPointType>()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            indexed_view
+                .iter_attribute::<u16>(&attributes::INTENSITY)
+                .collect::<Vec<_>>(),
+            masked_view
+                .iter_attribute::<u16>(&attributes::INTENSITY)
+                .collect::<Vec<_>>()
+        );
+
+        let size_of_point_entry = points.point_layout().size_of_point_entry() as usize;
+        for range in [0..0, 0..1, 3..17, 390..410, 399..400, 0..masked_view.len()] {
+            let mut expected = vec![0; range.len() * size_of_point_entry];
+            indexed_view.get_raw_points(range.clone(), &mut expected);
+            let mut actual = vec![0; range.len() * size_of_point_entry];
+            masked_view.get_raw_points(range, &mut actual);
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn test_masked_point_view_mut() {
+        let mut points = test_points(10);
+        let replacement = test_points(2);
+        let mask = [
+            true, false, false, false, false, true, false, false, true, false,
+        ];
+        {
+            let mut view = MaskedPointViewMut::new(&mut points, &mask);
+            assert_eq!(3, view.len());
+            view.set_attribute(&attributes::INTENSITY, 0, 42_u16);
+            view.splice(1..3, &replacement);
+        }
+
+        assert_eq!(42, points.get_attribute::<u16>(&attributes::INTENSITY, 0));
+        assert_eq!(test_point(0), points.get_point::<TestPointType>(5));
+        assert_eq!(test_point(1), points.get_point::<TestPointType>(8));
+        assert_eq!(test_point(1), points.get_point::<TestPointType>(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_masked_point_view_with_invalid_mask() {
+        let points = test_points(10);
+        MaskedPointView::new(&points, &[true; 9]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_masked_point_view_out_of_bounds() {
+        let points = test_points(10);
+        let mask = [
+            false, true, false, false, false, false, false, false, false, true,
+        ];
+        MaskedPointView::new(&points, &mask).get_point::<TestPointType>(2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_indexed_point_view_with_invalid_mask() {
+        let points = test_points(10);
+        IndexedPointView::from_mask(&points, &[true; 9]);
+    }
+}
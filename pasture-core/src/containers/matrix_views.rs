@@ -0,0 +1,158 @@
+use nalgebra::{Dynamic, MatrixSliceMN, MatrixSliceMutMN, Scalar};
+
+use crate::layout::{PointAttributeDataType, PointAttributeDefinition, PrimitiveType};
+
+/// Read-only `nalgebra` matrix view of an attribute inside a point buffer. The view has one row per point and one column
+/// per component of the attribute, so a view of `POSITION_3D` is a Nx3 matrix. Both strides are dynamic, which allows
+/// views of Interleaved as well as PerAttribute memory without copying
+pub type AttributeMatrixView<'a, T> = MatrixSliceMN<'a, T, Dynamic, Dynamic, Dynamic, Dynamic>;
+
+/// Mutable version of [AttributeMatrixView]
+pub type AttributeMatrixViewMut<'a, T> =
+    MatrixSliceMutMN<'a, T, Dynamic, Dynamic, Dynamic, Dynamic>;
+
+/// Returns the number of components of type `T` that make up a single value of `attribute`
+fn components_of_attribute<T: PrimitiveType>(attribute: &PointAttributeDefinition) -> usize {
+    let datatype = attribute.datatype();
+    let is_scalar_type = T::data_type()
+        .scalar_type()
+        .map(PointAttributeDataType::from)
+        == Some(T::data_type());
+    if !is_scalar_type || datatype.scalar_type() != T::data_type().scalar_type() {
+        panic!(
+            "Matrix views of attribute {} require its scalar type as type T (got {})",
+            attribute,
+            T::data_type()
+        );
+    }
+    (datatype.size() / T::data_type().size()) as usize
+}
+
+/// Checks that `data` can be viewed as values of type `T` with the given stride, and returns the number of whole `T`
+/// values that `data` contains
+fn check_matrix_view_memory<T: PrimitiveType>(data: *const u8, len: usize, stride: usize) -> usize {
+    let size_of_value = std::mem::size_of::<T>();
+    let num_values = len / size_of_value;
+    if num_values > 0 && data.align_offset(std::mem::align_of::<T>()) != 0 {
+        panic!("Matrix views require memory that is aligned to the scalar type of the attribute");
+    }
+    if !stride.is_multiple_of(size_of_value) {
+        panic!(
+            "Matrix views require a point stride ({} bytes) that is a multiple of the size of the scalar type",
+            stride
+        );
+    }
+    num_values
+}
+
+/// Creates an [AttributeMatrixView] for `num_points` values of `attribute` within `data`. The first value starts at the
+/// beginning of `data` and consecutive values are `stride` bytes apart
+///
+/// # Panics
+///
+/// If `T` is not the scalar type of `attribute`, if `data` is not aligned to `T` or if `stride` is not a multiple of the
+/// size of `T`
+pub(crate) fn attribute_matrix_view<'a, T: PrimitiveType + Scalar>(
+    data: &'a [u8],
+    num_points: usize,
+    stride: usize,
+    attribute: &PointAttributeDefinition,
+) -> AttributeMatrixView<'a, T> {
+    let components = components_of_attribute::<T>(attribute);
+    let num_values = check_matrix_view_memory::<T>(data.as_ptr(), data.len(), stride);
+    // Empty memory might not be aligned, so it is not reinterpreted
+    let values: &[T] = if num_values == 0 {
+        &[]
+    } else {
+        unsafe { std::slice::from_raw_parts(data.as_ptr() as *const T, num_values) }
+    };
+    AttributeMatrixView::from_slice_with_strides_generic(
+        values,
+        Dynamic::new(num_points),
+        Dynamic::new(components),
+        Dynamic::new(stride / std::mem::size_of::<T>()),
+        Dynamic::new(1),
+    )
+}
+
+/// Mutable version of [attribute_matrix_view]
+pub(crate) fn attribute_matrix_view_mut<'a, T: PrimitiveType + Scalar>(
+    data: &'a mut [u8],
+    num_points: usize,
+    stride: usize,
+    attribute: &PointAttributeDefinition,
+) -> AttributeMatrixViewMut<'a, T> {
+    let components = components_of_attribute::<T>(attribute);
+    let num_values = check_matrix_view_memory::<T>(data.as_ptr(), data.len(), stride);
+    let values: &mut [T] = if num_values == 0 {
+        &mut []
+    } else {
+        unsafe { std::slice::from_raw_parts_mut(data.as_mut_ptr() as *mut T, num_values) }
+    };
+    AttributeMatrixViewMut::from_slice_with_strides_generic(
+        values,
+        Dynamic::new(num_points),
+        Dynamic::new(components),
+        Dynamic::new(stride / std::mem::size_of::<T>()),
+        Dynamic::new(1),
+    )
+}
+
+/// Returns the shape and strides (in values of type `T`) of an ndarray view of `num_points` values of `attribute`, after
+/// checking that the view fits into the `num_values` values of type `T` of the underlying memory
+#[cfg(feature = "ndarray")]
+fn ndarray_view_shape<T: PrimitiveType>(
+    num_values: usize,
+    num_points: usize,
+    stride: usize,
+    attribute: &PointAttributeDefinition,
+) -> ndarray::StrideShape<ndarray::Ix2> {
+    use ndarray::ShapeBuilder;
+
+    let components = components_of_attribute::<T>(attribute);
+    let stride = stride / std::mem::size_of::<T>();
+    if num_points > 0 && components > 0 && (num_points - 1) * stride + components > num_values {
+        panic!(
+            "Memory of attribute {} is too small for a view of {} points",
+            attribute, num_points
+        );
+    }
+    (num_points, components).strides((stride, 1))
+}
+
+/// Creates an `ndarray` view for `num_points` values of `attribute` within `data`, like [attribute_matrix_view]
+#[cfg(feature = "ndarray")]
+pub(crate) fn attribute_ndarray_view<'a, T: PrimitiveType>(
+    data: &'a [u8],
+    num_points: usize,
+    stride: usize,
+    attribute: &PointAttributeDefinition,
+) -> ndarray::ArrayView2<'a, T> {
+    let num_values = check_matrix_view_memory::<T>(data.as_ptr(), data.len(), stride);
+    let shape = ndarray_view_shape::<T>(num_values, num_points, stride, attribute);
+    // Empty memory might not be aligned, but ndarray requires an aligned pointer even for empty views
+    let ptr = if num_values == 0 {
+        std::ptr::NonNull::<T>::dangling().as_ptr() as *const T
+    } else {
+        data.as_ptr() as *const T
+    };
+    unsafe { ndarray::ArrayView2::from_shape_ptr(shape, ptr) }
+}
+
+/// Mutable version of [attribute_ndarray_view]
+#[cfg(feature = "ndarray")]
+pub(crate) fn attribute_ndarray_view_mut<'a, T: PrimitiveType>(
+    data: &'a mut [u8],
+    num_points: usize,
+    stride: usize,
+    attribute: &PointAttributeDefinition,
+) -> ndarray::ArrayViewMut2<'a, T> {
+    let num_values = check_matrix_view_memory::<T>(data.as_ptr(), data.len(), stride);
+    let shape = ndarray_view_shape::<T>(num_values, num_points, stride, attribute);
+    let ptr = if num_values == 0 {
+        std::ptr::NonNull::<T>::dangling().as_ptr()
+    } else {
+        data.as_mut_ptr() as *mut T
+    };
+    unsafe { ndarray::ArrayViewMut2::from_shape_ptr(shape, ptr) }
+}
@@ -0,0 +1,535 @@
+use std::{fs::File, fs::OpenOptions, ops::Range, path::Path};
+
+use anyhow::{bail, Context, Result};
+use memmap2::{Mmap, MmapMut, MmapOptions};
+
+use crate::layout::{PointAttributeDefinition, PointLayout};
+
+use super::{InterleavedPointBuffer, InterleavedPointBufferMut, PointBuffer};
+
+/// Checks that `point_count` point records with the given `point_layout` and `size_of_point_record` fit into `file` starting at
+/// `offset_to_first_point`. Returns the `PointLayout` for the point records, which contains padding if the point records are
+/// larger than the `point_layout`
+fn check_file_region(
+    file: &File,
+    point_layout: &PointLayout,
+    offset_to_first_point: u64,
+    point_count: usize,
+    size_of_point_record: usize,
+) -> Result<PointLayout> {
+    let alignment = point_layout.alignment_of_point_entry() as usize;
+    if (size_of_point_record as u64) < point_layout.size_of_point_entry()
+        || !size_of_point_record.is_multiple_of(alignment)
+    {
+        bail!(
+            "Size of point record ({}) is smaller than the size of a point entry ({}) or no multiple of its alignment ({})",
+            size_of_point_record,
+            point_layout.size_of_point_entry(),
+            alignment
+        );
+    }
+
+    let file_size = file.metadata()?.len();
+    // The values might come from the header of a file, so they have to be checked for overflow
+    let region_end = point_count
+        .checked_mul(size_of_point_record)
+        .and_then(|region_size| offset_to_first_point.checked_add(region_size as u64))
+        .with_context(|| {
+            format!(
+                "{} point records of size {} starting at offset {} exceed the range of addressable bytes",
+                point_count, size_of_point_record, offset_to_first_point
+            )
+        })?;
+    if region_end > file_size {
+        bail!(
+            "{} point records of size {} starting at offset {} exceed the file size ({} bytes)",
+            point_count,
+            size_of_point_record,
+            offset_to_first_point,
+            file_size
+        );
+    }
+
+    Ok(
+        if size_of_point_record as u64 == point_layout.size_of_point_entry() {
+            point_layout.clone()
+        } else {
+            point_layout.with_size_of_point_entry(size_of_point_record as u64)
+        },
+    )
+}
+
+/// Checks that point data starting at `points` satisfies the alignment requirements of `point_layout`
+fn check_alignment(points: &[u8], point_layout: &PointLayout) -> Result<()> {
+    let alignment = point_layout.alignment_of_point_entry() as usize;
+    if !(points.as_ptr() as usize).is_multiple_of(alignment) {
+        bail!(
+            "Point data in memory-mapped file is not aligned to the alignment of the PointLayout ({} bytes)",
+            alignment
+        );
+    }
+    Ok(())
+}
+
+/// `PointBuffer` type that uses Interleaved memory layout and reads the point data from a memory-mapped file. This is
+/// useful for large files with uncompressed point records, because the point data is only loaded from disk when it is
+/// accessed, and the operating system can evict unused pages at any time.
+///
+/// The point records in the file can be larger than the `PointLayout`, e.g. for LAS files with extra bytes. In this case,
+/// the `PointLayout` of the buffer has a larger size than the original `PointLayout`, as if created through
+/// [`PointLayout::with_size_of_point_entry`]
+///
+/// *Note*: The file must not be modified by this or any other process while it is mapped, otherwise the contents of the
+/// `MmapPointBuffer` are undefined
+pub struct MmapPointBuffer {
+    mmap: Option<Mmap>,
+    point_layout: PointLayout,
+    point_count: usize,
+    size_of_point_entry: usize,
+}
+
+impl MmapPointBuffer {
+    /// Maps `point_count` point records with the given `PointLayout` from the file at `path` into memory. The first point
+    /// record starts at `offset_to_first_point` bytes from the start of the file, and each point record is
+    /// `size_of_point_record` bytes large
+    ///
+    /// ```no_run
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// # use pasture_core::nalgebra::Vector3;
+    /// let layout = PointLayout::from_attributes(&[attributes::POSITION_3D]);
+    /// // 1000 points with an additional 8 bytes per point record, following a header of 128 bytes
+    /// let buffer = MmapPointBuffer::from_file("points.bin", layout, 128, 1000, 32).unwrap();
+    /// for position in buffer.iter_attribute::<Vector3<f64>>(&attributes::POSITION_3D) {
+    ///     println!("{}", position);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the file can't be opened or mapped into memory, if the point records exceed the size of the file, or if
+    /// `size_of_point_record` is incompatible with the size or alignment of `point_layout`
+    pub fn from_file<P: AsRef<Path>>(
+        path: P,
+        point_layout: PointLayout,
+        offset_to_first_point: u64,
+        point_count: usize,
+        size_of_point_record: usize,
+    ) -> Result<Self> {
+        let file = File::open(path)?;
+        let point_layout = check_file_region(
+            &file,
+            &point_layout,
+            offset_to_first_point,
+            point_count,
+            size_of_point_record,
+        )?;
+
+        let mmap = if point_count == 0 {
+            None
+        } else {
+            let mmap = unsafe {
+                MmapOptions::new()
+                    .offset(offset_to_first_point)
+                    .len(point_count * size_of_point_record)
+                    .map(&file)?
+            };
+            check_alignment(&mmap, &point_layout)?;
+            Some(mmap)
+        };
+
+        Ok(Self {
+            mmap,
+            point_layout,
+            point_count,
+            size_of_point_entry: size_of_point_record,
+        })
+    }
+
+    fn points(&self) -> &[u8] {
+        self.mmap.as_deref().unwrap_or(&[])
+    }
+}
+
+/// Like [`MmapPointBuffer`], but maps the file as writeable, so that the point data can be modified through the
+/// `InterleavedPointBufferMut` trait. Changes are written back to the file, either when [`MmapPointBufferMut::flush`] is
+/// called or when the `MmapPointBufferMut` is dropped
+pub struct MmapPointBufferMut {
+    mmap: Option<MmapMut>,
+    point_layout: PointLayout,
+    point_count: usize,
+    size_of_point_entry: usize,
+}
+
+impl MmapPointBufferMut {
+    /// Maps `point_count` point records with the given `PointLayout` from the file at `path` into memory as writeable. The
+    /// file must already contain all point records. See [`MmapPointBuffer::from_file`] for the meaning of the parameters
+    ///
+    /// # Errors
+    ///
+    /// If the file can't be opened for writing or mapped into memory, if the point records exceed the size of the file,
+    /// or if `size_of_point_record` is incompatible with the size or alignment of `point_layout`
+    pub fn from_file<P: AsRef<Path>>(
+        path: P,
+        point_layout: PointLayout,
+        offset_to_first_point: u64,
+        point_count: usize,
+        size_of_point_record: usize,
+    ) -> Result<Self> {
+        let file = OpenOptions::new().read(true).write(true).open(path)?;
+        let point_layout = check_file_region(
+            &file,
+            &point_layout,
+            offset_to_first_point,
+            point_count,
+            size_of_point_record,
+        )?;
+
+        let mmap = if point_count == 0 {
+            None
+        } else {
+            let mmap = unsafe {
+                MmapOptions::new()
+                    .offset(offset_to_first_point)
+                    .len(point_count * size_of_point_record)
+                    .map_mut(&file)?
+            };
+            check_alignment(&mmap, &point_layout)?;
+            Some(mmap)
+        };
+
+        Ok(Self {
+            mmap,
+            point_layout,
+            point_count,
+            size_of_point_entry: size_of_point_record,
+        })
+    }
+
+    /// Writes all modified point data back to the file
+    pub fn flush(&self) -> Result<()> {
+        if let Some(mmap) = &self.mmap {
+            mmap.flush()?;
+        }
+        Ok(())
+    }
+
+    fn points(&self) -> &[u8] {
+        self.mmap.as_deref().unwrap_or(&[])
+    }
+
+    fn points_mut(&mut self) -> &mut [u8] {
+        self.mmap.as_deref_mut().unwrap_or(&mut [])
+    }
+}
+
+macro_rules! impl_interleaved_point_buffer_for_mmap_buffer {
+    ($buffer:ident) => {
+        impl PointBuffer for $buffer {
+            fn get_raw_point(&self, point_index: usize, buf: &mut [u8]) {
+                buf.copy_from_slice(self.get_raw_point_ref(point_index));
+            }
+
+            fn get_raw_attribute(
+                &self,
+                point_index: usize,
+                attribute: &PointAttributeDefinition,
+                buf: &mut [u8],
+            ) {
+                if let Some(attribute_in_buffer) = self.point_layout.get_attribute(attribute) {
+                    let point_record = self.get_raw_point_ref(point_index);
+                    attribute_in_buffer.read_from_point_record(point_record, buf);
+                } else {
+                    panic!(
+                        "{}::get_raw_attribute: Attribute {:?} is not part of this PointBuffer's PointLayout!",
+                        stringify!($buffer),
+                        attribute
+                    );
+                }
+            }
+
+            fn get_raw_points(&self, index_range: Range<usize>, buf: &mut [u8]) {
+                let points_ref = self.get_raw_points_ref(index_range);
+                buf[0..points_ref.len()].copy_from_slice(points_ref);
+            }
+
+            fn get_raw_attribute_range(
+                &self,
+                index_range: Range<usize>,
+                attribute: &PointAttributeDefinition,
+                buf: &mut [u8],
+            ) {
+                if let Some(attribute_in_buffer) = self.point_layout.get_attribute(attribute) {
+                    let attribute_size = attribute.size() as usize;
+                    let point_records = self.get_raw_points_ref(index_range);
+                    for (point_record, target_buf_slice) in point_records
+                        .chunks_exact(self.size_of_point_entry)
+                        .zip(buf.chunks_exact_mut(attribute_size))
+                    {
+                        attribute_in_buffer.read_from_point_record(point_record, target_buf_slice);
+                    }
+                } else {
+                    panic!(
+                        "{}::get_raw_attribute_range: Attribute {:?} is not part of this PointBuffer's PointLayout!",
+                        stringify!($buffer),
+                        attribute
+                    );
+                }
+            }
+
+            fn len(&self) -> usize {
+                self.point_count
+            }
+
+            fn point_layout(&self) -> &PointLayout {
+                &self.point_layout
+            }
+
+            fn as_interleaved(&self) -> Option<&dyn InterleavedPointBuffer> {
+                Some(self)
+            }
+        }
+
+        impl InterleavedPointBuffer for $buffer {
+            fn get_raw_point_ref(&self, point_index: usize) -> &[u8] {
+                if point_index >= self.len() {
+                    panic!(
+                        "{}::get_raw_point_ref: Point index {} out of bounds!",
+                        stringify!($buffer),
+                        point_index
+                    );
+                }
+
+                let offset_to_point = point_index * self.size_of_point_entry;
+                &self.points()[offset_to_point..offset_to_point + self.size_of_point_entry]
+            }
+
+            fn get_raw_points_ref(&self, index_range: Range<usize>) -> &[u8] {
+                if index_range.start > index_range.end || index_range.end > self.len() {
+                    panic!(
+                        "{}::get_raw_points_ref: Point indices {:?} out of bounds!",
+                        stringify!($buffer),
+                        index_range
+                    );
+                }
+
+                let offset_to_point = index_range.start * self.size_of_point_entry;
+                let total_bytes_of_range = index_range.len() * self.size_of_point_entry;
+                &self.points()[offset_to_point..offset_to_point + total_bytes_of_range]
+            }
+        }
+    };
+}
+
+impl_interleaved_point_buffer_for_mmap_buffer!(MmapPointBuffer);
+impl_interleaved_point_buffer_for_mmap_buffer!(MmapPointBufferMut);
+
+impl InterleavedPointBufferMut for MmapPointBufferMut {
+    fn get_raw_point_mut(&mut self, point_index: usize) -> &mut [u8] {
+        if point_index >= self.len() {
+            panic!(
+                "MmapPointBufferMut::get_raw_point_mut: Point index {} out of bounds!",
+                point_index
+            );
+        }
+
+        let offset_to_point = point_index * self.size_of_point_entry;
+        let size_of_point_entry = self.size_of_point_entry;
+        &mut self.points_mut()[offset_to_point..offset_to_point + size_of_point_entry]
+    }
+
+    fn get_raw_points_mut(&mut self, index_range: Range<usize>) -> &mut [u8] {
+        if index_range.start > index_range.end || index_range.end > self.len() {
+            panic!(
+                "MmapPointBufferMut::get_raw_points_mut: Point indices {:?} out of bounds!",
+                index_range
+            );
+        }
+
+        let offset_to_point = index_range.start * self.size_of_point_entry;
+        let total_bytes_of_range = index_range.len() * self.size_of_point_entry;
+        &mut self.points_mut()[offset_to_point..offset_to_point + total_bytes_of_range]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::containers::{
+        InterleavedPointBufferExt, InterleavedPointBufferMutExt, PointBufferExt,
+    };
+    use crate::layout::{attributes, PointAttributeDataType, PointType};
+    use crate::util::view_raw_bytes;
+    use nalgebra::Vector3;
+    use pasture_derive::PointType;
+    use std::{
+        io::Write,
+        path::PathBuf,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    // We need this, otherwise we can't use the derive(PointType) macro from within pasture_core because the macro
+    // doesn't recognize the name 'pasture_core' :/
+    use crate as pasture_core;
+
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone, PartialEq, PointType)]
+    struct TestPointType(
+        #[pasture(BUILTIN_POSITION_3D)] Vector3<f64>,
+        #[pasture(BUILTIN_INTENSITY)] u16,
+    );
+
+    fn test_points() -> Vec<TestPointType> {
+        (0..10)
+            .map(|index| TestPointType(Vector3::new(index as f64, 1.0, 2.0), index as u16))
+            .collect()
+    }
+
+    /// Writes the test points into a temporary file, after a header of `header_size` bytes and with `extra_bytes`
+    /// additional bytes after each point. The file name is unique for each call, so that tests running in parallel,
+    /// also in different processes, don't overwrite each others files
+    fn write_test_file(header_size: usize, extra_bytes: usize) -> Result<PathBuf> {
+        static FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let path = std::env::temp_dir().join(format!(
+            "pasture_test_mmap_{}_{}.bin",
+            std::process::id(),
+            FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let mut file = File::create(&path)?;
+        file.write_all(&vec![0xFF; header_size])?;
+        for point in test_points() {
+            file.write_all(unsafe { view_raw_bytes(&point) })?;
+            file.write_all(&vec![0xFF; extra_bytes])?;
+        }
+        Ok(path)
+    }
+
+    #[test]
+    fn test_mmap_point_buffer() -> Result<()> {
+        let path = write_test_file(64, 0)?;
+        let buffer = MmapPointBuffer::from_file(&path, TestPointType::layout(), 64, 10, 32)?;
+
+        assert_eq!(10, buffer.len());
+        assert_eq!(
+            test_points(),
+            buffer
+                .iter_point_ref::<TestPointType>()
+                .copied()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            (0..10).collect::<Vec<u16>>(),
+            buffer
+                .iter_attribute::<u16>(&attributes::INTENSITY)
+                .collect::<Vec<_>>()
+        );
+
+        drop(buffer);
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_mmap_point_buffer_with_padded_records() -> Result<()> {
+        let path = write_test_file(8, 16)?;
+        let buffer = MmapPointBuffer::from_file(&path, TestPointType::layout(), 8, 10, 48)?;
+
+        assert_eq!(48, buffer.point_layout().size_of_point_entry());
+        assert_eq!(
+            test_points()
+                .iter()
+                .map(|point| point.0)
+                .collect::<Vec<_>>(),
+            buffer
+                .iter_attribute::<Vector3<f64>>(&attributes::POSITION_3D)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            (0..10)
+                .map(|intensity| intensity as u32)
+                .collect::<Vec<_>>(),
+            buffer
+                .iter_attribute_as::<u32>(
+                    &attributes::INTENSITY.with_custom_datatype(PointAttributeDataType::U32)
+                )
+                .collect::<Vec<_>>()
+        );
+
+        assert!(MmapPointBuffer::from_file(&path, TestPointType::layout(), 8, 10, 36).is_err());
+        assert!(MmapPointBuffer::from_file(&path, TestPointType::layout(), 8, 11, 48).is_err());
+
+        drop(buffer);
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_mmap_point_buffer_mut() -> Result<()> {
+        let path = write_test_file(0, 0)?;
+        {
+            let mut buffer =
+                MmapPointBufferMut::from_file(&path, TestPointType::layout(), 0, 10, 32)?;
+            for point in buffer.iter_point_mut::<TestPointType>() {
+                point.1 *= 2;
+            }
+            buffer.flush()?;
+        }
+
+        let buffer = MmapPointBuffer::from_file(&path, TestPointType::layout(), 0, 10, 32)?;
+        assert_eq!(
+            (0..10).map(|intensity| intensity * 2).collect::<Vec<u16>>(),
+            buffer
+                .iter_attribute::<u16>(&attributes::INTENSITY)
+                .collect::<Vec<_>>()
+        );
+
+        drop(buffer);
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_mmap_point_buffer_with_overflowing_region() -> Result<()> {
+        let path = write_test_file(0, 0)?;
+        // The size of the point records wraps around to 32 bytes
+        let wrapping_point_count = usize::MAX / 32 + 2;
+        assert!(MmapPointBuffer::from_file(
+            &path,
+            TestPointType::layout(),
+            0,
+            wrapping_point_count,
+            32
+        )
+        .is_err());
+        assert!(MmapPointBufferMut::from_file(
+            &path,
+            TestPointType::layout(),
+            0,
+            wrapping_point_count,
+            32
+        )
+        .is_err());
+        assert!(
+            MmapPointBuffer::from_file(&path, TestPointType::layout(), u64::MAX - 16, 1, 32)
+                .is_err()
+        );
+
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_mmap_point_buffer() -> Result<()> {
+        let path = write_test_file(0, 0)?;
+        let buffer = MmapPointBuffer::from_file(&path, TestPointType::layout(), 320, 0, 32)?;
+        assert!(buffer.is_empty());
+        assert_eq!(
+            0,
+            buffer.iter_attribute::<u16>(&attributes::INTENSITY).count()
+        );
+
+        drop(buffer);
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+}
@@ -10,7 +10,16 @@
 //! On top of these traits, Pasture provides some specific implementations for storing contiguous
 //! point data in [Interleaved](InterleavedVecPointStorage) or [PerAttribute](PerAttributeVecPointStorage)
 //! layouts, as well as [non-owning](InterleavedPointView) and [sliced](InterleavedPointBufferSlice) versions
-//! of these buffers.
+//! of these buffers. If the type of the points is known at compile time, a [TypedPointVec] stores them in a
+//! strongly typed `Vec`. Selections of points within any buffer can be accessed through [indexed](IndexedPointView)
+//! and [masked](MaskedPointView) views. A [SharedPointBuffer] shares its point data between all of its clones and copies it only upon
+//! mutation. Point data in uncompressed files can be accessed without copying through the
+//! [memory-mapped](MmapPointBuffer) buffers,
+//! and point clouds that exceed the available memory can be stored in a [ChunkedPointStorage], which spills
+//! chunks of points to disk. The `Vec`-based buffers implement `Serialize` and `Deserialize`, storing the
+//! point data as raw bytes, so they can be cached or sent between processes using compact formats such as
+//! bincode. With the `arrow` feature, buffers can be converted to and from Apache Arrow `RecordBatch`es. Attributes can be
+//! viewed as `nalgebra` matrices without copying, and with the `ndarray` feature also as `ndarray` arrays.
 //!
 //! Lastly, this module exposes some helper functions for iterating over the point data inside any of
 //! these buffers.
@@ -35,3 +44,28 @@ pub use self::slice_buffers::*;
 
 mod untyped_point;
 pub use self::untyped_point::*;
+
+mod mmap_buffers;
+pub use self::mmap_buffers::*;
+
+mod chunked_buffers;
+pub use self::chunked_buffers::*;
+
+mod indexed_views;
+pub use self::indexed_views::*;
+
+mod typed_point_vec;
+pub use self::typed_point_vec::*;
+
+mod matrix_views;
+pub use self::matrix_views::{AttributeMatrixView, AttributeMatrixViewMut};
+
+mod serde_buffers;
+
+mod shared_buffers;
+pub use self::shared_buffers::*;
+
+#[cfg(feature = "arrow")]
+mod arrow;
+#[cfg(feature = "arrow")]
+pub use self::arrow::*;
@@ -1,9 +1,13 @@
 use std::{mem::MaybeUninit, ops::Range};
 
+use nalgebra::Scalar;
+use rayon::prelude::*;
+
 use crate::{
     layout::{
-        conversion::get_converter_for_attributes, PointAttributeDefinition, PointLayout, PointType,
-        PrimitiveType,
+        conversion::{get_converter_for_attributes, AttributeConversionFn},
+        PointAttributeDataType, PointAttributeDefinition, PointAttributeMember, PointLayout,
+        PointType, PrimitiveType,
     },
     util::view_raw_bytes,
 };
@@ -16,9 +20,15 @@ use super::{
     iterators::PointIteratorByMut,
     iterators::PointIteratorByRef,
     iterators::PointIteratorByValue,
-    PerAttributePointBufferSlice, PerAttributePointBufferSliceMut,
+    matrix_views::{attribute_matrix_view, attribute_matrix_view_mut},
+    AttributeMatrixView, AttributeMatrixViewMut, PerAttributePointBufferSlice,
+    PerAttributePointBufferSliceMut, UntypedPointIterator, UntypedPointIteratorMut,
+    UntypedPointSlice,
 };
 
+#[cfg(feature = "ndarray")]
+use super::matrix_views::{attribute_ndarray_view, attribute_ndarray_view_mut};
+
 // TODO Can we maybe impl<T: PointBufferWriteable> &T and provide some push<U> methods?
 
 /// Base trait for all containers that store point data. A PointBuffer stores any number of point entries
@@ -104,8 +114,8 @@ pub trait PointBufferWriteable: PointBuffer {
     ///
     /// # Panics
     ///
-    /// Panics if `points` is neither an `InterleavedPointBuffer` nor a `PerAttributePointBuffer`. *Note:* All the builtin buffers supplied by
-    /// Pasture always implement at least one of these traits, so it is always safe to call `push` with any of the builtin buffers.
+    /// Implementations may panic if `points` is neither an `InterleavedPointBuffer` nor a `PerAttributePointBuffer`. *Note:* The builtin
+    /// owning buffers supplied by Pasture accept any `PointBuffer`, so it is always safe to call `push` on them.
     fn push(&mut self, points: &dyn PointBuffer);
 
     /// Replaces the specified `range` in the associated `PointBuffer` with the given `replace_with` buffer.
@@ -114,8 +124,8 @@ pub trait PointBufferWriteable: PointBuffer {
     ///
     /// Panics if the starting point is greater than the end point or if the end point is greater than the length of the associated `PointBuffer`.
     /// Panics if the length of `replace_with` is less than the length of `range`
-    /// Panics if `replace_with` is neither an `InterleavedPointBuffer` nor a `PerAttributePointBuffer`. *Note:* All the builtin buffers supplied by
-    /// Pasture always implement at least one of these traits, so it is always safe to call `splice` with any of the builtin buffers.
+    /// Implementations may panic if `replace_with` is neither an `InterleavedPointBuffer` nor a `PerAttributePointBuffer`. *Note:* The builtin
+    /// owning buffers supplied by Pasture accept any `PointBuffer`, so it is always safe to call `splice` on them.
     fn splice(&mut self, range: Range<usize>, replace_with: &dyn PointBuffer);
 
     /// Clears the contents of the associated `PointBufferMut`
@@ -124,6 +134,90 @@ pub trait PointBufferWriteable: PointBuffer {
     /// Resizes this buffer to the given number of `new_points`. This will trim the buffer if `new_points` is smaller
     /// than the current number of points, or create default-initialized points if `new_points` is larger.
     fn resize(&mut self, new_points: usize);
+
+    /// Try to downcast the associated `PointBufferWriteable` into an `InterleavedPointBufferMut`
+    fn as_interleaved_mut(&mut self) -> Option<&mut dyn InterleavedPointBufferMut> {
+        None
+    }
+
+    /// Try to downcast the associated `PointBufferWriteable` into a `PerAttributePointBufferMut`
+    fn as_per_attribute_mut<'b>(
+        &'b mut self,
+    ) -> Option<&'b mut dyn PerAttributePointBufferMut<'b>> {
+        None
+    }
+
+    /// Removes all points for which the corresponding entry in `keep` is `false` from the associated `PointBufferWriteable`,
+    /// preserving the order of the remaining points. This is the untyped counterpart of [`PointBufferWriteableExt::retain`].
+    /// The default implementation moves each remaining point through `get_raw_point` and `set_raw_point` and then shrinks
+    /// the buffer using `resize`, buffers with contiguous memory provide more efficient implementations
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `keep` does not match the length of the associated `PointBufferWriteable`. Implementations
+    /// may panic if they can't change their number of points
+    fn retain_by_mask(&mut self, keep: &[bool]) {
+        if keep.len() != self.len() {
+            panic!(
+                "PointBufferWriteable::retain_by_mask: Mask length ({}) does not match buffer length ({})",
+                keep.len(),
+                self.len()
+            );
+        }
+        let mut point = vec![0; self.point_layout().size_of_point_entry() as usize];
+        let mut new_len = 0;
+        for (point_index, _) in keep.iter().enumerate().filter(|(_, keep)| **keep) {
+            if point_index != new_len {
+                self.get_raw_point(point_index, &mut point);
+                self.set_raw_point(new_len, &point);
+            }
+            new_len += 1;
+        }
+        self.resize(new_len);
+    }
+
+    /// Removes the point at `point_index` from the associated `PointBufferWriteable` by replacing it with the last point.
+    /// Like `Vec::swap_remove`, this does not preserve the order of the points, but it only has to move a single point
+    ///
+    /// # Panics
+    ///
+    /// Panics if `point_index` is out of bounds. Implementations may panic if they can't change their number of points
+    fn swap_remove(&mut self, point_index: usize) {
+        let len = self.len();
+        if point_index >= len {
+            panic!(
+                "PointBufferWriteable::swap_remove: Index {} is out of bounds for buffer of length {}",
+                point_index, len
+            );
+        }
+        if point_index != len - 1 {
+            let mut point = vec![0; self.point_layout().size_of_point_entry() as usize];
+            self.get_raw_point(len - 1, &mut point);
+            self.set_raw_point(point_index, &point);
+        }
+        self.resize(len - 1);
+    }
+
+    /// Removes the points at the given `indices` from the associated `PointBufferWriteable`, preserving the order of the
+    /// remaining points. The `indices` can be in any order and may contain duplicates
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `indices` is out of bounds. Implementations may panic if they can't change their number of points
+    fn remove_indices(&mut self, indices: &[usize]) {
+        let mut keep = vec![true; self.len()];
+        for &point_index in indices {
+            if point_index >= keep.len() {
+                panic!(
+                    "PointBufferWriteable::remove_indices: Index {} is out of bounds for buffer of length {}",
+                    point_index,
+                    keep.len()
+                );
+            }
+            keep[point_index] = false;
+        }
+        self.retain_by_mask(&keep);
+    }
 }
 
 /// Trait for `PointBuffer` types that store point data in Interleaved memory layout. In an `InterleavedPointBuffer`, all attributes
@@ -258,6 +352,9 @@ pub trait PointBufferExt<B: PointBuffer + ?Sized> {
 
     /// Returns an iterator over all points in the associated `PointBuffer`, strongly typed to the `PointType` `T`
     fn iter_point<T: PointType>(&self) -> PointIteratorByValue<'_, T, B>;
+    /// Returns an iterator over all points in the associated `PointBuffer` as [UntypedPointSlice]s, which is useful if the
+    /// `PointLayout` of the buffer is not known at compile time. See [UntypedPointIterator] for an example
+    fn iter_untyped_points(&self) -> UntypedPointIterator<'_, B>;
     /// Returns an iterator over the given `attribute` of all points in the associated `PointBuffer`, strongly typed to the `PrimitiveType` `T`.
     ///
     /// For iterating over multiple attributes at once, use the [attributes!] macro.
@@ -272,7 +369,9 @@ pub trait PointBufferExt<B: PointBuffer + ?Sized> {
     ) -> AttributeIteratorByValue<'a, T, B>;
     /// Returns an iterator over the given `attribute` of all points in the associated `PointBuffer`, converted to the `PrimitiveType` `T`. This iterator
     /// supports conversion of types, so it works even if the `attribute` inside the buffer is stored as some other type `U`, as long as there is a valid
-    /// conversion from `U` to `T`. Regarding conversions, see the [conversions module](crate::layout::conversion).
+    /// conversion from `U` to `T`. Regarding conversions, see the [conversions module](crate::layout::conversion). If the attribute is
+    /// [quantized](crate::layout::PointAttributeQuantization) inside the buffer, its values are dequantized automatically (and quantized
+    /// again if `attribute` itself is quantized).
     ///
     /// For iterating over multiple attributes at once, use the [attributes!] macro.
     ///
@@ -284,6 +383,58 @@ pub trait PointBufferExt<B: PointBuffer + ?Sized> {
         &'a self,
         attribute: &'a PointAttributeDefinition,
     ) -> AttributeIteratorByValueWithConversion<'a, T, B>;
+
+    /// Parallel version of [`iter_point`](PointBufferExt::iter_point), using the [`rayon`](https://docs.rs/rayon) crate. Requires
+    /// that the associated `PointBuffer` can be shared between threads.
+    ///
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// # use pasture_core::nalgebra::Vector3;
+    /// # use pasture_derive::PointType;
+    /// use rayon::prelude::*;
+    ///
+    /// #[derive(PointType, Copy, Clone)]
+    /// #[repr(C)]
+    /// struct SimplePoint {
+    ///     #[pasture(BUILTIN_POSITION_3D)]
+    ///     pub position: Vector3<f64>,
+    ///     #[pasture(BUILTIN_INTENSITY)]
+    ///     pub intensity: u16,
+    /// }
+    ///
+    /// let mut buffer = InterleavedVecPointStorage::new(SimplePoint::layout());
+    /// for intensity in 0..100 {
+    ///     buffer.push_point(SimplePoint { position: Vector3::new(0.0, 0.0, 0.0), intensity });
+    /// }
+    ///
+    /// let total_intensity: u32 = buffer
+    ///     .par_iter_point::<SimplePoint>()
+    ///     .map(|point| point.intensity as u32)
+    ///     .sum();
+    /// assert_eq!(4950, total_intensity);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `PointLayout` of `T` does not match the `PointLayout` of the associated `PointBuffer`
+    fn par_iter_point<T: PointType + Send>(&self) -> impl IndexedParallelIterator<Item = T> + '_
+    where
+        B: Sync;
+    /// Parallel version of [`iter_attribute`](PointBufferExt::iter_attribute), using the [`rayon`](https://docs.rs/rayon) crate.
+    /// Requires that the associated `PointBuffer` can be shared between threads. For iterating over multiple attributes at once,
+    /// use the [par_attributes!] macro.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `attribute` is not part of the `PointLayout` of the buffer.<br>
+    /// Panics if the data type of `attribute` inside the associated `PointBuffer` is not equal to `T`.
+    fn par_iter_attribute<'a, T: PrimitiveType>(
+        &'a self,
+        attribute: &'a PointAttributeDefinition,
+    ) -> impl IndexedParallelIterator<Item = T> + 'a
+    where
+        B: Sync;
 }
 
 impl<B: PointBuffer + ?Sized> PointBufferExt<B> for B {
@@ -324,6 +475,10 @@ impl<B: PointBuffer + ?Sized> PointBufferExt<B> for B {
         PointIteratorByValue::new(self)
     }
 
+    fn iter_untyped_points(&self) -> UntypedPointIterator<'_, B> {
+        UntypedPointIterator::new(self)
+    }
+
     fn iter_attribute<'a, T: PrimitiveType>(
         &'a self,
         attribute: &'a PointAttributeDefinition,
@@ -337,6 +492,372 @@ impl<B: PointBuffer + ?Sized> PointBufferExt<B> for B {
     ) -> AttributeIteratorByValueWithConversion<'a, T, B> {
         AttributeIteratorByValueWithConversion::new(self, attribute)
     }
+
+    fn par_iter_point<T: PointType + Send>(&self) -> impl IndexedParallelIterator<Item = T> + '_
+    where
+        B: Sync,
+    {
+        if *self.point_layout() != T::layout() {
+            panic!("PointLayout of type T does not match PointLayout of buffer (buffer layout: {}, T layout: {})", self.point_layout(), T::layout());
+        }
+        (0..self.len())
+            .into_par_iter()
+            .map(move |index| self.get_point::<T>(index))
+    }
+
+    fn par_iter_attribute<'a, T: PrimitiveType>(
+        &'a self,
+        attribute: &'a PointAttributeDefinition,
+    ) -> impl IndexedParallelIterator<Item = T> + 'a
+    where
+        B: Sync,
+    {
+        if attribute.datatype() != T::data_type() {
+            panic!("Type T does not match datatype of attribute {}", attribute);
+        }
+        if !self.point_layout().has_attribute(attribute) {
+            panic!(
+                "Attribute {} not contained in PointLayout of buffer ({})",
+                attribute,
+                self.point_layout()
+            );
+        }
+        (0..self.len())
+            .into_par_iter()
+            .map(move |index| self.get_attribute::<T>(attribute, index))
+    }
+}
+
+/// Tuple of `PrimitiveType`s whose values can be transformed together using [`PointBufferWriteableExt::transform_attributes`]. This
+/// trait is implemented for tuples with up to twelve elements
+pub trait PrimitiveTypeTuple: Sized {
+    /// Returns the `PointAttributeDataType`s of the elements of this tuple
+    fn data_types() -> Vec<PointAttributeDataType>;
+    /// Reads a tuple from `buf`, which contains the values of all elements of the tuple tightly packed in order
+    ///
+    /// # Panics
+    ///
+    /// If `buf` is smaller than the sum of the sizes of all elements of this tuple
+    fn read_from_raw(buf: &[u8]) -> Self;
+    /// Writes the values of all elements of this tuple tightly packed in order into `buf`
+    ///
+    /// # Panics
+    ///
+    /// If `buf` is smaller than the sum of the sizes of all elements of this tuple
+    fn write_to_raw(&self, buf: &mut [u8]);
+}
+
+/// Reads a value of type `T` from `buf` at `offset` and advances `offset` by the size of `T`
+fn read_primitive<T: PrimitiveType>(buf: &[u8], offset: &mut usize) -> T {
+    let size = std::mem::size_of::<T>();
+    let value = unsafe { (buf[*offset..*offset + size].as_ptr() as *const T).read_unaligned() };
+    *offset += size;
+    value
+}
+
+/// Writes `value` into `buf` at `offset` and advances `offset` by the size of `T`
+fn write_primitive<T: PrimitiveType>(value: &T, buf: &mut [u8], offset: &mut usize) {
+    let size = std::mem::size_of::<T>();
+    buf[*offset..*offset + size].copy_from_slice(unsafe { view_raw_bytes(value) });
+    *offset += size;
+}
+
+macro_rules! impl_primitive_type_tuple {
+    ($($t:ident),+ and $($idx:tt),+) => {
+        impl<$($t: PrimitiveType),+> PrimitiveTypeTuple for ($($t,)+) {
+            fn data_types() -> Vec<PointAttributeDataType> {
+                vec![$($t::data_type()),+]
+            }
+
+            fn read_from_raw(buf: &[u8]) -> Self {
+                let mut offset = 0;
+                ($(read_primitive::<$t>(buf, &mut offset),)+)
+            }
+
+            fn write_to_raw(&self, buf: &mut [u8]) {
+                let mut offset = 0;
+                $(write_primitive(&self.$idx, buf, &mut offset);)+
+            }
+        }
+    };
+}
+
+impl_primitive_type_tuple!(T1 and 0);
+impl_primitive_type_tuple!(T1, T2 and 0, 1);
+impl_primitive_type_tuple!(T1, T2, T3 and 0, 1, 2);
+impl_primitive_type_tuple!(T1, T2, T3, T4 and 0, 1, 2, 3);
+impl_primitive_type_tuple!(T1, T2, T3, T4, T5 and 0, 1, 2, 3, 4);
+impl_primitive_type_tuple!(T1, T2, T3, T4, T5, T6 and 0, 1, 2, 3, 4, 5);
+impl_primitive_type_tuple!(T1, T2, T3, T4, T5, T6, T7 and 0, 1, 2, 3, 4, 5, 6);
+impl_primitive_type_tuple!(T1, T2, T3, T4, T5, T6, T7, T8 and 0, 1, 2, 3, 4, 5, 6, 7);
+impl_primitive_type_tuple!(T1, T2, T3, T4, T5, T6, T7, T8, T9 and 0, 1, 2, 3, 4, 5, 6, 7, 8);
+impl_primitive_type_tuple!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10 and 0, 1, 2, 3, 4, 5, 6, 7, 8, 9);
+impl_primitive_type_tuple!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11 and 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
+impl_primitive_type_tuple!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12 and 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
+
+/// Number of bytes of attribute data that `transform_attributes` processes as a single block
+const TRANSFORM_BLOCK_SIZE_IN_BYTES: usize = 64 * 1024;
+
+/// Raw memory of the attribute values of a block of points that is transformed by `transform_attributes`
+enum RawAttributeMemory<'a> {
+    /// Point records in Interleaved memory layout
+    Interleaved(&'a mut [u8]),
+    /// The tightly packed values of each transformed attribute
+    PerAttribute(Vec<&'a mut [u8]>),
+}
+
+/// A block of points that is transformed by `transform_attributes`
+struct RawAttributeBlock<'a> {
+    first_point: usize,
+    count: usize,
+    memory: RawAttributeMemory<'a>,
+}
+
+/// Helper structure for transforming the values of some attributes with a function that accepts a `PrimitiveTypeTuple`. The
+/// attribute values are copied (and converted, if necessary) into a buffer in the memory layout of the tuple, transformed and
+/// then copied back into the point buffer
+struct AttributeTransformation {
+    attributes: Vec<PointAttributeMember>,
+    /// Conversion functions into and from the type of the corresponding tuple element, for all attributes whose datatype differs
+    /// from the type of the tuple element
+    converters: Vec<Option<(AttributeConversionFn, AttributeConversionFn)>>,
+    /// The range of each tuple element in the raw memory of the tuple
+    value_ranges: Vec<Range<usize>>,
+    size_of_point_entry: usize,
+}
+
+impl AttributeTransformation {
+    fn new<T: PrimitiveTypeTuple>(layout: &PointLayout, attribute_names: &[&str]) -> Self {
+        let data_types = T::data_types();
+        if data_types.len() != attribute_names.len() {
+            panic!(
+                "Number of attributes ({}) does not match the number of elements of the tuple type ({})",
+                attribute_names.len(),
+                data_types.len()
+            );
+        }
+        for (index, attribute_name) in attribute_names.iter().enumerate() {
+            if attribute_names[..index].contains(attribute_name) {
+                panic!(
+                    "Attribute {} can't be transformed more than once",
+                    attribute_name
+                );
+            }
+        }
+
+        let mut attributes = Vec::with_capacity(data_types.len());
+        let mut converters = Vec::with_capacity(data_types.len());
+        let mut value_ranges = Vec::with_capacity(data_types.len());
+        let mut value_offset = 0;
+        for (&attribute_name, data_type) in attribute_names.iter().zip(data_types) {
+            let attribute = layout
+                .get_attribute_by_name(attribute_name)
+                .unwrap_or_else(|| {
+                    panic!(
+                        "attribute {} not found in PointLayout of this buffer",
+                        attribute_name
+                    )
+                });
+            let value_attribute = PointAttributeDefinition::custom_owned(attribute_name, data_type);
+            if attribute.datatype() == data_type {
+                converters.push(None);
+            } else {
+                let attribute_definition: PointAttributeDefinition = attribute.into();
+                match (
+                    get_converter_for_attributes(&attribute_definition, &value_attribute),
+                    get_converter_for_attributes(&value_attribute, &attribute_definition),
+                ) {
+                    (Some(convert_to_value), Some(convert_from_value)) => {
+                        converters.push(Some((convert_to_value, convert_from_value)))
+                    }
+                    _ => panic!("No conversion possible between requested attribute {} and attribute in buffer {}", value_attribute, attribute_definition),
+                }
+            }
+            attributes.push(attribute.clone());
+            value_ranges.push(value_offset..value_offset + data_type.size() as usize);
+            value_offset += data_type.size() as usize;
+        }
+
+        Self {
+            attributes,
+            converters,
+            value_ranges,
+            size_of_point_entry: layout.size_of_point_entry() as usize,
+        }
+    }
+
+    /// Splits the memory of the given `buffer` into blocks that can be transformed independently of each other. Returns `None`
+    /// if `buffer` provides no access to its memory or if some of the attributes are stored as bit fields
+    fn split_into_blocks<'a, B: PointBufferWriteable + ?Sized>(
+        &self,
+        buffer: &'a mut B,
+    ) -> Option<Vec<RawAttributeBlock<'a>>> {
+        if self
+            .attributes
+            .iter()
+            .any(|attribute| attribute.bit_field().is_some())
+        {
+            return None;
+        }
+        let len = buffer.len();
+
+        if buffer.as_interleaved().is_some() {
+            let points = buffer.as_interleaved_mut()?.get_raw_points_mut(0..len);
+            let points_per_block =
+                (TRANSFORM_BLOCK_SIZE_IN_BYTES / self.size_of_point_entry.max(1)).max(1);
+            let blocks = points
+                .chunks_mut((points_per_block * self.size_of_point_entry).max(1))
+                .enumerate()
+                .map(|(block_index, points)| RawAttributeBlock {
+                    first_point: block_index * points_per_block,
+                    count: points.len() / self.size_of_point_entry,
+                    memory: RawAttributeMemory::Interleaved(points),
+                })
+                .collect();
+            return Some(blocks);
+        }
+
+        let per_attribute = buffer.as_per_attribute_mut()?;
+        // The attributes are distinct (see 'new'), so the memory of each attribute is disjoint from the memory of all other
+        // attributes and it is safe to borrow all of them mutably at the same time
+        let attribute_memories = self
+            .attributes
+            .iter()
+            .map(|attribute| {
+                let memory = per_attribute.get_raw_attribute_range_mut(0..len, &attribute.into());
+                unsafe { std::slice::from_raw_parts_mut(memory.as_mut_ptr(), memory.len()) }
+            })
+            .collect::<Vec<&'a mut [u8]>>();
+        let size_of_values = self
+            .attributes
+            .iter()
+            .map(|attribute| attribute.size() as usize)
+            .sum::<usize>();
+        let points_per_block = (TRANSFORM_BLOCK_SIZE_IN_BYTES / size_of_values.max(1)).max(1);
+        let mut blocks = (0..len.div_ceil(points_per_block))
+            .map(|block_index| {
+                let first_point = block_index * points_per_block;
+                RawAttributeBlock {
+                    first_point,
+                    count: points_per_block.min(len - first_point),
+                    memory: RawAttributeMemory::PerAttribute(Vec::with_capacity(
+                        self.attributes.len(),
+                    )),
+                }
+            })
+            .collect::<Vec<_>>();
+        for (memory, attribute) in attribute_memories.into_iter().zip(self.attributes.iter()) {
+            let chunk_size = (points_per_block * attribute.size() as usize).max(1);
+            for (block, chunk) in blocks.iter_mut().zip(memory.chunks_mut(chunk_size)) {
+                if let RawAttributeMemory::PerAttribute(chunks) = &mut block.memory {
+                    chunks.push(chunk);
+                }
+            }
+        }
+        Some(blocks)
+    }
+
+    /// Returns the raw memory of the attribute at `attribute_index` for the point at `point_index` within `memory`
+    fn attribute_value_mut<'m>(
+        &self,
+        memory: &'m mut RawAttributeMemory<'_>,
+        attribute_index: usize,
+        point_index: usize,
+    ) -> &'m mut [u8] {
+        let attribute = &self.attributes[attribute_index];
+        let size = attribute.size() as usize;
+        match memory {
+            RawAttributeMemory::Interleaved(points) => {
+                let start = point_index * self.size_of_point_entry + attribute.offset() as usize;
+                &mut points[start..start + size]
+            }
+            RawAttributeMemory::PerAttribute(attributes) => {
+                &mut attributes[attribute_index][point_index * size..(point_index + 1) * size]
+            }
+        }
+    }
+
+    fn transform_block<T: PrimitiveTypeTuple, F: Fn(usize, &mut T)>(
+        &self,
+        mut block: RawAttributeBlock<'_>,
+        func: &F,
+    ) {
+        let mut tuple_memory = vec![0; self.value_ranges.last().map_or(0, |range| range.end)];
+        for point_index in 0..block.count {
+            for (attribute_index, value_range) in self.value_ranges.iter().enumerate() {
+                let value = &mut tuple_memory[value_range.clone()];
+                let attribute_value =
+                    self.attribute_value_mut(&mut block.memory, attribute_index, point_index);
+                match self.converters[attribute_index] {
+                    Some((convert_to_value, _)) => unsafe {
+                        convert_to_value(attribute_value, value)
+                    },
+                    None => value.copy_from_slice(attribute_value),
+                }
+            }
+
+            let mut tuple = T::read_from_raw(&tuple_memory);
+            func(block.first_point + point_index, &mut tuple);
+            tuple.write_to_raw(&mut tuple_memory);
+
+            for (attribute_index, value_range) in self.value_ranges.iter().enumerate() {
+                let value = &tuple_memory[value_range.clone()];
+                let attribute_value =
+                    self.attribute_value_mut(&mut block.memory, attribute_index, point_index);
+                match self.converters[attribute_index] {
+                    Some((_, convert_from_value)) => unsafe {
+                        convert_from_value(value, attribute_value)
+                    },
+                    None => attribute_value.copy_from_slice(value),
+                }
+            }
+        }
+    }
+
+    /// Fallback for buffers that provide no access to their memory. It is important that we use `get_raw_attribute` and
+    /// `set_raw_attribute` here, since we can make no assumptions on the memory layout of the point data
+    fn transform_point_by_point<
+        B: PointBufferWriteable + ?Sized,
+        T: PrimitiveTypeTuple,
+        F: Fn(usize, &mut T),
+    >(
+        &self,
+        buffer: &mut B,
+        func: &F,
+    ) {
+        let attribute_definitions = self
+            .attributes
+            .iter()
+            .map(|attribute| attribute.into())
+            .collect::<Vec<PointAttributeDefinition>>();
+        let mut attribute_values = self
+            .attributes
+            .iter()
+            .map(|attribute| vec![0; attribute.size() as usize])
+            .collect::<Vec<_>>();
+        for point_index in 0..buffer.len() {
+            for (attribute, value) in attribute_definitions
+                .iter()
+                .zip(attribute_values.iter_mut())
+            {
+                buffer.get_raw_attribute(point_index, attribute, value);
+            }
+            let block = RawAttributeBlock {
+                first_point: point_index,
+                count: 1,
+                memory: RawAttributeMemory::PerAttribute(
+                    attribute_values
+                        .iter_mut()
+                        .map(|value| value.as_mut_slice())
+                        .collect(),
+                ),
+            };
+            self.transform_block(block, func);
+            for (attribute, value) in attribute_definitions.iter().zip(attribute_values.iter()) {
+                buffer.set_raw_attribute(point_index, attribute, value);
+            }
+        }
+    }
 }
 
 /// Extension trait that provides generic methods for manipulating point and attribute data in a `PointBufferWriteable`
@@ -358,12 +879,18 @@ pub trait PointBufferWriteableExt<B: PointBufferWriteable + ?Sized> {
         attribute_value: T,
     );
 
+    /// Returns an iterator over all points in the associated `PointBuffer` as mutable [UntypedPointSlice]s. Changes to the
+    /// points are written back to the buffer. See [UntypedPointIteratorMut] for an example
+    fn iter_untyped_points_mut(&mut self) -> UntypedPointIteratorMut<'_, B>;
+
     /// Applies the given transformation `func` to all values of the attribute with the given `attribute_name` in the buffer. The function gets a mutable
     /// borrow to the attribute value, through which the attribute value can be manipulated in-place. This is an easier alternative
     /// to calling `get_attribute` and `set_attribute` in a loop.
     /// **This method also performs data type conversions!** So e.g. if the underlying `PointBuffer` stores a POSITION_3D attribute as
     /// `Vector3<f32>`, you can call this method with a function accepting e.g. `Vector3<f64>` and the data will be converted internally
     /// using the default conversion (see `layout/conversion.rs` for more information on conversions).
+    /// Buffers that can be downcast into an `InterleavedPointBufferMut` or a `PerAttributePointBufferMut` are transformed directly
+    /// in their memory, all other buffers are transformed through `get_raw_attribute` and `set_raw_attribute`.
     /// # Panics
     /// If no attribute with the name of `attribute` is present in this buffer.
     /// If no conversion between type `T` and the underlying data type of the `attribute` in this buffer can be performed.
@@ -389,9 +916,130 @@ pub trait PointBufferWriteableExt<B: PointBufferWriteable + ?Sized> {
     /// ```
     fn transform_attribute<T: PrimitiveType, F: Fn(usize, &mut T) -> ()>(
         &mut self,
-        attribute_name: &'static str,
+        attribute_name: &str,
         func: F,
     );
+
+    /// Like `transform_attribute`, but transforms the attribute values in parallel. Uses the [`rayon`](https://docs.rs/rayon) crate for
+    /// parallelization. Buffers that can't be downcast into an `InterleavedPointBufferMut` or a `PerAttributePointBufferMut` are
+    /// transformed sequentially
+    /// # Panics
+    /// If no attribute with the name of `attribute` is present in this buffer.
+    /// If no conversion between type `T` and the underlying data type of the `attribute` in this buffer can be performed.
+    fn par_transform_attribute<T: PrimitiveType, F: Fn(usize, &mut T) + Sync + Send>(
+        &mut self,
+        attribute_name: &str,
+        func: F,
+    );
+
+    /// Applies the given transformation `func` to the values of multiple attributes of all points in the buffer. The values of the
+    /// attributes with the given `attribute_names` are passed to `func` as a tuple, so that the new value of an attribute can depend on
+    /// the values of the other attributes. Like `transform_attribute`, this performs data type conversions between the elements of
+    /// the tuple `T` and the attributes in the buffer
+    /// # Panics
+    /// If the number of `attribute_names` does not match the number of elements of `T`, or if an attribute name is given more than once.
+    /// If no attribute with one of the `attribute_names` is present in this buffer.
+    /// If no conversion between an element of `T` and the underlying data type of the corresponding attribute can be performed.
+    /// # Examples
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// # use pasture_core::nalgebra::Vector3;
+    /// let layout = PointLayout::from_attributes(&[attributes::POSITION_3D, attributes::CLASSIFICATION]);
+    /// let mut storage = InterleavedVecPointStorage::new(layout);
+    /// storage.resize(2);
+    /// storage.set_attribute(&attributes::CLASSIFICATION, 1, 2_u8);
+    ///
+    /// // Move all ground points (classification 2) down
+    /// storage.transform_attributes(
+    ///     &[attributes::POSITION_3D.name(), attributes::CLASSIFICATION.name()],
+    ///     |_index, (position, classification): &mut (Vector3<f64>, u8)| {
+    ///         if *classification == 2 {
+    ///             position.z -= 1.0;
+    ///         }
+    ///     },
+    /// );
+    /// assert_eq!(Vector3::new(0.0, 0.0, 0.0), storage.get_attribute::<Vector3<f64>>(&attributes::POSITION_3D, 0));
+    /// assert_eq!(Vector3::new(0.0, 0.0, -1.0), storage.get_attribute::<Vector3<f64>>(&attributes::POSITION_3D, 1));
+    /// ```
+    fn transform_attributes<T: PrimitiveTypeTuple, F: Fn(usize, &mut T)>(
+        &mut self,
+        attribute_names: &[&str],
+        func: F,
+    );
+
+    /// Like `transform_attributes`, but transforms the attribute values in parallel. Uses the [`rayon`](https://docs.rs/rayon) crate
+    /// for parallelization. Buffers that can't be downcast into an `InterleavedPointBufferMut` or a `PerAttributePointBufferMut` are
+    /// transformed sequentially
+    /// # Panics
+    /// If the number of `attribute_names` does not match the number of elements of `T`, or if an attribute name is given more than once.
+    /// If no attribute with one of the `attribute_names` is present in this buffer.
+    /// If no conversion between an element of `T` and the underlying data type of the corresponding attribute can be performed.
+    fn par_transform_attributes<T: PrimitiveTypeTuple, F: Fn(usize, &mut T) + Sync + Send>(
+        &mut self,
+        attribute_names: &[&str],
+        func: F,
+    );
+
+    /// Retains only the points for which `predicate` returns `true`, strongly typed to the `PointType` `T`. The order of the
+    /// remaining points is preserved
+    /// # Panics
+    /// If the `PointLayout` of `T` does not match the `PointLayout` of this buffer.
+    /// # Examples
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// # use pasture_derive::PointType;
+    ///
+    /// #[repr(C)]
+    /// #[derive(PointType, Debug, PartialEq, Eq)]
+    /// struct MyPointType(#[pasture(BUILTIN_INTENSITY)] u16);
+    ///
+    /// let mut storage = InterleavedVecPointStorage::new(MyPointType::layout());
+    /// storage.push_points(&[MyPointType(1), MyPointType(2), MyPointType(3), MyPointType(4)]);
+    /// storage.retain(|point: &MyPointType| point.0 % 2 == 0);
+    /// assert_eq!(vec![MyPointType(2), MyPointType(4)], storage.iter_point::<MyPointType>().collect::<Vec<_>>());
+    /// ```
+    fn retain<T: PointType, F: FnMut(&T) -> bool>(&mut self, predicate: F);
+
+    /// Retains only the points for which `predicate` returns `true`. The points are passed to `predicate` as `UntypedPoint`s,
+    /// which is useful if the `PointLayout` of this buffer is not known at compile time. The order of the remaining points is
+    /// preserved
+    /// # Examples
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// let mut storage = PerAttributeVecPointStorage::new(PointLayout::from_attributes(&[attributes::INTENSITY]));
+    /// storage.resize(3);
+    /// storage.set_attribute(&attributes::INTENSITY, 1, 42_u16);
+    ///
+    /// storage.retain_untyped(|point| point.get_attribute::<u16>(&attributes::INTENSITY).unwrap() == 42);
+    /// assert_eq!(1, storage.len());
+    /// assert_eq!(42, storage.get_attribute::<u16>(&attributes::INTENSITY, 0));
+    /// ```
+    fn retain_untyped<F: FnMut(&UntypedPointSlice<'_>) -> bool>(&mut self, predicate: F);
+
+    /// Removes consecutive points that have the same value for the given `attribute`, keeping the first point of each run.
+    /// Like `Vec::dedup`, this only removes all duplicates if the buffer is sorted by `attribute`
+    /// # Panics
+    /// If the `PointLayout` of this buffer does not contain the given `attribute`.
+    /// If the `PointAttributeDataType` of `attribute` does not match the type `T`.
+    /// # Examples
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// # use pasture_core::nalgebra::Vector3;
+    /// let mut storage = InterleavedVecPointStorage::new(PointLayout::from_attributes(&[attributes::POSITION_3D]));
+    /// storage.resize(3);
+    /// storage.set_attribute(&attributes::POSITION_3D, 2, Vector3::new(1.0, 2.0, 3.0));
+    ///
+    /// storage.dedup_by_attribute::<Vector3<f64>>(&attributes::POSITION_3D);
+    /// assert_eq!(2, storage.len());
+    /// ```
+    fn dedup_by_attribute<T: PrimitiveType + PartialEq>(
+        &mut self,
+        attribute: &PointAttributeDefinition,
+    );
 }
 
 impl<B: PointBufferWriteable + ?Sized> PointBufferWriteableExt<B> for B {
@@ -403,6 +1051,10 @@ impl<B: PointBufferWriteable + ?Sized> PointBufferWriteableExt<B> for B {
         self.set_raw_point(index, point_bytes);
     }
 
+    fn iter_untyped_points_mut(&mut self) -> UntypedPointIteratorMut<'_, B> {
+        UntypedPointIteratorMut::new(self)
+    }
+
     fn set_attribute<T: PrimitiveType>(
         &mut self,
         attribute: &PointAttributeDefinition,
@@ -415,76 +1067,94 @@ impl<B: PointBufferWriteable + ?Sized> PointBufferWriteableExt<B> for B {
 
     fn transform_attribute<T: PrimitiveType, F: Fn(usize, &mut T) -> ()>(
         &mut self,
-        attribute_name: &'static str,
+        attribute_name: &str,
         func: F,
     ) {
-        let source_attribute = PointAttributeDefinition::custom(attribute_name, T::data_type());
-        if let Some(target_attribute) = self.point_layout().get_attribute_by_name(attribute_name) {
-            // It is important that we use 'set_attribute' and 'get_attribute' here. This is the blanket implementation
-            // of 'transform_attribute', so it makes no assumptions on the memory layout of the point data. Since the
-            // attribute data might not be properly aligned, we can't get a mutable reference to the attribute value inside
-            // the buffer, since reading from such a reference will be UB. Only if the buffer is a PerAttribute buffer could
-            // we do this. Unfortunately, we can't implement the trait again for a more specific type, so we would have to
-            // do the test if the underlying buffer is PerAttribute here using a virtual call. We could implement this in
-            // the future, for now it is left here as a TODO
-            if target_attribute.datatype() == source_attribute.datatype() {
-                for point_index in 0..self.len() {
-                    let mut attribute_value: T = self.get_attribute(&source_attribute, point_index);
-                    func(point_index, &mut attribute_value);
-                    self.set_attribute(&source_attribute, point_index, attribute_value);
-                }
-            } else {
-                // If the datatypes are different, we have to convert from and to the target datatype
-                let target_attribute_definition: PointAttributeDefinition = target_attribute.into();
-                if let (Some(convert_to_t), Some(convert_from_t)) = (
-                    get_converter_for_attributes(&target_attribute_definition, &source_attribute),
-                    get_converter_for_attributes(&source_attribute, &target_attribute_definition),
-                ) {
-                    // The following code is a bit involved. Assume that 'U' is the type of the attribute stored in
-                    // the buffer (but we don't know this type at compile-time). Then the process is as follows:
-                    // 1) Get the raw bytes for the value 'U'
-                    // 2) Create a temporary variable of type 'T'
-                    // 3) Convert from 'U' to 'T'
-                    // 4) Apply the transformation function to the 'T' value
-                    // 5) Convert from 'T' to 'U'
-                    // 6) Set the raw bytes of 'U' in the buffer
-                    let mut buffer = vec![0; target_attribute_definition.size() as usize];
-                    for point_index in 0..self.len() {
-                        self.get_raw_attribute(
-                            point_index,
-                            &target_attribute_definition,
-                            buffer.as_mut_slice(),
-                        );
-
-                        let mut as_t = MaybeUninit::<T>::uninit();
-                        let mut as_t = unsafe {
-                            let tmp_value_bytes = std::slice::from_raw_parts_mut(
-                                as_t.as_mut_ptr() as *mut u8,
-                                std::mem::size_of::<T>(),
-                            );
-                            convert_to_t(buffer.as_slice(), tmp_value_bytes);
-                            as_t.assume_init()
-                        };
-
-                        func(point_index, &mut as_t);
-
-                        unsafe {
-                            convert_from_t(view_raw_bytes(&as_t), buffer.as_mut_slice());
-                        }
-
-                        self.set_raw_attribute(
-                            point_index,
-                            &target_attribute_definition,
-                            buffer.as_slice(),
-                        );
-                    }
+        self.transform_attributes(&[attribute_name], |point_index, (value,): &mut (T,)| {
+            func(point_index, value)
+        });
+    }
+
+    fn par_transform_attribute<T: PrimitiveType, F: Fn(usize, &mut T) + Sync + Send>(
+        &mut self,
+        attribute_name: &str,
+        func: F,
+    ) {
+        self.par_transform_attributes(&[attribute_name], |point_index, (value,): &mut (T,)| {
+            func(point_index, value)
+        });
+    }
+
+    fn transform_attributes<T: PrimitiveTypeTuple, F: Fn(usize, &mut T)>(
+        &mut self,
+        attribute_names: &[&str],
+        func: F,
+    ) {
+        let transformation =
+            AttributeTransformation::new::<T>(self.point_layout(), attribute_names);
+        match transformation.split_into_blocks(self) {
+            Some(blocks) => blocks
+                .into_iter()
+                .for_each(|block| transformation.transform_block(block, &func)),
+            None => transformation.transform_point_by_point(self, &func),
+        }
+    }
+
+    fn par_transform_attributes<T: PrimitiveTypeTuple, F: Fn(usize, &mut T) + Sync + Send>(
+        &mut self,
+        attribute_names: &[&str],
+        func: F,
+    ) {
+        let transformation =
+            AttributeTransformation::new::<T>(self.point_layout(), attribute_names);
+        match transformation.split_into_blocks(self) {
+            Some(blocks) => blocks
+                .into_par_iter()
+                .for_each(|block| transformation.transform_block(block, &func)),
+            None => transformation.transform_point_by_point(self, &func),
+        }
+    }
+
+    fn retain<T: PointType, F: FnMut(&T) -> bool>(&mut self, mut predicate: F) {
+        if T::layout() != *self.point_layout() {
+            panic!("PointLayout of type T does not match PointLayout of this buffer");
+        }
+        let keep = self
+            .iter_point::<T>()
+            .map(|point| predicate(&point))
+            .collect::<Vec<_>>();
+        self.retain_by_mask(&keep);
+    }
+
+    fn retain_untyped<F: FnMut(&UntypedPointSlice<'_>) -> bool>(&mut self, mut predicate: F) {
+        let layout = self.point_layout().clone();
+        let mut point = vec![0; layout.size_of_point_entry() as usize];
+        let keep = (0..self.len())
+            .map(|point_index| {
+                self.get_raw_point(point_index, &mut point);
+                predicate(&UntypedPointSlice::new(&layout, &mut point))
+            })
+            .collect::<Vec<_>>();
+        self.retain_by_mask(&keep);
+    }
+
+    fn dedup_by_attribute<T: PrimitiveType + PartialEq>(
+        &mut self,
+        attribute: &PointAttributeDefinition,
+    ) {
+        let mut last_retained_value = None;
+        let keep = self
+            .iter_attribute::<T>(attribute)
+            .map(|value| {
+                if last_retained_value.as_ref() == Some(&value) {
+                    false
                 } else {
-                    panic!("No conversion possible between requested attribute {} and attribute in buffer {}", source_attribute, target_attribute_definition);
+                    last_retained_value = Some(value);
+                    true
                 }
-            }
-        } else {
-            panic!("attribute not found in PointLayout of this buffer");
-        }
+            })
+            .collect::<Vec<_>>();
+        self.retain_by_mask(&keep);
     }
 }
 
@@ -508,6 +1178,73 @@ pub trait InterleavedPointBufferExt {
     ///
     /// Panics if the associated `InterleavedPointBuffer` does not store points with type `T`
     fn iter_point_ref<T: PointType>(&self) -> PointIteratorByRef<'_, T>;
+    /// Parallel version of [`iter_point_ref`](InterleavedPointBufferExt::iter_point_ref), using the [`rayon`](https://docs.rs/rayon) crate
+    ///
+    /// # Panics
+    ///
+    /// Panics if the associated `InterleavedPointBuffer` does not store points with type `T`
+    fn par_iter_point_ref<T: PointType + Sync>(&self) -> rayon::slice::Iter<'_, T>;
+    /// Returns a matrix view of the given `attribute` for all points in the associated `InterleavedPointBuffer`. The
+    /// view has one row per point and one column per component of `attribute`, its scalar values are of type `T`. The
+    /// rows of the view are strided by the size of a single point, so no data is copied
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// # use pasture_core::nalgebra::Vector3;
+    /// # use pasture_derive::PointType;
+    /// #[derive(PointType, Copy, Clone)]
+    /// #[repr(C)]
+    /// struct SimplePoint {
+    ///     #[pasture(BUILTIN_POSITION_3D)]
+    ///     pub position: Vector3<f64>,
+    ///     #[pasture(BUILTIN_INTENSITY)]
+    ///     pub intensity: u16,
+    /// }
+    ///
+    /// let mut buffer = InterleavedVecPointStorage::new(SimplePoint::layout());
+    /// buffer.push_points(&[
+    ///     SimplePoint { position: Vector3::new(1.0, 2.0, 3.0), intensity: 42 },
+    ///     SimplePoint { position: Vector3::new(4.0, 5.0, 6.0), intensity: 43 },
+    /// ]);
+    /// let positions = buffer.attribute_matrix_view::<f64>(&attributes::POSITION_3D);
+    /// assert_eq!((2, 3), positions.shape());
+    /// assert_eq!(5.0, positions[(1, 1)]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `attribute` is not part of the `PointLayout` of the buffer or is a bit field.
+    /// Panics if `T` is not the scalar type of `attribute`.
+    /// Panics if the memory of the buffer is not aligned to `T`, or if the size of a point is not a multiple of the size of `T`
+    fn attribute_matrix_view<T: PrimitiveType + Scalar>(
+        &self,
+        attribute: &PointAttributeDefinition,
+    ) -> AttributeMatrixView<'_, T>;
+    /// Like [`attribute_matrix_view`](InterleavedPointBufferExt::attribute_matrix_view), but returns an `ndarray` view with
+    /// one row per point and one column per component of `attribute`. Requires the `ndarray` feature
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// let mut buffer = InterleavedVecPointStorage::new(PointLayout::from_attributes(&[
+    ///     attributes::POSITION_3D,
+    ///     attributes::INTENSITY,
+    /// ]));
+    /// buffer.resize(10);
+    ///
+    /// let positions = buffer.attribute_ndarray_view::<f64>(&attributes::POSITION_3D);
+    /// assert_eq!(&[10, 3], positions.shape());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `attribute` is not part of the `PointLayout` of the buffer or is a bit field.
+    /// Panics if `T` is not the scalar type of `attribute`.
+    /// Panics if the memory of the buffer is not aligned to `T`, or if the size of a point is not a multiple of the size of `T`
+    #[cfg(feature = "ndarray")]
+    fn attribute_ndarray_view<T: PrimitiveType>(
+        &self,
+        attribute: &PointAttributeDefinition,
+    ) -> ndarray::ArrayView2<'_, T>;
 }
 
 impl<B: InterleavedPointBuffer + ?Sized> InterleavedPointBufferExt for B {
@@ -528,6 +1265,64 @@ impl<B: InterleavedPointBuffer + ?Sized> InterleavedPointBufferExt for B {
     fn iter_point_ref<T: PointType>(&self) -> PointIteratorByRef<'_, T> {
         PointIteratorByRef::new(self)
     }
+
+    fn par_iter_point_ref<T: PointType + Sync>(&self) -> rayon::slice::Iter<'_, T> {
+        if *self.point_layout() != T::layout() {
+            panic!("PointLayout of type T does not match PointLayout of buffer (buffer layout: {}, T layout: {})", self.point_layout(), T::layout());
+        }
+        self.get_points_ref::<T>(0..self.len()).par_iter()
+    }
+
+    fn attribute_matrix_view<T: PrimitiveType + Scalar>(
+        &self,
+        attribute: &PointAttributeDefinition,
+    ) -> AttributeMatrixView<'_, T> {
+        let offset = offset_of_strided_attribute(self.point_layout(), attribute);
+        let stride = self.point_layout().size_of_point_entry() as usize;
+        let raw_points = self.get_raw_points_ref(0..self.len());
+        attribute_matrix_view(
+            &raw_points[offset.min(raw_points.len())..],
+            self.len(),
+            stride,
+            attribute,
+        )
+    }
+
+    #[cfg(feature = "ndarray")]
+    fn attribute_ndarray_view<T: PrimitiveType>(
+        &self,
+        attribute: &PointAttributeDefinition,
+    ) -> ndarray::ArrayView2<'_, T> {
+        let offset = offset_of_strided_attribute(self.point_layout(), attribute);
+        let stride = self.point_layout().size_of_point_entry() as usize;
+        let raw_points = self.get_raw_points_ref(0..self.len());
+        attribute_ndarray_view(
+            &raw_points[offset.min(raw_points.len())..],
+            self.len(),
+            stride,
+            attribute,
+        )
+    }
+}
+
+/// Returns the offset of `attribute` within the points of `layout` for matrix views of Interleaved buffers
+fn offset_of_strided_attribute(
+    layout: &PointLayout,
+    attribute: &PointAttributeDefinition,
+) -> usize {
+    let member = layout.get_attribute(attribute).unwrap_or_else(|| {
+        panic!(
+            "Attribute {} is not part of the PointLayout of the buffer",
+            attribute
+        )
+    });
+    if member.bit_field().is_some() {
+        panic!(
+            "Matrix views of bit field attribute {} are not supported",
+            member
+        );
+    }
+    member.offset() as usize
 }
 
 /// Extension trait that provides generic methods for accessing point data in an `InterleavedPointBufferMut`
@@ -550,6 +1345,62 @@ pub trait InterleavedPointBufferMutExt {
     ///
     /// Panics if the associated `InterleavedPointBuffer` does not store points with type `T`
     fn iter_point_mut<T: PointType>(&mut self) -> PointIteratorByMut<'_, T>;
+    /// Parallel version of [`iter_point_mut`](InterleavedPointBufferMutExt::iter_point_mut), using the [`rayon`](https://docs.rs/rayon) crate
+    ///
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// # use pasture_core::nalgebra::Vector3;
+    /// # use pasture_derive::PointType;
+    /// use rayon::prelude::*;
+    ///
+    /// #[derive(PointType, Copy, Clone)]
+    /// #[repr(C)]
+    /// struct SimplePoint {
+    ///     #[pasture(BUILTIN_POSITION_3D)]
+    ///     pub position: Vector3<f64>,
+    ///     #[pasture(BUILTIN_INTENSITY)]
+    ///     pub intensity: u16,
+    /// }
+    ///
+    /// let mut buffer = InterleavedVecPointStorage::new(SimplePoint::layout());
+    /// buffer.resize(1000);
+    /// buffer
+    ///     .par_iter_point_mut::<SimplePoint>()
+    ///     .enumerate()
+    ///     .for_each(|(index, point)| point.position.x = index as f64);
+    ///
+    /// assert_eq!(999.0, buffer.get_point_ref::<SimplePoint>(999).position.x);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the associated `InterleavedPointBuffer` does not store points with type `T`
+    fn par_iter_point_mut<T: PointType + Send>(&mut self) -> rayon::slice::IterMut<'_, T>;
+    /// Mutable version of [`attribute_matrix_view`](InterleavedPointBufferExt::attribute_matrix_view)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `attribute` is not part of the `PointLayout` of the buffer or is a bit field.
+    /// Panics if `T` is not the scalar type of `attribute`.
+    /// Panics if the memory of the buffer is not aligned to `T`, or if the size of a point is not a multiple of the size of `T`
+    fn attribute_matrix_view_mut<T: PrimitiveType + Scalar>(
+        &mut self,
+        attribute: &PointAttributeDefinition,
+    ) -> AttributeMatrixViewMut<'_, T>;
+    /// Mutable version of [`attribute_ndarray_view`](InterleavedPointBufferExt::attribute_ndarray_view). Requires the
+    /// `ndarray` feature
+    ///
+    /// # Panics
+    ///
+    /// Panics if `attribute` is not part of the `PointLayout` of the buffer or is a bit field.
+    /// Panics if `T` is not the scalar type of `attribute`.
+    /// Panics if the memory of the buffer is not aligned to `T`, or if the size of a point is not a multiple of the size of `T`
+    #[cfg(feature = "ndarray")]
+    fn attribute_ndarray_view_mut<T: PrimitiveType>(
+        &mut self,
+        attribute: &PointAttributeDefinition,
+    ) -> ndarray::ArrayViewMut2<'_, T>;
 }
 
 impl<B: InterleavedPointBufferMut + ?Sized> InterleavedPointBufferMutExt for B {
@@ -570,6 +1421,39 @@ impl<B: InterleavedPointBufferMut + ?Sized> InterleavedPointBufferMutExt for B {
     fn iter_point_mut<T: PointType>(&mut self) -> PointIteratorByMut<'_, T> {
         PointIteratorByMut::new(self)
     }
+
+    fn par_iter_point_mut<T: PointType + Send>(&mut self) -> rayon::slice::IterMut<'_, T> {
+        if *self.point_layout() != T::layout() {
+            panic!("PointLayout of type T does not match PointLayout of buffer (buffer layout: {}, T layout: {})", self.point_layout(), T::layout());
+        }
+        let len = self.len();
+        self.get_points_mut::<T>(0..len).par_iter_mut()
+    }
+
+    fn attribute_matrix_view_mut<T: PrimitiveType + Scalar>(
+        &mut self,
+        attribute: &PointAttributeDefinition,
+    ) -> AttributeMatrixViewMut<'_, T> {
+        let offset = offset_of_strided_attribute(self.point_layout(), attribute);
+        let stride = self.point_layout().size_of_point_entry() as usize;
+        let len = self.len();
+        let raw_points = self.get_raw_points_mut(0..len);
+        let offset = offset.min(raw_points.len());
+        attribute_matrix_view_mut(&mut raw_points[offset..], len, stride, attribute)
+    }
+
+    #[cfg(feature = "ndarray")]
+    fn attribute_ndarray_view_mut<T: PrimitiveType>(
+        &mut self,
+        attribute: &PointAttributeDefinition,
+    ) -> ndarray::ArrayViewMut2<'_, T> {
+        let offset = offset_of_strided_attribute(self.point_layout(), attribute);
+        let stride = self.point_layout().size_of_point_entry() as usize;
+        let len = self.len();
+        let raw_points = self.get_raw_points_mut(0..len);
+        let offset = offset.min(raw_points.len());
+        attribute_ndarray_view_mut(&mut raw_points[offset..], len, stride, attribute)
+    }
 }
 
 /// Extension trait that provides generic methods for accessing attribute data in an `PerAttributePointBuffer`
@@ -603,6 +1487,68 @@ pub trait PerAttributePointBufferExt {
         &'a self,
         attribute: &'a PointAttributeDefinition,
     ) -> AttributeIteratorByRef<'a, T>;
+    /// Parallel version of [`iter_attribute_ref`](PerAttributePointBufferExt::iter_attribute_ref), using the [`rayon`](https://docs.rs/rayon)
+    /// crate. For iterating over multiple attributes at once, use the [par_attributes_ref!] macro.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `attribute` is not part of the `PointLayout` of the buffer.
+    /// Panics if the `attribute` inside the buffer is not stored as type `T`.
+    fn par_iter_attribute_ref<'a, T: PrimitiveType>(
+        &'a self,
+        attribute: &PointAttributeDefinition,
+    ) -> rayon::slice::Iter<'a, T>;
+    /// Returns a matrix view of the given `attribute` for all points in the associated `PerAttributePointBuffer`. The
+    /// view has one row per point and one column per component of `attribute`, its scalar values are of type `T`.
+    /// No data is copied, so this is the fastest way to pass attribute data to linear algebra routines
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// # use pasture_core::nalgebra::Vector3;
+    /// let mut buffer = PerAttributeVecPointStorage::new(PointLayout::from_attributes(&[attributes::POSITION_3D]));
+    /// buffer.resize(10);
+    /// buffer.set_attribute(&attributes::POSITION_3D, 9, Vector3::new(1.0, 2.0, 3.0));
+    ///
+    /// let positions = buffer.attribute_matrix_view::<f64>(&attributes::POSITION_3D);
+    /// assert_eq!((10, 3), positions.shape());
+    /// let centroid = positions.row_mean();
+    /// assert_eq!(0.3, centroid[2]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `attribute` is not part of the `PointLayout` of the buffer.
+    /// Panics if `T` is not the scalar type of `attribute`.
+    /// Panics if the memory of the attribute is not aligned to `T`
+    fn attribute_matrix_view<T: PrimitiveType + Scalar>(
+        &self,
+        attribute: &PointAttributeDefinition,
+    ) -> AttributeMatrixView<'_, T>;
+    /// Like [`attribute_matrix_view`](PerAttributePointBufferExt::attribute_matrix_view), but returns an `ndarray` view with
+    /// one row per point and one column per component of `attribute`. Requires the `ndarray` feature
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// # use pasture_core::nalgebra::Vector3;
+    /// let mut buffer = PerAttributeVecPointStorage::new(PointLayout::from_attributes(&[attributes::POSITION_3D]));
+    /// buffer.resize(10);
+    /// buffer.set_attribute(&attributes::POSITION_3D, 9, Vector3::new(1.0, 2.0, 3.0));
+    ///
+    /// let positions = buffer.attribute_ndarray_view::<f64>(&attributes::POSITION_3D);
+    /// assert_eq!(&[10, 3], positions.shape());
+    /// assert_eq!(3.0, positions[[9, 2]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `attribute` is not part of the `PointLayout` of the buffer.
+    /// Panics if `T` is not the scalar type of `attribute`.
+    /// Panics if the memory of the attribute is not aligned to `T`
+    #[cfg(feature = "ndarray")]
+    fn attribute_ndarray_view<T: PrimitiveType>(
+        &self,
+        attribute: &PointAttributeDefinition,
+    ) -> ndarray::ArrayView2<'_, T>;
 }
 
 impl<B: PerAttributePointBuffer + ?Sized> PerAttributePointBufferExt for B {
@@ -634,6 +1580,44 @@ impl<B: PerAttributePointBuffer + ?Sized> PerAttributePointBufferExt for B {
     ) -> AttributeIteratorByRef<'a, T> {
         AttributeIteratorByRef::new(self, attribute)
     }
+
+    fn par_iter_attribute_ref<'a, T: PrimitiveType>(
+        &'a self,
+        attribute: &PointAttributeDefinition,
+    ) -> rayon::slice::Iter<'a, T> {
+        if attribute.datatype() != T::data_type() {
+            panic!("Type T does not match datatype of attribute {}", attribute);
+        }
+        self.get_attribute_range_ref::<T>(0..self.len(), attribute)
+            .par_iter()
+    }
+
+    fn attribute_matrix_view<T: PrimitiveType + Scalar>(
+        &self,
+        attribute: &PointAttributeDefinition,
+    ) -> AttributeMatrixView<'_, T> {
+        let raw_attributes = self.get_raw_attribute_range_ref(0..self.len(), attribute);
+        attribute_matrix_view(
+            raw_attributes,
+            self.len(),
+            attribute.size() as usize,
+            attribute,
+        )
+    }
+
+    #[cfg(feature = "ndarray")]
+    fn attribute_ndarray_view<T: PrimitiveType>(
+        &self,
+        attribute: &PointAttributeDefinition,
+    ) -> ndarray::ArrayView2<'_, T> {
+        let raw_attributes = self.get_raw_attribute_range_ref(0..self.len(), attribute);
+        attribute_ndarray_view(
+            raw_attributes,
+            self.len(),
+            attribute.size() as usize,
+            attribute,
+        )
+    }
 }
 
 /// Extension trait that provides generic methods for accessing attribute data in an `PerAttributePointBufferMut`
@@ -667,6 +1651,55 @@ pub trait PerAttributePointBufferMutExt {
         &'a mut self,
         attribute: &'a PointAttributeDefinition,
     ) -> AttributeIteratorByMut<'a, T>;
+    /// Parallel version of [`iter_attribute_mut`](PerAttributePointBufferMutExt::iter_attribute_mut), using the
+    /// [`rayon`](https://docs.rs/rayon) crate. For iterating over multiple attributes at once, use the [par_attributes_mut!] macro.
+    ///
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut buffer = PerAttributeVecPointStorage::new(PointLayout::from_attributes(&[attributes::INTENSITY]));
+    /// buffer.resize(1000);
+    /// buffer
+    ///     .par_iter_attribute_mut::<u16>(&attributes::INTENSITY)
+    ///     .for_each(|intensity| *intensity = 42);
+    ///
+    /// assert_eq!(42, buffer.get_attribute::<u16>(&attributes::INTENSITY, 999));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `attribute` is not part of the `PointLayout` of the buffer.
+    /// Panics if the `attribute` inside the buffer is not stored as type `T`.
+    fn par_iter_attribute_mut<'a, T: PrimitiveType>(
+        &'a mut self,
+        attribute: &PointAttributeDefinition,
+    ) -> rayon::slice::IterMut<'a, T>;
+    /// Mutable version of [`attribute_matrix_view`](PerAttributePointBufferExt::attribute_matrix_view)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `attribute` is not part of the `PointLayout` of the buffer.
+    /// Panics if `T` is not the scalar type of `attribute`.
+    /// Panics if the memory of the attribute is not aligned to `T`
+    fn attribute_matrix_view_mut<T: PrimitiveType + Scalar>(
+        &mut self,
+        attribute: &PointAttributeDefinition,
+    ) -> AttributeMatrixViewMut<'_, T>;
+    /// Mutable version of [`attribute_ndarray_view`](PerAttributePointBufferExt::attribute_ndarray_view). Requires the
+    /// `ndarray` feature
+    ///
+    /// # Panics
+    ///
+    /// Panics if `attribute` is not part of the `PointLayout` of the buffer.
+    /// Panics if `T` is not the scalar type of `attribute`.
+    /// Panics if the memory of the attribute is not aligned to `T`
+    #[cfg(feature = "ndarray")]
+    fn attribute_ndarray_view_mut<T: PrimitiveType>(
+        &mut self,
+        attribute: &PointAttributeDefinition,
+    ) -> ndarray::ArrayViewMut2<'_, T>;
 }
 
 impl<'b, B: PerAttributePointBufferMut<'b> + ?Sized> PerAttributePointBufferMutExt for B {
@@ -698,4 +1731,35 @@ impl<'b, B: PerAttributePointBufferMut<'b> + ?Sized> PerAttributePointBufferMutE
     ) -> AttributeIteratorByMut<'a, T> {
         AttributeIteratorByMut::new(self, attribute)
     }
+
+    fn par_iter_attribute_mut<'a, T: PrimitiveType>(
+        &'a mut self,
+        attribute: &PointAttributeDefinition,
+    ) -> rayon::slice::IterMut<'a, T> {
+        if attribute.datatype() != T::data_type() {
+            panic!("Type T does not match datatype of attribute {}", attribute);
+        }
+        let len = self.len();
+        self.get_attribute_range_mut::<T>(0..len, attribute)
+            .par_iter_mut()
+    }
+
+    fn attribute_matrix_view_mut<T: PrimitiveType + Scalar>(
+        &mut self,
+        attribute: &PointAttributeDefinition,
+    ) -> AttributeMatrixViewMut<'_, T> {
+        let len = self.len();
+        let raw_attributes = self.get_raw_attribute_range_mut(0..len, attribute);
+        attribute_matrix_view_mut(raw_attributes, len, attribute.size() as usize, attribute)
+    }
+
+    #[cfg(feature = "ndarray")]
+    fn attribute_ndarray_view_mut<T: PrimitiveType>(
+        &mut self,
+        attribute: &PointAttributeDefinition,
+    ) -> ndarray::ArrayViewMut2<'_, T> {
+        let len = self.len();
+        let raw_attributes = self.get_raw_attribute_range_mut(0..len, attribute);
+        attribute_ndarray_view_mut(raw_attributes, len, attribute.size() as usize, attribute)
+    }
 }
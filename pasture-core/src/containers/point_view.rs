@@ -127,12 +127,9 @@ impl<'d> PointBuffer for InterleavedPointView<'d> {
 
         if let Some(attribute_in_buffer) = self.point_layout.get_attribute(attribute) {
             let offset_to_point_bytes = point_index * self.size_of_point_entry as usize;
-            let offset_to_attribute = offset_to_point_bytes + attribute_in_buffer.offset() as usize;
-            let attribute_size = attribute.size() as usize;
-
-            buf.copy_from_slice(
-                &self.point_data[offset_to_attribute..offset_to_attribute + attribute_size],
-            );
+            let point_record = &self.point_data
+                [offset_to_point_bytes..offset_to_point_bytes + self.size_of_point_entry];
+            attribute_in_buffer.read_from_point_record(point_record, buf);
         } else {
             panic!("InterleavedPointView::get_raw_attribute: Attribute {:?} is not part of this PointBuffer's PointLayout!", attribute);
         }
@@ -162,15 +159,13 @@ impl<'d> PointBuffer for InterleavedPointView<'d> {
 
             for point_index in index_range {
                 let offset_to_point_bytes = point_index * self.size_of_point_entry as usize;
-                let offset_to_attribute =
-                    offset_to_point_bytes + attribute_in_buffer.offset() as usize;
+                let point_record = &self.point_data
+                    [offset_to_point_bytes..offset_to_point_bytes + self.size_of_point_entry];
                 let offset_in_target_buf = (point_index - start_index) * attribute_size;
                 let target_buf_slice =
                     &mut buf[offset_in_target_buf..offset_in_target_buf + attribute_size];
 
-                target_buf_slice.copy_from_slice(
-                    &self.point_data[offset_to_attribute..offset_to_attribute + attribute_size],
-                );
+                attribute_in_buffer.read_from_point_record(point_record, target_buf_slice);
             }
         } else {
             panic!("InterleavedPointView::get_raw_attribute: Attribute {:?} is not part of this PointBuffer's PointLayout!", attribute);
@@ -480,12 +475,10 @@ impl<'d> PointBuffer for PerAttributePointView<'d> {
             let attribute_buffer = self.point_data[idx];
             let attribute_size = attribute.size() as usize;
             let offset_in_buffer = point_index * attribute_size;
-            let offset_in_point = attribute.offset() as usize;
 
-            let buf_slice = &mut buf[offset_in_point..offset_in_point + attribute_size];
             let attribute_slice =
                 &attribute_buffer[offset_in_buffer..offset_in_buffer + attribute_size];
-            buf_slice.copy_from_slice(attribute_slice);
+            attribute.write_to_point_record(attribute_slice, buf);
         }
     }
 
@@ -518,12 +511,11 @@ impl<'d> PointBuffer for PerAttributePointView<'d> {
                 let attribute_slice = &attribute_buffer
                     [offset_in_attribute_buffer..offset_in_attribute_buffer + attribute_size];
 
-                let offset_in_point = attribute.offset() as usize;
-                let offset_in_points_buffer = point_index * point_size + offset_in_point;
-                let buf_slice =
-                    &mut buf[offset_in_points_buffer..offset_in_points_buffer + attribute_size];
+                let offset_in_points_buffer = point_index * point_size;
+                let point_slice =
+                    &mut buf[offset_in_points_buffer..offset_in_points_buffer + point_size];
 
-                buf_slice.copy_from_slice(attribute_slice);
+                attribute.write_to_point_record(attribute_slice, point_slice);
             }
         }
     }
@@ -0,0 +1,540 @@
+use std::{collections::HashMap, fmt};
+
+use serde::{
+    de::{self, Error as _, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::layout::{PointAttributeDataType, PointLayout};
+
+use super::{
+    InterleavedPointBuffer, InterleavedVecPointStorage, PerAttributePointBuffer,
+    PerAttributeVecPointStorage, PointBuffer,
+};
+
+/// Byte order of the raw point data in a serialized point buffer
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+enum Endianness {
+    Little,
+    Big,
+}
+
+impl Endianness {
+    fn native() -> Self {
+        if cfg!(target_endian = "little") {
+            Endianness::Little
+        } else {
+            Endianness::Big
+        }
+    }
+}
+
+/// Raw memory that is serialized as a single byte string, so that compact formats such as bincode store it without
+/// any overhead per byte
+struct RawBytes<'a>(&'a [u8]);
+
+impl Serialize for RawBytes<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+/// Owned version of `RawBytes` for deserialization
+struct ByteBuf(Vec<u8>);
+
+impl<'de> Deserialize<'de> for ByteBuf {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ByteBufVisitor;
+
+        impl<'de> Visitor<'de> for ByteBufVisitor {
+            type Value = ByteBuf;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a byte array")
+            }
+
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                Ok(ByteBuf(v.to_vec()))
+            }
+
+            fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+                Ok(ByteBuf(v))
+            }
+
+            // Self-describing formats such as JSON store byte strings as sequences of numbers
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(byte) = seq.next_element()? {
+                    bytes.push(byte);
+                }
+                Ok(ByteBuf(bytes))
+            }
+        }
+
+        deserializer.deserialize_byte_buf(ByteBufVisitor)
+    }
+}
+
+/// Reverses the byte order of all scalar components of the given `value` of type `datatype`
+fn swap_endianness_of_value(datatype: PointAttributeDataType, value: &mut [u8]) {
+    match datatype {
+        // The validity byte and the padding in front of the value have no byte order
+        PointAttributeDataType::Nullable(value_type) => {
+            let value_type = value_type.datatype();
+            let value_offset = value.len() - value_type.size() as usize;
+            swap_endianness_of_value(value_type, &mut value[value_offset..]);
+        }
+        // Bool and ByteArray values have no byte order
+        _ => {
+            if let Some(scalar_type) = datatype.scalar_type() {
+                for component in value.chunks_exact_mut(scalar_type.size() as usize) {
+                    component.reverse();
+                }
+            }
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename = "InterleavedVecPointStorage")]
+struct SerializedInterleavedStorage<'a> {
+    layout: &'a PointLayout,
+    endianness: Endianness,
+    points: RawBytes<'a>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "InterleavedVecPointStorage")]
+struct UncheckedInterleavedStorage {
+    layout: PointLayout,
+    endianness: Endianness,
+    points: ByteBuf,
+}
+
+/// `InterleavedVecPointStorage` is serialized as its `PointLayout` together with the raw memory of all points and the
+/// byte order of this memory. Deserializing on a machine with a different byte order converts all attributes (except
+/// for bit fields) into the native byte order
+impl Serialize for InterleavedVecPointStorage {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializedInterleavedStorage {
+            layout: self.point_layout(),
+            endianness: Endianness::native(),
+            points: RawBytes(self.get_raw_points_ref(0..self.len())),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for InterleavedVecPointStorage {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let UncheckedInterleavedStorage {
+            layout,
+            endianness,
+            points: ByteBuf(mut points),
+        } = UncheckedInterleavedStorage::deserialize(deserializer)?;
+
+        let size_of_point_entry = layout.size_of_point_entry() as usize;
+        // Points of an empty layout have no data, so the only valid data is no data
+        let is_valid_size = if size_of_point_entry == 0 {
+            points.is_empty()
+        } else {
+            points.len().is_multiple_of(size_of_point_entry)
+        };
+        if !is_valid_size {
+            return Err(D::Error::custom(format!(
+                "Size of point data ({} bytes) is no multiple of the size of a point ({} bytes)",
+                points.len(),
+                size_of_point_entry
+            )));
+        }
+
+        if endianness != Endianness::native() && size_of_point_entry > 0 {
+            for point in points.chunks_exact_mut(size_of_point_entry) {
+                for attribute in layout.attributes() {
+                    if attribute.bit_field().is_some() {
+                        continue;
+                    }
+                    let offset = attribute.offset() as usize;
+                    swap_endianness_of_value(
+                        attribute.datatype(),
+                        &mut point[offset..offset + attribute.size() as usize],
+                    );
+                }
+            }
+        }
+
+        Ok(InterleavedVecPointStorage::from_raw_points(layout, points))
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename = "PerAttributeVecPointStorage")]
+struct SerializedPerAttributeStorage<'a> {
+    layout: &'a PointLayout,
+    endianness: Endianness,
+    attributes: Vec<RawBytes<'a>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "PerAttributeVecPointStorage")]
+struct UncheckedPerAttributeStorage {
+    layout: PointLayout,
+    endianness: Endianness,
+    attributes: Vec<ByteBuf>,
+}
+
+/// `PerAttributeVecPointStorage` is serialized as its `PointLayout` together with the raw memory of each attribute, in
+/// the order of the attributes within the `PointLayout`, and the byte order of this memory. Deserializing on a machine
+/// with a different byte order converts all attributes into the native byte order
+impl Serialize for PerAttributeVecPointStorage {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let len = self.len();
+        let attributes = self
+            .point_layout()
+            .attributes()
+            .map(|attribute| RawBytes(self.get_raw_attribute_range_ref(0..len, &attribute.into())))
+            .collect();
+        SerializedPerAttributeStorage {
+            layout: self.point_layout(),
+            endianness: Endianness::native(),
+            attributes,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PerAttributeVecPointStorage {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let UncheckedPerAttributeStorage {
+            layout,
+            endianness,
+            attributes,
+        } = UncheckedPerAttributeStorage::deserialize(deserializer)?;
+
+        let num_attributes = layout.attributes().count();
+        if attributes.len() != num_attributes {
+            return Err(D::Error::custom(format!(
+                "Expected data for {} attributes, but got {}",
+                num_attributes,
+                attributes.len()
+            )));
+        }
+
+        // The number of points is determined by the first attribute, all other attributes must store the same number
+        // of points
+        let mut num_points = None;
+        let mut attribute_buffers = HashMap::with_capacity(num_attributes);
+        for (attribute, ByteBuf(mut data)) in layout.attributes().zip(attributes) {
+            let size = attribute.size() as usize;
+            if size == 0 {
+                return Err(D::Error::custom(format!(
+                    "Attribute {} has a size of zero",
+                    attribute
+                )));
+            }
+            if !data.len().is_multiple_of(size) {
+                return Err(D::Error::custom(format!(
+                    "Data of attribute {} has {} bytes, which is no multiple of the size of the attribute ({} bytes)",
+                    attribute,
+                    data.len(),
+                    size
+                )));
+            }
+            let num_points_of_attribute = data.len() / size;
+            match num_points {
+                None => num_points = Some(num_points_of_attribute),
+                Some(num_points) if num_points != num_points_of_attribute => {
+                    return Err(D::Error::custom(format!(
+                        "Data of attribute {} contains {} points, but the previous attributes contain {} points",
+                        attribute, num_points_of_attribute, num_points
+                    )));
+                }
+                Some(_) => {}
+            }
+            if endianness != Endianness::native() {
+                for value in data.chunks_exact_mut(size) {
+                    swap_endianness_of_value(attribute.datatype(), value);
+                }
+            }
+            attribute_buffers.insert(attribute.name().to_owned(), data);
+        }
+
+        Ok(PerAttributeVecPointStorage::from_attribute_buffers(
+            layout,
+            attribute_buffers,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        containers::{InterleavedPointBufferMut, PointBufferExt, PointBufferWriteable},
+        layout::{attributes, Nullable, PointType},
+    };
+    use nalgebra::Vector3;
+    use pasture_derive::PointType;
+
+    // We need this, otherwise we can't use the derive(PointType) macro from within pasture_core because the macro
+    // doesn't recognize the name 'pasture_core' :/
+    use crate as pasture_core;
+
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone, PartialEq, PointType)]
+    struct SerdeTestPoint {
+        #[pasture(BUILTIN_POSITION_3D)]
+        pub position: Vector3<f64>,
+        #[pasture(BUILTIN_INTENSITY)]
+        pub intensity: u16,
+        #[pasture(BUILTIN_CLASSIFICATION)]
+        pub classification: u8,
+        #[pasture(BUILTIN_GPS_TIME)]
+        pub gps_time: Nullable<f64>,
+    }
+
+    fn test_points() -> Vec<SerdeTestPoint> {
+        (0..20)
+            .map(|index| SerdeTestPoint {
+                position: Vector3::new(index as f64, index as f64 + 0.5, -(index as f64)),
+                intensity: index as u16 * 257,
+                classification: index as u8,
+                gps_time: if index % 3 == 0 {
+                    Nullable::none()
+                } else {
+                    Nullable::some(index as f64 * 1.5)
+                },
+            })
+            .collect()
+    }
+
+    fn swap_point(point: &SerdeTestPoint) -> Vec<u8> {
+        let mut storage = InterleavedVecPointStorage::new(SerdeTestPoint::layout());
+        storage.push_point(*point);
+        let mut raw_point = storage.get_raw_point_ref(0).to_vec();
+        for attribute in SerdeTestPoint::layout().attributes() {
+            let offset = attribute.offset() as usize;
+            swap_endianness_of_value(
+                attribute.datatype(),
+                &mut raw_point[offset..offset + attribute.size() as usize],
+            );
+        }
+        raw_point
+    }
+
+    #[test]
+    fn test_serialize_interleaved_storage() {
+        let points = test_points();
+        let mut storage = InterleavedVecPointStorage::new(SerdeTestPoint::layout());
+        storage.push_points(&points);
+
+        let bytes = bincode::serialize(&storage).unwrap();
+        // Layout and a handful of length prefixes, but no overhead per point
+        let point_bytes = points.len() * SerdeTestPoint::layout().size_of_point_entry() as usize;
+        let layout_bytes = bincode::serialize(storage.point_layout()).unwrap().len();
+        assert!(bytes.len() <= point_bytes + layout_bytes + 16);
+
+        let deserialized: InterleavedVecPointStorage = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(storage.point_layout(), deserialized.point_layout());
+        assert_eq!(
+            points,
+            deserialized
+                .iter_point::<SerdeTestPoint>()
+                .collect::<Vec<_>>()
+        );
+
+        let json = serde_json::to_string(&storage).unwrap();
+        let deserialized: InterleavedVecPointStorage = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            storage.get_raw_points_ref(0..points.len()),
+            deserialized.get_raw_points_ref(0..points.len())
+        );
+    }
+
+    #[test]
+    fn test_serialize_per_attribute_storage() {
+        let points = test_points();
+        let mut storage = PerAttributeVecPointStorage::new(SerdeTestPoint::layout());
+        storage.push_points(&points);
+
+        let bytes = bincode::serialize(&storage).unwrap();
+        let deserialized: PerAttributeVecPointStorage = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(storage.point_layout(), deserialized.point_layout());
+        assert_eq!(
+            points,
+            deserialized
+                .iter_point::<SerdeTestPoint>()
+                .collect::<Vec<_>>()
+        );
+
+        let json = serde_json::to_string(&storage).unwrap();
+        let deserialized: PerAttributeVecPointStorage = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            points,
+            deserialized
+                .iter_point::<SerdeTestPoint>()
+                .collect::<Vec<_>>()
+        );
+
+        let empty = PerAttributeVecPointStorage::new(SerdeTestPoint::layout());
+        let bytes = bincode::serialize(&empty).unwrap();
+        let deserialized: PerAttributeVecPointStorage = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(0, deserialized.len());
+    }
+
+    #[test]
+    fn test_serialize_storages_with_empty_layout() {
+        let layout = PointLayout::from_attributes(&[]);
+
+        let interleaved = InterleavedVecPointStorage::new(layout.clone());
+        let bytes = bincode::serialize(&interleaved).unwrap();
+        let deserialized: InterleavedVecPointStorage = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(&layout, deserialized.point_layout());
+        assert_eq!(0, deserialized.len());
+
+        let per_attribute = PerAttributeVecPointStorage::new(layout.clone());
+        let json = serde_json::to_string(&per_attribute).unwrap();
+        let deserialized: PerAttributeVecPointStorage = serde_json::from_str(&json).unwrap();
+        assert_eq!(&layout, deserialized.point_layout());
+        assert_eq!(0, deserialized.len());
+
+        let bytes = bincode::serialize(&SerializedInterleavedStorage {
+            layout: &layout,
+            endianness: Endianness::native(),
+            points: RawBytes(&[0; 4]),
+        })
+        .unwrap();
+        assert!(bincode::deserialize::<InterleavedVecPointStorage>(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_foreign_endianness() {
+        let points = test_points();
+        let layout = SerdeTestPoint::layout();
+        let foreign_endianness = match Endianness::native() {
+            Endianness::Little => Endianness::Big,
+            Endianness::Big => Endianness::Little,
+        };
+
+        let swapped_points = points.iter().flat_map(swap_point).collect::<Vec<_>>();
+        // Only the values of nullable attributes are swapped, their validity bytes stay in front
+        let gps_time_offset = layout
+            .get_attribute_by_name(attributes::GPS_TIME.name())
+            .unwrap()
+            .offset() as usize;
+        assert_eq!(1, swap_point(&points[1])[gps_time_offset]);
+        assert_eq!(0, swap_point(&points[0])[gps_time_offset]);
+        let bytes = bincode::serialize(&SerializedInterleavedStorage {
+            layout: &layout,
+            endianness: foreign_endianness,
+            points: RawBytes(&swapped_points),
+        })
+        .unwrap();
+        let deserialized: InterleavedVecPointStorage = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(
+            points,
+            deserialized
+                .iter_point::<SerdeTestPoint>()
+                .collect::<Vec<_>>()
+        );
+
+        let mut swapped_storage = InterleavedVecPointStorage::new(layout.clone());
+        swapped_storage.resize(points.len());
+        swapped_storage
+            .get_raw_points_mut(0..points.len())
+            .copy_from_slice(&swapped_points);
+        let per_attribute = PerAttributeVecPointStorage::from(&swapped_storage);
+        let swapped_attributes = layout
+            .attributes()
+            .map(|attribute| {
+                RawBytes(
+                    per_attribute.get_raw_attribute_range_ref(0..points.len(), &attribute.into()),
+                )
+            })
+            .collect();
+        let bytes = bincode::serialize(&SerializedPerAttributeStorage {
+            layout: &layout,
+            endianness: foreign_endianness,
+            attributes: swapped_attributes,
+        })
+        .unwrap();
+        let deserialized: PerAttributeVecPointStorage = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(
+            points,
+            deserialized
+                .iter_point::<SerdeTestPoint>()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Some(1.5),
+            deserialized
+                .get_attribute::<Nullable<f64>>(
+                    &attributes::GPS_TIME.with_custom_datatype(PointAttributeDataType::nullable(
+                        PointAttributeDataType::F64
+                    )),
+                    1
+                )
+                .get()
+        );
+    }
+
+    #[test]
+    fn test_deserialize_invalid_data() {
+        let layout = SerdeTestPoint::layout();
+        let bytes = bincode::serialize(&SerializedInterleavedStorage {
+            layout: &layout,
+            endianness: Endianness::native(),
+            points: RawBytes(&[0; 7]),
+        })
+        .unwrap();
+        assert!(bincode::deserialize::<InterleavedVecPointStorage>(&bytes).is_err());
+
+        let bytes = bincode::serialize(&SerializedPerAttributeStorage {
+            layout: &layout,
+            endianness: Endianness::native(),
+            attributes: vec![RawBytes(&[0; 24]), RawBytes(&[0; 4])],
+        })
+        .unwrap();
+        assert!(bincode::deserialize::<PerAttributeVecPointStorage>(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_malformed_per_attribute_storage() {
+        let layout = SerdeTestPoint::layout();
+        let serialize = |attributes: Vec<&[u8]>| {
+            serde_json::to_value(&SerializedPerAttributeStorage {
+                layout: &layout,
+                endianness: Endianness::native(),
+                attributes: attributes.into_iter().map(RawBytes).collect(),
+            })
+            .unwrap()
+        };
+
+        let valid = serialize(vec![&[0; 48], &[0; 4], &[0; 2], &[0; 32]]);
+        assert_eq!(
+            2,
+            serde_json::from_value::<PerAttributeVecPointStorage>(valid.clone())
+                .unwrap()
+                .len()
+        );
+
+        // Size of the first attribute is no multiple of the size of the attribute
+        let malformed = serialize(vec![&[0; 47], &[0; 4], &[0; 2], &[0; 32]]);
+        assert!(serde_json::from_value::<PerAttributeVecPointStorage>(malformed).is_err());
+
+        // Attributes contain different numbers of points
+        let malformed = serialize(vec![&[0; 48], &[0; 6], &[0; 2], &[0; 32]]);
+        assert!(serde_json::from_value::<PerAttributeVecPointStorage>(malformed).is_err());
+        let malformed = serialize(vec![&[0; 48], &[0; 4], &[0; 3], &[0; 32]]);
+        assert!(serde_json::from_value::<PerAttributeVecPointStorage>(malformed).is_err());
+
+        // Attributes with a size of zero, as the first attribute and as a later attribute
+        for attribute_index in [0, 2] {
+            let mut malformed = valid.clone();
+            malformed["layout"]["attributes"][attribute_index]["datatype"] =
+                serde_json::json!({ "ByteArray": 0 });
+            malformed["attributes"][attribute_index] = serde_json::json!([]);
+            assert!(serde_json::from_value::<PerAttributeVecPointStorage>(malformed).is_err());
+        }
+    }
+}
@@ -0,0 +1,495 @@
+use std::{collections::HashMap, ops::Range, sync::Arc};
+
+use crate::layout::{PointAttributeDefinition, PointLayout};
+
+use super::{
+    InterleavedPointBuffer, InterleavedPointBufferMut, InterleavedVecPointStorage,
+    PerAttributePointBuffer, PerAttributePointBufferMut, PerAttributePointBufferSlice,
+    PerAttributeVecPointStorage, PointBuffer, PointBufferWriteable,
+};
+
+/// Owning `PointBuffer` types that can be wrapped in a [SharedPointBuffer]
+trait SharedStorage: PointBufferWriteable + Sized {
+    /// Returns a new buffer with a copy of the given `range` of points
+    fn copy_range(&self, range: Range<usize>) -> Self;
+
+    fn as_shared_interleaved(
+        _shared: &SharedPointBuffer<Self>,
+    ) -> Option<&dyn InterleavedPointBuffer> {
+        None
+    }
+
+    fn as_shared_per_attribute(
+        _shared: &SharedPointBuffer<Self>,
+    ) -> Option<&dyn PerAttributePointBuffer> {
+        None
+    }
+}
+
+impl SharedStorage for InterleavedVecPointStorage {
+    fn copy_range(&self, range: Range<usize>) -> Self {
+        InterleavedVecPointStorage::from_raw_points(
+            self.point_layout().clone(),
+            self.get_raw_points_ref(range).to_vec(),
+        )
+    }
+
+    fn as_shared_interleaved(
+        shared: &SharedPointBuffer<Self>,
+    ) -> Option<&dyn InterleavedPointBuffer> {
+        Some(shared)
+    }
+}
+
+impl SharedStorage for PerAttributeVecPointStorage {
+    fn copy_range(&self, range: Range<usize>) -> Self {
+        let attributes = self
+            .point_layout()
+            .attributes()
+            .map(|attribute| {
+                let data = self.get_raw_attribute_range_ref(range.clone(), &attribute.into());
+                (attribute.name().to_owned(), data.to_vec())
+            })
+            .collect::<HashMap<_, _>>();
+        PerAttributeVecPointStorage::from_attribute_buffers(self.point_layout().clone(), attributes)
+    }
+
+    fn as_shared_per_attribute(
+        shared: &SharedPointBuffer<Self>,
+    ) -> Option<&dyn PerAttributePointBuffer> {
+        Some(shared)
+    }
+}
+
+/// `PointBuffer` that shares its point data with all of its clones. Cloning a `SharedPointBuffer` only increments a
+/// reference count, which makes it cheap to pass the same point cloud to multiple consumers. The point data is copied
+/// lazily, upon the first mutation through [PointBufferWriteable] of a `SharedPointBuffer` whose data is shared, so
+/// mutations never affect other `SharedPointBuffer`s.
+///
+/// Slices of a `SharedPointBuffer` created through [slice_shared](SharedPointBuffer::slice_shared) are themselves
+/// `SharedPointBuffer`s that keep the point data alive, so they can outlive the buffer that they were created from.
+///
+/// Use the [SharedInterleavedVecPointStorage] and [SharedPerAttributeVecPointStorage] aliases for the supported
+/// buffer types
+/// ```
+/// # use pasture_core::containers::*;
+/// # use pasture_core::layout::*;
+/// let mut buffer = PerAttributeVecPointStorage::new(PointLayout::from_attributes(&[attributes::INTENSITY]));
+/// buffer.resize(100);
+///
+/// let shared = SharedPerAttributeVecPointStorage::new(buffer);
+/// let mut clone = shared.clone();
+/// assert!(clone.shares_memory_with(&shared));
+///
+/// clone.set_attribute(&attributes::INTENSITY, 0, 42_u16);
+/// assert!(!clone.shares_memory_with(&shared));
+/// assert_eq!(42, clone.get_attribute::<u16>(&attributes::INTENSITY, 0));
+/// assert_eq!(0, shared.get_attribute::<u16>(&attributes::INTENSITY, 0));
+/// ```
+pub struct SharedPointBuffer<B> {
+    storage: Arc<B>,
+    range: Range<usize>,
+}
+
+/// [SharedPointBuffer] that stores its points in an [InterleavedVecPointStorage]
+pub type SharedInterleavedVecPointStorage = SharedPointBuffer<InterleavedVecPointStorage>;
+/// [SharedPointBuffer] that stores its points in a [PerAttributeVecPointStorage]
+pub type SharedPerAttributeVecPointStorage = SharedPointBuffer<PerAttributeVecPointStorage>;
+
+impl<B: PointBuffer> SharedPointBuffer<B> {
+    /// Creates a new `SharedPointBuffer` that takes ownership of the given `buffer`
+    pub fn new(buffer: B) -> Self {
+        let range = 0..buffer.len();
+        Self {
+            storage: Arc::new(buffer),
+            range,
+        }
+    }
+
+    /// Returns a `SharedPointBuffer` for the given `range` of points within the associated `SharedPointBuffer`. The
+    /// returned buffer shares the point data with the associated buffer, so this operation does not copy any points
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of `range` is greater than the end of `range`, or if the end of `range` is greater than `self.len()`
+    pub fn slice_shared(&self, range: Range<usize>) -> Self {
+        Self {
+            storage: Arc::clone(&self.storage),
+            range: self.range_in_storage(range),
+        }
+    }
+
+    /// Returns `true` if the associated `SharedPointBuffer` and `other` refer to the same point data
+    pub fn shares_memory_with(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.storage, &other.storage)
+    }
+
+    fn point_index_in_storage(&self, point_index: usize) -> usize {
+        if point_index >= self.range.len() {
+            panic!(
+                "SharedPointBuffer: Point index {} out of bounds (len is {})",
+                point_index,
+                self.range.len()
+            );
+        }
+        self.range.start + point_index
+    }
+
+    fn range_in_storage(&self, range: Range<usize>) -> Range<usize> {
+        if range.start > range.end || range.end > self.range.len() {
+            panic!(
+                "SharedPointBuffer: Range {:?} out of bounds (len is {})",
+                range,
+                self.range.len()
+            );
+        }
+        self.range.start + range.start..self.range.start + range.end
+    }
+}
+
+/// Returns mutable access to the point data of `shared`, copying its points into a new storage if the current storage
+/// is shared with other buffers or contains points outside of `shared`
+fn make_mut<B: SharedStorage>(shared: &mut SharedPointBuffer<B>) -> &mut B {
+    let covers_storage = shared.range.start == 0 && shared.range.end == shared.storage.len();
+    if !covers_storage || Arc::get_mut(&mut shared.storage).is_none() {
+        shared.storage = Arc::new(shared.storage.copy_range(shared.range.clone()));
+        shared.range = 0..shared.storage.len();
+    }
+    Arc::get_mut(&mut shared.storage).unwrap()
+}
+
+/// Applies the mutation `func` to the point data of `shared`, updating the range of `shared` afterwards
+fn mutate<B: SharedStorage, R, F: FnOnce(&mut B) -> R>(
+    shared: &mut SharedPointBuffer<B>,
+    func: F,
+) -> R {
+    let storage = make_mut(shared);
+    let result = func(storage);
+    let len = storage.len();
+    shared.range = 0..len;
+    result
+}
+
+fn into_storage<B: SharedStorage>(shared: SharedPointBuffer<B>) -> B {
+    let covers_storage = shared.range.start == 0 && shared.range.end == shared.storage.len();
+    match Arc::try_unwrap(shared.storage) {
+        Ok(storage) if covers_storage => storage,
+        Ok(storage) => storage.copy_range(shared.range),
+        Err(storage) => storage.copy_range(shared.range),
+    }
+}
+
+impl<B> Clone for SharedPointBuffer<B> {
+    fn clone(&self) -> Self {
+        Self {
+            storage: Arc::clone(&self.storage),
+            range: self.range.clone(),
+        }
+    }
+}
+
+impl<B: PointBuffer> From<B> for SharedPointBuffer<B> {
+    fn from(buffer: B) -> Self {
+        Self::new(buffer)
+    }
+}
+
+/// Converts the `SharedPointBuffer` back into its storage. This does not copy if the buffer is the only owner of the
+/// point data
+impl From<SharedInterleavedVecPointStorage> for InterleavedVecPointStorage {
+    fn from(shared: SharedInterleavedVecPointStorage) -> Self {
+        into_storage(shared)
+    }
+}
+
+/// Converts the `SharedPointBuffer` back into its storage. This does not copy if the buffer is the only owner of the
+/// point data
+impl From<SharedPerAttributeVecPointStorage> for PerAttributeVecPointStorage {
+    fn from(shared: SharedPerAttributeVecPointStorage) -> Self {
+        into_storage(shared)
+    }
+}
+
+impl<B: SharedStorage> PointBuffer for SharedPointBuffer<B> {
+    fn get_raw_point(&self, point_index: usize, buf: &mut [u8]) {
+        self.storage
+            .get_raw_point(self.point_index_in_storage(point_index), buf);
+    }
+
+    fn get_raw_attribute(
+        &self,
+        point_index: usize,
+        attribute: &PointAttributeDefinition,
+        buf: &mut [u8],
+    ) {
+        self.storage
+            .get_raw_attribute(self.point_index_in_storage(point_index), attribute, buf);
+    }
+
+    fn get_raw_points(&self, index_range: Range<usize>, buf: &mut [u8]) {
+        self.storage
+            .get_raw_points(self.range_in_storage(index_range), buf);
+    }
+
+    fn get_raw_attribute_range(
+        &self,
+        index_range: Range<usize>,
+        attribute: &PointAttributeDefinition,
+        buf: &mut [u8],
+    ) {
+        self.storage
+            .get_raw_attribute_range(self.range_in_storage(index_range), attribute, buf);
+    }
+
+    fn len(&self) -> usize {
+        self.range.end - self.range.start
+    }
+
+    fn point_layout(&self) -> &PointLayout {
+        self.storage.point_layout()
+    }
+
+    fn as_interleaved(&self) -> Option<&dyn InterleavedPointBuffer> {
+        B::as_shared_interleaved(self)
+    }
+
+    fn as_per_attribute(&self) -> Option<&dyn PerAttributePointBuffer> {
+        B::as_shared_per_attribute(self)
+    }
+}
+
+impl<B: SharedStorage> PointBufferWriteable for SharedPointBuffer<B> {
+    fn set_raw_point(&mut self, point_index: usize, buf: &[u8]) {
+        mutate(self, |storage| storage.set_raw_point(point_index, buf));
+    }
+
+    fn set_raw_attribute(
+        &mut self,
+        point_index: usize,
+        attribute: &PointAttributeDefinition,
+        buf: &[u8],
+    ) {
+        mutate(self, |storage| {
+            storage.set_raw_attribute(point_index, attribute, buf)
+        });
+    }
+
+    fn push(&mut self, points: &dyn PointBuffer) {
+        mutate(self, |storage| storage.push(points));
+    }
+
+    fn splice(&mut self, range: Range<usize>, replace_with: &dyn PointBuffer) {
+        mutate(self, |storage| storage.splice(range, replace_with));
+    }
+
+    fn clear(&mut self) {
+        // Copying the points is not necessary if they are removed anyways
+        if Arc::get_mut(&mut self.storage).is_none() {
+            self.storage = Arc::new(self.storage.copy_range(0..0));
+            self.range = 0..0;
+        }
+        mutate(self, |storage| storage.clear());
+    }
+
+    fn resize(&mut self, new_points: usize) {
+        mutate(self, |storage| storage.resize(new_points));
+    }
+
+    fn as_interleaved_mut(&mut self) -> Option<&mut dyn InterleavedPointBufferMut> {
+        make_mut(self).as_interleaved_mut()
+    }
+
+    fn as_per_attribute_mut<'b>(
+        &'b mut self,
+    ) -> Option<&'b mut dyn PerAttributePointBufferMut<'b>> {
+        make_mut(self).as_per_attribute_mut()
+    }
+
+    fn retain_by_mask(&mut self, keep: &[bool]) {
+        mutate(self, |storage| storage.retain_by_mask(keep));
+    }
+
+    fn swap_remove(&mut self, point_index: usize) {
+        mutate(self, |storage| storage.swap_remove(point_index));
+    }
+}
+
+impl InterleavedPointBuffer for SharedInterleavedVecPointStorage {
+    fn get_raw_point_ref(&self, point_index: usize) -> &[u8] {
+        self.storage
+            .get_raw_point_ref(self.point_index_in_storage(point_index))
+    }
+
+    fn get_raw_points_ref(&self, index_range: Range<usize>) -> &[u8] {
+        self.storage
+            .get_raw_points_ref(self.range_in_storage(index_range))
+    }
+}
+
+impl InterleavedPointBufferMut for SharedInterleavedVecPointStorage {
+    // The indices are checked before the points are copied, after copying they are relative to the new storage
+
+    fn get_raw_point_mut(&mut self, point_index: usize) -> &mut [u8] {
+        self.point_index_in_storage(point_index);
+        make_mut(self).get_raw_point_mut(point_index)
+    }
+
+    fn get_raw_points_mut(&mut self, index_range: Range<usize>) -> &mut [u8] {
+        self.range_in_storage(index_range.clone());
+        make_mut(self).get_raw_points_mut(index_range)
+    }
+}
+
+impl PerAttributePointBuffer for SharedPerAttributeVecPointStorage {
+    fn get_raw_attribute_ref(
+        &self,
+        point_index: usize,
+        attribute: &PointAttributeDefinition,
+    ) -> &[u8] {
+        self.storage
+            .get_raw_attribute_ref(self.point_index_in_storage(point_index), attribute)
+    }
+
+    fn get_raw_attribute_range_ref(
+        &self,
+        index_range: Range<usize>,
+        attribute: &PointAttributeDefinition,
+    ) -> &[u8] {
+        self.storage
+            .get_raw_attribute_range_ref(self.range_in_storage(index_range), attribute)
+    }
+
+    fn slice(&self, range: Range<usize>) -> PerAttributePointBufferSlice<'_> {
+        PerAttributePointBufferSlice::new(self, range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        containers::{InterleavedPointBufferExt, PointBufferExt, PointBufferWriteableExt},
+        layout::{attributes, PointType},
+    };
+    use pasture_derive::PointType;
+
+    // We need this, otherwise we can't use the derive(PointType) macro from within pasture_core because the macro
+    // doesn't recognize the name 'pasture_core' :/
+    use crate as pasture_core;
+
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone, PartialEq, PointType)]
+    struct SharedTestPoint {
+        #[pasture(BUILTIN_GPS_TIME)]
+        pub gps_time: f64,
+        #[pasture(BUILTIN_INTENSITY)]
+        pub intensity: u16,
+    }
+
+    fn test_points() -> Vec<SharedTestPoint> {
+        (0..10)
+            .map(|index| SharedTestPoint {
+                gps_time: index as f64,
+                intensity: index as u16,
+            })
+            .collect()
+    }
+
+    fn test_copy_on_write<B: SharedStorage>(buffer: B) {
+        let points = test_points();
+        let shared = SharedPointBuffer::new(buffer);
+        let mut clone = shared.clone();
+        assert!(clone.shares_memory_with(&shared));
+        assert_eq!(
+            points,
+            clone.iter_point::<SharedTestPoint>().collect::<Vec<_>>()
+        );
+
+        clone.set_attribute(&attributes::INTENSITY, 3, 42_u16);
+        assert!(!clone.shares_memory_with(&shared));
+        assert_eq!(42, clone.get_attribute::<u16>(&attributes::INTENSITY, 3));
+        assert_eq!(3, shared.get_attribute::<u16>(&attributes::INTENSITY, 3));
+
+        // The clone is the only owner of its points now, so further mutations happen in place
+        let storage_before = Arc::as_ptr(&clone.storage);
+        clone.transform_attribute(attributes::GPS_TIME.name(), |_, gps_time: &mut f64| {
+            *gps_time *= 2.0;
+        });
+        assert_eq!(storage_before, Arc::as_ptr(&clone.storage));
+        assert_eq!(18.0, clone.get_attribute::<f64>(&attributes::GPS_TIME, 9));
+        assert_eq!(9.0, shared.get_attribute::<f64>(&attributes::GPS_TIME, 9));
+
+        let mut slice = {
+            let original = shared.clone();
+            original.slice_shared(2..6)
+        };
+        assert_eq!(4, slice.len());
+        assert!(slice.shares_memory_with(&shared));
+        assert_eq!(
+            &points[2..6],
+            slice.iter_point::<SharedTestPoint>().collect::<Vec<_>>()
+        );
+
+        let sub_slice = slice.slice_shared(1..3);
+        assert_eq!(points[3], sub_slice.get_point::<SharedTestPoint>(0));
+
+        let mut extra_point = InterleavedVecPointStorage::new(SharedTestPoint::layout());
+        extra_point.push_point(points[0]);
+        slice.push(&extra_point);
+        assert!(!slice.shares_memory_with(&shared));
+        assert_eq!(5, slice.len());
+        assert_eq!(points[0], slice.get_point::<SharedTestPoint>(4));
+        assert_eq!(points[2], slice.get_point::<SharedTestPoint>(0));
+        assert_eq!(10, shared.len());
+        assert_eq!(points[3], sub_slice.get_point::<SharedTestPoint>(0));
+
+        let mut cleared = shared.clone();
+        cleared.clear();
+        assert_eq!(0, cleared.len());
+        assert_eq!(10, shared.len());
+    }
+
+    #[test]
+    fn test_shared_buffers() {
+        let mut interleaved = InterleavedVecPointStorage::new(SharedTestPoint::layout());
+        interleaved.push_points(&test_points());
+        test_copy_on_write(interleaved);
+
+        let mut per_attribute = PerAttributeVecPointStorage::new(SharedTestPoint::layout());
+        per_attribute.push_points(&test_points());
+        test_copy_on_write(per_attribute);
+    }
+
+    #[test]
+    fn test_shared_buffer_into_storage() {
+        let points = test_points();
+        let mut interleaved = InterleavedVecPointStorage::new(SharedTestPoint::layout());
+        interleaved.push_points(&points);
+        let shared = SharedInterleavedVecPointStorage::new(interleaved);
+        assert!(shared.as_interleaved().is_some());
+        assert!(shared.as_per_attribute().is_none());
+
+        let slice = shared.slice_shared(5..10);
+        let storage = InterleavedVecPointStorage::from(slice);
+        assert_eq!(
+            &points[5..10],
+            storage.get_points_ref::<SharedTestPoint>(0..5)
+        );
+
+        let storage = InterleavedVecPointStorage::from(shared);
+        assert_eq!(
+            points.as_slice(),
+            storage.get_points_ref::<SharedTestPoint>(0..10)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_shared_buffer_slice_out_of_bounds() {
+        let mut per_attribute = PerAttributeVecPointStorage::new(SharedTestPoint::layout());
+        per_attribute.push_points(&test_points());
+        let shared = SharedPerAttributeVecPointStorage::new(per_attribute);
+        let slice = shared.slice_shared(5..10);
+        slice.get_point::<SharedTestPoint>(5);
+    }
+}
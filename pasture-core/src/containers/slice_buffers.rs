@@ -3,7 +3,8 @@ use std::ops::Range;
 use crate::layout::{PointAttributeDefinition, PointLayout};
 
 use super::{
-    InterleavedPointBuffer, PerAttributePointBuffer, PerAttributePointBufferMut, PointBuffer,
+    InterleavedPointBuffer, InterleavedPointBufferMut, PerAttributePointBuffer,
+    PerAttributePointBufferMut, PointBuffer,
 };
 
 /// Non-owning, read-only slice of the data of an `InterleavedPointBuffer`
@@ -93,6 +94,235 @@ impl<'p> InterleavedPointBuffer for InterleavedPointBufferSlice<'p> {
     }
 }
 
+/// Non-owning, mutable slice of the data of an `InterleavedPointBufferMut`. In contrast to [`InterleavedPointBufferSlice`],
+/// this type borrows the point data directly, so it can be split into disjoint mutable slices using
+/// [`split_at_mut`](InterleavedPointBufferSliceMut::split_at_mut) and
+/// [`chunks_mut`](InterleavedPointBufferSliceMut::chunks_mut), which can then be processed on different threads
+pub struct InterleavedPointBufferSliceMut<'p> {
+    points: &'p mut [u8],
+    layout: PointLayout,
+}
+
+impl<'p> InterleavedPointBufferSliceMut<'p> {
+    /// Creates a new `InterleavedPointBufferSliceMut` pointing to the given range within the given buffer
+    ///
+    /// # Panics
+    ///
+    /// Panics if the end of `range_in_buffer` is larger than `buffer.len()`
+    pub fn new(
+        buffer: &'p mut dyn InterleavedPointBufferMut,
+        range_in_buffer: Range<usize>,
+    ) -> Self {
+        if range_in_buffer.start > range_in_buffer.end || range_in_buffer.end > buffer.len() {
+            panic!(
+                "InterleavedPointBufferSliceMut::new: Range {:?} is out of bounds!",
+                range_in_buffer
+            );
+        }
+        let layout = buffer.point_layout().clone();
+        Self {
+            points: buffer.get_raw_points_mut(range_in_buffer),
+            layout,
+        }
+    }
+
+    /// Creates a new `InterleavedPointBufferSliceMut` from the raw memory of point records with the given `PointLayout`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `points` is no multiple of the size of a single point in `layout`
+    pub fn from_raw_slice(points: &'p mut [u8], layout: PointLayout) -> Self {
+        if !points
+            .len()
+            .is_multiple_of(layout.size_of_point_entry() as usize)
+        {
+            panic!("InterleavedPointBufferSliceMut::from_raw_slice: Length of points is no multiple of the size of a single point!");
+        }
+        Self { points, layout }
+    }
+
+    fn size_of_point_entry(&self) -> usize {
+        self.layout.size_of_point_entry() as usize
+    }
+
+    /// Divides the associated `InterleavedPointBufferSliceMut` into two mutable slices at the point index `mid`. The first
+    /// slice contains the points `[0, mid)`, the second slice contains the points `[mid, len)`
+    ///
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// let mut buffer = InterleavedVecPointStorage::new(PointLayout::from_attributes(&[attributes::INTENSITY]));
+    /// buffer.resize(10);
+    /// let mut slice = buffer.slice_mut(0..10);
+    /// let (mut left, mut right) = slice.split_at_mut(4);
+    /// left.get_raw_point_mut(0).copy_from_slice(&1_u16.to_ne_bytes());
+    /// right.get_raw_point_mut(0).copy_from_slice(&2_u16.to_ne_bytes());
+    ///
+    /// assert_eq!(1, buffer.get_attribute::<u16>(&attributes::INTENSITY, 0));
+    /// assert_eq!(2, buffer.get_attribute::<u16>(&attributes::INTENSITY, 4));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > self.len()`
+    pub fn split_at_mut(
+        &mut self,
+        mid: usize,
+    ) -> (
+        InterleavedPointBufferSliceMut<'_>,
+        InterleavedPointBufferSliceMut<'_>,
+    ) {
+        if mid > self.len() {
+            panic!(
+                "InterleavedPointBufferSliceMut::split_at_mut: Index {} is out of bounds!",
+                mid
+            );
+        }
+        let (left, right) = self
+            .points
+            .split_at_mut(mid * self.layout.size_of_point_entry() as usize);
+        (
+            InterleavedPointBufferSliceMut {
+                points: left,
+                layout: self.layout.clone(),
+            },
+            InterleavedPointBufferSliceMut {
+                points: right,
+                layout: self.layout.clone(),
+            },
+        )
+    }
+
+    /// Returns an iterator over disjoint mutable slices of `chunk_size` points each. The last slice contains less than
+    /// `chunk_size` points if the length of the associated `InterleavedPointBufferSliceMut` is no multiple of `chunk_size`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero
+    pub fn chunks_mut(
+        &mut self,
+        chunk_size: usize,
+    ) -> impl Iterator<Item = InterleavedPointBufferSliceMut<'_>> {
+        if chunk_size == 0 {
+            panic!("InterleavedPointBufferSliceMut::chunks_mut: Chunk size must not be zero!");
+        }
+        let layout = &self.layout;
+        self.points
+            .chunks_mut(chunk_size * layout.size_of_point_entry() as usize)
+            .map(move |points| InterleavedPointBufferSliceMut {
+                points,
+                layout: layout.clone(),
+            })
+    }
+}
+
+impl<'p> PointBuffer for InterleavedPointBufferSliceMut<'p> {
+    fn get_raw_point(&self, point_index: usize, buf: &mut [u8]) {
+        buf.copy_from_slice(self.get_raw_point_ref(point_index));
+    }
+
+    fn get_raw_attribute(
+        &self,
+        point_index: usize,
+        attribute: &PointAttributeDefinition,
+        buf: &mut [u8],
+    ) {
+        if let Some(attribute_in_buffer) = self.layout.get_attribute(attribute) {
+            let point_record = self.get_raw_point_ref(point_index);
+            attribute_in_buffer.read_from_point_record(point_record, buf);
+        } else {
+            panic!("InterleavedPointBufferSliceMut::get_raw_attribute: Attribute {:?} is not part of this PointBuffer's PointLayout!", attribute);
+        }
+    }
+
+    fn get_raw_points(&self, index_range: Range<usize>, buf: &mut [u8]) {
+        let points_ref = self.get_raw_points_ref(index_range);
+        buf[0..points_ref.len()].copy_from_slice(points_ref);
+    }
+
+    fn get_raw_attribute_range(
+        &self,
+        index_range: Range<usize>,
+        attribute: &PointAttributeDefinition,
+        buf: &mut [u8],
+    ) {
+        if let Some(attribute_in_buffer) = self.layout.get_attribute(attribute) {
+            let attribute_size = attribute.size() as usize;
+            let point_records = self.get_raw_points_ref(index_range);
+            for (point_record, target_buf_slice) in point_records
+                .chunks_exact(self.size_of_point_entry())
+                .zip(buf.chunks_exact_mut(attribute_size))
+            {
+                attribute_in_buffer.read_from_point_record(point_record, target_buf_slice);
+            }
+        } else {
+            panic!("InterleavedPointBufferSliceMut::get_raw_attribute_range: Attribute {:?} is not part of this PointBuffer's PointLayout!", attribute);
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.points.len() / self.size_of_point_entry()
+    }
+
+    fn point_layout(&self) -> &PointLayout {
+        &self.layout
+    }
+
+    fn as_interleaved(&self) -> Option<&dyn InterleavedPointBuffer> {
+        Some(self)
+    }
+}
+
+impl<'p> InterleavedPointBuffer for InterleavedPointBufferSliceMut<'p> {
+    fn get_raw_point_ref(&self, point_index: usize) -> &[u8] {
+        if point_index >= self.len() {
+            panic!(
+                "InterleavedPointBufferSliceMut::get_raw_point_ref: Point index {} out of bounds!",
+                point_index
+            );
+        }
+        let offset_to_point = point_index * self.size_of_point_entry();
+        &self.points[offset_to_point..offset_to_point + self.size_of_point_entry()]
+    }
+
+    fn get_raw_points_ref(&self, index_range: Range<usize>) -> &[u8] {
+        if index_range.start > index_range.end || index_range.end > self.len() {
+            panic!(
+                "InterleavedPointBufferSliceMut::get_raw_points_ref: Point indices {:?} out of bounds!",
+                index_range
+            );
+        }
+        let size_of_point_entry = self.size_of_point_entry();
+        &self.points[index_range.start * size_of_point_entry..index_range.end * size_of_point_entry]
+    }
+}
+
+impl<'p> InterleavedPointBufferMut for InterleavedPointBufferSliceMut<'p> {
+    fn get_raw_point_mut(&mut self, point_index: usize) -> &mut [u8] {
+        if point_index >= self.len() {
+            panic!(
+                "InterleavedPointBufferSliceMut::get_raw_point_mut: Point index {} out of bounds!",
+                point_index
+            );
+        }
+        let size_of_point_entry = self.size_of_point_entry();
+        let offset_to_point = point_index * size_of_point_entry;
+        &mut self.points[offset_to_point..offset_to_point + size_of_point_entry]
+    }
+
+    fn get_raw_points_mut(&mut self, index_range: Range<usize>) -> &mut [u8] {
+        if index_range.start > index_range.end || index_range.end > self.len() {
+            panic!(
+                "InterleavedPointBufferSliceMut::get_raw_points_mut: Point indices {:?} out of bounds!",
+                index_range
+            );
+        }
+        let size_of_point_entry = self.size_of_point_entry();
+        &mut self.points
+            [index_range.start * size_of_point_entry..index_range.end * size_of_point_entry]
+    }
+}
+
 /// Non-owning, read-only slice of the data of a `PerAttributePointBuffer`
 pub struct PerAttributePointBufferSlice<'p> {
     buffer: &'p dyn PerAttributePointBuffer,
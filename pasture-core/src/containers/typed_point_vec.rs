@@ -0,0 +1,557 @@
+use std::{
+    convert::TryFrom,
+    iter::FromIterator,
+    mem::ManuallyDrop,
+    ops::{Deref, DerefMut, Range},
+};
+
+use anyhow::{bail, Result};
+
+use crate::layout::{PointAttributeDefinition, PointLayout, PointType};
+
+use super::{
+    InterleavedPointBuffer, InterleavedPointBufferMut, InterleavedVecPointStorage, PointBuffer,
+    PointBufferWriteable,
+};
+
+/// Strongly typed `PointBuffer` that stores points of the `PointType` `T` in a `Vec<T>`. In contrast to the untyped
+/// buffers, the type of the points is known at compile time, so a `TypedPointVec` derefs to `&[T]` and `&mut [T]` and
+/// no runtime checks of the `PointLayout` are necessary to access the points. At the same time, a `TypedPointVec`
+/// implements the `PointBuffer` traits for Interleaved memory layout, so it can be passed to all functions that accept
+/// an untyped buffer.
+///
+/// A `TypedPointVec` can be converted into an `InterleavedVecPointStorage` and back (if the `PointLayout` matches).
+/// These conversions are not free in general: The memory of an `InterleavedVecPointStorage` is only aligned to a single
+/// byte, so for all `PointType`s with a larger alignment (which includes all `#[repr(C)]` types with members larger
+/// than one byte), all points are copied into a new allocation. Only `PointType`s with an alignment of 1 (i.e.
+/// `#[repr(packed)]` types) reuse the memory of the source buffer
+/// ```
+/// # use pasture_core::containers::*;
+/// # use pasture_core::layout::*;
+/// # use pasture_derive::PointType;
+/// # use std::convert::TryFrom;
+/// #[repr(C)]
+/// #[derive(PointType, Copy, Clone, Debug, PartialEq)]
+/// struct MyPointType(#[pasture(BUILTIN_INTENSITY)] u16, #[pasture(BUILTIN_CLASSIFICATION)] u8);
+///
+/// let mut points = TypedPointVec::from(vec![MyPointType(42, 1), MyPointType(43, 2)]);
+/// points[1].0 += 1;
+/// assert_eq!(
+///     vec![42, 44],
+///     points.iter_attribute::<u16>(&attributes::INTENSITY).collect::<Vec<_>>()
+/// );
+///
+/// let storage = InterleavedVecPointStorage::from(points);
+/// let points = TypedPointVec::<MyPointType>::try_from(storage).unwrap();
+/// assert_eq!(&[MyPointType(42, 1), MyPointType(44, 2)], points.as_slice());
+/// ```
+#[derive(Clone, Debug)]
+pub struct TypedPointVec<T: PointType> {
+    points: Vec<T>,
+    layout: PointLayout,
+}
+
+impl<T: PointType> TypedPointVec<T> {
+    /// Creates a new empty `TypedPointVec`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// # use pasture_derive::PointType;
+    /// #[repr(C)]
+    /// #[derive(PointType)]
+    /// struct MyPointType(#[pasture(BUILTIN_INTENSITY)] u16);
+    ///
+    /// let points = TypedPointVec::<MyPointType>::new();
+    /// # assert_eq!(0, points.len());
+    /// ```
+    pub fn new() -> Self {
+        Self::from_vec(Vec::new())
+    }
+
+    /// Creates a new empty `TypedPointVec` with enough capacity to store `capacity` points, like `Vec::with_capacity`
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::from_vec(Vec::with_capacity(capacity))
+    }
+
+    /// Creates a new `TypedPointVec` that takes ownership of the given `points`
+    pub fn from_vec(points: Vec<T>) -> Self {
+        Self {
+            points,
+            layout: T::layout(),
+        }
+    }
+
+    /// Pushes the given `point` into the associated `TypedPointVec`
+    pub fn push_point(&mut self, point: T) {
+        self.points.push(point);
+    }
+
+    /// Returns the points of the associated `TypedPointVec` as a slice
+    pub fn as_slice(&self) -> &[T] {
+        &self.points
+    }
+
+    /// Returns the points of the associated `TypedPointVec` as a mutable slice
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.points
+    }
+
+    /// Converts the associated `TypedPointVec` into the underlying `Vec<T>`
+    pub fn into_vec(self) -> Vec<T> {
+        self.points
+    }
+
+    fn raw_points(&self) -> &[u8] {
+        unsafe {
+            std::slice::from_raw_parts(
+                self.points.as_ptr() as *const u8,
+                self.points.len() * std::mem::size_of::<T>(),
+            )
+        }
+    }
+
+    fn raw_points_mut(&mut self) -> &mut [u8] {
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                self.points.as_mut_ptr() as *mut u8,
+                self.points.len() * std::mem::size_of::<T>(),
+            )
+        }
+    }
+
+    fn raw_point_range(&self, point_index: usize) -> Range<usize> {
+        let size_of_point = std::mem::size_of::<T>();
+        point_index * size_of_point..(point_index + 1) * size_of_point
+    }
+}
+
+impl<T: PointType> Default for TypedPointVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PointType> Deref for TypedPointVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.points
+    }
+}
+
+impl<T: PointType> DerefMut for TypedPointVec<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.points
+    }
+}
+
+impl<T: PointType> PointBuffer for TypedPointVec<T> {
+    fn get_raw_point(&self, point_index: usize, buf: &mut [u8]) {
+        buf.copy_from_slice(self.get_raw_point_ref(point_index));
+    }
+
+    fn get_raw_attribute(
+        &self,
+        point_index: usize,
+        attribute: &PointAttributeDefinition,
+        buf: &mut [u8],
+    ) {
+        if let Some(attribute_in_buffer) = self.layout.get_attribute(attribute) {
+            attribute_in_buffer.read_from_point_record(self.get_raw_point_ref(point_index), buf);
+        } else {
+            panic!("TypedPointVec::get_raw_attribute: Attribute {:?} is not part of this PointBuffer's PointLayout!", attribute);
+        }
+    }
+
+    fn get_raw_points(&self, point_indices: Range<usize>, buf: &mut [u8]) {
+        let points_ref = self.get_raw_points_ref(point_indices);
+        buf[0..points_ref.len()].copy_from_slice(points_ref);
+    }
+
+    fn get_raw_attribute_range(
+        &self,
+        point_indices: Range<usize>,
+        attribute: &PointAttributeDefinition,
+        buf: &mut [u8],
+    ) {
+        if let Some(attribute_in_buffer) = self.layout.get_attribute(attribute) {
+            let attribute_size = attribute.size() as usize;
+            let point_records = self
+                .get_raw_points_ref(point_indices)
+                .chunks_exact(std::mem::size_of::<T>());
+            for (point_record, target_buf) in
+                point_records.zip(buf.chunks_exact_mut(attribute_size))
+            {
+                attribute_in_buffer.read_from_point_record(point_record, target_buf);
+            }
+        } else {
+            panic!("TypedPointVec::get_raw_attribute_range: Attribute {:?} is not part of this PointBuffer's PointLayout!", attribute);
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    fn point_layout(&self) -> &PointLayout {
+        &self.layout
+    }
+
+    fn as_interleaved(&self) -> Option<&dyn InterleavedPointBuffer> {
+        Some(self)
+    }
+}
+
+impl<T: PointType> PointBufferWriteable for TypedPointVec<T> {
+    fn set_raw_point(&mut self, point_index: usize, buf: &[u8]) {
+        self.get_raw_point_mut(point_index).copy_from_slice(buf);
+    }
+
+    fn set_raw_attribute(
+        &mut self,
+        point_index: usize,
+        attribute: &PointAttributeDefinition,
+        buf: &[u8],
+    ) {
+        let attribute_member = self
+            .layout
+            .get_attribute(attribute)
+            .expect("Attribute not found in this PointBuffer's PointLayout")
+            .clone();
+        if buf.len() != attribute_member.size() as usize {
+            panic!("Size of buffer does not match the size of the point attribute")
+        }
+        attribute_member.write_to_point_record(buf, self.get_raw_point_mut(point_index));
+    }
+
+    fn push(&mut self, points: &dyn PointBuffer) {
+        if *points.point_layout() != self.layout {
+            panic!("TypedPointVec::push: Layout of points in new buffer does not match this PointLayout");
+        }
+        let old_len = self.len();
+        self.resize(old_len + points.len());
+        points.get_raw_points(
+            0..points.len(),
+            self.get_raw_points_mut(old_len..self.len()),
+        );
+    }
+
+    fn splice(&mut self, range: Range<usize>, replace_with: &dyn PointBuffer) {
+        if *replace_with.point_layout() != self.layout {
+            panic!("TypedPointVec::splice: replace_with layout does not match this PointLayout!");
+        }
+        if range.start > range.end {
+            panic!("Range start is greater than range end");
+        }
+        if range.end > self.len() {
+            panic!("Range is out of bounds");
+        }
+        replace_with.get_raw_points(0..range.len(), self.get_raw_points_mut(range));
+    }
+
+    fn clear(&mut self) {
+        self.points.clear();
+    }
+
+    /// Resizes the associated `TypedPointVec` to `new_points`. Like in the untyped buffers, new points are zero-initialized
+    /// instead of using `Default`, since `PointType`s are plain old data
+    fn resize(&mut self, new_points: usize) {
+        if new_points <= self.len() {
+            self.points.truncate(new_points);
+            return;
+        }
+        self.points.reserve(new_points - self.len());
+        unsafe {
+            std::ptr::write_bytes(
+                self.points.as_mut_ptr().add(self.len()),
+                0,
+                new_points - self.len(),
+            );
+            self.points.set_len(new_points);
+        }
+    }
+
+    fn as_interleaved_mut(&mut self) -> Option<&mut dyn InterleavedPointBufferMut> {
+        Some(self)
+    }
+
+    fn retain_by_mask(&mut self, keep: &[bool]) {
+        if keep.len() != self.len() {
+            panic!(
+                "TypedPointVec::retain_by_mask: Mask length ({}) does not match buffer length ({})",
+                keep.len(),
+                self.len()
+            );
+        }
+        let mut keep = keep.iter();
+        self.points.retain(|_| *keep.next().unwrap());
+    }
+
+    fn swap_remove(&mut self, point_index: usize) {
+        if point_index >= self.len() {
+            panic!(
+                "TypedPointVec::swap_remove: Index {} is out of bounds for buffer of length {}",
+                point_index,
+                self.len()
+            );
+        }
+        self.points.swap_remove(point_index);
+    }
+}
+
+impl<T: PointType> InterleavedPointBuffer for TypedPointVec<T> {
+    fn get_raw_point_ref(&self, point_index: usize) -> &[u8] {
+        if point_index >= self.len() {
+            panic!(
+                "TypedPointVec::get_raw_point_ref: Point index {} out of bounds!",
+                point_index
+            );
+        }
+        &self.raw_points()[self.raw_point_range(point_index)]
+    }
+
+    fn get_raw_points_ref(&self, index_range: Range<usize>) -> &[u8] {
+        if index_range.end > self.len() {
+            panic!(
+                "TypedPointVec::get_raw_points_ref: Point indices {:?} out of bounds!",
+                index_range
+            );
+        }
+        let start = self.raw_point_range(index_range.start).start;
+        let end = self.raw_point_range(index_range.end).start;
+        &self.raw_points()[start..end]
+    }
+}
+
+impl<T: PointType> InterleavedPointBufferMut for TypedPointVec<T> {
+    fn get_raw_point_mut(&mut self, point_index: usize) -> &mut [u8] {
+        if point_index >= self.len() {
+            panic!(
+                "TypedPointVec::get_raw_point_mut: Point index {} out of bounds!",
+                point_index
+            );
+        }
+        let range = self.raw_point_range(point_index);
+        &mut self.raw_points_mut()[range]
+    }
+
+    fn get_raw_points_mut(&mut self, index_range: Range<usize>) -> &mut [u8] {
+        if index_range.end > self.len() {
+            panic!(
+                "TypedPointVec::get_raw_points_mut: Point indices {:?} out of bounds!",
+                index_range
+            );
+        }
+        let start = self.raw_point_range(index_range.start).start;
+        let end = self.raw_point_range(index_range.end).start;
+        &mut self.raw_points_mut()[start..end]
+    }
+}
+
+impl<T: PointType> From<Vec<T>> for TypedPointVec<T> {
+    fn from(points: Vec<T>) -> Self {
+        Self::from_vec(points)
+    }
+}
+
+impl<T: PointType> From<TypedPointVec<T>> for Vec<T> {
+    fn from(points: TypedPointVec<T>) -> Self {
+        points.into_vec()
+    }
+}
+
+impl<T: PointType> FromIterator<T> for TypedPointVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_vec(iter.into_iter().collect())
+    }
+}
+
+impl<T: PointType> Extend<T> for TypedPointVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.points.extend(iter);
+    }
+}
+
+/// Converts a `TypedPointVec<T>` into an `InterleavedVecPointStorage`.
+///
+/// *Note:* This conversion is only free if `T` has an alignment of 1 (i.e. for `#[repr(packed)]` types). For all other
+/// `PointType`s, it allocates a new buffer and copies all points, because the memory of an `InterleavedVecPointStorage`
+/// is only aligned to a single byte
+impl<T: PointType> From<TypedPointVec<T>> for InterleavedVecPointStorage {
+    /// Converts the given `TypedPointVec<T>` into an `InterleavedVecPointStorage`. Allocates a new buffer and copies all
+    /// points if `T` has an alignment larger than 1, otherwise the memory of `points` is reused
+    fn from(points: TypedPointVec<T>) -> Self {
+        let size_of_point = std::mem::size_of::<T>();
+        // The memory of a Vec<u8> is deallocated with an alignment of 1, so the allocation of the Vec<T> can only be
+        // reused if T has the same alignment
+        let raw_points = if std::mem::align_of::<T>() == 1 {
+            let mut points = ManuallyDrop::new(points.points);
+            unsafe {
+                Vec::from_raw_parts(
+                    points.as_mut_ptr() as *mut u8,
+                    points.len() * size_of_point,
+                    points.capacity() * size_of_point,
+                )
+            }
+        } else {
+            points.raw_points().to_vec()
+        };
+        InterleavedVecPointStorage::from_raw_points(T::layout(), raw_points)
+    }
+}
+
+/// Converts an `InterleavedVecPointStorage` into a `TypedPointVec<T>`, if the `PointLayout` of the storage matches the
+/// `PointLayout` of `T`.
+///
+/// *Note:* This conversion is only free if `T` has an alignment of 1 (i.e. for `#[repr(packed)]` types). For all other
+/// `PointType`s, it allocates a new buffer and copies all points, because the memory of an `InterleavedVecPointStorage`
+/// is only aligned to a single byte
+impl<T: PointType> TryFrom<InterleavedVecPointStorage> for TypedPointVec<T> {
+    type Error = anyhow::Error;
+
+    /// Converts the given `InterleavedVecPointStorage` into a `TypedPointVec<T>`. Fails if the `PointLayout` of the storage
+    /// does not match the `PointLayout` of `T`. Allocates a new buffer and copies all points if `T` has an alignment
+    /// larger than 1, otherwise the memory of `storage` is reused
+    fn try_from(storage: InterleavedVecPointStorage) -> Result<Self> {
+        if *storage.point_layout() != T::layout() {
+            bail!(
+                "PointLayout of the buffer ({}) does not match the PointLayout of type T ({})",
+                storage.point_layout(),
+                T::layout()
+            );
+        }
+        let size_of_point = std::mem::size_of::<T>();
+        let raw_points = storage.into_raw_points();
+        let points = if std::mem::align_of::<T>() == 1
+            && raw_points.capacity().is_multiple_of(size_of_point)
+        {
+            let mut raw_points = ManuallyDrop::new(raw_points);
+            unsafe {
+                Vec::from_raw_parts(
+                    raw_points.as_mut_ptr() as *mut T,
+                    raw_points.len() / size_of_point,
+                    raw_points.capacity() / size_of_point,
+                )
+            }
+        } else {
+            raw_points
+                .chunks_exact(size_of_point)
+                .map(|point| unsafe { (point.as_ptr() as *const T).read_unaligned() })
+                .collect()
+        };
+        Ok(Self::from_vec(points))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::containers::{
+        InterleavedPointBufferExt, PerAttributeVecPointStorage, PointBufferExt,
+        PointBufferWriteableExt,
+    };
+    use crate::layout::attributes;
+    use nalgebra::Vector3;
+    use pasture_derive::PointType;
+
+    // We need this, otherwise we can't use the derive(PointType) macro from within pasture_core because the macro
+    // doesn't recognize the name 'pasture_core' :/
+    use crate as pasture_core;
+
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone, PartialEq, PointType)]
+    struct AlignedPointType(
+        #[pasture(BUILTIN_POSITION_3D)] Vector3<f64>,
+        #[pasture(BUILTIN_INTENSITY)] u16,
+    );
+
+    #[repr(packed)]
+    #[derive(Debug, Copy, Clone, PartialEq, PointType)]
+    struct PackedPointType(
+        #[pasture(BUILTIN_INTENSITY)] u16,
+        #[pasture(BUILTIN_GPS_TIME)] f64,
+    );
+
+    #[test]
+    fn test_typed_point_vec_as_point_buffer() {
+        let mut points = (0..10)
+            .map(|idx| AlignedPointType(Vector3::new(idx as f64, 0.0, 0.0), idx as u16))
+            .collect::<TypedPointVec<_>>();
+        assert_eq!(
+            std::mem::size_of::<AlignedPointType>(),
+            points.point_layout().size_of_point_entry() as usize
+        );
+        assert_eq!(10, PointBuffer::len(&points));
+        assert_eq!(
+            (0..10).collect::<Vec<u16>>(),
+            points
+                .iter_attribute::<u16>(&attributes::INTENSITY)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(&points[3], points.get_point_ref::<AlignedPointType>(3));
+
+        points.set_attribute(&attributes::INTENSITY, 3, 42_u16);
+        assert_eq!(42, points[3].1);
+
+        let per_attribute = PerAttributeVecPointStorage::from(points.as_slice());
+        points.push(&per_attribute);
+        assert_eq!(20, points.len());
+        assert_eq!(points[3], points[13]);
+
+        points.splice(0..1, &TypedPointVec::from(vec![points[9]]));
+        assert_eq!(points[9], points[0]);
+
+        points.retain(|point: &AlignedPointType| point.1 % 2 == 0);
+        assert_eq!(11, points.len());
+
+        points.resize(12);
+        assert_eq!(AlignedPointType(Vector3::new(0.0, 0.0, 0.0), 0), points[11]);
+        points.clear();
+        assert!(points.is_empty());
+    }
+
+    #[test]
+    fn test_typed_point_vec_to_and_from_interleaved_storage() {
+        let aligned_points = (0..10)
+            .map(|idx| AlignedPointType(Vector3::new(idx as f64, 1.0, 2.0), idx as u16))
+            .collect::<Vec<_>>();
+        // Types with an alignment larger than 1 are copied in both directions
+        let typed = TypedPointVec::from(aligned_points.clone());
+        let points_ptr = typed.as_ptr() as *const u8;
+        let storage = InterleavedVecPointStorage::from(typed);
+        assert_ne!(points_ptr, storage.get_raw_points_ref(0..10).as_ptr());
+        assert_eq!(
+            aligned_points.as_slice(),
+            storage.get_points_ref::<AlignedPointType>(0..10)
+        );
+        let storage_ptr = storage.get_raw_points_ref(0..10).as_ptr();
+        let typed = TypedPointVec::<AlignedPointType>::try_from(storage).unwrap();
+        assert_ne!(storage_ptr, typed.as_ptr() as *const u8);
+        assert_eq!(aligned_points, typed.into_vec());
+
+        // Packed types reuse the allocation of the source buffer
+        let packed_points = (0..10)
+            .map(|idx| PackedPointType(idx as u16, idx as f64))
+            .collect::<Vec<_>>();
+        let typed = TypedPointVec::from(packed_points.clone());
+        let points_ptr = typed.as_ptr() as *const u8;
+        let storage = InterleavedVecPointStorage::from(typed);
+        assert_eq!(points_ptr, storage.get_raw_points_ref(0..10).as_ptr());
+        assert_eq!(
+            packed_points,
+            storage.iter_point::<PackedPointType>().collect::<Vec<_>>()
+        );
+        let typed = TypedPointVec::<PackedPointType>::try_from(storage).unwrap();
+        assert_eq!(points_ptr, typed.as_ptr() as *const u8);
+        assert_eq!(packed_points.as_slice(), typed.as_slice());
+    }
+
+    #[test]
+    fn test_typed_point_vec_from_storage_with_wrong_layout() {
+        let storage = InterleavedVecPointStorage::new(AlignedPointType::layout());
+        assert!(TypedPointVec::<PackedPointType>::try_from(storage).is_err());
+    }
+}
@@ -1,9 +1,10 @@
-use crate::containers::InterleavedPointView;
-use crate::layout::conversion::get_converter_for_attributes;
+use crate::containers::{InterleavedPointView, PointBuffer, PointBufferWriteable};
+use crate::layout::conversion::try_get_converter_for_attributes;
 use crate::layout::{PointAttributeDefinition, PointLayout, PrimitiveType};
 use anyhow::{bail, Context, Result};
 use std::io::Cursor;
 use std::mem::MaybeUninit;
+use std::sync::Arc;
 
 /// A trait to handle points that layout can't be known to compile time.
 pub trait UntypedPoint {
@@ -43,6 +44,182 @@ pub trait UntypedPoint {
     fn get_interleaved_point_view(&self) -> InterleavedPointView;
 }
 
+/// Returns the byte range of `attribute` within a single point record in the given `layout`
+fn attribute_range_in_record(
+    layout: &PointLayout,
+    attribute: &PointAttributeDefinition,
+    record_len: usize,
+) -> Result<std::ops::Range<usize>> {
+    let attribute = layout
+        .get_attribute(attribute)
+        .with_context(|| "Cannot find attribute.")?;
+    if attribute.bit_field().is_some() {
+        bail!(
+            "Bit field attribute {} can't be accessed as a byte slice.",
+            attribute
+        );
+    }
+    let start = attribute.offset() as usize;
+    let end = start + attribute.datatype().size() as usize;
+    if record_len < end {
+        bail!("Buffer size to small.");
+    }
+    Ok(start..end)
+}
+
+fn get_raw_attribute_from_record<'point>(
+    layout: &PointLayout,
+    record: &'point [u8],
+    attribute: &PointAttributeDefinition,
+) -> Result<&'point [u8]> {
+    let range = attribute_range_in_record(layout, attribute, record.len())?;
+    Ok(&record[range])
+}
+
+fn get_raw_attribute_from_record_mut<'point>(
+    layout: &PointLayout,
+    record: &'point mut [u8],
+    attribute: &PointAttributeDefinition,
+) -> Result<&'point mut [u8]> {
+    let range = attribute_range_in_record(layout, attribute, record.len())?;
+    Ok(&mut record[range])
+}
+
+fn set_raw_attribute_in_record(
+    layout: &PointLayout,
+    record: &mut [u8],
+    attribute: &PointAttributeDefinition,
+    value_byte_slice: &[u8],
+) -> Result<()> {
+    let attribute = layout
+        .get_attribute(attribute)
+        .with_context(|| "Cannot find attribute.")?;
+    let start = attribute.offset() as usize;
+    let end = start + attribute.size_in_point_record() as usize;
+    if record.len() < end {
+        bail!("Buffer size to small.");
+    }
+    attribute.write_to_point_record(value_byte_slice, record);
+    Ok(())
+}
+
+fn get_attribute_from_record<T: PrimitiveType>(
+    layout: &PointLayout,
+    record: &[u8],
+    attribute: &PointAttributeDefinition,
+) -> Result<T> {
+    let mut target_attribute = MaybeUninit::<T>::uninit();
+    let source_attribute_byte_slice = get_raw_attribute_from_record(layout, record, attribute)?;
+    // access via [u8] slice
+    let target_attribute_byte_slice = unsafe {
+        std::slice::from_raw_parts_mut(
+            target_attribute.as_mut_ptr() as *mut u8,
+            std::mem::size_of::<T>(),
+        )
+    };
+    if T::data_type() != attribute.datatype() {
+        let target_attribute_definition =
+            PointAttributeDefinition::with_custom_datatype(attribute, T::data_type());
+        let converter = match try_get_converter_for_attributes(attribute, &target_attribute_definition)? {
+                    Some(c) => c,
+                    None => bail!("Can't convert from attribute {} to attribute {} because no valid conversion exists", attribute, target_attribute_definition),
+                };
+        unsafe { converter(source_attribute_byte_slice, target_attribute_byte_slice) };
+    } else {
+        target_attribute_byte_slice.copy_from_slice(source_attribute_byte_slice);
+    }
+    Ok(unsafe { target_attribute.assume_init() })
+}
+
+fn set_attribute_in_record<T: PrimitiveType>(
+    layout: &PointLayout,
+    record: &mut [u8],
+    attribute: &PointAttributeDefinition,
+    value: &T,
+) -> Result<()> {
+    let ptr = value as *const _;
+    let value_byte_slice =
+        unsafe { std::slice::from_raw_parts(ptr as *mut u8, std::mem::size_of::<T>()) };
+    if T::data_type() != attribute.datatype() {
+        let source_attribute_definition =
+            PointAttributeDefinition::with_custom_datatype(attribute, T::data_type());
+        let converter = try_get_converter_for_attributes(&source_attribute_definition, attribute)?
+                .ok_or_else(|| anyhow::anyhow!("Can't convert from attribute {} to attribute {} because no valid conversion exists", 
+                attribute, source_attribute_definition))?;
+        unsafe {
+            converter(
+                value_byte_slice,
+                get_raw_attribute_from_record_mut(layout, record, attribute)?,
+            )
+        };
+    } else {
+        set_raw_attribute_in_record(layout, record, attribute, value_byte_slice)?;
+    }
+    Ok(())
+}
+
+/// Implements `UntypedPoint` for a type that stores its `PointLayout` in the field `layout` and the data of the point
+/// in the field `$record`
+macro_rules! impl_untyped_point {
+    ($type:ty, $record:ident) => {
+        impl UntypedPoint for $type {
+            fn get_raw_attribute<'point>(
+                &'point self,
+                attribute: &PointAttributeDefinition,
+            ) -> Result<&'point [u8]> {
+                get_raw_attribute_from_record(&self.layout, &self.$record[..], attribute)
+            }
+
+            fn get_raw_attribute_mut<'point>(
+                &'point mut self,
+                attribute: &PointAttributeDefinition,
+            ) -> Result<&'point mut [u8]> {
+                get_raw_attribute_from_record_mut(&self.layout, &mut self.$record[..], attribute)
+            }
+
+            fn set_raw_attribute(
+                &mut self,
+                attribute: &PointAttributeDefinition,
+                value_byte_slice: &[u8],
+            ) -> Result<()> {
+                set_raw_attribute_in_record(
+                    &self.layout,
+                    &mut self.$record[..],
+                    attribute,
+                    value_byte_slice,
+                )
+            }
+
+            fn get_attribute<'point, T: PrimitiveType>(
+                &'point self,
+                attribute: &PointAttributeDefinition,
+            ) -> Result<T> {
+                get_attribute_from_record(&self.layout, &self.$record[..], attribute)
+            }
+
+            fn set_attribute<T: PrimitiveType>(
+                &mut self,
+                attribute: &PointAttributeDefinition,
+                value: &T,
+            ) -> Result<()> {
+                set_attribute_in_record(&self.layout, &mut self.$record[..], attribute, value)
+            }
+
+            fn get_layout(&self) -> &PointLayout {
+                &self.layout
+            }
+
+            fn get_cursor(&mut self) -> Cursor<&mut [u8]> {
+                Cursor::new(&mut self.$record[..])
+            }
+
+            fn get_interleaved_point_view(&self) -> InterleavedPointView<'_> {
+                InterleavedPointView::from_raw_slice(&self.$record[..], (*self.layout).clone())
+            }
+        }
+    };
+}
+
 /// An implementaion of `UntypedPoint` trait that has an internal buffer.
 pub struct UntypedPointBuffer<'layout> {
     layout: &'layout PointLayout,
@@ -57,239 +234,261 @@ impl<'layout> UntypedPointBuffer<'layout> {
         }
     }
 }
-impl UntypedPoint for UntypedPointBuffer<'_> {
-    fn get_raw_attribute<'point>(
-        &'point self,
-        attribute: &PointAttributeDefinition,
-    ) -> Result<&'point [u8]> {
-        let attribute = self
-            .layout
-            .get_attribute(attribute)
-            .with_context(|| "Cannot find attribute.")?;
-        let start = attribute.offset() as usize;
-        let end = start + attribute.datatype().size() as usize;
-        if self.buffer.len() < end {
-            bail!("Buffer size to small.");
-        }
-        Ok(&self.buffer[start..end])
+
+impl_untyped_point!(UntypedPointBuffer<'_>, buffer);
+
+/// An implementaion of `UntypedPoint` trait that handles an external buffer.
+pub struct UntypedPointSlice<'point> {
+    layout: &'point PointLayout,
+    slice: &'point mut [u8],
+}
+
+impl<'point> UntypedPointSlice<'point> {
+    pub fn new(layout: &'point PointLayout, slice: &'point mut [u8]) -> Self {
+        Self { layout, slice }
     }
-    fn set_raw_attribute(
-        &mut self,
-        attribute: &PointAttributeDefinition,
-        value_byte_slice: &[u8],
 
-    ) -> Result<()> {
-        let attribute = self
-            .layout
-            .get_attribute(attribute)
-            .with_context(|| "Cannot find attribute.")?;
-        let start = attribute.offset() as usize;
-        let end = start + attribute.datatype().size() as usize;
-        if self.buffer.len() < end {
-            bail!("Buffer size to small.");
+    /// Copies the data of this point into a new [OwnedUntypedPoint]. This also copies the `PointLayout` into a new
+    /// `Arc`, so when creating many owned points, prefer [to_owned_point_with_layout](UntypedPointSlice::to_owned_point_with_layout)
+    pub fn to_owned_point(&self) -> OwnedUntypedPoint {
+        OwnedUntypedPoint::from_raw_point(Arc::new(self.layout.clone()), self.slice.to_vec())
+    }
+
+    /// Copies the data of this point into a new [OwnedUntypedPoint] that shares the given `layout`. Use this together
+    /// with the `shared_layout` method of [UntypedPointIterator] or [UntypedPointIteratorMut] to create many owned points
+    /// without copying the `PointLayout` for each of them
+    ///
+    /// # Panics
+    ///
+    /// If `layout` is not equal to the `PointLayout` of this point
+    pub fn to_owned_point_with_layout(&self, layout: &Arc<PointLayout>) -> OwnedUntypedPoint {
+        if !std::ptr::eq(&**layout, self.layout) && **layout != *self.layout {
+            panic!("PointLayout does not match the PointLayout of this point");
         }
-        self.buffer[start..end].copy_from_slice(value_byte_slice);
-        Ok(())
+        OwnedUntypedPoint::from_raw_point(layout.clone(), self.slice.to_vec())
     }
+}
 
-    fn get_layout(&self) -> &PointLayout {
-        self.layout
+impl_untyped_point!(UntypedPointSlice<'_>, slice);
+
+/// An implementation of the `UntypedPoint` trait that owns both its data and its `PointLayout`. In contrast to
+/// [UntypedPointBuffer], it does not borrow its `PointLayout`, so it can be stored, sent to other threads or returned
+/// from functions. The `PointLayout` is shared through an `Arc`, so many points with the same layout can be created
+/// without copying the layout
+/// ```
+/// # use std::sync::Arc;
+/// # use pasture_core::containers::*;
+/// # use pasture_core::layout::*;
+/// let layout = Arc::new(PointLayout::from_attributes(&[attributes::INTENSITY]));
+/// let mut point = OwnedUntypedPoint::new(layout.clone());
+/// point.set_attribute(&attributes::INTENSITY, &42_u16).unwrap();
+///
+/// let point = std::thread::spawn(move || point).join().unwrap();
+/// assert_eq!(42, point.get_attribute::<u16>(&attributes::INTENSITY).unwrap());
+/// ```
+#[derive(Clone)]
+pub struct OwnedUntypedPoint {
+    layout: Arc<PointLayout>,
+    buffer: Vec<u8>,
+}
+
+impl OwnedUntypedPoint {
+    /// Creates a new `OwnedUntypedPoint` with the given `PointLayout`. All bytes of the point are initialized to zero
+    pub fn new(layout: Arc<PointLayout>) -> Self {
+        let buffer = vec![0; layout.size_of_point_entry() as usize];
+        Self { layout, buffer }
     }
 
-    fn get_cursor(&mut self) -> Cursor<&mut [u8]> {
-        Cursor::new(&mut self.buffer)
+    /// Creates a new `OwnedUntypedPoint` with the given `PointLayout` from the raw memory of a single point
+    ///
+    /// # Panics
+    ///
+    /// If the length of `data` does not match the size of a single point in `layout`
+    pub fn from_raw_point(layout: Arc<PointLayout>, data: Vec<u8>) -> Self {
+        if data.len() != layout.size_of_point_entry() as usize {
+            panic!(
+                "Raw point data has size {} but the PointLayout requires {} bytes per point",
+                data.len(),
+                layout.size_of_point_entry()
+            );
+        }
+        Self {
+            layout,
+            buffer: data,
+        }
     }
 
-    fn get_interleaved_point_view(&self) -> InterleavedPointView {
-        InterleavedPointView::from_raw_slice(&self.buffer, self.layout.clone())
+    /// Returns the shared `PointLayout` of this point
+    pub fn shared_layout(&self) -> &Arc<PointLayout> {
+        &self.layout
     }
 
-    fn get_attribute<'point, T: PrimitiveType>(
-        &'point self,
-        attribute: &PointAttributeDefinition,
-    ) -> Result<T> {
-        let mut target_attribute = MaybeUninit::<T>::uninit();
-        let source_attribute_byte_slice = self.get_raw_attribute(attribute)?;
-        // access via [u8] slice
-        let target_attribute_byte_slice = unsafe {
-            std::slice::from_raw_parts_mut(
-                target_attribute.as_mut_ptr() as *mut u8,
-                std::mem::size_of::<T>(),
-            )
-        };
-        if T::data_type() != attribute.datatype() {
-            let target_attribute_definition =
-                PointAttributeDefinition::with_custom_datatype(attribute, T::data_type());
-            let converter = match get_converter_for_attributes(attribute, &target_attribute_definition ) {
-                        Some(c) => c,
-                        None => bail!("Can't convert from attribute {} to attribute {} because no valid conversion exists", attribute, target_attribute_definition),
-                    };
-            unsafe { converter(source_attribute_byte_slice, target_attribute_byte_slice) };
-        } else {
-            target_attribute_byte_slice.copy_from_slice(source_attribute_byte_slice);
-        }
-        Ok(unsafe { target_attribute.assume_init() })
+    /// Returns the raw memory of this point
+    pub fn raw_point(&self) -> &[u8] {
+        &self.buffer
     }
 
-    fn set_attribute<T: PrimitiveType>(
-        &mut self,
-        attribute: &PointAttributeDefinition,
-        value: &T,
-    ) -> Result<()> {
-        let ptr = value as *const _;
-        let value_byte_slice =
-            unsafe { std::slice::from_raw_parts(ptr as *mut u8, std::mem::size_of::<T>()) };
-        if T::data_type() != attribute.datatype() {
-            let source_attribute_definition =
-                PointAttributeDefinition::with_custom_datatype(attribute, T::data_type());
-            let converter = get_converter_for_attributes(&source_attribute_definition, attribute)
-                    .ok_or_else(|| anyhow::anyhow!("Can't convert from attribute {} to attribute {} because no valid conversion exists", 
-                    attribute, source_attribute_definition))?;
-            unsafe { converter(value_byte_slice, self.get_raw_attribute_mut(attribute)?) };
-        } else {
-            self.set_raw_attribute(attribute, value_byte_slice)?;
-        }
-        Ok(())
+    /// Returns an [UntypedPointSlice] that refers to the data of this point
+    pub fn as_point_slice(&mut self) -> UntypedPointSlice<'_> {
+        UntypedPointSlice::new(&self.layout, &mut self.buffer)
     }
 
-    fn get_raw_attribute_mut<'point>(
-        &'point mut self,
-        attribute: &PointAttributeDefinition,
-    ) -> Result<&'point mut [u8]> {
-        let attribute = self
-            .layout
-            .get_attribute(attribute)
-            .with_context(|| "Cannot find attribute.")?;
-        let start = attribute.offset() as usize;
-        let end = start + attribute.datatype().size() as usize;
-        if self.buffer.len() < end {
-            bail!("Buffer size to small.");
-        }
-        Ok(&mut self.buffer[start..end])
+    /// Consumes this point and returns its raw memory
+    pub fn into_raw_point(self) -> Vec<u8> {
+        self.buffer
     }
 }
 
-/// An implementaion of `UntypedPoint` trait that handles an external buffer.
-pub struct UntypedPointSlice<'point> {
-    layout: &'point PointLayout,
-    slice: &'point mut [u8],
+impl From<UntypedPointBuffer<'_>> for OwnedUntypedPoint {
+    fn from(point: UntypedPointBuffer<'_>) -> Self {
+        Self {
+            layout: Arc::new(point.layout.clone()),
+            buffer: point.buffer,
+        }
+    }
 }
 
-impl<'point> UntypedPointSlice<'point> {
-    pub fn new(layout: &'point PointLayout, slice: &'point mut [u8]) -> Self {
-        Self { layout, slice }
-    }
+impl_untyped_point!(OwnedUntypedPoint, buffer);
+
+/// Iterator over all points of a `PointBuffer` that yields each point as an [UntypedPointSlice]. Since the buffer might
+/// not store its points contiguously in memory, each point is copied into a single scratch buffer that is reused for
+/// all points, so no memory is allocated per point. As the yielded points borrow from the iterator, this type can't
+/// implement `Iterator`. Instead, call [next_point](UntypedPointIterator::next_point) in a loop. Changes to the yielded
+/// points are not written back to the buffer, use [UntypedPointIteratorMut] for this. The `PointLayout` of the buffer is
+/// shared by all yielded points, so [to_owned_point_with_layout](UntypedPointSlice::to_owned_point_with_layout) together
+/// with [shared_layout](UntypedPointIterator::shared_layout) creates owned points without copying the layout
+/// ```
+/// # use pasture_core::containers::*;
+/// # use pasture_core::layout::*;
+/// let mut buffer = PerAttributeVecPointStorage::new(PointLayout::from_attributes(&[attributes::INTENSITY]));
+/// buffer.resize(3);
+/// buffer.set_attribute(&attributes::INTENSITY, 1, 42_u16);
+///
+/// let mut intensities = vec![];
+/// let mut points = buffer.iter_untyped_points();
+/// while let Some(point) = points.next_point() {
+///     intensities.push(point.get_attribute::<u16>(&attributes::INTENSITY).unwrap());
+/// }
+/// assert_eq!(vec![0, 42, 0], intensities);
+///
+/// let mut points = buffer.iter_untyped_points();
+/// let layout = points.shared_layout().clone();
+/// let mut owned_points = vec![];
+/// while let Some(point) = points.next_point() {
+///     owned_points.push(point.to_owned_point_with_layout(&layout));
+/// }
+/// assert!(std::sync::Arc::ptr_eq(&layout, owned_points[0].shared_layout()));
+/// ```
+pub struct UntypedPointIterator<'a, B: PointBuffer + ?Sized> {
+    buffer: &'a B,
+    layout: Arc<PointLayout>,
+    point_data: Vec<u8>,
+    current_index: usize,
 }
 
-impl UntypedPoint for UntypedPointSlice<'_> {
-    fn get_attribute<'point, T: PrimitiveType>(
-        &'point self,
-        attribute: &PointAttributeDefinition,
-    ) -> Result<T> {
-        let mut target_attribute = MaybeUninit::<T>::uninit();
-        let source_attribute_byte_slice = self.get_raw_attribute(attribute)?;
-        // access via [u8] slice
-        let target_attribute_byte_slice = unsafe {
-            std::slice::from_raw_parts_mut(
-                target_attribute.as_mut_ptr() as *mut u8,
-                std::mem::size_of::<T>(),
-            )
-        };
-        if T::data_type() != attribute.datatype() {
-            let target_attribute_definition =
-                PointAttributeDefinition::with_custom_datatype(attribute, T::data_type());
-            let converter = match get_converter_for_attributes(attribute, &target_attribute_definition ) {
-                        Some(c) => c,
-                        None => bail!("Can't convert from attribute {} to attribute {} because no valid conversion exists", attribute, target_attribute_definition),
-                    };
-            unsafe { converter(source_attribute_byte_slice, target_attribute_byte_slice) };
-        } else {
-            target_attribute_byte_slice.copy_from_slice(source_attribute_byte_slice);
+impl<'a, B: PointBuffer + ?Sized> UntypedPointIterator<'a, B> {
+    /// Creates a new `UntypedPointIterator` over all points in the given `PointBuffer`
+    pub fn new(buffer: &'a B) -> Self {
+        Self {
+            buffer,
+            layout: Arc::new(buffer.point_layout().clone()),
+            point_data: vec![0; buffer.point_layout().size_of_point_entry() as usize],
+            current_index: 0,
         }
-        Ok(unsafe { target_attribute.assume_init() })
     }
 
-    fn get_raw_attribute<'point>(
-        &'point self,
-        attribute: &PointAttributeDefinition,
-    ) -> Result<&'point [u8]> {
-        let attribute = self
-            .layout
-            .get_attribute(attribute)
-            .with_context(|| "Cannot find attribute.")?;
-        let start = attribute.offset() as usize;
-        let end = start + attribute.datatype().size() as usize;
-        if self.slice.len() < end {
-            bail!("Buffer size to small.");
-        }
-        Ok(&self.slice[start..end])
+    /// Returns the `PointLayout` of the yielded points, shared through an `Arc`
+    pub fn shared_layout(&self) -> &Arc<PointLayout> {
+        &self.layout
     }
 
-    fn get_raw_attribute_mut<'point>(
-        &'point mut self,
-        attribute: &PointAttributeDefinition,
-    ) -> Result<&'point mut [u8]> {
-        let attribute = self
-            .layout
-            .get_attribute(attribute)
-            .with_context(|| "Cannot find attribute.")?;
-        let start = attribute.offset() as usize;
-        let end = start + attribute.datatype().size() as usize;
-        if self.slice.len() < end {
-            bail!("Buffer size to small.");
+    /// Returns the next point of the buffer, or `None` if all points have been visited
+    pub fn next_point(&mut self) -> Option<UntypedPointSlice<'_>> {
+        if self.current_index == self.buffer.len() {
+            return None;
         }
-        Ok(&mut self.slice[start..end])
+        self.buffer
+            .get_raw_point(self.current_index, &mut self.point_data);
+        self.current_index += 1;
+        Some(UntypedPointSlice::new(&self.layout, &mut self.point_data))
     }
+}
 
-    fn set_raw_attribute(
-        &mut self,
-        attribute: &PointAttributeDefinition,
-        value: &[u8],
-    ) -> Result<()> {
-        let attribute = self
-            .layout
-            .get_attribute(attribute)
-            .with_context(|| "Cannot find attribute.")?;
-        let start = attribute.offset() as usize;
-        let end = start as usize + attribute.datatype().size() as usize;
-        if self.slice.len() < end {
-            bail!("Buffer size to small.");
+/// Iterator over all points of a `PointBufferWriteable` that yields each point as a mutable [UntypedPointSlice]. Like
+/// [UntypedPointIterator], each point is copied into a reusable scratch buffer. Changes to a point are written back to
+/// the buffer once the next point is requested or the iterator is dropped. Points whose data was not changed are not
+/// written back, so iterating over e.g. a `SharedPointBuffer` without modifying any point keeps its memory shared
+/// ```
+/// # use pasture_core::containers::*;
+/// # use pasture_core::layout::*;
+/// let mut buffer = InterleavedVecPointStorage::new(PointLayout::from_attributes(&[attributes::INTENSITY]));
+/// buffer.resize(3);
+///
+/// let mut points = buffer.iter_untyped_points_mut();
+/// let mut intensity = 0_u16;
+/// while let Some(mut point) = points.next_point() {
+///     intensity += 1;
+///     point.set_attribute(&attributes::INTENSITY, &intensity).unwrap();
+/// }
+/// drop(points);
+/// assert_eq!(vec![1, 2, 3], buffer.iter_attribute::<u16>(&attributes::INTENSITY).collect::<Vec<_>>());
+/// ```
+pub struct UntypedPointIteratorMut<'a, B: PointBufferWriteable + ?Sized> {
+    buffer: &'a mut B,
+    layout: Arc<PointLayout>,
+    point_data: Vec<u8>,
+    original_point_data: Vec<u8>,
+    current_index: usize,
+    current_point_index: Option<usize>,
+}
+
+impl<'a, B: PointBufferWriteable + ?Sized> UntypedPointIteratorMut<'a, B> {
+    /// Creates a new `UntypedPointIteratorMut` over all points in the given `PointBufferWriteable`
+    pub fn new(buffer: &'a mut B) -> Self {
+        let layout = Arc::new(buffer.point_layout().clone());
+        let point_data = vec![0; layout.size_of_point_entry() as usize];
+        Self {
+            buffer,
+            layout,
+            original_point_data: point_data.clone(),
+            point_data,
+            current_index: 0,
+            current_point_index: None,
         }
-        self.slice[start..end].copy_from_slice(value);
-        Ok(())
     }
 
-    fn get_layout(&self) -> &PointLayout {
-        self.layout
+    /// Returns the `PointLayout` of the yielded points, shared through an `Arc`
+    pub fn shared_layout(&self) -> &Arc<PointLayout> {
+        &self.layout
     }
 
-    fn get_cursor(&mut self) -> Cursor<&mut [u8]> {
-        Cursor::new(self.slice)
+    /// Returns the next point of the buffer, or `None` if all points have been visited. Changes to the previously
+    /// returned point are written back to the buffer
+    pub fn next_point(&mut self) -> Option<UntypedPointSlice<'_>> {
+        self.write_back_current_point();
+        if self.current_index == self.buffer.len() {
+            return None;
+        }
+        self.buffer
+            .get_raw_point(self.current_index, &mut self.point_data);
+        self.original_point_data.copy_from_slice(&self.point_data);
+        self.current_point_index = Some(self.current_index);
+        self.current_index += 1;
+        Some(UntypedPointSlice::new(&self.layout, &mut self.point_data))
     }
 
-    fn get_interleaved_point_view(&self) -> InterleavedPointView {
-        InterleavedPointView::from_raw_slice(&self.slice, self.layout.clone())
+    fn write_back_current_point(&mut self) {
+        if let Some(point_index) = self.current_point_index.take() {
+            if self.point_data != self.original_point_data {
+                self.buffer.set_raw_point(point_index, &self.point_data);
+            }
+        }
     }
+}
 
-    fn set_attribute<T: PrimitiveType>(
-        &mut self,
-        attribute: &PointAttributeDefinition,
-        value: &T,
-    ) -> Result<()> {
-        let ptr = value as *const _;
-        let value_byte_slice =
-            unsafe { std::slice::from_raw_parts(ptr as *mut u8, std::mem::size_of::<T>()) };
-        if T::data_type() != attribute.datatype() {
-            let source_attribute_definition =
-                PointAttributeDefinition::with_custom_datatype(attribute, T::data_type());
-            let converter = get_converter_for_attributes(&source_attribute_definition, attribute)
-                    .ok_or_else(|| anyhow::anyhow!("Can't convert from attribute {} to attribute {} because no valid conversion exists", 
-                    attribute, source_attribute_definition))?;
-            unsafe { converter(value_byte_slice, self.get_raw_attribute_mut(attribute)?) };
-        } else {
-            self.set_raw_attribute(attribute, value_byte_slice)?;
-        }
-        Ok(())
+impl<B: PointBufferWriteable + ?Sized> Drop for UntypedPointIteratorMut<'_, B> {
+    fn drop(&mut self) {
+        self.write_back_current_point();
     }
 }
 
@@ -302,6 +501,10 @@ impl UntypedPoint for UntypedPointSlice<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::containers::{
+        InterleavedVecPointStorage, PerAttributeVecPointStorage, PointBufferExt,
+        PointBufferWriteableExt, SharedPointBuffer,
+    };
     use crate::layout::attributes;
     use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
     use nalgebra::Vector3;
@@ -365,9 +568,7 @@ mod tests {
         let mut point = UntypedPointSlice::new(&layout, &mut buffer);
         let intensity_value: u16 = 42;
 
-        let offset = layout
-            .offset_of(&attributes::INTENSITY)
-            .unwrap();
+        let offset = layout.offset_of(&attributes::INTENSITY).unwrap();
         // Write
         let mut cursor = point.get_cursor();
         cursor.set_position(offset);
@@ -419,6 +620,132 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_owned_point() -> Result<()> {
+        let layout =
+            PointLayout::from_attributes(&[attributes::POSITION_3D, attributes::INTENSITY]);
+        let mut point = UntypedPointBuffer::new(&layout);
+        point.set_attribute(&attributes::INTENSITY, &42_u16)?;
+
+        let mut owned_point: OwnedUntypedPoint = point.into();
+        owned_point.set_attribute(&attributes::POSITION_3D, &Vector3::new(1.0, 2.0, 3.0))?;
+        let owned_point = std::thread::spawn(move || owned_point).join().unwrap();
+
+        assert_eq!(layout, **owned_point.shared_layout());
+        assert_eq!(
+            42,
+            owned_point.get_attribute::<u16>(&attributes::INTENSITY)?
+        );
+        assert_eq!(
+            Vector3::new(1.0, 2.0, 3.0),
+            owned_point.get_attribute::<Vector3<f64>>(&attributes::POSITION_3D)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_iter_untyped_points() -> Result<()> {
+        let layout =
+            PointLayout::from_attributes(&[attributes::POSITION_3D, attributes::INTENSITY]);
+        let mut buffer = PerAttributeVecPointStorage::new(layout);
+        buffer.resize(4);
+        {
+            let mut points = buffer.iter_untyped_points_mut();
+            let mut intensity = 0_u16;
+            while let Some(mut point) = points.next_point() {
+                intensity += 1;
+                point.set_attribute(&attributes::INTENSITY, &intensity)?;
+            }
+        }
+        assert_eq!(
+            vec![1, 2, 3, 4],
+            buffer
+                .iter_attribute::<u16>(&attributes::INTENSITY)
+                .collect::<Vec<_>>()
+        );
+
+        let mut owned_points = vec![];
+        let mut points = buffer.iter_untyped_points();
+        while let Some(point) = points.next_point() {
+            owned_points.push(point.to_owned_point());
+        }
+        assert_eq!(4, owned_points.len());
+        for (index, point) in owned_points.iter().enumerate() {
+            assert_eq!(
+                index as u16 + 1,
+                point.get_attribute::<u16>(&attributes::INTENSITY)?
+            );
+        }
+
+        let mut points = buffer.iter_untyped_points();
+        let layout = points.shared_layout().clone();
+        let mut shared_owned_points = vec![];
+        while let Some(point) = points.next_point() {
+            shared_owned_points.push(point.to_owned_point_with_layout(&layout));
+        }
+        assert_eq!(4, shared_owned_points.len());
+        for (index, point) in shared_owned_points.iter().enumerate() {
+            assert!(Arc::ptr_eq(&layout, point.shared_layout()));
+            assert_eq!(
+                index as u16 + 1,
+                point.get_attribute::<u16>(&attributes::INTENSITY)?
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "PointLayout does not match")]
+    fn test_to_owned_point_with_wrong_layout() {
+        let layout =
+            PointLayout::from_attributes(&[attributes::POSITION_3D, attributes::INTENSITY]);
+        let mut buffer: Vec<u8> = vec![0; layout.size_of_point_entry() as usize];
+        let point = UntypedPointSlice::new(&layout, &mut buffer);
+        let other_layout = Arc::new(PointLayout::from_attributes(&[
+            attributes::INTENSITY,
+            attributes::POSITION_3D,
+        ]));
+        point.to_owned_point_with_layout(&other_layout);
+    }
+
+    #[test]
+    fn test_iter_untyped_points_mut_keeps_unmodified_buffer_shared() -> Result<()> {
+        let mut storage =
+            InterleavedVecPointStorage::new(PointLayout::from_attributes(&[attributes::INTENSITY]));
+        storage.resize(3);
+        let original = SharedPointBuffer::new(storage);
+
+        let mut shared = original.clone();
+        {
+            let mut points = shared.iter_untyped_points_mut();
+            while let Some(point) = points.next_point() {
+                point.get_attribute::<u16>(&attributes::INTENSITY)?;
+            }
+        }
+        assert!(shared.shares_memory_with(&original));
+
+        {
+            let mut points = shared.iter_untyped_points_mut();
+            if let Some(mut point) = points.next_point() {
+                point.set_attribute(&attributes::INTENSITY, &42_u16)?;
+            }
+        }
+        assert!(!shared.shares_memory_with(&original));
+        assert_eq!(
+            vec![42, 0, 0],
+            shared
+                .iter_attribute::<u16>(&attributes::INTENSITY)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![0, 0, 0],
+            original
+                .iter_attribute::<u16>(&attributes::INTENSITY)
+                .collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
     #[test]
     #[should_panic(expected = "Invalid conversion")]
     fn test_error_invalid_conversion() {
@@ -1,17 +1,52 @@
 use std::{collections::HashMap, iter::FromIterator, ops::Range};
 
 use crate::{
-    layout::{PointAttributeDefinition, PointLayout, PointType, PrimitiveType},
+    layout::{
+        conversion::RawPointConverter, FieldAlignment, PointAttributeDefinition, PointLayout,
+        PointType, PrimitiveType,
+    },
     util::{sort_untyped_slice_by_permutation, view_raw_bytes},
 };
 
 use super::{
     InterleavedPointBuffer, InterleavedPointBufferMut, InterleavedPointBufferSlice,
-    PerAttributePointBuffer, PerAttributePointBufferMut, PerAttributePointBufferSlice,
-    PerAttributePointBufferSliceMut, PointBuffer, PointBufferWriteable,
+    InterleavedPointBufferSliceMut, PerAttributePointBuffer, PerAttributePointBufferMut,
+    PerAttributePointBufferSlice, PerAttributePointBufferSliceMut, PointBuffer,
+    PointBufferWriteable,
 };
 use rayon::prelude::*;
 
+/// Number of bytes of interleaved point data that are transposed at once when converting between Interleaved and PerAttribute
+/// memory layout. Transposing in blocks keeps both the source and the target data of a block in the CPU cache
+const TRANSPOSE_BLOCK_SIZE_IN_BYTES: usize = 64 * 1024;
+
+/// Returns the number of points with the given `size_of_point_entry` that are transposed at once
+fn transpose_block_size(size_of_point_entry: usize) -> usize {
+    (TRANSPOSE_BLOCK_SIZE_IN_BYTES / size_of_point_entry.max(1)).max(1)
+}
+
+/// Calls `func` for each run of consecutive points that are retained according to `keep` and that have to be moved, passing
+/// the range of the run and the index that the first point of the run is moved to. Returns the number of retained points
+fn for_each_retained_run<F: FnMut(Range<usize>, usize)>(keep: &[bool], mut func: F) -> usize {
+    let mut new_len = 0;
+    let mut point_index = 0;
+    while point_index < keep.len() {
+        if !keep[point_index] {
+            point_index += 1;
+            continue;
+        }
+        let run_start = point_index;
+        while point_index < keep.len() && keep[point_index] {
+            point_index += 1;
+        }
+        if run_start != new_len {
+            func(run_start..point_index, new_len);
+        }
+        new_len += point_index - run_start;
+    }
+    new_len
+}
+
 /// `PointBuffer` type that uses Interleaved memory layout and `Vec`-based owning storage for point data
 pub struct InterleavedVecPointStorage {
     layout: PointLayout,
@@ -179,6 +214,77 @@ impl InterleavedVecPointStorage {
         InterleavedPointBufferSlice::new(self, range)
     }
 
+    /// Returns a mutable slice of the associated `InterleavedVecPointStorage`. See [`slice`](Self::slice) for more
+    /// information
+    pub fn slice_mut(&mut self, range: Range<usize>) -> InterleavedPointBufferSliceMut<'_> {
+        InterleavedPointBufferSliceMut::new(self, range)
+    }
+
+    /// Divides the associated `InterleavedVecPointStorage` into two disjoint mutable slices at the point index `mid`,
+    /// which can be modified independently, e.g. on different threads
+    ///
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// let mut buffer = InterleavedVecPointStorage::new(PointLayout::from_attributes(&[attributes::INTENSITY]));
+    /// buffer.resize(10);
+    /// let (mut left, mut right) = buffer.split_at_mut(5);
+    /// std::thread::scope(|scope| {
+    ///     scope.spawn(move || left.get_raw_points_mut(0..5).fill(1));
+    ///     scope.spawn(move || right.get_raw_points_mut(0..5).fill(2));
+    /// });
+    ///
+    /// assert_eq!(0x0101, buffer.get_attribute::<u16>(&attributes::INTENSITY, 4));
+    /// assert_eq!(0x0202, buffer.get_attribute::<u16>(&attributes::INTENSITY, 5));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > self.len()`
+    pub fn split_at_mut(
+        &mut self,
+        mid: usize,
+    ) -> (
+        InterleavedPointBufferSliceMut<'_>,
+        InterleavedPointBufferSliceMut<'_>,
+    ) {
+        if mid > self.len() {
+            panic!(
+                "InterleavedVecPointStorage::split_at_mut: Index {} is out of bounds!",
+                mid
+            );
+        }
+        let (left, right) = self
+            .points
+            .split_at_mut(mid * self.size_of_point_entry as usize);
+        (
+            InterleavedPointBufferSliceMut::from_raw_slice(left, self.layout.clone()),
+            InterleavedPointBufferSliceMut::from_raw_slice(right, self.layout.clone()),
+        )
+    }
+
+    /// Returns an iterator over disjoint mutable slices of `chunk_size` points each of the associated
+    /// `InterleavedVecPointStorage`. The last slice contains less than `chunk_size` points if the length of the buffer
+    /// is no multiple of `chunk_size`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero
+    pub fn chunks_mut(
+        &mut self,
+        chunk_size: usize,
+    ) -> impl Iterator<Item = InterleavedPointBufferSliceMut<'_>> {
+        if chunk_size == 0 {
+            panic!("InterleavedVecPointStorage::chunks_mut: Chunk size must not be zero!");
+        }
+        let layout = &self.layout;
+        self.points
+            .chunks_mut(chunk_size * self.size_of_point_entry as usize)
+            .map(move |points| {
+                InterleavedPointBufferSliceMut::from_raw_slice(points, layout.clone())
+            })
+    }
+
     /// Sorts all points in the associated `InterleavedVecPointStorage` using the order of the `PointType` `T`.
     ///
     /// # Panics
@@ -211,6 +317,193 @@ impl InterleavedVecPointStorage {
         typed_points.sort_by(comparator);
     }
 
+    /// Adds the given `attribute` to the `PointLayout` of the associated `InterleavedVecPointStorage` and sets its value
+    /// to `default_value` for all points. The new attribute is appended to the end of the `PointLayout` using
+    /// [`FieldAlignment::Default`](crate::layout::FieldAlignment::Default), which requires all points to be copied
+    /// into a new memory block with the new point size
+    ///
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// # use pasture_core::nalgebra::Vector3;
+    /// let mut storage = InterleavedVecPointStorage::new(PointLayout::from_attributes(&[attributes::POSITION_3D]));
+    /// storage.resize(2);
+    /// storage.add_attribute(&attributes::CLASSIFICATION, 2_u8);
+    /// assert!(storage.point_layout().has_attribute(&attributes::CLASSIFICATION));
+    /// assert_eq!(2, storage.get_attribute::<u8>(&attributes::CLASSIFICATION, 1));
+    /// # assert_eq!(Vector3::new(0.0, 0.0, 0.0), storage.get_attribute::<Vector3<f64>>(&attributes::POSITION_3D, 1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If an attribute with the same name is already part of the `PointLayout`, or if the datatype of `T` does not match
+    /// the datatype of `attribute`
+    pub fn add_attribute<T: PrimitiveType>(
+        &mut self,
+        attribute: &PointAttributeDefinition,
+        default_value: T,
+    ) {
+        if attribute.datatype() != T::data_type() {
+            panic!("InterleavedVecPointStorage::add_attribute: Type T does not match the datatype of the attribute {}", attribute);
+        }
+
+        let mut new_layout = self.layout.clone();
+        new_layout.add_attribute(attribute.clone(), FieldAlignment::Default);
+        let new_attribute = new_layout.get_attribute_by_name(attribute.name()).unwrap();
+        let attribute_offset = new_attribute.offset() as usize;
+        let attribute_range = attribute_offset..attribute_offset + new_attribute.size() as usize;
+        let default_value_bytes = unsafe { view_raw_bytes(&default_value) };
+
+        let old_size_of_point_entry = self.size_of_point_entry as usize;
+        let new_size_of_point_entry = new_layout.size_of_point_entry() as usize;
+        let mut new_points = vec![0; self.len() * new_size_of_point_entry];
+        for (old_point, new_point) in self
+            .points
+            .chunks_exact(old_size_of_point_entry)
+            .zip(new_points.chunks_exact_mut(new_size_of_point_entry))
+        {
+            new_point[..old_size_of_point_entry].copy_from_slice(old_point);
+            new_point[attribute_range.clone()].copy_from_slice(default_value_bytes);
+        }
+
+        self.layout = new_layout;
+        self.points = new_points;
+        self.size_of_point_entry = new_size_of_point_entry as u64;
+    }
+
+    /// Removes the given `attribute` from the `PointLayout` of the associated `InterleavedVecPointStorage`. The remaining
+    /// attributes are repacked like in [`PointLayout::subset`], so all points are copied into a new memory block
+    ///
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// let mut storage = InterleavedVecPointStorage::new(PointLayout::from_attributes(&[attributes::POSITION_3D, attributes::INTENSITY]));
+    /// storage.resize(2);
+    /// storage.remove_attribute(&attributes::POSITION_3D);
+    /// assert_eq!(2, storage.point_layout().size_of_point_entry());
+    /// # assert_eq!(2, storage.len());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `attribute` is not part of the `PointLayout` of the associated `InterleavedVecPointStorage`
+    pub fn remove_attribute(&mut self, attribute: &PointAttributeDefinition) {
+        if !self.layout.has_attribute(attribute) {
+            panic!("InterleavedVecPointStorage::remove_attribute: Attribute {} is not part of this buffers PointLayout!", attribute);
+        }
+
+        let remaining_attributes = self
+            .layout
+            .attributes()
+            .filter(|member| member.name() != attribute.name())
+            .map(|member| member.into())
+            .collect::<Vec<PointAttributeDefinition>>();
+        let (new_layout, mapping) = self.layout.subset(&remaining_attributes);
+
+        let new_size_of_point_entry = new_layout.size_of_point_entry() as usize;
+        let mut new_points = vec![0; self.len() * new_size_of_point_entry];
+        unsafe {
+            RawPointConverter::from_mapping(&mapping).convert_batch(
+                &self.points,
+                self.size_of_point_entry as usize,
+                &mut new_points,
+                new_size_of_point_entry,
+                self.len(),
+            );
+        }
+
+        self.layout = new_layout;
+        self.points = new_points;
+        self.size_of_point_entry = new_size_of_point_entry as u64;
+    }
+
+    /// Converts the associated `InterleavedVecPointStorage` into a `PerAttributeVecPointStorage` with the same `PointLayout`.
+    /// The point data is transposed in blocks of points that fit into the CPU cache, which is much faster than pushing the
+    /// points one by one
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// # use pasture_core::nalgebra::Vector3;
+    /// # use pasture_derive::PointType;
+    /// #[repr(C)]
+    /// #[derive(PointType, Copy, Clone, Debug, PartialEq)]
+    /// struct MyPointType(#[pasture(BUILTIN_INTENSITY)] u16, #[pasture(BUILTIN_POSITION_3D)] Vector3<f64>);
+    ///
+    /// let interleaved = InterleavedVecPointStorage::from(vec![
+    ///     MyPointType(42, Vector3::new(1.0, 2.0, 3.0)),
+    ///     MyPointType(43, Vector3::new(4.0, 5.0, 6.0)),
+    /// ]);
+    /// let per_attribute = interleaved.to_per_attribute();
+    /// assert_eq!(
+    ///     &[42, 43],
+    ///     per_attribute.get_attribute_range_ref::<u16>(0..2, &attributes::INTENSITY)
+    /// );
+    /// ```
+    pub fn to_per_attribute(&self) -> PerAttributeVecPointStorage {
+        self.transpose_to_per_attribute(self.layout.clone(), false)
+    }
+
+    /// Like `to_per_attribute`, but transposes the blocks of points in parallel. Uses the [`rayon`]() crate for parallelization
+    pub fn par_to_per_attribute(&self) -> PerAttributeVecPointStorage {
+        self.transpose_to_per_attribute(self.layout.clone(), true)
+    }
+
+    /// Converts the associated `InterleavedVecPointStorage` into a `PerAttributeVecPointStorage` with the given `PointLayout`.
+    /// This transposes the point data and converts the datatypes of the attributes in the same pass. Attributes are matched
+    /// by name, conversions between datatypes and quantizations work as described in [`RawPointConverter::from_to`]. Attributes
+    /// of `layout` that are not part of this buffer are zero-initialized, attributes of this buffer that are not part of `layout`
+    /// are dropped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// let mut interleaved = InterleavedVecPointStorage::new(PointLayout::from_attributes(&[attributes::INTENSITY]));
+    /// interleaved.resize(2);
+    /// interleaved.set_attribute(&attributes::INTENSITY, 1, 42_u16);
+    ///
+    /// let intensity_u32 = attributes::INTENSITY.with_custom_datatype(PointAttributeDataType::U32);
+    /// let per_attribute = interleaved.to_per_attribute_with_layout(PointLayout::from_attributes(&[intensity_u32.clone()]));
+    /// assert_eq!(&[0, 42], per_attribute.get_attribute_range_ref::<u32>(0..2, &intensity_u32));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If an attribute that is part of both layouts can't be converted into the datatype of `layout`
+    pub fn to_per_attribute_with_layout(&self, layout: PointLayout) -> PerAttributeVecPointStorage {
+        self.transpose_to_per_attribute(layout, false)
+    }
+
+    /// Like `to_per_attribute_with_layout`, but transposes the blocks of points in parallel. Uses the [`rayon`]() crate for
+    /// parallelization
+    pub fn par_to_per_attribute_with_layout(
+        &self,
+        layout: PointLayout,
+    ) -> PerAttributeVecPointStorage {
+        self.transpose_to_per_attribute(layout, true)
+    }
+
+    /// Creates a new `InterleavedVecPointStorage` that takes ownership of the given `points`, which must be stored in the given
+    /// `layout`
+    pub(crate) fn from_raw_points(layout: PointLayout, points: Vec<u8>) -> Self {
+        let size_of_point_entry = layout.size_of_point_entry();
+        debug_assert!((points.len() as u64).is_multiple_of(size_of_point_entry.max(1)));
+        Self {
+            layout,
+            points,
+            size_of_point_entry,
+        }
+    }
+
+    /// Returns the raw memory of the points of the associated `InterleavedVecPointStorage`
+    pub(crate) fn into_raw_points(self) -> Vec<u8> {
+        self.points
+    }
+
     /// Reserve capacity for at least `additional_points` new points to be inserted into this `PointBuffer`
     fn reserve(&mut self, additional_points: usize) {
         let additional_bytes = additional_points * self.size_of_point_entry as usize;
@@ -248,12 +541,8 @@ impl InterleavedVecPointStorage {
             for (attribute, attribute_buffer) in attribute_buffers.iter() {
                 let slice_start = idx * attribute.size() as usize;
                 let slice_end = (idx + 1) * attribute.size() as usize;
-                let offset_in_point = attribute.offset() as usize;
-
-                let point_slice = &mut single_point_blob
-                    [offset_in_point..offset_in_point + attribute.size() as usize];
                 let attribute_slice = &attribute_buffer[slice_start..slice_end];
-                point_slice.copy_from_slice(attribute_slice);
+                attribute.write_to_point_record(attribute_slice, &mut single_point_blob);
             }
             self.points.extend_from_slice(single_point_blob.as_slice());
         }
@@ -306,18 +595,124 @@ impl InterleavedVecPointStorage {
             .collect::<Vec<_>>();
         for idx in 0..range.len() {
             let current_point_offset = (range.start + idx) * self.size_of_point_entry as usize;
+            let point_record = &mut self.points
+                [current_point_offset..current_point_offset + self.size_of_point_entry as usize];
             for (attribute, attribute_buffer) in attribute_buffers.iter() {
                 let slice_start = idx * attribute.size() as usize;
                 let slice_end = (idx + 1) * attribute.size() as usize;
-                let offset_in_point = attribute.offset() as usize;
                 let attribute_slice = &attribute_buffer[slice_start..slice_end];
+                attribute.write_to_point_record(attribute_slice, point_record);
+            }
+        }
+    }
+
+    /// Pushes the points of a buffer that is neither Interleaved nor PerAttribute (e.g. a view) by copying them through
+    /// the `PointBuffer` trait
+    fn push_generic(&mut self, points: &dyn PointBuffer) {
+        if points.point_layout() != self.point_layout() {
+            panic!("InterleavedVecPointStorage::push_generic: Layout of points in new buffer does not match this PointLayout");
+        }
+        let old_size = self.points.len();
+        self.points.resize(
+            old_size + points.len() * self.size_of_point_entry as usize,
+            0,
+        );
+        points.get_raw_points(0..points.len(), &mut self.points[old_size..]);
+    }
+
+    fn splice_generic(&mut self, range: Range<usize>, points: &dyn PointBuffer) {
+        if points.point_layout() != self.point_layout() {
+            panic!(
+                "InterleavedVecPointStorage::splice_generic: points layout does not match this PointLayout!"
+            );
+        }
+        if range.start > range.end {
+            panic!("Range start is greater than range end");
+        }
+        if range.end > self.len() {
+            panic!("Range is out of bounds");
+        }
+        let this_offset = range.start * self.size_of_point_entry as usize;
+        let this_range_len = range.len() * self.size_of_point_entry as usize;
+        points.get_raw_points(
+            0..range.len(),
+            &mut self.points[this_offset..(this_offset + this_range_len)],
+        );
+    }
+
+    fn transpose_to_per_attribute(
+        &self,
+        layout: PointLayout,
+        parallel: bool,
+    ) -> PerAttributeVecPointStorage {
+        let point_count = self.len();
+        let source_stride = self.size_of_point_entry as usize;
+        let points_per_block = transpose_block_size(source_stride);
+
+        // Each attribute is converted from our layout into a layout that only contains the target attribute, which is
+        // the memory layout of the attribute buffers of a PerAttributeVecPointStorage
+        let converters = layout
+            .attributes()
+            .map(|attribute| {
+                let target_attribute: PointAttributeDefinition = attribute.into();
+                let target_size = target_attribute.size() as usize;
+                let converter = RawPointConverter::from_to(
+                    &self.layout,
+                    &PointLayout::from_attributes(&[target_attribute]),
+                );
+                (target_size, converter)
+            })
+            .collect::<Vec<_>>();
+        let mut attribute_buffers = converters
+            .iter()
+            .map(|(target_size, _)| vec![0; point_count * target_size])
+            .collect::<Vec<_>>();
 
-                let offset_in_this_buffer = current_point_offset + offset_in_point;
-                let point_slice = &mut self.points
-                    [offset_in_this_buffer..offset_in_this_buffer + attribute.size() as usize];
-                point_slice.copy_from_slice(attribute_slice);
+        // Group the chunks of all attribute buffers by block, so that each block can be transposed independently
+        let mut blocks = (0..point_count.div_ceil(points_per_block))
+            .map(|_| Vec::with_capacity(converters.len()))
+            .collect::<Vec<_>>();
+        for (attribute_buffer, (target_size, _)) in
+            attribute_buffers.iter_mut().zip(converters.iter())
+        {
+            let chunk_size = (points_per_block * target_size).max(1);
+            for (block, chunk) in blocks
+                .iter_mut()
+                .zip(attribute_buffer.chunks_mut(chunk_size))
+            {
+                block.push(chunk);
             }
         }
+
+        let transpose_block = |(block_index, mut chunks): (usize, Vec<&mut [u8]>)| {
+            let first_point = block_index * points_per_block;
+            let count = points_per_block.min(point_count - first_point);
+            let source_points =
+                &self.points[first_point * source_stride..(first_point + count) * source_stride];
+            for (chunk, (target_size, converter)) in chunks.iter_mut().zip(converters.iter()) {
+                unsafe {
+                    converter.convert_batch(
+                        source_points,
+                        source_stride,
+                        chunk,
+                        *target_size,
+                        count,
+                    );
+                }
+            }
+        };
+        if parallel {
+            blocks.into_par_iter().enumerate().for_each(transpose_block);
+        } else {
+            blocks.into_iter().enumerate().for_each(transpose_block);
+        }
+
+        let attributes = layout
+            .attributes()
+            .map(|attribute| attribute.name().to_owned())
+            .zip(attribute_buffers)
+            .collect();
+        PerAttributeVecPointStorage { layout, attributes }
     }
 }
 
@@ -352,12 +747,9 @@ impl PointBuffer for InterleavedVecPointStorage {
 
         if let Some(attribute_in_buffer) = self.layout.get_attribute(attribute) {
             let offset_to_point_bytes = point_index * self.size_of_point_entry as usize;
-            let offset_to_attribute = offset_to_point_bytes + attribute_in_buffer.offset() as usize;
-            let attribute_size = attribute.size() as usize;
-
-            buf.copy_from_slice(
-                &self.points[offset_to_attribute..offset_to_attribute + attribute_size],
-            );
+            let point_record = &self.points
+                [offset_to_point_bytes..offset_to_point_bytes + self.size_of_point_entry as usize];
+            attribute_in_buffer.read_from_point_record(point_record, buf);
         } else {
             panic!("InterleavedVecPointStorage::get_raw_attribute: Attribute {:?} is not part of this PointBuffer's PointLayout!", attribute);
         }
@@ -387,15 +779,13 @@ impl PointBuffer for InterleavedVecPointStorage {
 
             for point_index in point_indices {
                 let offset_to_point_bytes = point_index * self.size_of_point_entry as usize;
-                let offset_to_attribute =
-                    offset_to_point_bytes + attribute_in_buffer.offset() as usize;
+                let point_record = &self.points[offset_to_point_bytes
+                    ..offset_to_point_bytes + self.size_of_point_entry as usize];
                 let offset_in_target_buf = (point_index - start_index) * attribute_size;
                 let target_buf_slice =
                     &mut buf[offset_in_target_buf..offset_in_target_buf + attribute_size];
 
-                target_buf_slice.copy_from_slice(
-                    &self.points[offset_to_attribute..offset_to_attribute + attribute_size],
-                );
+                attribute_in_buffer.read_from_point_record(point_record, target_buf_slice);
             }
         } else {
             panic!("InterleavedVecPointStorage::get_raw_attribute: Attribute {:?} is not part of this PointBuffer's PointLayout!", attribute);
@@ -403,7 +793,11 @@ impl PointBuffer for InterleavedVecPointStorage {
     }
 
     fn len(&self) -> usize {
-        self.points.len() / self.size_of_point_entry as usize
+        // A buffer with an empty `PointLayout` never contains any point data
+        self.points
+            .len()
+            .checked_div(self.size_of_point_entry as usize)
+            .unwrap_or(0)
     }
 
     fn point_layout(&self) -> &PointLayout {
@@ -422,7 +816,7 @@ impl PointBufferWriteable for InterleavedVecPointStorage {
         } else if let Some(per_attribute) = points.as_per_attribute() {
             self.push_per_attribute(per_attribute);
         } else {
-            panic!("InterleavedVecPointStorage::push: points buffer implements neither the InterleavedPointBuffer nor the PerAttributePointBuffer traits");
+            self.push_generic(points);
         }
     }
 
@@ -432,7 +826,7 @@ impl PointBufferWriteable for InterleavedVecPointStorage {
         } else if let Some(per_attribute) = replace_with.as_per_attribute() {
             self.splice_per_attribute(range, per_attribute);
         } else {
-            panic!("InterleavedVecPointStorage::splice: replace_with buffer implements neither the InterleavedPointBuffer nor the PerAttributePointBuffer traits");
+            self.splice_generic(range, replace_with);
         }
     }
 
@@ -449,6 +843,44 @@ impl PointBufferWriteable for InterleavedVecPointStorage {
             .resize(new_points * self.layout.size_of_point_entry() as usize, 0);
     }
 
+    fn as_interleaved_mut(&mut self) -> Option<&mut dyn InterleavedPointBufferMut> {
+        Some(self)
+    }
+
+    fn retain_by_mask(&mut self, keep: &[bool]) {
+        if keep.len() != self.len() {
+            panic!(
+                "InterleavedVecPointStorage::retain_by_mask: Mask length ({}) does not match buffer length ({})",
+                keep.len(),
+                self.len()
+            );
+        }
+        let point_size = self.size_of_point_entry as usize;
+        let new_len = for_each_retained_run(keep, |run, new_start| {
+            self.points.copy_within(
+                run.start * point_size..run.end * point_size,
+                new_start * point_size,
+            );
+        });
+        self.points.truncate(new_len * point_size);
+    }
+
+    fn swap_remove(&mut self, point_index: usize) {
+        let len = self.len();
+        if point_index >= len {
+            panic!(
+                "InterleavedVecPointStorage::swap_remove: Index {} is out of bounds for buffer of length {}",
+                point_index, len
+            );
+        }
+        let point_size = self.size_of_point_entry as usize;
+        self.points.copy_within(
+            (len - 1) * point_size..len * point_size,
+            point_index * point_size,
+        );
+        self.points.truncate((len - 1) * point_size);
+    }
+
     fn set_raw_point(&mut self, point_index: usize, buf: &[u8]) {
         if point_index >= self.len() {
             panic!("Point index is out of bounds")
@@ -481,12 +913,9 @@ impl PointBufferWriteable for InterleavedVecPointStorage {
             panic!("Size of buffer does not match the size of the point attribute")
         }
         let point_size = self.layout.size_of_point_entry() as usize;
-        let attribute_offset_within_point = attribute_member.offset() as usize;
-
-        let attribute_data_start = (point_index * point_size) + attribute_offset_within_point;
-        let attribute_data_end = attribute_data_start + attribute_size;
-        let target_attribute_slice = &mut self.points[attribute_data_start..attribute_data_end];
-        target_attribute_slice.copy_from_slice(buf);
+        let point_data_start = point_index * point_size;
+        let target_point_slice = &mut self.points[point_data_start..point_data_start + point_size];
+        attribute_member.write_to_point_record(buf, target_point_slice);
     }
 }
 
@@ -581,10 +1010,16 @@ impl<T: PointType> From<Vec<T>> for InterleavedVecPointStorage {
     }
 }
 
+impl From<&'_ PerAttributeVecPointStorage> for InterleavedVecPointStorage {
+    fn from(storage: &'_ PerAttributeVecPointStorage) -> Self {
+        storage.to_interleaved()
+    }
+}
+
 /// `PointBuffer` type that uses PerAttribute memory layout and `Vec`-based owning storage for point data
 pub struct PerAttributeVecPointStorage {
     layout: PointLayout,
-    attributes: HashMap<&'static str, Vec<u8>>,
+    attributes: HashMap<String, Vec<u8>>,
 }
 
 impl PerAttributeVecPointStorage {
@@ -602,7 +1037,7 @@ impl PerAttributeVecPointStorage {
     pub fn new(layout: PointLayout) -> Self {
         let attributes = layout
             .attributes()
-            .map(|attribute| (attribute.name(), vec![]))
+            .map(|attribute| (attribute.name().to_owned(), vec![]))
             .collect::<HashMap<_, _>>();
         Self { layout, attributes }
     }
@@ -625,7 +1060,10 @@ impl PerAttributeVecPointStorage {
             .attributes()
             .map(|attribute| {
                 let attribute_bytes = capacity * attribute.size() as usize;
-                (attribute.name(), Vec::with_capacity(attribute_bytes))
+                (
+                    attribute.name().to_owned(),
+                    Vec::with_capacity(attribute_bytes),
+                )
             })
             .collect::<HashMap<_, _>>();
         Self { layout, attributes }
@@ -744,7 +1182,7 @@ impl PerAttributeVecPointStorage {
         let attribute_sizes = self
             .attributes
             .keys()
-            .map(|&key| self.layout.get_attribute_by_name(key).unwrap().size())
+            .map(|key| self.layout.get_attribute_by_name(key).unwrap().size())
             .collect::<Vec<_>>();
 
         self.attributes
@@ -781,9 +1219,9 @@ impl PerAttributeVecPointStorage {
         let attribute_sizes = self
             .attributes
             .keys()
-            .map(|&key| {
+            .map(|key| {
                 (
-                    key.to_owned(),
+                    key.clone(),
                     self.layout.get_attribute_by_name(key).unwrap().size(),
                 )
             })
@@ -791,7 +1229,7 @@ impl PerAttributeVecPointStorage {
 
         self.attributes
             .par_iter_mut()
-            .for_each(|(&key, untyped_attribute)| {
+            .for_each(|(key, untyped_attribute)| {
                 let size = *attribute_sizes.get(key).unwrap();
                 sort_untyped_slice_by_permutation(
                     untyped_attribute.as_mut_slice(),
@@ -801,6 +1239,73 @@ impl PerAttributeVecPointStorage {
             });
     }
 
+    /// Adds the given `attribute` to the `PointLayout` of the associated `PerAttributeVecPointStorage` and sets its value
+    /// to `default_value` for all points. This only allocates the memory for the new attribute, the existing attributes are
+    /// not touched
+    ///
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// let mut storage = PerAttributeVecPointStorage::new(PointLayout::from_attributes(&[attributes::POSITION_3D]));
+    /// storage.resize(2);
+    /// storage.add_attribute(&attributes::CLASSIFICATION, 2_u8);
+    /// assert_eq!(2, storage.get_attribute::<u8>(&attributes::CLASSIFICATION, 1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If an attribute with the same name is already part of the `PointLayout`, or if the datatype of `T` does not match
+    /// the datatype of `attribute`
+    pub fn add_attribute<T: PrimitiveType>(
+        &mut self,
+        attribute: &PointAttributeDefinition,
+        default_value: T,
+    ) {
+        if attribute.datatype() != T::data_type() {
+            panic!("PerAttributeVecPointStorage::add_attribute: Type T does not match the datatype of the attribute {}", attribute);
+        }
+
+        let num_points = self.len();
+        self.layout
+            .add_attribute(attribute.clone(), FieldAlignment::Default);
+        let default_value_bytes = unsafe { view_raw_bytes(&default_value) };
+        self.attributes.insert(
+            attribute.name().to_owned(),
+            default_value_bytes.repeat(num_points),
+        );
+    }
+
+    /// Removes the given `attribute` from the `PointLayout` of the associated `PerAttributeVecPointStorage`, together with
+    /// the memory for this attribute
+    ///
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// let mut storage = PerAttributeVecPointStorage::new(PointLayout::from_attributes(&[attributes::POSITION_3D, attributes::INTENSITY]));
+    /// storage.resize(2);
+    /// storage.remove_attribute(&attributes::POSITION_3D);
+    /// assert!(!storage.point_layout().has_attribute(&attributes::POSITION_3D));
+    /// # assert_eq!(2, storage.len());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `attribute` is not part of the `PointLayout` of the associated `PerAttributeVecPointStorage`
+    pub fn remove_attribute(&mut self, attribute: &PointAttributeDefinition) {
+        if !self.layout.has_attribute(attribute) {
+            panic!("PerAttributeVecPointStorage::remove_attribute: Attribute {} is not part of this buffers PointLayout!", attribute);
+        }
+
+        let remaining_attributes = self
+            .layout
+            .attributes()
+            .filter(|member| member.name() != attribute.name())
+            .map(|member| member.into())
+            .collect::<Vec<PointAttributeDefinition>>();
+        self.layout = self.layout.subset(&remaining_attributes).0;
+        self.attributes.remove(attribute.name());
+    }
+
     /// Reserves space for at least `additional_points` additional points in the associated `PerAttributeVecPointStorage`
     pub fn reserve(&mut self, additional_points: usize) {
         for attribute in self.layout.attributes() {
@@ -810,6 +1315,94 @@ impl PerAttributeVecPointStorage {
         }
     }
 
+    /// Converts the associated `PerAttributeVecPointStorage` into an `InterleavedVecPointStorage` with the same `PointLayout`.
+    /// The point data is transposed in blocks of points that fit into the CPU cache, which is much faster than pushing the
+    /// points one by one
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// # use pasture_core::nalgebra::Vector3;
+    /// # use pasture_derive::PointType;
+    /// #[repr(C)]
+    /// #[derive(PointType, Copy, Clone, Debug, PartialEq)]
+    /// struct MyPointType(#[pasture(BUILTIN_INTENSITY)] u16, #[pasture(BUILTIN_POSITION_3D)] Vector3<f64>);
+    ///
+    /// let points = vec![
+    ///     MyPointType(42, Vector3::new(1.0, 2.0, 3.0)),
+    ///     MyPointType(43, Vector3::new(4.0, 5.0, 6.0)),
+    /// ];
+    /// let per_attribute = PerAttributeVecPointStorage::from(points.as_slice());
+    /// let interleaved = per_attribute.to_interleaved();
+    /// assert_eq!(points.as_slice(), interleaved.get_points_ref::<MyPointType>(0..2));
+    /// ```
+    pub fn to_interleaved(&self) -> InterleavedVecPointStorage {
+        self.transpose_to_interleaved(self.layout.clone(), false)
+    }
+
+    /// Like `to_interleaved`, but transposes the blocks of points in parallel. Uses the [`rayon`]() crate for parallelization
+    pub fn par_to_interleaved(&self) -> InterleavedVecPointStorage {
+        self.transpose_to_interleaved(self.layout.clone(), true)
+    }
+
+    /// Converts the associated `PerAttributeVecPointStorage` into an `InterleavedVecPointStorage` with the given `PointLayout`.
+    /// This transposes the point data and converts the datatypes of the attributes in the same pass. Attributes are matched
+    /// by name, conversions between datatypes and quantizations work as described in [`RawPointConverter::from_to`]. Attributes
+    /// of `layout` that are not part of this buffer are zero-initialized, attributes of this buffer that are not part of `layout`
+    /// are dropped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pasture_core::containers::*;
+    /// # use pasture_core::layout::*;
+    /// let mut per_attribute = PerAttributeVecPointStorage::new(PointLayout::from_attributes(&[attributes::INTENSITY]));
+    /// per_attribute.resize(2);
+    /// per_attribute.set_attribute(&attributes::INTENSITY, 1, 42_u16);
+    ///
+    /// let intensity_u32 = attributes::INTENSITY.with_custom_datatype(PointAttributeDataType::U32);
+    /// let target_layout = PointLayout::from_attributes(&[attributes::CLASSIFICATION, intensity_u32.clone()]);
+    /// let interleaved = per_attribute.to_interleaved_with_layout(target_layout);
+    /// assert_eq!(42, interleaved.get_attribute::<u32>(&intensity_u32, 1));
+    /// assert_eq!(0, interleaved.get_attribute::<u8>(&attributes::CLASSIFICATION, 1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If an attribute that is part of both layouts can't be converted into the datatype of `layout`
+    pub fn to_interleaved_with_layout(&self, layout: PointLayout) -> InterleavedVecPointStorage {
+        self.transpose_to_interleaved(layout, false)
+    }
+
+    /// Like `to_interleaved_with_layout`, but transposes the blocks of points in parallel. Uses the [`rayon`]() crate for
+    /// parallelization
+    pub fn par_to_interleaved_with_layout(
+        &self,
+        layout: PointLayout,
+    ) -> InterleavedVecPointStorage {
+        self.transpose_to_interleaved(layout, true)
+    }
+
+    /// Creates a new `PerAttributeVecPointStorage` that takes ownership of the given `attributes`, which must contain one
+    /// entry with the same number of points for each attribute in the given `layout`
+    pub(crate) fn from_attribute_buffers(
+        layout: PointLayout,
+        attributes: HashMap<String, Vec<u8>>,
+    ) -> Self {
+        debug_assert!(layout
+            .attributes()
+            .all(|attribute| attributes.contains_key(attribute.name())));
+        Self { layout, attributes }
+    }
+
+    /// Returns the raw memory of all attributes of the associated `PerAttributeVecPointStorage`
+    #[cfg(feature = "arrow")]
+    pub(crate) fn into_attribute_buffers(self) -> HashMap<String, Vec<u8>> {
+        self.attributes
+    }
+
     fn push_interleaved(&mut self, points: &dyn InterleavedPointBuffer) {
         if !points
             .point_layout()
@@ -819,18 +1412,20 @@ impl PerAttributeVecPointStorage {
         }
 
         let raw_point_data = points.get_raw_points_ref(0..points.len());
-        let stride = self.layout.size_of_point_entry() as usize;
+        // Offsets (and bit fields) are taken from the layout of 'points', as its memory layout might differ from ours
+        let stride = points.point_layout().size_of_point_entry() as usize;
 
         for (attribute_name, attribute_data) in self.attributes.iter_mut() {
-            let current_attribute = self.layout.get_attribute_by_name(attribute_name).unwrap();
-            let base_offset = current_attribute.offset() as usize;
+            let current_attribute = points
+                .point_layout()
+                .get_attribute_by_name(attribute_name)
+                .unwrap();
             let attribute_size = current_attribute.size() as usize;
+            let mut attribute_buffer = vec![0; attribute_size];
 
-            for idx in 0..points.len() {
-                let attribute_start = base_offset + (idx * stride);
-                let attribute_end = attribute_start + attribute_size;
-                let attribute_slice = &raw_point_data[attribute_start..attribute_end];
-                attribute_data.extend_from_slice(attribute_slice);
+            for point_record in raw_point_data.chunks_exact(stride) {
+                current_attribute.read_from_point_record(point_record, &mut attribute_buffer);
+                attribute_data.extend_from_slice(&attribute_buffer);
             }
         }
     }
@@ -867,24 +1462,22 @@ impl PerAttributeVecPointStorage {
         }
 
         let raw_point_data = points.get_raw_points_ref(0..range.len());
-        let stride = self.layout.size_of_point_entry() as usize;
+        let stride = points.point_layout().size_of_point_entry() as usize;
 
         for (attribute_name, attribute_data) in self.attributes.iter_mut() {
-            let current_attribute = self.layout.get_attribute_by_name(attribute_name).unwrap();
-            let base_offset = current_attribute.offset() as usize;
+            let current_attribute = points
+                .point_layout()
+                .get_attribute_by_name(attribute_name)
+                .unwrap();
             let attribute_size = current_attribute.size() as usize;
 
-            for idx in 0..range.len() {
+            for (idx, point_record) in raw_point_data.chunks_exact(stride).enumerate() {
                 let this_attribute_start = (range.start + idx) * attribute_size;
                 let this_attribute_end = this_attribute_start + attribute_size;
                 let this_attribute_slice =
                     &mut attribute_data[this_attribute_start..this_attribute_end];
 
-                let new_attribute_start = base_offset + (idx * stride);
-                let new_attribute_end = new_attribute_start + attribute_size;
-                let new_attribute_slice = &raw_point_data[new_attribute_start..new_attribute_end];
-
-                this_attribute_slice.copy_from_slice(new_attribute_slice);
+                current_attribute.read_from_point_record(point_record, this_attribute_slice);
             }
         }
     }
@@ -914,6 +1507,115 @@ impl PerAttributeVecPointStorage {
             this_attribute_slice.copy_from_slice(new_attribute_slice);
         }
     }
+
+    /// Pushes the points of a buffer that is neither Interleaved nor PerAttribute (e.g. a view) by copying them through
+    /// the `PointBuffer` trait
+    fn push_generic(&mut self, points: &dyn PointBuffer) {
+        if !points
+            .point_layout()
+            .compare_without_offsets(self.point_layout())
+        {
+            panic!("PerAttributeVecPointStorage::push_generic: Layout of 'points' does not match layout of this buffer!");
+        }
+        for attribute in self.layout.attributes() {
+            let attribute_data = self.attributes.get_mut(attribute.name()).unwrap();
+            let old_size = attribute_data.len();
+            attribute_data.resize(old_size + points.len() * attribute.size() as usize, 0);
+            points.get_raw_attribute_range(
+                0..points.len(),
+                &attribute.into(),
+                &mut attribute_data[old_size..],
+            );
+        }
+    }
+
+    fn splice_generic(&mut self, range: Range<usize>, points: &dyn PointBuffer) {
+        if range.start > range.end {
+            panic!("Range start is greater than range end");
+        }
+        if range.end > self.len() {
+            panic!("Range is out of bounds");
+        }
+        if !points
+            .point_layout()
+            .compare_without_offsets(self.point_layout())
+        {
+            panic!("PerAttributeVecPointStorage::splice_generic: points layout does not match this PointLayout!");
+        }
+        for attribute in self.layout.attributes() {
+            let this_attribute_offset = range.start * attribute.size() as usize;
+            let this_attribute_slice = &mut self.attributes.get_mut(attribute.name()).unwrap()
+                [this_attribute_offset
+                    ..this_attribute_offset + range.len() * attribute.size() as usize];
+            points.get_raw_attribute_range(0..range.len(), &attribute.into(), this_attribute_slice);
+        }
+    }
+
+    fn transpose_to_interleaved(
+        &self,
+        layout: PointLayout,
+        parallel: bool,
+    ) -> InterleavedVecPointStorage {
+        let point_count = self.len();
+        let target_stride = layout.size_of_point_entry() as usize;
+        let points_per_block = transpose_block_size(target_stride);
+
+        // Each attribute buffer is treated as a buffer of points in a layout that only contains this attribute
+        let converters = self
+            .layout
+            .attributes()
+            .map(|attribute| {
+                let source_attribute: PointAttributeDefinition = attribute.into();
+                let source_size = source_attribute.size() as usize;
+                let converter = RawPointConverter::from_to(
+                    &PointLayout::from_attributes(&[source_attribute]),
+                    &layout,
+                );
+                (
+                    self.attributes[attribute.name()].as_slice(),
+                    source_size,
+                    converter,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut points = vec![0; point_count * target_stride];
+        let transpose_block = |(block_index, target_points): (usize, &mut [u8])| {
+            let first_point = block_index * points_per_block;
+            let count = points_per_block.min(point_count - first_point);
+            for (attribute_buffer, source_size, converter) in converters.iter() {
+                let source_values = &attribute_buffer
+                    [first_point * source_size..(first_point + count) * source_size];
+                unsafe {
+                    converter.convert_batch(
+                        source_values,
+                        *source_size,
+                        target_points,
+                        target_stride,
+                        count,
+                    );
+                }
+            }
+        };
+        let chunk_size = (points_per_block * target_stride).max(1);
+        if parallel {
+            points
+                .par_chunks_mut(chunk_size)
+                .enumerate()
+                .for_each(transpose_block);
+        } else {
+            points
+                .chunks_mut(chunk_size)
+                .enumerate()
+                .for_each(transpose_block);
+        }
+
+        InterleavedVecPointStorage {
+            layout,
+            points,
+            size_of_point_entry: target_stride as u64,
+        }
+    }
 }
 
 impl PointBuffer for PerAttributeVecPointStorage {
@@ -929,12 +1631,10 @@ impl PointBuffer for PerAttributeVecPointStorage {
             let attribute_buffer = self.attributes.get(attribute.name()).unwrap();
             let attribute_size = attribute.size() as usize;
             let offset_in_buffer = point_index * attribute_size;
-            let offset_in_point = attribute.offset() as usize;
 
-            let buf_slice = &mut buf[offset_in_point..offset_in_point + attribute_size];
             let attribute_slice =
                 &attribute_buffer[offset_in_buffer..offset_in_buffer + attribute_size];
-            buf_slice.copy_from_slice(attribute_slice);
+            attribute.write_to_point_record(attribute_slice, buf);
         }
     }
 
@@ -969,12 +1669,11 @@ impl PointBuffer for PerAttributeVecPointStorage {
                     [offset_in_attribute_buffer..offset_in_attribute_buffer + attribute_size];
 
                 let point_index_in_buf = point_index - first_index;
-                let offset_in_point = attribute.offset() as usize;
-                let offset_in_points_buffer = point_index_in_buf * point_size + offset_in_point;
-                let buf_slice =
-                    &mut buf[offset_in_points_buffer..offset_in_points_buffer + attribute_size];
+                let offset_in_points_buffer = point_index_in_buf * point_size;
+                let point_slice =
+                    &mut buf[offset_in_points_buffer..offset_in_points_buffer + point_size];
 
-                buf_slice.copy_from_slice(attribute_slice);
+                attribute.write_to_point_record(attribute_slice, point_slice);
             }
         }
     }
@@ -990,7 +1689,11 @@ impl PointBuffer for PerAttributeVecPointStorage {
     }
 
     fn len(&self) -> usize {
-        let attribute = self.layout.attributes().next().unwrap();
+        // A buffer with an empty `PointLayout` never contains any point data
+        let attribute = match self.layout.attributes().next() {
+            Some(attribute) => attribute,
+            None => return 0,
+        };
         let attribute_buf = self.attributes.get(attribute.name()).unwrap();
         attribute_buf.len() / attribute.size() as usize
     }
@@ -1011,7 +1714,7 @@ impl PointBufferWriteable for PerAttributeVecPointStorage {
         } else if let Some(per_attribute) = points.as_per_attribute() {
             self.push_per_attribute(per_attribute);
         } else {
-            panic!("PerAttributeVecPointStorage::push: points buffer does not implement the InterleavedPointBuffer or the PerAttributePointBuffer trait");
+            self.push_generic(points);
         }
     }
 
@@ -1021,7 +1724,7 @@ impl PointBufferWriteable for PerAttributeVecPointStorage {
         } else if let Some(per_attribute) = replace_with.as_per_attribute() {
             self.splice_per_attribute(range, per_attribute);
         } else {
-            panic!("PerAttributeVecPointStorage::splice: replace_with buffer does not implement the InterleavedPointBuffer or the PerAttributePointBuffer trait");
+            self.splice_generic(range, replace_with);
         }
     }
 
@@ -1041,6 +1744,50 @@ impl PointBufferWriteable for PerAttributeVecPointStorage {
         }
     }
 
+    fn as_per_attribute_mut<'b>(
+        &'b mut self,
+    ) -> Option<&'b mut dyn PerAttributePointBufferMut<'b>> {
+        Some(self)
+    }
+
+    fn retain_by_mask(&mut self, keep: &[bool]) {
+        if keep.len() != self.len() {
+            panic!(
+                "PerAttributeVecPointStorage::retain_by_mask: Mask length ({}) does not match buffer length ({})",
+                keep.len(),
+                self.len()
+            );
+        }
+        for (key, buf) in &mut self.attributes {
+            let attribute_size = self.layout.get_attribute_by_name(key).unwrap().size() as usize;
+            let new_len = for_each_retained_run(keep, |run, new_start| {
+                buf.copy_within(
+                    run.start * attribute_size..run.end * attribute_size,
+                    new_start * attribute_size,
+                );
+            });
+            buf.truncate(new_len * attribute_size);
+        }
+    }
+
+    fn swap_remove(&mut self, point_index: usize) {
+        let len = self.len();
+        if point_index >= len {
+            panic!(
+                "PerAttributeVecPointStorage::swap_remove: Index {} is out of bounds for buffer of length {}",
+                point_index, len
+            );
+        }
+        for (key, buf) in &mut self.attributes {
+            let attribute_size = self.layout.get_attribute_by_name(key).unwrap().size() as usize;
+            buf.copy_within(
+                (len - 1) * attribute_size..len * attribute_size,
+                point_index * attribute_size,
+            );
+            buf.truncate((len - 1) * attribute_size);
+        }
+    }
+
     fn set_raw_point(&mut self, point_index: usize, buf: &[u8]) {
         if point_index >= self.len() {
             panic!("Point index is out of bounds")
@@ -1051,15 +1798,11 @@ impl PointBufferWriteable for PerAttributeVecPointStorage {
         for attribute in self.layout.attributes() {
             let attribute_size = attribute.size() as usize;
 
-            let attribute_start_in_buf = attribute.offset() as usize;
-            let attribute_end_in_buf = attribute_start_in_buf + attribute_size;
-            let attribute_slice = &buf[attribute_start_in_buf..attribute_end_in_buf];
-
             let target_slice_start = point_index * attribute_size;
             let target_slice_end = target_slice_start + attribute_size;
             let target_slice = &mut self.attributes.get_mut(attribute.name()).unwrap()
                 [target_slice_start..target_slice_end];
-            target_slice.copy_from_slice(attribute_slice);
+            attribute.read_from_point_record(buf, target_slice);
         }
     }
 
@@ -1243,6 +1986,12 @@ impl<T: PointType> From<Vec<T>> for PerAttributeVecPointStorage {
     }
 }
 
+impl From<&'_ InterleavedVecPointStorage> for PerAttributeVecPointStorage {
+    fn from(storage: &'_ InterleavedVecPointStorage) -> Self {
+        storage.to_per_attribute()
+    }
+}
+
 /**
  * Helper structure for pushing separate attributes into a `PerAttributeVecPointStorage`. Only through this type,
  * using the builder pattern, is it possible to correctly push data for one attribute at a time into the buffer.
@@ -1251,7 +2000,7 @@ impl<T: PointType> From<Vec<T>> for PerAttributeVecPointStorage {
  */
 pub struct PerAttributeVecPointStoragePusher<'a> {
     buffer: &'a mut PerAttributeVecPointStorage,
-    new_attribute_data: HashMap<&'static str, Vec<u8>>,
+    new_attribute_data: HashMap<String, Vec<u8>>,
 }
 
 impl<'a> PerAttributeVecPointStoragePusher<'a> {
@@ -1259,7 +2008,7 @@ impl<'a> PerAttributeVecPointStoragePusher<'a> {
         let new_attribute_data = buffer
             .attributes
             .keys()
-            .map(|key| (*key, Vec::new()))
+            .map(|key| (key.clone(), Vec::new()))
             .collect();
         Self {
             buffer,
@@ -1382,7 +2131,7 @@ impl<'a> PerAttributeVecPointStoragePusher<'a> {
         }
 
         for (k, mut v) in self.new_attribute_data.into_iter() {
-            let attribute_data = self.buffer.attributes.get_mut(k).unwrap();
+            let attribute_data = self.buffer.attributes.get_mut(&k).unwrap();
             attribute_data.append(&mut v);
         }
     }
@@ -1395,13 +2144,14 @@ mod tests {
 
     use super::*;
     use crate::containers::{
-        InterleavedPointView, PerAttributePointBufferExt, PerAttributePointView, PointBufferExt,
-        PointBufferWriteableExt,
+        IndexedPointViewMut, InterleavedPointBufferExt, InterleavedPointBufferMutExt,
+        InterleavedPointView, PerAttributePointBufferExt, PerAttributePointBufferMutExt,
+        PerAttributePointView, PointBufferExt, PointBufferWriteableExt, UntypedPoint,
     };
     use crate::layout::attributes::{CLASSIFICATION, COLOR_RGB, GPS_TIME, INTENSITY, POSITION_3D};
     use crate::util::view_raw_bytes;
     use crate::{
-        layout::{attributes, PointLayout},
+        layout::{attributes, Nullable, PointAttributeDataType, PointLayout},
         util::view_raw_bytes_mut,
     };
     use pasture_derive::PointType;
@@ -1424,6 +2174,14 @@ mod tests {
         #[pasture(BUILTIN_RETURN_NUMBER)] u8,
     );
 
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone, PartialEq, PointType)]
+    struct NullablePointType(
+        #[pasture(BUILTIN_INTENSITY)] u16,
+        #[pasture(BUILTIN_GPS_TIME)] Nullable<f64>,
+        #[pasture(BUILTIN_COLOR_RGB)] Nullable<Vector3<u16>>,
+    );
+
     trait OpqaueInterleavedBuffer: InterleavedPointBufferMut + PointBufferWriteable {}
     impl OpqaueInterleavedBuffer for InterleavedVecPointStorage {}
 
@@ -2244,21 +3002,81 @@ mod tests {
     }
 
     #[test]
-    fn test_interleaved_vec_storage_slice() {
+    fn test_interleaved_vec_storage_slice() {
+        let mut buffer = InterleavedVecPointStorage::new(TestPointType::layout());
+
+        let reference_point_1 = TestPointType(42, 0.123);
+        let reference_point_2 = TestPointType(42, 0.456);
+        buffer.push_point(reference_point_1);
+        buffer.push_point(reference_point_2);
+
+        let slice = buffer.slice(0..1);
+        assert_eq!(1, slice.len());
+
+        let first_point_ref = slice.get_raw_point_ref(0);
+        let first_point_ref_typed = unsafe { &*(first_point_ref.as_ptr() as *const TestPointType) };
+
+        assert_eq!(&reference_point_1, first_point_ref_typed);
+    }
+
+    #[test]
+    fn test_interleaved_vec_storage_slice_mut() {
+        let mut buffer = InterleavedVecPointStorage::new(TestPointType::layout());
+        for index in 0..10 {
+            buffer.push_point(TestPointType(index, 0.0));
+        }
+
+        {
+            let mut slice = buffer.slice_mut(2..8);
+            assert_eq!(6, slice.len());
+            assert_eq!(TestPointType(2, 0.0), slice.get_point::<TestPointType>(0));
+
+            let (mut left, mut right) = slice.split_at_mut(2);
+            assert_eq!(2, left.len());
+            assert_eq!(4, right.len());
+            *left.get_point_mut::<TestPointType>(1) = TestPointType(42, 1.0);
+
+            right.get_point_mut::<TestPointType>(3).1 = 2.0;
+            let (_, mut right_of_right) = right.split_at_mut(4);
+            assert_eq!(0, right_of_right.len());
+            assert!(right_of_right.get_raw_points_mut(0..0).is_empty());
+        }
+
+        assert_eq!(TestPointType(42, 1.0), buffer.get_point::<TestPointType>(3));
+        assert_eq!(TestPointType(7, 2.0), buffer.get_point::<TestPointType>(7));
+        assert_eq!(TestPointType(8, 0.0), buffer.get_point::<TestPointType>(8));
+    }
+
+    #[test]
+    fn test_interleaved_vec_storage_chunks_mut_on_multiple_threads() {
         let mut buffer = InterleavedVecPointStorage::new(TestPointType::layout());
+        buffer.resize(10);
 
-        let reference_point_1 = TestPointType(42, 0.123);
-        let reference_point_2 = TestPointType(42, 0.456);
-        buffer.push_point(reference_point_1);
-        buffer.push_point(reference_point_2);
-
-        let slice = buffer.slice(0..1);
-        assert_eq!(1, slice.len());
+        let chunks = buffer.chunks_mut(3).collect::<Vec<_>>();
+        assert_eq!(
+            vec![3, 3, 3, 1],
+            chunks.iter().map(|chunk| chunk.len()).collect::<Vec<_>>()
+        );
+        std::thread::scope(|scope| {
+            for (chunk_index, mut chunk) in chunks.into_iter().enumerate() {
+                scope.spawn(move || {
+                    for point in chunk.get_points_mut::<TestPointType>(0..chunk.len()) {
+                        point.0 = chunk_index as u16;
+                    }
+                });
+            }
+        });
 
-        let first_point_ref = slice.get_raw_point_ref(0);
-        let first_point_ref_typed = unsafe { &*(first_point_ref.as_ptr() as *const TestPointType) };
+        let intensities = buffer.iter_attribute::<u16>(&INTENSITY).collect::<Vec<_>>();
+        assert_eq!(vec![0, 0, 0, 1, 1, 1, 2, 2, 2, 3], intensities);
+    }
 
-        assert_eq!(&reference_point_1, first_point_ref_typed);
+    #[test]
+    #[should_panic]
+    fn test_interleaved_vec_storage_split_at_mut_out_of_bounds() {
+        let mut buffer = InterleavedVecPointStorage::new(TestPointType::layout());
+        buffer.resize(10);
+        buffer.split_at_mut(11);
     }
 
     #[test]
@@ -3426,4 +4244,685 @@ mod tests {
 
         buffer.transform_attribute(INTENSITY.name(), |_, _value: &mut Vector3<u16>| {});
     }
+
+    fn test_nullable_attributes_with_buffer(mut buffer: Box<dyn PointBufferWriteable>) {
+        let gps_time = GPS_TIME.with_custom_datatype(<Nullable<f64> as PrimitiveType>::data_type());
+        let color =
+            COLOR_RGB.with_custom_datatype(<Nullable<Vector3<u16>> as PrimitiveType>::data_type());
+
+        assert_eq!(
+            Some(1.5),
+            buffer.get_attribute::<Nullable<f64>>(&gps_time, 0).get()
+        );
+        assert_eq!(
+            None,
+            buffer.get_attribute::<Nullable<f64>>(&gps_time, 1).get()
+        );
+        assert_eq!(
+            vec![None, Some(Vector3::new(1, 2, 3))],
+            buffer
+                .iter_attribute::<Nullable<Vector3<u16>>>(&color)
+                .map(|color| color.get())
+                .collect::<Vec<_>>()
+        );
+        // Absent values are converted to zero when the attribute is requested as its value type
+        assert_eq!(
+            vec![1.5, 0.0],
+            buffer
+                .iter_attribute_as::<f64>(&GPS_TIME)
+                .collect::<Vec<_>>()
+        );
+
+        buffer.set_attribute(&gps_time, 0, Nullable::<f64>::none());
+        buffer.set_attribute(&color, 0, Nullable::some(Vector3::new(4_u16, 5, 6)));
+        assert_eq!(
+            NullablePointType(1, Nullable::none(), Nullable::some(Vector3::new(4, 5, 6))),
+            buffer.get_point::<NullablePointType>(0)
+        );
+    }
+
+    #[test]
+    fn test_nullable_attributes() {
+        let points = [
+            NullablePointType(1, Nullable::some(1.5), Nullable::none()),
+            NullablePointType(2, Nullable::none(), Nullable::some(Vector3::new(1, 2, 3))),
+        ];
+
+        let mut interleaved_buffer = InterleavedVecPointStorage::new(NullablePointType::layout());
+        interleaved_buffer.push_points(&points);
+        test_nullable_attributes_with_buffer(Box::new(interleaved_buffer));
+
+        let mut per_attribute_buffer =
+            PerAttributeVecPointStorage::new(NullablePointType::layout());
+        per_attribute_buffer.push_points(&points);
+        test_nullable_attributes_with_buffer(Box::new(per_attribute_buffer));
+    }
+
+    #[test]
+    fn test_bit_field_attributes() {
+        let packed_layout = PointLayout::from_members_and_alignment(
+            &[
+                INTENSITY.at_offset_in_type(0),
+                attributes::RETURN_NUMBER.at_bit_offset_in_type(2, 0, 4),
+                attributes::NUMBER_OF_RETURNS.at_bit_offset_in_type(2, 4, 4),
+                attributes::EDGE_OF_FLIGHT_LINE.at_bit_offset_in_type(3, 7, 1),
+            ],
+            1,
+        );
+        let raw_points: Vec<u8> = vec![
+            42, 0, 0x21, 0x80, //
+            43, 0, 0x32, 0x00, //
+            44, 0, 0x33, 0x80,
+        ];
+
+        let view = InterleavedPointView::from_raw_slice(raw_points.as_slice(), packed_layout);
+        let return_numbers = view
+            .iter_attribute::<u8>(&attributes::RETURN_NUMBER)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![1, 2, 3], return_numbers);
+        let number_of_returns_u16 =
+            attributes::NUMBER_OF_RETURNS.with_custom_datatype(PointAttributeDataType::U16);
+        let number_of_returns = view
+            .iter_attribute_as::<u16>(&number_of_returns_u16)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![2, 3, 3], number_of_returns);
+        assert!(!view.get_attribute::<bool>(&attributes::EDGE_OF_FLIGHT_LINE, 1));
+
+        let mut interleaved_buffer = InterleavedVecPointStorage::new(view.point_layout().clone());
+        interleaved_buffer.push(&view);
+        interleaved_buffer.set_attribute(&attributes::RETURN_NUMBER, 1, 3_u8);
+        interleaved_buffer.set_attribute(&attributes::EDGE_OF_FLIGHT_LINE, 1, true);
+        assert_eq!(
+            &[43, 0, 0x33, 0x80],
+            interleaved_buffer.get_raw_point_ref(1)
+        );
+
+        let unpacked_layout = PointLayout::from_attributes(&[
+            INTENSITY,
+            attributes::RETURN_NUMBER,
+            attributes::NUMBER_OF_RETURNS,
+            attributes::EDGE_OF_FLIGHT_LINE,
+        ]);
+        let mut per_attribute_buffer = PerAttributeVecPointStorage::new(unpacked_layout);
+        per_attribute_buffer.push(&interleaved_buffer);
+        let return_numbers = per_attribute_buffer
+            .iter_attribute_ref::<u8>(&attributes::RETURN_NUMBER)
+            .copied()
+            .collect::<Vec<_>>();
+        assert_eq!(vec![1, 3, 3], return_numbers);
+        let edge_of_flight_lines = per_attribute_buffer
+            .iter_attribute_ref::<bool>(&attributes::EDGE_OF_FLIGHT_LINE)
+            .copied()
+            .collect::<Vec<_>>();
+        assert_eq!(vec![true, true, true], edge_of_flight_lines);
+
+        // Packing the unpacked attributes again has to yield the original point records
+        let mut repacked_buffer = InterleavedVecPointStorage::new(view.point_layout().clone());
+        repacked_buffer.push(&per_attribute_buffer);
+        assert_eq!(
+            interleaved_buffer.get_raw_points_ref(0..3),
+            repacked_buffer.get_raw_points_ref(0..3)
+        );
+    }
+
+    #[test]
+    fn test_interleaved_vec_storage_add_and_remove_attribute() {
+        let mut storage = InterleavedVecPointStorage::new(TestPointType::layout());
+        storage.push_points(&[TestPointType(42, 0.123), TestPointType(43, 0.456)]);
+
+        storage.add_attribute(&POSITION_3D, Vector3::new(1.0, 2.0, 3.0));
+        assert!(storage.point_layout().has_attribute(&POSITION_3D));
+        assert_eq!(
+            vec![Vector3::new(1.0, 2.0, 3.0); 2],
+            storage
+                .iter_attribute::<Vector3<f64>>(&POSITION_3D)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![42, 43],
+            storage
+                .iter_attribute::<u16>(&INTENSITY)
+                .collect::<Vec<_>>()
+        );
+
+        storage.remove_attribute(&INTENSITY);
+        assert!(!storage.point_layout().has_attribute(&INTENSITY));
+        assert_eq!(32, storage.point_layout().size_of_point_entry());
+        assert_eq!(2, storage.len());
+        assert_eq!(
+            vec![0.123, 0.456],
+            storage.iter_attribute::<f64>(&GPS_TIME).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![Vector3::new(1.0, 2.0, 3.0); 2],
+            storage
+                .iter_attribute::<Vector3<f64>>(&POSITION_3D)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_per_attribute_vec_storage_add_and_remove_attribute() {
+        let mut storage = PerAttributeVecPointStorage::new(TestPointType::layout());
+        storage.push_points(&[TestPointType(42, 0.123), TestPointType(43, 0.456)]);
+
+        storage.add_attribute(&CLASSIFICATION, 7_u8);
+        assert_eq!(
+            &[7, 7],
+            storage.get_raw_attribute_range_ref(0..2, &CLASSIFICATION)
+        );
+
+        storage.remove_attribute(&INTENSITY);
+        assert!(!storage.point_layout().has_attribute(&INTENSITY));
+        assert_eq!(2, storage.len());
+        assert_eq!(
+            vec![0.123, 0.456],
+            storage.iter_attribute::<f64>(&GPS_TIME).collect::<Vec<_>>()
+        );
+
+        storage.push_point(TestPointTypeAfterRemoval(0.789, 1));
+        assert_eq!(3, storage.len());
+        assert_eq!(
+            &[7, 7, 1],
+            storage.get_raw_attribute_range_ref(0..3, &CLASSIFICATION)
+        );
+    }
+
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone, PartialEq, PointType)]
+    struct TestPointTypeAfterRemoval(
+        #[pasture(BUILTIN_GPS_TIME)] f64,
+        #[pasture(BUILTIN_CLASSIFICATION)] u8,
+    );
+
+    #[test]
+    #[should_panic]
+    fn test_add_existing_attribute() {
+        let mut storage = PerAttributeVecPointStorage::new(TestPointType::layout());
+        storage.add_attribute(&INTENSITY, 0_u16);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_attribute_with_wrong_type() {
+        let mut storage = InterleavedVecPointStorage::new(TestPointType::layout());
+        storage.add_attribute(&CLASSIFICATION, 0_u16);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_remove_missing_attribute() {
+        let mut storage = InterleavedVecPointStorage::new(TestPointType::layout());
+        storage.remove_attribute(&CLASSIFICATION);
+    }
+
+    #[test]
+    fn test_transpose_between_interleaved_and_per_attribute() {
+        // Enough points for multiple transposition blocks, with a partial last block
+        let points = (0..20_000)
+            .map(|idx| TestPointType(idx as u16, idx as f64 * 0.5))
+            .collect::<Vec<_>>();
+        let interleaved = InterleavedVecPointStorage::from(points.as_slice());
+        let expected_per_attribute = PerAttributeVecPointStorage::from(points.as_slice());
+
+        for per_attribute in [
+            interleaved.to_per_attribute(),
+            interleaved.par_to_per_attribute(),
+            PerAttributeVecPointStorage::from(&interleaved),
+        ] {
+            assert_eq!(interleaved.point_layout(), per_attribute.point_layout());
+            for attribute in [INTENSITY, GPS_TIME] {
+                assert_eq!(
+                    expected_per_attribute.get_raw_attribute_range_ref(0..points.len(), &attribute),
+                    per_attribute.get_raw_attribute_range_ref(0..points.len(), &attribute)
+                );
+            }
+        }
+
+        for transposed in [
+            expected_per_attribute.to_interleaved(),
+            expected_per_attribute.par_to_interleaved(),
+            InterleavedVecPointStorage::from(&expected_per_attribute),
+        ] {
+            assert_eq!(
+                points.as_slice(),
+                transposed.get_points_ref::<TestPointType>(0..points.len())
+            );
+        }
+
+        let empty = InterleavedVecPointStorage::new(TestPointType::layout());
+        assert_eq!(0, empty.par_to_per_attribute().len());
+        assert_eq!(0, empty.to_per_attribute().par_to_interleaved().len());
+    }
+
+    #[test]
+    fn test_transpose_with_layout_conversion() {
+        let points = (0..10_000)
+            .map(|idx| TestPointType(idx as u16, idx as f64))
+            .collect::<Vec<_>>();
+        let interleaved = InterleavedVecPointStorage::from(points.as_slice());
+
+        let intensity_u32 = INTENSITY.with_custom_datatype(PointAttributeDataType::U32);
+        let gps_time_f32 = GPS_TIME.with_custom_datatype(PointAttributeDataType::F32);
+        let target_layout = PointLayout::from_attributes(&[
+            gps_time_f32.clone(),
+            CLASSIFICATION,
+            intensity_u32.clone(),
+        ]);
+        let expected_intensities = (0..10_000).collect::<Vec<u32>>();
+        let expected_gps_times = (0..10_000).map(|idx| idx as f32).collect::<Vec<_>>();
+
+        let per_attribute = interleaved.to_per_attribute_with_layout(target_layout.clone());
+        assert_eq!(
+            per_attribute.point_layout(),
+            interleaved
+                .par_to_per_attribute_with_layout(target_layout.clone())
+                .point_layout()
+        );
+        assert_eq!(
+            expected_intensities.as_slice(),
+            per_attribute.get_attribute_range_ref::<u32>(0..points.len(), &intensity_u32)
+        );
+        assert_eq!(
+            expected_gps_times.as_slice(),
+            per_attribute.get_attribute_range_ref::<f32>(0..points.len(), &gps_time_f32)
+        );
+        assert!(per_attribute
+            .iter_attribute_ref::<u8>(&CLASSIFICATION)
+            .all(|&classification| classification == 0));
+
+        let per_attribute = PerAttributeVecPointStorage::from(points.as_slice());
+        for transposed in [
+            per_attribute.to_interleaved_with_layout(target_layout.clone()),
+            per_attribute.par_to_interleaved_with_layout(target_layout.clone()),
+        ] {
+            assert_eq!(&target_layout, transposed.point_layout());
+            assert_eq!(
+                expected_intensities,
+                transposed
+                    .iter_attribute::<u32>(&intensity_u32)
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(
+                expected_gps_times,
+                transposed
+                    .iter_attribute::<f32>(&gps_time_f32)
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn test_transpose_bit_field_attributes() {
+        let packed_layout = PointLayout::from_members_and_alignment(
+            &[
+                INTENSITY.at_offset_in_type(0),
+                attributes::RETURN_NUMBER.at_bit_offset_in_type(2, 0, 4),
+                attributes::NUMBER_OF_RETURNS.at_bit_offset_in_type(2, 4, 4),
+            ],
+            1,
+        );
+        let raw_points: Vec<u8> = vec![
+            42, 0, 0x21, //
+            43, 0, 0x32, //
+            44, 0, 0x33,
+        ];
+        let view = InterleavedPointView::from_raw_slice(raw_points.as_slice(), packed_layout);
+        let mut interleaved = InterleavedVecPointStorage::new(view.point_layout().clone());
+        interleaved.push(&view);
+
+        let unpacked_layout = PointLayout::from_attributes(&[
+            INTENSITY,
+            attributes::RETURN_NUMBER,
+            attributes::NUMBER_OF_RETURNS,
+        ]);
+        let per_attribute = interleaved.par_to_per_attribute_with_layout(unpacked_layout);
+        assert_eq!(
+            &[1, 2, 3],
+            per_attribute.get_attribute_range_ref::<u8>(0..3, &attributes::RETURN_NUMBER)
+        );
+        assert_eq!(
+            &[2, 3, 3],
+            per_attribute.get_attribute_range_ref::<u8>(0..3, &attributes::NUMBER_OF_RETURNS)
+        );
+
+        let repacked = per_attribute.to_interleaved_with_layout(view.point_layout().clone());
+        assert_eq!(raw_points.as_slice(), repacked.get_raw_points_ref(0..3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_transpose_with_inconvertible_layout() {
+        let interleaved = InterleavedVecPointStorage::from(vec![TestPointType(42, 0.5)]);
+        let gps_time_bytes = GPS_TIME.with_custom_datatype(PointAttributeDataType::ByteArray(3));
+        interleaved.to_per_attribute_with_layout(PointLayout::from_attributes(&[gps_time_bytes]));
+    }
+
+    fn test_remove_points_with_buffer(mut buffer: Box<dyn PointBufferWriteable>) {
+        let intensities = |buffer: &dyn PointBufferWriteable| {
+            buffer.iter_attribute::<u16>(&INTENSITY).collect::<Vec<_>>()
+        };
+
+        buffer.retain(|point: &TestPointType| point.0 % 3 != 0);
+        assert_eq!(vec![1, 2, 4, 5, 7, 8], intensities(buffer.as_ref()));
+        assert_eq!(TestPointType(4, 2.0), buffer.get_point::<TestPointType>(2));
+
+        buffer.retain_untyped(|point| point.get_attribute::<f64>(&GPS_TIME).unwrap() < 4.0);
+        assert_eq!(vec![1, 2, 4, 5, 7], intensities(buffer.as_ref()));
+
+        buffer.swap_remove(1);
+        assert_eq!(vec![1, 7, 4, 5], intensities(buffer.as_ref()));
+        buffer.swap_remove(3);
+        assert_eq!(vec![1, 7, 4], intensities(buffer.as_ref()));
+
+        buffer.remove_indices(&[2, 0, 2]);
+        assert_eq!(vec![7], intensities(buffer.as_ref()));
+        assert_eq!(TestPointType(7, 3.5), buffer.get_point::<TestPointType>(0));
+
+        buffer.remove_indices(&[]);
+        assert_eq!(1, buffer.len());
+        buffer.swap_remove(0);
+        assert_eq!(0, buffer.len());
+    }
+
+    fn test_dedup_with_buffer(mut buffer: Box<dyn PointBufferWriteable>) {
+        buffer.dedup_by_attribute::<f64>(&GPS_TIME);
+        assert_eq!(
+            vec![
+                TestPointType(1, 1.0),
+                TestPointType(3, 2.0),
+                TestPointType(4, 1.0),
+            ],
+            buffer.iter_point::<TestPointType>().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_remove_points() {
+        let points = (0..10)
+            .map(|idx| TestPointType(idx as u16, idx as f64 * 0.5))
+            .collect::<Vec<_>>();
+        test_remove_points_with_buffer(Box::new(InterleavedVecPointStorage::from(
+            points.as_slice(),
+        )));
+        test_remove_points_with_buffer(Box::new(PerAttributeVecPointStorage::from(
+            points.as_slice(),
+        )));
+
+        let duplicate_points = vec![
+            TestPointType(1, 1.0),
+            TestPointType(2, 1.0),
+            TestPointType(3, 2.0),
+            TestPointType(4, 1.0),
+            TestPointType(5, 1.0),
+        ];
+        test_dedup_with_buffer(Box::new(InterleavedVecPointStorage::from(
+            duplicate_points.as_slice(),
+        )));
+        test_dedup_with_buffer(Box::new(PerAttributeVecPointStorage::from(
+            duplicate_points.as_slice(),
+        )));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_retain_by_mask_with_wrong_length() {
+        let mut storage = PerAttributeVecPointStorage::from(vec![TestPointType(1, 1.0)]);
+        storage.retain_by_mask(&[true, false]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_swap_remove_out_of_bounds() {
+        let mut storage = InterleavedVecPointStorage::from(vec![TestPointType(1, 1.0)]);
+        storage.swap_remove(1);
+    }
+
+    fn test_transform_attributes_with_buffer(buffer: &mut dyn PointBufferWriteable) {
+        let len = buffer.len();
+        buffer.transform_attributes(
+            &[INTENSITY.name(), GPS_TIME.name()],
+            |index, (intensity, gps_time): &mut (u32, f64)| {
+                *intensity += (index % 100) as u32;
+                *gps_time += *intensity as f64;
+            },
+        );
+        let expected_points = (0..len)
+            .map(|idx| TestPointType((idx % 100) as u16 * 2, (idx % 100) as f64 * 2.0))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            expected_points,
+            buffer.iter_point::<TestPointType>().collect::<Vec<_>>()
+        );
+
+        buffer.par_transform_attributes(
+            &[GPS_TIME.name(), INTENSITY.name()],
+            |_, (gps_time, intensity): &mut (f64, u16)| {
+                *gps_time -= *intensity as f64;
+                *intensity /= 2;
+            },
+        );
+        buffer.par_transform_attribute(GPS_TIME.name(), |index, gps_time: &mut f32| {
+            *gps_time += (index % 100) as f32;
+        });
+        let expected_points = (0..len)
+            .map(|idx| TestPointType((idx % 100) as u16, (idx % 100) as f64))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            expected_points,
+            buffer.iter_point::<TestPointType>().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_transform_attributes() {
+        // Enough points for multiple blocks
+        let points = (0..20_000)
+            .map(|idx| TestPointType((idx % 100) as u16, 0.0))
+            .collect::<Vec<_>>();
+        test_transform_attributes_with_buffer(&mut InterleavedVecPointStorage::from(
+            points.as_slice(),
+        ));
+        test_transform_attributes_with_buffer(&mut PerAttributeVecPointStorage::from(
+            points.as_slice(),
+        ));
+
+        // A view provides no access to the memory of the viewed buffer, so it is transformed point by point
+        let mut storage = InterleavedVecPointStorage::from(points.as_slice());
+        let indices = (0..points.len()).collect::<Vec<_>>();
+        test_transform_attributes_with_buffer(&mut IndexedPointViewMut::new(
+            &mut storage,
+            &indices,
+        ));
+    }
+
+    #[test]
+    fn test_transform_bit_field_attribute() {
+        let packed_layout = PointLayout::from_members_and_alignment(
+            &[
+                INTENSITY.at_offset_in_type(0),
+                attributes::RETURN_NUMBER.at_bit_offset_in_type(2, 0, 4),
+                attributes::NUMBER_OF_RETURNS.at_bit_offset_in_type(2, 4, 4),
+            ],
+            1,
+        );
+        let raw_points: Vec<u8> = vec![42, 0, 0x21, 43, 0, 0x32];
+        let view = InterleavedPointView::from_raw_slice(raw_points.as_slice(), packed_layout);
+        let mut storage = InterleavedVecPointStorage::new(view.point_layout().clone());
+        storage.push(&view);
+
+        storage.par_transform_attribute(
+            attributes::RETURN_NUMBER.name(),
+            |_, return_number: &mut u8| {
+                *return_number += 1;
+            },
+        );
+        assert_eq!(
+            &[42, 0, 0x22, 43, 0, 0x33],
+            storage.get_raw_points_ref(0..2)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_transform_attributes_with_wrong_number_of_attributes() {
+        let mut storage = PerAttributeVecPointStorage::from(vec![TestPointType(1, 1.0)]);
+        storage.transform_attributes(&[INTENSITY.name()], |_, _: &mut (u16, f64)| {});
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_transform_attributes_with_duplicate_attribute() {
+        let mut storage = PerAttributeVecPointStorage::from(vec![TestPointType(1, 1.0)]);
+        storage.par_transform_attributes(
+            &[INTENSITY.name(), INTENSITY.name()],
+            |_, _: &mut (u16, u16)| {},
+        );
+    }
+
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone, PartialEq, PointType)]
+    struct AlignedPointType(
+        #[pasture(BUILTIN_POSITION_3D)] Vector3<f64>,
+        #[pasture(BUILTIN_INTENSITY)] u16,
+    );
+
+    #[test]
+    fn test_attribute_matrix_views() {
+        let points = (0..16)
+            .map(|index| {
+                AlignedPointType(
+                    Vector3::new(index as f64, index as f64 * 2.0, index as f64 * 3.0),
+                    index as u16,
+                )
+            })
+            .collect::<Vec<_>>();
+        let mut interleaved = InterleavedVecPointStorage::new(AlignedPointType::layout());
+        interleaved.push_points(&points);
+        let mut per_attribute = PerAttributeVecPointStorage::new(AlignedPointType::layout());
+        per_attribute.push_points(&points);
+
+        {
+            let interleaved_positions = interleaved.attribute_matrix_view::<f64>(&POSITION_3D);
+            let per_attribute_positions = per_attribute.attribute_matrix_view::<f64>(&POSITION_3D);
+            assert_eq!((16, 3), interleaved_positions.shape());
+            assert_eq!(interleaved_positions, per_attribute_positions);
+            for (index, point) in points.iter().enumerate() {
+                assert_eq!(point.0.transpose(), interleaved_positions.row(index));
+            }
+
+            let intensities = interleaved.attribute_matrix_view::<u16>(&INTENSITY);
+            assert_eq!((16, 1), intensities.shape());
+            assert_eq!(15, intensities[(15, 0)]);
+        }
+
+        interleaved
+            .attribute_matrix_view_mut::<f64>(&POSITION_3D)
+            .column_mut(2)
+            .fill(-1.0);
+        per_attribute
+            .attribute_matrix_view_mut::<f64>(&POSITION_3D)
+            .column_mut(2)
+            .fill(-1.0);
+        for index in 0..points.len() {
+            let expected_position = Vector3::new(index as f64, index as f64 * 2.0, -1.0);
+            assert_eq!(
+                expected_position,
+                interleaved.get_attribute::<Vector3<f64>>(&POSITION_3D, index)
+            );
+            assert_eq!(
+                expected_position,
+                per_attribute.get_attribute::<Vector3<f64>>(&POSITION_3D, index)
+            );
+            assert_eq!(
+                index as u16,
+                interleaved.get_attribute::<u16>(&INTENSITY, index)
+            );
+        }
+
+        let empty = PerAttributeVecPointStorage::new(AlignedPointType::layout());
+        assert_eq!(
+            (0, 3),
+            empty.attribute_matrix_view::<f64>(&POSITION_3D).shape()
+        );
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn test_attribute_ndarray_views() {
+        let points = (0..16)
+            .map(|index| {
+                AlignedPointType(
+                    Vector3::new(index as f64, index as f64 * 2.0, index as f64 * 3.0),
+                    index as u16,
+                )
+            })
+            .collect::<Vec<_>>();
+        let mut interleaved = InterleavedVecPointStorage::new(AlignedPointType::layout());
+        interleaved.push_points(&points);
+        let mut per_attribute = PerAttributeVecPointStorage::new(AlignedPointType::layout());
+        per_attribute.push_points(&points);
+
+        {
+            let interleaved_positions = interleaved.attribute_ndarray_view::<f64>(&POSITION_3D);
+            let per_attribute_positions = per_attribute.attribute_ndarray_view::<f64>(&POSITION_3D);
+            assert_eq!(&[16, 3], interleaved_positions.shape());
+            assert_eq!(interleaved_positions, per_attribute_positions);
+            for (index, point) in points.iter().enumerate() {
+                assert_eq!(
+                    point.0.as_slice(),
+                    interleaved_positions.row(index).to_vec().as_slice()
+                );
+            }
+
+            let intensities = per_attribute.attribute_ndarray_view::<u16>(&INTENSITY);
+            assert_eq!(&[16, 1], intensities.shape());
+            assert_eq!(15, intensities[[15, 0]]);
+        }
+
+        interleaved
+            .attribute_ndarray_view_mut::<f64>(&POSITION_3D)
+            .column_mut(2)
+            .fill(-1.0);
+        per_attribute
+            .attribute_ndarray_view_mut::<f64>(&POSITION_3D)
+            .column_mut(2)
+            .fill(-1.0);
+        for index in 0..points.len() {
+            let expected_position = Vector3::new(index as f64, index as f64 * 2.0, -1.0);
+            assert_eq!(
+                expected_position,
+                interleaved.get_attribute::<Vector3<f64>>(&POSITION_3D, index)
+            );
+            assert_eq!(
+                expected_position,
+                per_attribute.get_attribute::<Vector3<f64>>(&POSITION_3D, index)
+            );
+            assert_eq!(
+                index as u16,
+                interleaved.get_attribute::<u16>(&INTENSITY, index)
+            );
+        }
+
+        let empty = InterleavedVecPointStorage::new(AlignedPointType::layout());
+        assert_eq!(
+            &[0, 3],
+            empty.attribute_ndarray_view::<f64>(&POSITION_3D).shape()
+        );
+        let mut empty = PerAttributeVecPointStorage::new(AlignedPointType::layout());
+        assert_eq!(
+            &[0, 3],
+            empty
+                .attribute_ndarray_view_mut::<f64>(&POSITION_3D)
+                .shape()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_attribute_matrix_view_with_wrong_scalar_type() {
+        let mut storage = PerAttributeVecPointStorage::new(AlignedPointType::layout());
+        storage.resize(4);
+        storage.attribute_matrix_view::<f32>(&POSITION_3D);
+    }
 }
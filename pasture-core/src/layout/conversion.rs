@@ -13,47 +13,96 @@
 //! The conversion then operates on these two buffers. As this is a *highly* unsafe operation where all sorts of things
 //! could go wrong, any conversion is only valid together with the *exact* `PointLayout` of both `A` and `B`!
 
+use anyhow::{anyhow, bail, Result};
 use lazy_static::lazy_static;
 use nalgebra::{Scalar, Vector3};
-use std::{collections::HashMap, ops::Range};
+use std::{borrow::Cow, collections::HashMap, sync::RwLock};
 
-use crate::layout::{PointAttributeDataType, PointAttributeDefinition, PointLayout};
+use crate::layout::{
+    PointAttributeDataType, PointAttributeDefinition, PointAttributeMember,
+    PointAttributeQuantization, PointAttributeScalarType, PointLayout, PointLayoutMapping,
+};
 
 /// Helper structure that contains the relevant data to convert a single attribute from a source binary
 /// buffer to a target binary buffer.
 struct RawAttributeConverter {
-    conversion_fn: AttributeConversionFn,
-    source_range: Range<usize>,
-    target_range: Range<usize>,
+    converter: AttributeConverter,
+    source_attribute: PointAttributeMember,
+    target_attribute: PointAttributeMember,
 }
 
 impl RawAttributeConverter {
     pub fn new(
-        conversion_fn: AttributeConversionFn,
-        source_offset: u64,
-        source_size: u64,
-        target_offset: u64,
-        target_size: u64,
+        converter: AttributeConverter,
+        source_attribute: &PointAttributeMember,
+        target_attribute: &PointAttributeMember,
     ) -> Self {
         Self {
-            conversion_fn,
-            source_range: Range {
-                start: source_offset as usize,
-                end: (source_offset + source_size) as usize,
-            },
-            target_range: Range {
-                start: target_offset as usize,
-                end: (target_offset + target_size) as usize,
-            },
+            converter,
+            source_attribute: source_attribute.clone(),
+            target_attribute: target_attribute.clone(),
         }
     }
 
     /// Performs the conversion
     unsafe fn convert(&self, source_point: &[u8], target_point: &mut [u8]) {
-        let source_slice = &source_point[self.source_range.start..self.source_range.end];
-        let target_slice = &mut target_point[self.target_range.start..self.source_range.end];
+        // Bit field attributes have to be unpacked before (or packed after) the conversion. Their values
+        // are at most 8 bytes large, so this can be done on the stack
+        let mut source_value = [0; 8];
+        let source_slice = if self.source_attribute.bit_field().is_some() {
+            let source_value = &mut source_value[..self.source_attribute.size() as usize];
+            self.source_attribute
+                .read_from_point_record(source_point, source_value);
+            &*source_value
+        } else {
+            let source_start = self.source_attribute.offset() as usize;
+            &source_point[source_start..source_start + self.source_attribute.size() as usize]
+        };
+
+        if self.target_attribute.bit_field().is_some() {
+            let mut target_value = [0; 8];
+            let target_value = &mut target_value[..self.target_attribute.size() as usize];
+            self.converter.convert(source_slice, target_value);
+            self.target_attribute
+                .write_to_point_record(target_value, target_point);
+        } else {
+            let target_start = self.target_attribute.offset() as usize;
+            let target_slice = &mut target_point
+                [target_start..target_start + self.target_attribute.size() as usize];
+            self.converter.convert(source_slice, target_slice);
+        }
+    }
 
-        (self.conversion_fn)(source_slice, target_slice);
+    /// Performs the conversion for `count` points that are `source_stride` and `target_stride` bytes apart
+    unsafe fn convert_batch(
+        &self,
+        source_points: &[u8],
+        source_stride: usize,
+        target_points: &mut [u8],
+        target_stride: usize,
+        count: usize,
+    ) {
+        if count == 0 {
+            return;
+        }
+        if self.source_attribute.bit_field().is_some()
+            || self.target_attribute.bit_field().is_some()
+        {
+            for point_index in 0..count {
+                self.convert(
+                    &source_points[point_index * source_stride..],
+                    &mut target_points[point_index * target_stride..],
+                );
+            }
+        } else {
+            self.converter.convert_batch(
+                &source_points[self.source_attribute.offset() as usize..],
+                source_stride,
+                &mut target_points[self.target_attribute.offset() as usize..],
+                target_stride,
+                count,
+            );
+        }
     }
 }
 
@@ -64,34 +113,90 @@ pub struct RawPointConverter {
 
 impl RawPointConverter {
     /// Creates a new `RawPointConverter` that converts points `from_layout` to `to_layout`. The converter converts
-    /// all attributes that are present in both `from_layout` and `to_layout` and which can be converted.
+    /// all attributes that are present in both `from_layout` and `to_layout` and which can be converted. Attributes
+    /// with the same datatype in both layouts are copied, which also unpacks (or packs) attributes that are stored
+    /// as [bit fields](crate::layout::PointAttributeBitField). Quantized attributes are dequantized and/or quantized
+    /// as described by [`AttributeConverter`]. Use [`RawPointConverter::try_from_to`] to handle invalid conversions
+    /// without panicking
+    ///
+    /// # Panics
+    ///
+    /// If an attribute that is present in both layouts can't be converted
     pub fn from_to(from_layout: &PointLayout, to_layout: &PointLayout) -> RawPointConverter {
-        let converters = from_layout
-            .attributes()
-            .filter(|&from_attribute| to_layout.has_attribute_with_name(from_attribute.name()))
-            .map(|from_attribute| {
-                let to_attribute = to_layout
-                    .get_attribute_by_name(from_attribute.name())
-                    .unwrap();
-                let conversion_fn =
-                    get_converter_for_attributes(&from_attribute.into(), &to_attribute.into());
-                conversion_fn.map(|conversion_fn| {
-                    RawAttributeConverter::new(
-                        conversion_fn,
-                        from_attribute.offset(),
-                        from_attribute.size(),
-                        to_attribute.offset(),
-                        to_attribute.size(),
-                    )
-                })
-            })
-            .filter(|converter| converter.is_some())
-            .map(|converter| converter.unwrap())
-            .collect::<Vec<_>>();
+        Self::try_from_to(from_layout, to_layout)
+            .unwrap_or_else(|why| panic!("RawPointConverter::from_to: {}", why))
+    }
 
-        Self {
-            attribute_converters: converters,
+    /// Like [`RawPointConverter::from_to`], but returns an error if an attribute that is present in both layouts can't be
+    /// converted
+    pub fn try_from_to(
+        from_layout: &PointLayout,
+        to_layout: &PointLayout,
+    ) -> Result<RawPointConverter> {
+        Self::try_from_mapping(&PointLayoutMapping::by_name(from_layout, to_layout))
+    }
+
+    /// Creates a new `RawPointConverter` that converts points according to the given [`PointLayoutMapping`]. Each source
+    /// attribute of the mapping is converted into its target attribute, like in [`RawPointConverter::from_to`]. Renamed
+    /// attributes are converted as if they had the name of their source attribute
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// # use pasture_core::layout::conversion::*;
+    /// let layout = PointLayout::from_attributes(&[attributes::INTENSITY]);
+    /// let (renamed_layout, mapping) = layout.with_renamed_attribute(&attributes::INTENSITY, "Reflectance");
+    /// let (target_layout, datatype_mapping) = renamed_layout.with_attribute_datatype(
+    ///     &renamed_layout.at(0).into(),
+    ///     PointAttributeDataType::U32,
+    /// );
+    /// # assert_eq!(1, datatype_mapping.attributes().count());
+    ///
+    /// let converter = RawPointConverter::from_mapping(&mapping);
+    /// let mut renamed_point = [0; 2];
+    /// unsafe {
+    ///     converter.convert(&42_u16.to_ne_bytes(), &mut renamed_point);
+    /// }
+    /// let converter = RawPointConverter::from_mapping(&datatype_mapping);
+    /// let mut target_point = [0; 4];
+    /// unsafe {
+    ///     converter.convert(&renamed_point, &mut target_point);
+    /// }
+    /// assert_eq!(42, u32::from_ne_bytes(target_point));
+    /// # assert_eq!(4, target_layout.size_of_point_entry());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If a source attribute of the mapping can't be converted into its target attribute
+    pub fn from_mapping(mapping: &PointLayoutMapping) -> RawPointConverter {
+        Self::try_from_mapping(mapping)
+            .unwrap_or_else(|why| panic!("RawPointConverter::from_mapping: {}", why))
+    }
+
+    /// Like [`RawPointConverter::from_mapping`], but returns an error if a source attribute of the mapping can't be
+    /// converted into its target attribute
+    pub fn try_from_mapping(mapping: &PointLayoutMapping) -> Result<RawPointConverter> {
+        let mut converters = vec![];
+        for (from_attribute, to_attribute) in mapping.attributes() {
+            let from_definition: PointAttributeDefinition = from_attribute.into();
+            let to_definition = PointAttributeDefinition::from(to_attribute)
+                .with_name(from_definition.name().to_owned());
+            let converter = if from_definition == to_definition {
+                Some(AttributeConverter::copy(from_definition.size() as usize))
+            } else {
+                AttributeConverter::try_new(&from_definition, &to_definition)?
+            };
+            if let Some(converter) = converter {
+                converters.push(RawAttributeConverter::new(
+                    converter,
+                    from_attribute,
+                    to_attribute,
+                ));
+            }
         }
+
+        Ok(Self {
+            attribute_converters: converters,
+        })
     }
 
     /// Converts the `source_point` into the `target_point`
@@ -100,99 +205,605 @@ impl RawPointConverter {
             converter.convert(source_point, target_point);
         }
     }
+
+    /// Converts `count` points from `source_points` into `target_points`, where consecutive points are `source_stride`
+    /// and `target_stride` bytes apart (e.g. the sizes of the point layouts for interleaved data). This converts each
+    /// attribute for all points at once (see [`AttributeConverter::convert_batch`]), which is much faster than calling
+    /// [`RawPointConverter::convert`] for each point
+    ///
+    /// # Safety
+    ///
+    /// `source_points` and `target_points` must contain point records in the layouts that this converter was created from
+    ///
+    /// # Panics
+    ///
+    /// If `source_points` or `target_points` are too small to hold `count` points with the given strides
+    pub unsafe fn convert_batch(
+        &self,
+        source_points: &[u8],
+        source_stride: usize,
+        target_points: &mut [u8],
+        target_stride: usize,
+        count: usize,
+    ) {
+        for converter in self.attribute_converters.iter() {
+            converter.convert_batch(
+                source_points,
+                source_stride,
+                target_points,
+                target_stride,
+                count,
+            );
+        }
+    }
 }
 
 /// Function pointer type for functions that convert between attributes with different datatypes
 pub type AttributeConversionFn = unsafe fn(&[u8], &mut [u8]) -> ();
 
-/// Returns a conversion function for converting from `from_attribute` into `to_attribute`. Both attributes must have the
-/// same name but can have different datatypes. Conversion functions operate on raw byte buffers, where the first argument
-/// is a buffer that represents a single value of `from_attribute` and the second buffer is a single mutable value of
-/// `to_attribute`. If both attributes are equal, `None` is returned.
-///
-/// # Panics
-///
-/// If no conversion from `from_attribute` into `to_attribute` is possible
-pub fn get_converter_for_attributes(
-    from_attribute: &PointAttributeDefinition,
-    to_attribute: &PointAttributeDefinition,
-) -> Option<AttributeConversionFn> {
-    if from_attribute.name() != to_attribute.name() {
-        panic!("get_converter_for_attributes: from and to attributes must have the same name!");
-    }
-    if from_attribute.datatype() == to_attribute.datatype() {
-        return None;
-    }
+/// Function pointer type for functions that convert a range of attribute values between different datatypes. The arguments
+/// are the source buffer, the distance between two consecutive source values in bytes (the stride), the target buffer, the
+/// stride of the target buffer and the number of values to convert
+pub type AttributeBatchConversionFn = unsafe fn(&[u8], usize, &mut [u8], usize, usize) -> ();
 
-    match from_attribute.name() {
-        "Position3D" => get_position_converter(from_attribute.datatype(), to_attribute.datatype()),
-        "ColorRGB" => get_color_rgb_converter(from_attribute.datatype(), to_attribute.datatype()),
-        _ => get_generic_converter(from_attribute.datatype(), to_attribute.datatype()),
+/// Converts `count` values of type `F` in `from` into values of type `T` in `to` using `convert_value`. Consecutive values are
+/// `from_stride` and `to_stride` bytes apart. Tightly packed values are converted in a separate loop, which the compiler can
+/// vectorize
+#[inline(always)]
+unsafe fn convert_strided<F: Copy, T, C: Fn(F) -> T>(
+    from: &[u8],
+    from_stride: usize,
+    to: &mut [u8],
+    to_stride: usize,
+    count: usize,
+    convert_value: C,
+) {
+    if from_stride == std::mem::size_of::<F>() && to_stride == std::mem::size_of::<T>() {
+        let from_ptr = from.as_ptr() as *const F;
+        let to_ptr = to.as_mut_ptr() as *mut T;
+        for index in 0..count {
+            to_ptr
+                .add(index)
+                .write_unaligned(convert_value(from_ptr.add(index).read_unaligned()));
+        }
+    } else {
+        let from_ptr = from.as_ptr();
+        let to_ptr = to.as_mut_ptr();
+        for index in 0..count {
+            let value = (from_ptr.add(index * from_stride) as *const F).read_unaligned();
+            (to_ptr.add(index * to_stride) as *mut T).write_unaligned(convert_value(value));
+        }
     }
 }
 
-fn get_position_converter(
-    from_type: PointAttributeDataType,
-    to_type: PointAttributeDataType,
-) -> Option<AttributeConversionFn> {
-    lazy_static! {
-        static ref POSITION_CONVERTERS: HashMap<(PointAttributeDataType, PointAttributeDataType), AttributeConversionFn> = {
-            let mut converters = HashMap::<
-                (PointAttributeDataType, PointAttributeDataType),
-                AttributeConversionFn,
-            >::new();
-            converters.insert(
-                (
-                    PointAttributeDataType::Vec3f64,
-                    PointAttributeDataType::Vec3f32,
+/// Converter for the values of a single attribute that takes the [quantization](crate::layout::PointAttributeQuantization)
+/// of the source and target attributes into account. Conversions between attributes that are not quantized use the same
+/// conversion functions as [`get_converter_for_attributes`], conversions involving quantized attributes dequantize each
+/// component of the source attribute into an `f64` value and then quantize it into the target attribute (if necessary)
+/// ```
+/// # use pasture_core::layout::*;
+/// # use pasture_core::layout::conversion::*;
+/// let quantized_intensity = attributes::INTENSITY.with_quantization(PointAttributeQuantization::new(1.0 / 65535.0, 0.0));
+/// let normalized_intensity = attributes::INTENSITY.with_custom_datatype(PointAttributeDataType::F32);
+/// let converter = AttributeConverter::new(&quantized_intensity, &normalized_intensity).unwrap();
+///
+/// let mut normalized_value = [0; 4];
+/// unsafe {
+///     converter.convert(&65535_u16.to_ne_bytes(), &mut normalized_value);
+/// }
+/// assert_eq!(1.0, f32::from_ne_bytes(normalized_value));
+/// ```
+pub struct AttributeConverter {
+    kind: AttributeConverterKind,
+    source_size: usize,
+    target_size: usize,
+}
+
+enum AttributeConverterKind {
+    /// Plain copy between attributes with the same datatype
+    Copy,
+    /// Conversion using a plain conversion function, and optionally a batch conversion function
+    Raw {
+        conversion_fn: AttributeConversionFn,
+        batch_conversion_fn: Option<AttributeBatchConversionFn>,
+    },
+    /// Conversion that dequantizes and/or quantizes each component
+    Quantized {
+        source_scalar_type: PointAttributeScalarType,
+        source_quantization: Option<PointAttributeQuantization>,
+        target_scalar_type: PointAttributeScalarType,
+        target_quantization: Option<PointAttributeQuantization>,
+        components: usize,
+    },
+}
+
+impl AttributeConverter {
+    /// Creates a new `AttributeConverter` that converts values of `from_attribute` into values of `to_attribute`. Both
+    /// attributes must have the same name. Returns `None` if both attributes have the same datatype and quantization, in
+    /// which case no conversion is necessary
+    ///
+    /// # Panics
+    ///
+    /// If the names of the attributes differ or if no conversion from `from_attribute` into `to_attribute` is possible.
+    /// Conversions involving quantized attributes are possible if both attributes have a scalar type and the same number
+    /// of components. Use [`AttributeConverter::try_new`] to handle invalid conversions without panicking
+    pub fn new(
+        from_attribute: &PointAttributeDefinition,
+        to_attribute: &PointAttributeDefinition,
+    ) -> Option<Self> {
+        Self::try_new(from_attribute, to_attribute)
+            .unwrap_or_else(|why| panic!("AttributeConverter::new: {}", why))
+    }
+
+    /// Like [`AttributeConverter::new`], but returns an error if the names of the attributes differ or if no conversion
+    /// from `from_attribute` into `to_attribute` is possible
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// # use pasture_core::layout::conversion::*;
+    /// let position_u8 = attributes::POSITION_3D.with_custom_datatype(PointAttributeDataType::U8);
+    /// assert!(AttributeConverter::try_new(&attributes::POSITION_3D, &position_u8).is_err());
+    /// ```
+    pub fn try_new(
+        from_attribute: &PointAttributeDefinition,
+        to_attribute: &PointAttributeDefinition,
+    ) -> Result<Option<Self>> {
+        let source_size = from_attribute.size() as usize;
+        let target_size = to_attribute.size() as usize;
+        if from_attribute.quantization().is_none() && to_attribute.quantization().is_none() {
+            return Ok(
+                try_get_converter_for_attributes(from_attribute, to_attribute)?.map(
+                    |conversion_fn| Self {
+                        kind: AttributeConverterKind::Raw {
+                            conversion_fn,
+                            batch_conversion_fn: ATTRIBUTE_CONVERTERS
+                                .read()
+                                .unwrap()
+                                .get_batch(from_attribute, to_attribute),
+                        },
+                        source_size,
+                        target_size,
+                    },
                 ),
-                convert_position_from_vec3f64_to_vec3f32,
             );
-            converters.insert(
-                (
-                    PointAttributeDataType::Vec3f32,
-                    PointAttributeDataType::Vec3f64,
-                ),
-                convert_position_from_vec3f32_to_vec3f64,
+        }
+        if from_attribute.name() != to_attribute.name() {
+            bail!("from and to attributes must have the same name!");
+        }
+        if from_attribute == to_attribute {
+            return Ok(None);
+        }
+
+        let source_scalar_type = from_attribute.datatype().scalar_type();
+        let target_scalar_type = to_attribute.datatype().scalar_type();
+        match (source_scalar_type, target_scalar_type) {
+            (Some(source_scalar_type), Some(target_scalar_type))
+                if from_attribute.size() / source_scalar_type.size()
+                    == to_attribute.size() / target_scalar_type.size() =>
+            {
+                Ok(Some(Self {
+                    kind: AttributeConverterKind::Quantized {
+                        source_scalar_type,
+                        source_quantization: from_attribute.quantization().cloned(),
+                        target_scalar_type,
+                        target_quantization: to_attribute.quantization().cloned(),
+                        components: (from_attribute.size() / source_scalar_type.size()) as usize,
+                    },
+                    source_size,
+                    target_size,
+                }))
+            }
+            _ => Err(anyhow!(
+                "Invalid conversion from {} to {}",
+                from_attribute,
+                to_attribute
+            )),
+        }
+    }
+
+    /// Converts the value in `from` into the value in `to`. Both buffers must have the size of the datatypes of the
+    /// attributes that this `AttributeConverter` was created from
+    ///
+    /// # Safety
+    ///
+    /// `from` and `to` must contain at least as many bytes as the source and target datatypes require
+    pub unsafe fn convert(&self, from: &[u8], to: &mut [u8]) {
+        match &self.kind {
+            AttributeConverterKind::Copy => to.copy_from_slice(from),
+            AttributeConverterKind::Raw { conversion_fn, .. } => conversion_fn(from, to),
+            AttributeConverterKind::Quantized {
+                source_scalar_type,
+                source_quantization,
+                target_scalar_type,
+                target_quantization,
+                components,
+            } => {
+                let source_size = source_scalar_type.size() as usize;
+                let target_size = target_scalar_type.size() as usize;
+                for component in 0..*components {
+                    let mut value = read_component_as_f64(
+                        *source_scalar_type,
+                        &from[component * source_size..(component + 1) * source_size],
+                    );
+                    if let Some(quantization) = source_quantization {
+                        value = quantization.dequantize(component, value);
+                    }
+                    if let Some(quantization) = target_quantization {
+                        value = quantization.quantize(component, value);
+                        // Quantized integer values are rounded to the nearest integer instead of being truncated
+                        if !matches!(
+                            target_scalar_type,
+                            PointAttributeScalarType::F32 | PointAttributeScalarType::F64
+                        ) {
+                            value = value.round();
+                        }
+                    }
+                    write_component_from_f64(
+                        *target_scalar_type,
+                        value,
+                        &mut to[component * target_size..(component + 1) * target_size],
+                    );
+                }
+            }
+        }
+    }
+
+    /// Converts `count` values from `from` into `to`, where consecutive values are `from_stride` and `to_stride` bytes apart.
+    /// Conversions between primitive types are performed in a single tight loop over all values, which is much faster than
+    /// calling [`AttributeConverter::convert`] for each value. Conversions for which no batch conversion function is available
+    /// (e.g. custom conversion functions registered through [`register_attribute_converter`]) fall back to converting each
+    /// value separately
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// # use pasture_core::layout::conversion::*;
+    /// let intensity_u32 = attributes::INTENSITY.with_custom_datatype(PointAttributeDataType::U32);
+    /// let converter = AttributeConverter::new(&attributes::INTENSITY, &intensity_u32).unwrap();
+    ///
+    /// // Convert every second value of `source` into `target`
+    /// let source = [1_u16, 0, 2, 0, 3].iter().flat_map(|v| v.to_ne_bytes()).collect::<Vec<_>>();
+    /// let mut target = vec![0; 12];
+    /// unsafe {
+    ///     converter.convert_batch(&source, 4, &mut target, 4, 3);
+    /// }
+    /// let mut third_value = [0; 4];
+    /// third_value.copy_from_slice(&target[8..12]);
+    /// assert_eq!(3, u32::from_ne_bytes(third_value));
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// `from` and `to` must contain values of the source and target datatypes at the given strides
+    ///
+    /// # Panics
+    ///
+    /// If `from` or `to` are too small to hold `count` values with the given strides
+    pub unsafe fn convert_batch(
+        &self,
+        from: &[u8],
+        from_stride: usize,
+        to: &mut [u8],
+        to_stride: usize,
+        count: usize,
+    ) {
+        if count == 0 {
+            return;
+        }
+        if from.len() < (count - 1) * from_stride + self.source_size
+            || to.len() < (count - 1) * to_stride + self.target_size
+        {
+            panic!(
+                "AttributeConverter::convert_batch: Buffers are too small for {} values",
+                count
             );
-            converters
+        }
+
+        match &self.kind {
+            AttributeConverterKind::Copy
+                if from_stride == self.source_size && to_stride == self.target_size =>
+            {
+                let bytes = count * self.source_size;
+                to[..bytes].copy_from_slice(&from[..bytes]);
+            }
+            AttributeConverterKind::Raw {
+                batch_conversion_fn: Some(batch_conversion_fn),
+                ..
+            } => batch_conversion_fn(from, from_stride, to, to_stride, count),
+            _ => {
+                for index in 0..count {
+                    let from_start = index * from_stride;
+                    let to_start = index * to_stride;
+                    self.convert(
+                        &from[from_start..from_start + self.source_size],
+                        &mut to[to_start..to_start + self.target_size],
+                    );
+                }
+            }
+        }
+    }
+
+    /// Creates an `AttributeConverter` that copies values with the given `size`
+    fn copy(size: usize) -> Self {
+        Self {
+            kind: AttributeConverterKind::Copy,
+            source_size: size,
+            target_size: size,
+        }
+    }
+}
+
+/// Registry for the conversion functions between attributes with different datatypes. Conversion functions are registered
+/// for an attribute name together with a source and target datatype, which allows conversions that depend on the semantics
+/// of an attribute (e.g. scaling RGB colors from 8 to 16 bits instead of casting them). If no conversion function is
+/// registered for a pair of attributes, the registry falls back to a generic conversion between the datatypes, as described
+/// in [`get_converter_for_attributes`]
+/// ```
+/// # use pasture_core::layout::*;
+/// # use pasture_core::layout::conversion::*;
+/// // Converts the GPS time in seconds into whole microseconds
+/// unsafe fn gps_time_to_microseconds(from: &[u8], to: &mut [u8]) {
+///     let seconds = (from.as_ptr() as *const f64).read_unaligned();
+///     (to.as_mut_ptr() as *mut u64).write_unaligned((seconds * 1e6) as u64);
+/// }
+///
+/// let mut registry = AttributeConverterRegistry::new();
+/// registry.register(attributes::GPS_TIME.name().to_owned(), PointAttributeDataType::F64, PointAttributeDataType::U64, gps_time_to_microseconds);
+///
+/// let microseconds = attributes::GPS_TIME.with_custom_datatype(PointAttributeDataType::U64);
+/// let converter = registry.get(&attributes::GPS_TIME, &microseconds).unwrap().unwrap();
+/// let mut value = [0; 8];
+/// unsafe {
+///     converter(&1.5_f64.to_ne_bytes(), &mut value);
+/// }
+/// assert_eq!(1_500_000, u64::from_ne_bytes(value));
+///
+/// // There is no conversion from a floating point value into a byte array
+/// let bytes = attributes::GPS_TIME.with_custom_datatype(PointAttributeDataType::ByteArray(8));
+/// assert!(registry.get(&attributes::GPS_TIME, &bytes).is_err());
+/// ```
+#[derive(Clone)]
+pub struct AttributeConverterRegistry {
+    converters: HashMap<
+        Cow<'static, str>,
+        HashMap<(PointAttributeDataType, PointAttributeDataType), AttributeConversionFn>,
+    >,
+    batch_converters: HashMap<
+        Cow<'static, str>,
+        HashMap<(PointAttributeDataType, PointAttributeDataType), AttributeBatchConversionFn>,
+    >,
+}
+
+impl AttributeConverterRegistry {
+    /// Creates a new `AttributeConverterRegistry` that contains the built-in conversion functions of pasture, i.e. conversions
+    /// between `Vec3f64` and `Vec3f32` positions and between `Vec3u16` and `Vec3u8` RGB colors
+    pub fn new() -> Self {
+        let mut registry = Self {
+            converters: HashMap::new(),
+            batch_converters: HashMap::new(),
         };
+        registry.register(
+            "Position3D",
+            PointAttributeDataType::Vec3f64,
+            PointAttributeDataType::Vec3f32,
+            convert_position_from_vec3f64_to_vec3f32,
+        );
+        registry.register(
+            "Position3D",
+            PointAttributeDataType::Vec3f32,
+            PointAttributeDataType::Vec3f64,
+            convert_position_from_vec3f32_to_vec3f64,
+        );
+        registry.register(
+            "ColorRGB",
+            PointAttributeDataType::Vec3u16,
+            PointAttributeDataType::Vec3u8,
+            convert_color_rgb_from_vec3u16_to_vec3u8,
+        );
+        registry.register(
+            "ColorRGB",
+            PointAttributeDataType::Vec3u8,
+            PointAttributeDataType::Vec3u16,
+            convert_color_rgb_from_vec3u8_to_vec3u16,
+        );
+
+        registry.register_batch(
+            "Position3D",
+            PointAttributeDataType::Vec3f64,
+            PointAttributeDataType::Vec3f32,
+            batch_convert_position_from_vec3f64_to_vec3f32,
+        );
+        registry.register_batch(
+            "Position3D",
+            PointAttributeDataType::Vec3f32,
+            PointAttributeDataType::Vec3f64,
+            batch_convert_position_from_vec3f32_to_vec3f64,
+        );
+        registry.register_batch(
+            "ColorRGB",
+            PointAttributeDataType::Vec3u16,
+            PointAttributeDataType::Vec3u8,
+            batch_convert_color_rgb_from_vec3u16_to_vec3u8,
+        );
+        registry.register_batch(
+            "ColorRGB",
+            PointAttributeDataType::Vec3u8,
+            PointAttributeDataType::Vec3u16,
+            batch_convert_color_rgb_from_vec3u8_to_vec3u16,
+        );
+        registry
     }
 
-    let key = (from_type, to_type);
-    POSITION_CONVERTERS.get(&key).map(|&fptr| fptr)
+    /// Registers the `converter` function for converting values of the attribute with the given `attribute_name` from
+    /// `from_type` into `to_type`. Returns the conversion function that was previously registered for this combination
+    /// of attribute name and datatypes, if there was any
+    pub fn register<S: Into<Cow<'static, str>>>(
+        &mut self,
+        attribute_name: S,
+        from_type: PointAttributeDataType,
+        to_type: PointAttributeDataType,
+        converter: AttributeConversionFn,
+    ) -> Option<AttributeConversionFn> {
+        self.converters
+            .entry(attribute_name.into())
+            .or_default()
+            .insert((from_type, to_type), converter)
+    }
+
+    /// Registers the `batch_converter` function for converting ranges of values of the attribute with the given
+    /// `attribute_name` from `from_type` into `to_type`. The batch conversion function has to yield the same results as the
+    /// conversion function registered through [`AttributeConverterRegistry::register`]. Returns the batch conversion function
+    /// that was previously registered for this combination of attribute name and datatypes, if there was any
+    pub fn register_batch<S: Into<Cow<'static, str>>>(
+        &mut self,
+        attribute_name: S,
+        from_type: PointAttributeDataType,
+        to_type: PointAttributeDataType,
+        batch_converter: AttributeBatchConversionFn,
+    ) -> Option<AttributeBatchConversionFn> {
+        self.batch_converters
+            .entry(attribute_name.into())
+            .or_default()
+            .insert((from_type, to_type), batch_converter)
+    }
+
+    /// Returns a batch conversion function for converting ranges of values from `from_attribute` into `to_attribute`. Batch
+    /// conversion functions registered for the attribute take precedence over the generic batch conversions between primitive
+    /// types. Returns `None` if the attributes have different names or the same datatype, if no batch conversion exists, or
+    /// if only a regular conversion function is registered for the attribute (see [`AttributeConverterRegistry::register`]).
+    /// In the latter cases, the conversion function from [`AttributeConverterRegistry::get`] has to be used for each value
+    pub fn get_batch(
+        &self,
+        from_attribute: &PointAttributeDefinition,
+        to_attribute: &PointAttributeDefinition,
+    ) -> Option<AttributeBatchConversionFn> {
+        if from_attribute.name() != to_attribute.name()
+            || from_attribute.datatype() == to_attribute.datatype()
+        {
+            return None;
+        }
+
+        let key = (from_attribute.datatype(), to_attribute.datatype());
+        if let Some(batch_converter) = self
+            .batch_converters
+            .get(from_attribute.name())
+            .and_then(|converters| converters.get(&key))
+        {
+            return Some(*batch_converter);
+        }
+        let has_registered_converter = self
+            .converters
+            .get(from_attribute.name())
+            .map(|converters| converters.contains_key(&key))
+            .unwrap_or(false);
+        if has_registered_converter {
+            None
+        } else {
+            get_generic_batch_converter(key.0, key.1)
+        }
+    }
+
+    /// Returns a conversion function for converting from `from_attribute` into `to_attribute`. Both attributes must have
+    /// the same name but can have different datatypes. Conversion functions registered for the attribute take precedence
+    /// over the generic conversions. If both attributes have the same datatype, `Ok(None)` is returned
+    ///
+    /// # Errors
+    ///
+    /// If the names of the attributes differ or if no conversion from `from_attribute` into `to_attribute` is possible
+    pub fn get(
+        &self,
+        from_attribute: &PointAttributeDefinition,
+        to_attribute: &PointAttributeDefinition,
+    ) -> Result<Option<AttributeConversionFn>> {
+        if from_attribute.name() != to_attribute.name() {
+            bail!(
+                "Can't convert from attribute {} to attribute {} because they have different names",
+                from_attribute,
+                to_attribute
+            );
+        }
+        if from_attribute.datatype() == to_attribute.datatype() {
+            return Ok(None);
+        }
+
+        let key = (from_attribute.datatype(), to_attribute.datatype());
+        self.converters
+            .get(from_attribute.name())
+            .and_then(|converters| converters.get(&key))
+            .copied()
+            .or_else(|| get_generic_converter(key.0, key.1))
+            .map(Some)
+            .ok_or_else(|| {
+                anyhow!(
+                    "Invalid conversion {} -> {} for attribute {}",
+                    key.0,
+                    key.1,
+                    from_attribute.name()
+                )
+            })
+    }
+}
+
+impl Default for AttributeConverterRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+lazy_static! {
+    static ref ATTRIBUTE_CONVERTERS: RwLock<AttributeConverterRegistry> =
+        RwLock::new(AttributeConverterRegistry::new());
 }
 
-fn get_color_rgb_converter(
+/// Registers the `converter` function in the global [`AttributeConverterRegistry`], which is used by all conversions within
+/// pasture (e.g. [`get_converter_for_attributes`], [`AttributeConverter`] and [`RawPointConverter`]). See
+/// [`AttributeConverterRegistry::register`] for details
+pub fn register_attribute_converter<S: Into<Cow<'static, str>>>(
+    attribute_name: S,
     from_type: PointAttributeDataType,
     to_type: PointAttributeDataType,
+    converter: AttributeConversionFn,
 ) -> Option<AttributeConversionFn> {
-    lazy_static! {
-        static ref COLOR_RGB_CONVERTERS: HashMap<(PointAttributeDataType, PointAttributeDataType), AttributeConversionFn> = {
-            let mut converters = HashMap::<
-                (PointAttributeDataType, PointAttributeDataType),
-                AttributeConversionFn,
-            >::new();
-            converters.insert(
-                (
-                    PointAttributeDataType::Vec3u16,
-                    PointAttributeDataType::Vec3u8,
-                ),
-                convert_color_rgb_from_vec3u16_to_vec3u8,
-            );
-            converters.insert(
-                (
-                    PointAttributeDataType::Vec3u8,
-                    PointAttributeDataType::Vec3u16,
-                ),
-                convert_color_rgb_from_vec3u8_to_vec3u16,
-            );
-            converters
-        };
-    }
+    ATTRIBUTE_CONVERTERS
+        .write()
+        .unwrap()
+        .register(attribute_name, from_type, to_type, converter)
+}
 
-    let key = (from_type, to_type);
-    COLOR_RGB_CONVERTERS.get(&key).map(|&fptr| fptr)
+/// Returns a conversion function for converting from `from_attribute` into `to_attribute` from the global
+/// [`AttributeConverterRegistry`]. See [`AttributeConverterRegistry::get`] for details
+///
+/// # Errors
+///
+/// If the names of the attributes differ or if no conversion from `from_attribute` into `to_attribute` is possible
+pub fn try_get_converter_for_attributes(
+    from_attribute: &PointAttributeDefinition,
+    to_attribute: &PointAttributeDefinition,
+) -> Result<Option<AttributeConversionFn>> {
+    ATTRIBUTE_CONVERTERS
+        .read()
+        .unwrap()
+        .get(from_attribute, to_attribute)
+}
+
+/// Returns a conversion function for converting from `from_attribute` into `to_attribute`. Both attributes must have the
+/// same name but can have different datatypes. Conversion functions operate on raw byte buffers, where the first argument
+/// is a buffer that represents a single value of `from_attribute` and the second buffer is a single mutable value of
+/// `to_attribute`. If both attributes are equal, `None` is returned.
+///
+/// Conversion functions are looked up in the global [`AttributeConverterRegistry`], to which custom conversion functions can
+/// be added using [`register_attribute_converter`]. If no conversion function is registered, a generic conversion between
+/// primitive types is used. Smaller types are converted to larger types through `.into()` calls, larger types are converted
+/// to smaller types through coercions (using `as`). Vectors and matrices are converted component-wise, using the same rules
+/// as their scalar types. Nullable types can be converted from and to their value type. Use
+/// [`try_get_converter_for_attributes`] to handle invalid conversions without panicking
+///
+/// # Panics
+///
+/// If the names of the attributes differ or if no conversion from `from_attribute` into `to_attribute` is possible
+pub fn get_converter_for_attributes(
+    from_attribute: &PointAttributeDefinition,
+    to_attribute: &PointAttributeDefinition,
+) -> Option<AttributeConversionFn> {
+    try_get_converter_for_attributes(from_attribute, to_attribute)
+        .unwrap_or_else(|why| panic!("get_converter_for_attributes: {}", why))
 }
 
 macro_rules! insert_converter_using_into {
@@ -219,8 +830,127 @@ macro_rules! insert_converter_using_as {
     };
 }
 
-/// Returns a generic converter that can convert between primitive types. Going from smaller to larger types is realized
-/// through `.into()` calls, while going from larger to smaller types is done through coercions (using `as`) where possible
+/// Selects the instance of the generic conversion function `$convert` for the given number of `$components`
+macro_rules! select_by_components {
+    ($convert:ident, $fn_type:ty, $components:expr) => {
+        match $components {
+            1 => Some($convert::<1> as $fn_type),
+            2 => Some($convert::<2> as $fn_type),
+            3 => Some($convert::<3> as $fn_type),
+            4 => Some($convert::<4> as $fn_type),
+            5 => Some($convert::<5> as $fn_type),
+            6 => Some($convert::<6> as $fn_type),
+            7 => Some($convert::<7> as $fn_type),
+            8 => Some($convert::<8> as $fn_type),
+            9 => Some($convert::<9> as $fn_type),
+            10 => Some($convert::<10> as $fn_type),
+            11 => Some($convert::<11> as $fn_type),
+            12 => Some($convert::<12> as $fn_type),
+            13 => Some($convert::<13> as $fn_type),
+            14 => Some($convert::<14> as $fn_type),
+            15 => Some($convert::<15> as $fn_type),
+            16 => Some($convert::<16> as $fn_type),
+            _ => None,
+        }
+    };
+}
+
+/// Component-wise conversion of vectors and matrices with `N` components. This uses `as` to convert the components, the
+/// callers have to make sure that only valid conversions (as defined by `get_generic_converter`) are performed
+macro_rules! convert_components_using_as {
+    ($type_from:ident, $type_to:ident, $components:expr) => {{
+        unsafe fn convert<const N: usize>(from: &[u8], to: &mut [u8]) {
+            let from_ptr = from.as_ptr() as *const $type_from;
+            let to_ptr = to.as_mut_ptr() as *mut $type_to;
+            for component in 0..N {
+                let from_typed = from_ptr.add(component).read_unaligned();
+                to_ptr
+                    .add(component)
+                    .write_unaligned(from_typed as $type_to);
+            }
+        }
+
+        select_by_components!(convert, AttributeConversionFn, $components)
+    }};
+}
+
+/// Batch version of `convert_components_using_as`, which converts ranges of values with `N` components each
+macro_rules! batch_convert_components_using_as {
+    ($type_from:ident, $type_to:ident, $components:expr) => {{
+        unsafe fn convert<const N: usize>(
+            from: &[u8],
+            from_stride: usize,
+            to: &mut [u8],
+            to_stride: usize,
+            count: usize,
+        ) {
+            convert_strided(
+                from,
+                from_stride,
+                to,
+                to_stride,
+                count,
+                |value: [$type_from; N]| value.map(|component| component as $type_to),
+            );
+        }
+
+        select_by_components!(convert, AttributeBatchConversionFn, $components)
+    }};
+}
+
+/// Selects the component-wise conversion created by `$convert_macro` for all pairs of scalar types that pasture can convert
+/// between
+macro_rules! component_converters {
+    ($convert_macro:ident, $scalar_from:expr, $scalar_to:expr, $components:expr) => {
+        component_converters!(
+            @pairs $convert_macro,
+            $scalar_from,
+            $scalar_to,
+            $components,
+            (U8, u8, U16, u16),
+            (U8, u8, U32, u32),
+            (U8, u8, U64, u64),
+            (U16, u16, U32, u32),
+            (U16, u16, U64, u64),
+            (U32, u32, U64, u64),
+            (I8, i8, I16, i16),
+            (I8, i8, I32, i32),
+            (I8, i8, I64, i64),
+            (I16, i16, I32, i32),
+            (I16, i16, I64, i64),
+            (I32, i32, I64, i64),
+            (U16, u16, U8, u8),
+            (U32, u32, U8, u8),
+            (U64, u64, U8, u8),
+            (U32, u32, U16, u16),
+            (U64, u64, U16, u16),
+            (U64, u64, U32, u32),
+            (I16, i16, I8, i8),
+            (I32, i32, I8, i8),
+            (I64, i64, I8, i8),
+            (I32, i32, I16, i16),
+            (I64, i64, I16, i16),
+            (I64, i64, I32, i32),
+            (F32, f32, F64, f64),
+            (F64, f64, F32, f32)
+        )
+    };
+    (@pairs $convert_macro:ident, $scalar_from:expr, $scalar_to:expr, $components:expr, $(($variant_from:ident, $type_from:ident, $variant_to:ident, $type_to:ident)),*) => {
+        match ($scalar_from, $scalar_to) {
+            $(
+                (PointAttributeScalarType::$variant_from, PointAttributeScalarType::$variant_to) => {
+                    $convert_macro!($type_from, $type_to, $components)
+                }
+            )*
+            _ => None,
+        }
+    };
+}
+
+/// Returns a generic converter that can convert between primitive types, or `None` if no such converter exists. Going from
+/// smaller to larger types is realized through `.into()` calls, while going from larger to smaller types is done through
+/// coercions (using `as`) where possible. Vectors and matrices are converted component-wise, using the same rules as their
+/// scalar types. Nullable types can be converted from and to their value type
 fn get_generic_converter(
     from_type: PointAttributeDataType,
     to_type: PointAttributeDataType,
@@ -259,6 +989,7 @@ fn get_generic_converter(
             insert_converter_using_as!(I64, I16, convert_i64_to_i16, converters);
             insert_converter_using_as!(I64, I32, convert_i64_to_i32, converters);
 
+            insert_converter_using_into!(f32, f64, F32, F64, converters);
             insert_converter_using_as!(F64, F32, convert_f64_to_f32, converters);
 
             converters
@@ -266,10 +997,94 @@ fn get_generic_converter(
     }
 
     let key = (from_type, to_type);
-    let f = GENERIC_CONVERTERS
+    GENERIC_CONVERTERS
         .get(&key)
-        .unwrap_or_else(|| panic!("Invalid conversion {} -> {}", from_type, to_type));
-    Some(*f)
+        .copied()
+        .or_else(|| get_component_wise_converter(from_type, to_type))
+        .or_else(|| get_nullable_converter(from_type, to_type))
+}
+
+/// Returns a converter for vector or matrix types that have the same dimensions but different scalar types
+fn get_component_wise_converter(
+    from_type: PointAttributeDataType,
+    to_type: PointAttributeDataType,
+) -> Option<AttributeConversionFn> {
+    let components = match (from_type.normalized(), to_type.normalized()) {
+        (
+            PointAttributeDataType::Vector(_, from_components),
+            PointAttributeDataType::Vector(_, to_components),
+        ) if from_components == to_components => from_components as usize,
+        (
+            PointAttributeDataType::Matrix(_, from_rows, from_columns),
+            PointAttributeDataType::Matrix(_, to_rows, to_columns),
+        ) if from_rows == to_rows && from_columns == to_columns => {
+            from_rows as usize * from_columns as usize
+        }
+        _ => return None,
+    };
+
+    component_converters!(
+        convert_components_using_as,
+        from_type.scalar_type()?,
+        to_type.scalar_type()?,
+        components
+    )
+}
+
+/// Returns the number of components of `from_type` and `to_type` if they are scalars, vectors or matrices with the same
+/// dimensions
+fn matching_component_count(
+    from_type: PointAttributeDataType,
+    to_type: PointAttributeDataType,
+) -> Option<usize> {
+    match (from_type.normalized(), to_type.normalized()) {
+        (
+            PointAttributeDataType::Vector(_, from_components),
+            PointAttributeDataType::Vector(_, to_components),
+        ) if from_components == to_components => Some(from_components as usize),
+        (
+            PointAttributeDataType::Matrix(_, from_rows, from_columns),
+            PointAttributeDataType::Matrix(_, to_rows, to_columns),
+        ) if from_rows == to_rows && from_columns == to_columns => {
+            Some(from_rows as usize * from_columns as usize)
+        }
+        (from, to)
+            if from == from_type.scalar_type()?.into() && to == to_type.scalar_type()?.into() =>
+        {
+            Some(1)
+        }
+        _ => None,
+    }
+}
+
+/// Returns a batch converter between primitive types, which behaves like the converter returned from `get_generic_converter`
+/// for each value. Nullable types are not supported
+fn get_generic_batch_converter(
+    from_type: PointAttributeDataType,
+    to_type: PointAttributeDataType,
+) -> Option<AttributeBatchConversionFn> {
+    component_converters!(
+        batch_convert_components_using_as,
+        from_type.scalar_type()?,
+        to_type.scalar_type()?,
+        matching_component_count(from_type, to_type)?
+    )
+}
+
+/// Returns a converter from a nullable type to its value type or vice versa
+fn get_nullable_converter(
+    from_type: PointAttributeDataType,
+    to_type: PointAttributeDataType,
+) -> Option<AttributeConversionFn> {
+    match (from_type, to_type) {
+        (PointAttributeDataType::Nullable(value_type), _) if value_type.datatype() == to_type => {
+            Some(convert_from_nullable)
+        }
+        (_, PointAttributeDataType::Nullable(value_type)) if value_type.datatype() == from_type => {
+            Some(convert_to_nullable)
+        }
+        _ => None,
+    }
 }
 
 /// Unit conversion function (when from and to represent the same datatype)
@@ -399,6 +1214,73 @@ unsafe fn convert_color_rgb_from_vec3u8_to_vec3u16(from: &[u8], to: &mut [u8]) {
     to_vec.z = ((from_vec.z as u16) << 8) as u16;
 }
 
+/// Batch version of `convert_position_from_vec3f64_to_vec3f32`
+unsafe fn batch_convert_position_from_vec3f64_to_vec3f32(
+    from: &[u8],
+    from_stride: usize,
+    to: &mut [u8],
+    to_stride: usize,
+    count: usize,
+) {
+    convert_strided(
+        from,
+        from_stride,
+        to,
+        to_stride,
+        count,
+        |position: [f64; 3]| position.map(|component| component as f32),
+    );
+}
+
+/// Batch version of `convert_position_from_vec3f32_to_vec3f64`
+unsafe fn batch_convert_position_from_vec3f32_to_vec3f64(
+    from: &[u8],
+    from_stride: usize,
+    to: &mut [u8],
+    to_stride: usize,
+    count: usize,
+) {
+    convert_strided(
+        from,
+        from_stride,
+        to,
+        to_stride,
+        count,
+        |position: [f32; 3]| position.map(|component| component as f64),
+    );
+}
+
+/// Batch version of `convert_color_rgb_from_vec3u16_to_vec3u8`
+unsafe fn batch_convert_color_rgb_from_vec3u16_to_vec3u8(
+    from: &[u8],
+    from_stride: usize,
+    to: &mut [u8],
+    to_stride: usize,
+    count: usize,
+) {
+    convert_strided(
+        from,
+        from_stride,
+        to,
+        to_stride,
+        count,
+        |color: [u16; 3]| color.map(|component| (component >> 8) as u8),
+    );
+}
+
+/// Batch version of `convert_color_rgb_from_vec3u8_to_vec3u16`
+unsafe fn batch_convert_color_rgb_from_vec3u8_to_vec3u16(
+    from: &[u8],
+    from_stride: usize,
+    to: &mut [u8],
+    to_stride: usize,
+    count: usize,
+) {
+    convert_strided(from, from_stride, to, to_stride, count, |color: [u8; 3]| {
+        color.map(|component| (component as u16) << 8)
+    });
+}
+
 unsafe fn _convert_generic_vec3<F, T>(from: &[u8], to: &mut [u8])
 where
     F: Into<T> + Copy + Scalar,
@@ -412,6 +1294,26 @@ where
     to_typed.z = from_typed.z.into();
 }
 
+/// Conversion of a nullable value into its value type. Absent values are converted into zero. Since the validity tag is
+/// as wide as the alignment of the value type, the width of the tag is the difference in size between `from` and `to`
+unsafe fn convert_from_nullable(from: &[u8], to: &mut [u8]) {
+    let (tag, value) = from.split_at(from.len() - to.len());
+    if tag[0] != 0 {
+        to.copy_from_slice(value);
+    } else {
+        to.fill(0);
+    }
+}
+
+/// Conversion of a value into its nullable type. The value is always present after this conversion
+unsafe fn convert_to_nullable(from: &[u8], to: &mut [u8]) {
+    let (tag, value) = to.split_at_mut(to.len() - from.len());
+    // The first byte is the validity of the value, the remaining bytes are padding
+    tag.fill(0);
+    tag[0] = 1;
+    value.copy_from_slice(from);
+}
+
 unsafe fn convert_using_into<F, T>(from: &[u8], to: &mut [u8])
 where
     F: Into<T> + Copy,
@@ -445,3 +1347,494 @@ convert_using_as!(i64, i16, convert_i64_to_i16);
 convert_using_as!(i64, i32, convert_i64_to_i32);
 
 convert_using_as!(f64, f32, convert_f64_to_f32);
+
+/// Reads a single component of type `scalar_type` from `from` and converts it to `f64`
+unsafe fn read_component_as_f64(scalar_type: PointAttributeScalarType, from: &[u8]) -> f64 {
+    macro_rules! read_as_f64 {
+        ($type:ty) => {
+            (from.as_ptr() as *const $type).read_unaligned() as f64
+        };
+    }
+    match scalar_type {
+        PointAttributeScalarType::U8 => read_as_f64!(u8),
+        PointAttributeScalarType::I8 => read_as_f64!(i8),
+        PointAttributeScalarType::U16 => read_as_f64!(u16),
+        PointAttributeScalarType::I16 => read_as_f64!(i16),
+        PointAttributeScalarType::U32 => read_as_f64!(u32),
+        PointAttributeScalarType::I32 => read_as_f64!(i32),
+        PointAttributeScalarType::U64 => read_as_f64!(u64),
+        PointAttributeScalarType::I64 => read_as_f64!(i64),
+        PointAttributeScalarType::F32 => read_as_f64!(f32),
+        PointAttributeScalarType::F64 => read_as_f64!(f64),
+    }
+}
+
+/// Writes `value` as a single component of type `scalar_type` into `to`. Values that are out of range for integer types
+/// saturate to the minimum or maximum value of the type
+unsafe fn write_component_from_f64(
+    scalar_type: PointAttributeScalarType,
+    value: f64,
+    to: &mut [u8],
+) {
+    macro_rules! write_from_f64 {
+        ($type:ty) => {
+            (to.as_mut_ptr() as *mut $type).write_unaligned(value as $type)
+        };
+    }
+    match scalar_type {
+        PointAttributeScalarType::U8 => write_from_f64!(u8),
+        PointAttributeScalarType::I8 => write_from_f64!(i8),
+        PointAttributeScalarType::U16 => write_from_f64!(u16),
+        PointAttributeScalarType::I16 => write_from_f64!(i16),
+        PointAttributeScalarType::U32 => write_from_f64!(u32),
+        PointAttributeScalarType::I32 => write_from_f64!(i32),
+        PointAttributeScalarType::U64 => write_from_f64!(u64),
+        PointAttributeScalarType::I64 => write_from_f64!(i64),
+        PointAttributeScalarType::F32 => write_from_f64!(f32),
+        PointAttributeScalarType::F64 => write_from_f64!(f64),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::{attributes, PointType};
+    use crate::util::{view_raw_bytes, view_raw_bytes_mut};
+    use nalgebra::{Matrix3, Vector2, Vector4};
+    use pasture_derive::PointType;
+
+    #[derive(Debug, Default, PointType, Copy, Clone, PartialEq)]
+    #[repr(C)]
+    struct SourcePoint {
+        #[pasture(BUILTIN_INTENSITY)]
+        intensity: u16,
+        #[pasture(attribute = "TextureCoordinates")]
+        texture_coordinates: Vector2<f64>,
+        #[pasture(attribute = "ColorRGBNIR")]
+        color: Vector4<u16>,
+        #[pasture(attribute = "Covariance")]
+        covariance: Matrix3<f32>,
+    }
+
+    #[derive(Debug, Default, PointType, Copy, Clone, PartialEq)]
+    #[repr(C)]
+    struct TargetPoint {
+        #[pasture(attribute = "Covariance")]
+        covariance: Matrix3<f64>,
+        #[pasture(attribute = "TextureCoordinates")]
+        texture_coordinates: Vector2<f32>,
+        #[pasture(attribute = "ColorRGBNIR")]
+        color: Vector4<u8>,
+        #[pasture(BUILTIN_INTENSITY)]
+        intensity: u32,
+    }
+
+    #[test]
+    fn test_raw_point_converter_with_compound_types() {
+        let source = SourcePoint {
+            intensity: 42,
+            texture_coordinates: Vector2::new(0.25, 0.75),
+            color: Vector4::new(1, 2, 3, 4),
+            covariance: Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0),
+        };
+        let mut target = TargetPoint::default();
+
+        let converter = RawPointConverter::from_to(&SourcePoint::layout(), &TargetPoint::layout());
+        unsafe {
+            converter.convert(view_raw_bytes(&source), view_raw_bytes_mut(&mut target));
+        }
+
+        let expected = TargetPoint {
+            covariance: Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0),
+            texture_coordinates: Vector2::new(0.25, 0.75),
+            color: Vector4::new(1, 2, 3, 4),
+            intensity: 42,
+        };
+        assert_eq!(expected, target);
+    }
+
+    #[test]
+    fn test_raw_point_converter_with_bit_fields() {
+        let packed_layout = PointLayout::from_members_and_alignment(
+            &[
+                attributes::INTENSITY.at_offset_in_type(0),
+                attributes::RETURN_NUMBER.at_bit_offset_in_type(2, 0, 4),
+                attributes::NUMBER_OF_RETURNS.at_bit_offset_in_type(2, 4, 4),
+            ],
+            1,
+        );
+        let unpacked_layout = PointLayout::from_attributes(&[
+            attributes::INTENSITY,
+            attributes::RETURN_NUMBER.with_custom_datatype(PointAttributeDataType::U16),
+            attributes::NUMBER_OF_RETURNS,
+        ]);
+
+        let packed_point: [u8; 3] = [42, 0, 0x53];
+        let mut unpacked_point = vec![0; unpacked_layout.size_of_point_entry() as usize];
+        let converter = RawPointConverter::from_to(&packed_layout, &unpacked_layout);
+        unsafe {
+            converter.convert(&packed_point, &mut unpacked_point);
+        }
+        assert_eq!(vec![42, 0, 3, 0, 5, 0], unpacked_point);
+
+        let mut repacked_point = [0; 3];
+        let converter = RawPointConverter::from_to(&unpacked_layout, &packed_layout);
+        unsafe {
+            converter.convert(&unpacked_point, &mut repacked_point);
+        }
+        assert_eq!(packed_point, repacked_point);
+    }
+
+    #[test]
+    fn test_raw_point_converter_with_quantized_attributes() {
+        let quantized_layout = PointLayout::from_attributes(&[
+            attributes::POSITION_3D
+                .with_custom_datatype(PointAttributeDataType::vector(
+                    PointAttributeScalarType::I32,
+                    3,
+                ))
+                .with_quantization(PointAttributeQuantization::per_component(
+                    &[0.5, 0.5, 0.25],
+                    &[-10.0, 0.0, 10.0],
+                )),
+            attributes::INTENSITY
+                .with_custom_datatype(PointAttributeDataType::U8)
+                .with_quantization(PointAttributeQuantization::new(256.0, 0.0)),
+        ]);
+        let dequantized_layout =
+            PointLayout::from_attributes(&[attributes::POSITION_3D, attributes::INTENSITY]);
+
+        let mut quantized_point = vec![0; quantized_layout.size_of_point_entry() as usize];
+        quantized_point[0..12].copy_from_slice(unsafe { view_raw_bytes(&Vector3::new(2, 4, -8)) });
+        quantized_point[12] = 3;
+
+        let mut dequantized_point = vec![0; dequantized_layout.size_of_point_entry() as usize];
+        let converter = RawPointConverter::from_to(&quantized_layout, &dequantized_layout);
+        unsafe {
+            converter.convert(&quantized_point, &mut dequantized_point);
+        }
+        let position =
+            unsafe { (dequantized_point.as_ptr() as *const Vector3<f64>).read_unaligned() };
+        assert_eq!(Vector3::new(-9.0, 2.0, 8.0), position);
+        assert_eq!(
+            768,
+            u16::from_ne_bytes([dequantized_point[24], dequantized_point[25]])
+        );
+
+        let mut requantized_point = vec![0; quantized_layout.size_of_point_entry() as usize];
+        let converter = RawPointConverter::from_to(&dequantized_layout, &quantized_layout);
+        unsafe {
+            converter.convert(&dequantized_point, &mut requantized_point);
+        }
+        assert_eq!(quantized_point, requantized_point);
+    }
+
+    #[test]
+    fn test_attribute_converter_rounds_only_integer_quantized_values() {
+        let quantization = PointAttributeQuantization::new(0.5, 0.0);
+        let source = 1.8_f64.to_ne_bytes();
+
+        let quantized_f32 = attributes::GPS_TIME
+            .with_custom_datatype(PointAttributeDataType::F32)
+            .with_quantization(quantization.clone());
+        let converter = AttributeConverter::new(&attributes::GPS_TIME, &quantized_f32).unwrap();
+        let mut target = [0; 4];
+        unsafe {
+            converter.convert(&source, &mut target);
+        }
+        assert_eq!(3.6_f32, f32::from_ne_bytes(target));
+
+        let quantized_i32 = attributes::GPS_TIME
+            .with_custom_datatype(PointAttributeDataType::I32)
+            .with_quantization(quantization);
+        let converter = AttributeConverter::new(&attributes::GPS_TIME, &quantized_i32).unwrap();
+        let mut target = [0; 4];
+        unsafe {
+            converter.convert(&source, &mut target);
+        }
+        assert_eq!(4, i32::from_ne_bytes(target));
+    }
+
+    #[test]
+    fn test_raw_point_converter_from_layout_mappings() {
+        let layout_a = PointLayout::from_attributes(&[
+            attributes::INTENSITY,
+            attributes::POSITION_3D.with_custom_datatype(PointAttributeDataType::Vec3f32),
+        ]);
+        let layout_b = PointLayout::from_attributes(&[attributes::POSITION_3D]);
+        let (union, mapping_a, mapping_b) = layout_a.union(&layout_b);
+        let (renamed_union, rename_mapping) =
+            union.with_renamed_attribute(&attributes::INTENSITY, "Reflectance");
+
+        let mut point_a = vec![0; layout_a.size_of_point_entry() as usize];
+        point_a[0..2].copy_from_slice(&42_u16.to_ne_bytes());
+        point_a[4..16].copy_from_slice(unsafe { view_raw_bytes(&Vector3::new(1.0_f32, 2.0, 3.0)) });
+        let point_b = unsafe { view_raw_bytes(&Vector3::new(4.0_f64, 5.0, 6.0)) }.to_vec();
+
+        let mut union_point = vec![0; union.size_of_point_entry() as usize];
+        unsafe {
+            RawPointConverter::from_mapping(&mapping_a).convert(&point_a, &mut union_point);
+        }
+        let mut renamed_point = vec![0; renamed_union.size_of_point_entry() as usize];
+        unsafe {
+            RawPointConverter::from_mapping(&rename_mapping)
+                .convert(&union_point, &mut renamed_point);
+        }
+        assert_eq!(union_point, renamed_point);
+        assert_eq!(&point_a[..], &union_point[..]);
+
+        // The union uses the position datatype of layout_a, so the positions of layout_b are converted
+        unsafe {
+            RawPointConverter::from_mapping(&mapping_b).convert(&point_b, &mut union_point);
+        }
+        assert_eq!(42, u16::from_ne_bytes([union_point[0], union_point[1]]));
+        let position =
+            unsafe { (union_point[4..].as_ptr() as *const Vector3<f32>).read_unaligned() };
+        assert_eq!(Vector3::new(4.0, 5.0, 6.0), position);
+    }
+
+    #[test]
+    fn test_raw_point_converter_batch_matches_per_point_conversion() {
+        let source_points: [SourcePoint; 16] = std::array::from_fn(|index| {
+            let index = index as u16;
+            SourcePoint {
+                intensity: index * 1000,
+                texture_coordinates: Vector2::new(index as f64 * 0.5, -(index as f64)),
+                color: Vector4::new(index, index + 1, index + 2, 300),
+                covariance: Matrix3::from_element(index as f32 * 1.5),
+            }
+        });
+        let source_bytes = unsafe { view_raw_bytes(&source_points) };
+        let source_stride = SourcePoint::layout().size_of_point_entry() as usize;
+        let target_stride = TargetPoint::layout().size_of_point_entry() as usize;
+
+        let converter = RawPointConverter::from_to(&SourcePoint::layout(), &TargetPoint::layout());
+        let mut expected = [TargetPoint::default(); 16];
+        for (source, target) in source_points.iter().zip(expected.iter_mut()) {
+            unsafe {
+                converter.convert(view_raw_bytes(source), view_raw_bytes_mut(target));
+            }
+        }
+
+        let mut targets = [TargetPoint::default(); 16];
+        unsafe {
+            converter.convert_batch(
+                source_bytes,
+                source_stride,
+                view_raw_bytes_mut(&mut targets),
+                target_stride,
+                source_points.len(),
+            );
+        }
+        assert_eq!(expected, targets);
+
+        // Convert only every second point into a tightly packed target buffer
+        let mut targets = [TargetPoint::default(); 8];
+        unsafe {
+            converter.convert_batch(
+                source_bytes,
+                2 * source_stride,
+                view_raw_bytes_mut(&mut targets),
+                target_stride,
+                8,
+            );
+        }
+        let expected_every_second = expected.iter().step_by(2).copied().collect::<Vec<_>>();
+        assert_eq!(expected_every_second, targets.to_vec());
+    }
+
+    #[test]
+    fn test_attribute_converter_batch_with_bit_fields_and_quantization() {
+        let packed_layout = PointLayout::from_members_and_alignment(
+            &[
+                attributes::INTENSITY
+                    .with_custom_datatype(PointAttributeDataType::U8)
+                    .with_quantization(PointAttributeQuantization::new(256.0, 0.0))
+                    .at_offset_in_type(0),
+                attributes::RETURN_NUMBER.at_bit_offset_in_type(1, 0, 4),
+                attributes::NUMBER_OF_RETURNS.at_bit_offset_in_type(1, 4, 4),
+            ],
+            1,
+        );
+        let unpacked_layout = PointLayout::from_attributes(&[
+            attributes::INTENSITY,
+            attributes::RETURN_NUMBER,
+            attributes::NUMBER_OF_RETURNS,
+        ]);
+        let packed_points: Vec<u8> = vec![1, 0x21, 2, 0x32, 3, 0x43];
+
+        let mut unpacked_points = vec![0; 3 * unpacked_layout.size_of_point_entry() as usize];
+        let converter = RawPointConverter::from_to(&packed_layout, &unpacked_layout);
+        unsafe {
+            converter.convert_batch(
+                &packed_points,
+                2,
+                &mut unpacked_points,
+                unpacked_layout.size_of_point_entry() as usize,
+                3,
+            );
+        }
+        assert_eq!(vec![0, 1, 1, 2, 0, 2, 2, 3, 0, 3, 3, 4], unpacked_points);
+
+        let mut repacked_points = vec![0; packed_points.len()];
+        let converter = RawPointConverter::from_to(&unpacked_layout, &packed_layout);
+        unsafe {
+            converter.convert_batch(
+                &unpacked_points,
+                unpacked_layout.size_of_point_entry() as usize,
+                &mut repacked_points,
+                2,
+                3,
+            );
+        }
+        assert_eq!(packed_points, repacked_points);
+    }
+
+    #[test]
+    #[should_panic(expected = "too small")]
+    fn test_attribute_converter_batch_with_too_small_buffer() {
+        let converter = AttributeConverter::new(
+            &attributes::INTENSITY,
+            &attributes::INTENSITY.with_custom_datatype(PointAttributeDataType::U32),
+        )
+        .unwrap();
+        let mut target = [0; 8];
+        unsafe {
+            converter.convert_batch(&[0; 6], 2, &mut target, 4, 3);
+        }
+    }
+
+    unsafe fn convert_u8_to_u16_with_scaling(from: &[u8], to: &mut [u8]) {
+        to.copy_from_slice(&(from[0] as u16 * 257).to_ne_bytes());
+    }
+
+    #[test]
+    fn test_attribute_converter_registry() {
+        let reflectance =
+            PointAttributeDefinition::custom("Reflectance", PointAttributeDataType::U8);
+        let reflectance_u16 = reflectance.with_custom_datatype(PointAttributeDataType::U16);
+        let convert = |registry: &AttributeConverterRegistry| {
+            let converter = registry
+                .get(&reflectance, &reflectance_u16)
+                .unwrap()
+                .unwrap();
+            let mut target = [0; 2];
+            unsafe {
+                converter(&[255], &mut target);
+            }
+            u16::from_ne_bytes(target)
+        };
+
+        let mut registry = AttributeConverterRegistry::new();
+        assert_eq!(255, convert(&registry));
+        assert!(registry
+            .register(
+                "Reflectance",
+                PointAttributeDataType::U8,
+                PointAttributeDataType::U16,
+                convert_u8_to_u16_with_scaling,
+            )
+            .is_none());
+        assert_eq!(65535, convert(&registry));
+        // Registered converters only apply to attributes with the same name
+        assert!(AttributeConverterRegistry::new()
+            .get(&reflectance, &reflectance_u16)
+            .is_ok());
+
+        // Without a registered batch converter, the registered converter has to be used for each value
+        assert!(registry.get_batch(&reflectance, &reflectance_u16).is_none());
+        assert!(AttributeConverterRegistry::new()
+            .get_batch(&reflectance, &reflectance_u16)
+            .is_some());
+
+        assert!(registry.get(&reflectance, &reflectance).unwrap().is_none());
+        assert!(registry.get(&reflectance, &attributes::INTENSITY).is_err());
+        assert!(registry
+            .get(
+                &reflectance,
+                &reflectance.with_custom_datatype(PointAttributeDataType::Vec3u8)
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_register_global_attribute_converter() {
+        let layout_u8 = PointLayout::from_attributes(&[PointAttributeDefinition::custom(
+            "GlobalReflectance",
+            PointAttributeDataType::U8,
+        )]);
+        let (layout_u16, mapping) =
+            layout_u8.with_attribute_datatype(&layout_u8.at(0).into(), PointAttributeDataType::U16);
+        assert_eq!(2, layout_u16.size_of_point_entry());
+
+        register_attribute_converter(
+            "GlobalReflectance",
+            PointAttributeDataType::U8,
+            PointAttributeDataType::U16,
+            convert_u8_to_u16_with_scaling,
+        );
+        let mut target = [0; 2];
+        unsafe {
+            RawPointConverter::from_mapping(&mapping).convert(&[2], &mut target);
+        }
+        assert_eq!(514, u16::from_ne_bytes(target));
+    }
+
+    #[test]
+    fn test_position_converter_falls_back_to_generic_converter() {
+        let from = attributes::POSITION_3D;
+        let to = attributes::POSITION_3D.with_custom_datatype(PointAttributeDataType::Vector(
+            PointAttributeScalarType::F64,
+            3,
+        ));
+        assert!(get_converter_for_attributes(&from, &to).is_none());
+
+        let from = attributes::POSITION_3D.with_custom_datatype(PointAttributeDataType::Vector(
+            PointAttributeScalarType::I32,
+            3,
+        ));
+        let to = attributes::POSITION_3D.with_custom_datatype(PointAttributeDataType::Vector(
+            PointAttributeScalarType::I64,
+            3,
+        ));
+        assert!(get_converter_for_attributes(&from, &to).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid conversion")]
+    fn test_invalid_component_wise_conversion() {
+        let from = PointAttributeDefinition::custom(
+            "Covariance",
+            PointAttributeDataType::Matrix(PointAttributeScalarType::F64, 3, 3),
+        );
+        let to = PointAttributeDefinition::custom(
+            "Covariance",
+            PointAttributeDataType::Vector(PointAttributeScalarType::F32, 9),
+        );
+        get_converter_for_attributes(&from, &to);
+    }
+
+    #[test]
+    fn test_try_new_converters_with_invalid_conversions() {
+        let from = attributes::POSITION_3D;
+        let to = attributes::POSITION_3D.with_custom_datatype(PointAttributeDataType::U8);
+        assert!(AttributeConverter::try_new(&from, &to).is_err());
+        assert!(AttributeConverter::try_new(&from, &attributes::INTENSITY).is_err());
+        assert!(AttributeConverter::try_new(&from, &from).unwrap().is_none());
+
+        let from_layout = PointLayout::from_attributes(&[attributes::INTENSITY, from]);
+        let to_layout = PointLayout::from_attributes(&[attributes::INTENSITY, to]);
+        assert!(RawPointConverter::try_from_to(&from_layout, &to_layout).is_err());
+        assert!(RawPointConverter::try_from_to(&from_layout, &from_layout).is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid conversion")]
+    fn test_raw_point_converter_with_invalid_conversion() {
+        let from_layout = PointLayout::from_attributes(&[attributes::POSITION_3D]);
+        let to_layout = PointLayout::from_attributes(&[
+            attributes::POSITION_3D.with_custom_datatype(PointAttributeDataType::U8)
+        ]);
+        RawPointConverter::from_to(&from_layout, &to_layout);
+    }
+}
@@ -1,7 +1,16 @@
-use std::{alloc::Layout, fmt::Display};
-
+use std::{
+    alloc::Layout,
+    borrow::Cow,
+    convert::TryFrom,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    ops::Range,
+};
+
+use anyhow::{anyhow, Result};
 use itertools::Itertools;
-use nalgebra::{Vector3, Vector4};
+use nalgebra::{Matrix2, Matrix3, Matrix4, Vector2, Vector3, Vector4, Vector5, Vector6};
+use serde::{Deserialize, Serialize};
 use static_assertions::const_assert;
 
 use crate::math::Alignable;
@@ -22,15 +31,101 @@ mod private {
     impl Sealed for f32 {}
     impl Sealed for f64 {}
     impl Sealed for bool {}
-    impl Sealed for Vector3<u8> {}
-    impl Sealed for Vector3<u16> {}
-    impl Sealed for Vector3<f32> {}
-    impl Sealed for Vector3<f64> {}
-    impl Sealed for Vector4<u8> {}
+    impl<T: PrimitiveScalar> Sealed for Vector2<T> {}
+    impl<T: PrimitiveScalar> Sealed for Vector3<T> {}
+    impl<T: PrimitiveScalar> Sealed for Vector4<T> {}
+    impl<T: PrimitiveScalar> Sealed for Vector5<T> {}
+    impl<T: PrimitiveScalar> Sealed for Vector6<T> {}
+    impl<T: PrimitiveScalar> Sealed for Matrix2<T> {}
+    impl<T: PrimitiveScalar> Sealed for Matrix3<T> {}
+    impl<T: PrimitiveScalar> Sealed for Matrix4<T> {}
+    impl<const N: usize> Sealed for [u8; N] {}
+    impl<T: NullableValue> Sealed for Nullable<T> {}
+}
+
+/// Possible scalar types for the components of vector and matrix attributes (see [`PointAttributeDataType::Vector`] and
+/// [`PointAttributeDataType::Matrix`])
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PointAttributeScalarType {
+    /// An unsigned 8-bit integer value, corresponding to Rusts `u8` type
+    U8,
+    /// A signed 8-bit integer value, corresponding to Rusts `i8` type
+    I8,
+    /// An unsigned 16-bit integer value, corresponding to Rusts `u16` type
+    U16,
+    /// A signed 16-bit integer value, corresponding to Rusts `i16` type
+    I16,
+    /// An unsigned 32-bit integer value, corresponding to Rusts `u32` type
+    U32,
+    /// A signed 32-bit integer value, corresponding to Rusts `i32` type
+    I32,
+    /// An unsigned 64-bit integer value, corresponding to Rusts `u64` type
+    U64,
+    /// A signed 64-bit integer value, corresponding to Rusts `i64` type
+    I64,
+    /// A single-precision floating point value, corresponding to Rusts `f32` type
+    F32,
+    /// A double-precision floating point value, corresponding to Rusts `f64` type
+    F64,
+}
+
+impl PointAttributeScalarType {
+    /// Size of the associated `PointAttributeScalarType`
+    pub fn size(&self) -> u64 {
+        PointAttributeDataType::from(*self).size()
+    }
+
+    /// Minimum required alignment of the associated `PointAttributeScalarType`
+    pub fn min_alignment(&self) -> u64 {
+        PointAttributeDataType::from(*self).min_alignment()
+    }
+}
+
+impl Display for PointAttributeScalarType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PointAttributeScalarType::U8 => write!(f, "u8"),
+            PointAttributeScalarType::I8 => write!(f, "i8"),
+            PointAttributeScalarType::U16 => write!(f, "u16"),
+            PointAttributeScalarType::I16 => write!(f, "i16"),
+            PointAttributeScalarType::U32 => write!(f, "u32"),
+            PointAttributeScalarType::I32 => write!(f, "i32"),
+            PointAttributeScalarType::U64 => write!(f, "u64"),
+            PointAttributeScalarType::I64 => write!(f, "i64"),
+            PointAttributeScalarType::F32 => write!(f, "f32"),
+            PointAttributeScalarType::F64 => write!(f, "f64"),
+        }
+    }
+}
+
+impl From<PointAttributeScalarType> for PointAttributeDataType {
+    fn from(scalar_type: PointAttributeScalarType) -> Self {
+        match scalar_type {
+            PointAttributeScalarType::U8 => PointAttributeDataType::U8,
+            PointAttributeScalarType::I8 => PointAttributeDataType::I8,
+            PointAttributeScalarType::U16 => PointAttributeDataType::U16,
+            PointAttributeScalarType::I16 => PointAttributeDataType::I16,
+            PointAttributeScalarType::U32 => PointAttributeDataType::U32,
+            PointAttributeScalarType::I32 => PointAttributeDataType::I32,
+            PointAttributeScalarType::U64 => PointAttributeDataType::U64,
+            PointAttributeScalarType::I64 => PointAttributeDataType::I64,
+            PointAttributeScalarType::F32 => PointAttributeDataType::F32,
+            PointAttributeScalarType::F64 => PointAttributeDataType::F64,
+        }
+    }
 }
 
 /// Possible data types for individual point attributes
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+///
+/// Besides the scalar types, there are vectors (`Vector`) and matrices (`Matrix`) of arbitrary dimensions over any
+/// of the [scalar types](PointAttributeScalarType), as well as fixed-size arrays of raw bytes (`ByteArray`). For historical
+/// reasons, some vector types also have dedicated variants (e.g. `Vec3f64`). These compare equal to their generic
+/// counterparts, so `PointAttributeDataType::Vec3f64 == PointAttributeDataType::Vector(PointAttributeScalarType::F64, 3)`.
+/// Use [`PointAttributeDataType::vector`] to obtain the preferred representation of a vector type.
+///
+/// Any of these types except `Bool` can be made nullable (see [`PointAttributeDataType::nullable`]), which corresponds
+/// to the [`Nullable<T>`] type.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
 pub enum PointAttributeDataType {
     /// An unsigned 8-bit integer value, corresponding to Rusts `u8` type
     U8,
@@ -64,12 +159,153 @@ pub enum PointAttributeDataType {
     Vec3f64,
     /// A 4-component vector storing unsigned 8-bit integer values. Corresponding to the `Vector4<u8>` type of the [nalgebra crate](https://crates.io/crates/nalgebra)
     Vec4u8,
+    /// A vector with the given number of components of a scalar type. `Vector(PointAttributeScalarType::F32, 2)` corresponds
+    /// to the `Vector2<f32>` type of the [nalgebra crate](https://crates.io/crates/nalgebra)
+    Vector(PointAttributeScalarType, u8),
+    /// A matrix with the given number of rows and columns of a scalar type, stored in column-major order.
+    /// `Matrix(PointAttributeScalarType::F64, 3, 3)` corresponds to the `Matrix3<f64>` type of the [nalgebra crate](https://crates.io/crates/nalgebra)
+    Matrix(PointAttributeScalarType, u8, u8),
+    /// A fixed-size array of raw bytes with the given length, corresponding to Rusts `[u8; N]` type
+    ByteArray(u32),
+    /// A value of the given type that may be absent, corresponding to the [`Nullable<T>`] type. Use
+    /// [`PointAttributeDataType::nullable`] to create this type.
+    ///
+    /// A nullable value starts with a single validity byte, which is `1` if the value is present and `0` if it is absent.
+    /// It is followed by zero bytes up to the alignment of the value type, and then by the value itself. Absent values
+    /// are stored as zero bytes. [`Nullable<T>`] has exactly this memory layout, so it can be used in `PointType`s and with
+    /// the typed accessors of all point buffers.
+    Nullable(NullableValueType),
     //TODO REFACTOR Vector types should probably be Point3 instead, or at least use nalgebra::Point3 as their underlying type!
-    //TODO Instead of representing each VecN<T> type as a separate literal, might it be possible to do: Vec3(PointAttributeDataType)?
-    //Not in that way of course, because of recursive datastructures, but something like that?
 }
 
 impl PointAttributeDataType {
+    /// Returns the `PointAttributeDataType` for a vector with `components` entries of type `scalar_type`. For vector types
+    /// that have a dedicated variant (such as `Vec3f64`), this variant is returned
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// let texture_coordinates = PointAttributeDataType::vector(PointAttributeScalarType::F32, 2);
+    /// # assert_eq!(texture_coordinates, PointAttributeDataType::Vector(PointAttributeScalarType::F32, 2));
+    /// assert_eq!(8, texture_coordinates.size());
+    /// let position = PointAttributeDataType::vector(PointAttributeScalarType::F64, 3);
+    /// assert!(matches!(position, PointAttributeDataType::Vec3f64));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `components` is zero
+    pub fn vector(scalar_type: PointAttributeScalarType, components: u8) -> Self {
+        if components == 0 {
+            panic!("PointAttributeDataType::vector: Vectors must have at least one component");
+        }
+        match (scalar_type, components) {
+            (PointAttributeScalarType::U8, 3) => PointAttributeDataType::Vec3u8,
+            (PointAttributeScalarType::U16, 3) => PointAttributeDataType::Vec3u16,
+            (PointAttributeScalarType::F32, 3) => PointAttributeDataType::Vec3f32,
+            (PointAttributeScalarType::F64, 3) => PointAttributeDataType::Vec3f64,
+            (PointAttributeScalarType::U8, 4) => PointAttributeDataType::Vec4u8,
+            _ => PointAttributeDataType::Vector(scalar_type, components),
+        }
+    }
+
+    /// Returns the `PointAttributeDataType` for a matrix with `rows` rows and `columns` columns of type `scalar_type`
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// let transform = PointAttributeDataType::matrix(PointAttributeScalarType::F64, 4, 4);
+    /// assert_eq!(transform, <nalgebra::Matrix4<f64> as PrimitiveType>::data_type());
+    /// assert_eq!(128, transform.size());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `rows` or `columns` is zero
+    pub fn matrix(scalar_type: PointAttributeScalarType, rows: u8, columns: u8) -> Self {
+        if rows == 0 || columns == 0 {
+            panic!("PointAttributeDataType::matrix: Matrices must have at least one row and one column");
+        }
+        PointAttributeDataType::Matrix(scalar_type, rows, columns)
+    }
+
+    /// Returns the `PointAttributeDataType` for an array of `length` raw bytes
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// let raw = PointAttributeDataType::byte_array(16);
+    /// assert_eq!(raw, <[u8; 16] as PrimitiveType>::data_type());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `length` is zero
+    pub fn byte_array(length: u32) -> Self {
+        if length == 0 {
+            panic!("PointAttributeDataType::byte_array: Byte arrays must not be empty");
+        }
+        PointAttributeDataType::ByteArray(length)
+    }
+
+    /// Returns the nullable version of the given `datatype`, i.e. the `PointAttributeDataType` corresponding to
+    /// `Nullable<T>` for the type `T` that `datatype` describes
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// let gps_time = PointAttributeDataType::nullable(PointAttributeDataType::F64);
+    /// assert_eq!(gps_time, <Nullable<f64> as PrimitiveType>::data_type());
+    /// assert_eq!(16, gps_time.size());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `datatype` is `Bool`, is already nullable or has a size of zero
+    pub fn nullable(datatype: PointAttributeDataType) -> Self {
+        if let Err(why) = datatype.check_size() {
+            panic!("PointAttributeDataType::nullable: {}", why);
+        }
+        match datatype {
+            PointAttributeDataType::Bool => {
+                panic!("PointAttributeDataType::nullable: Bool can't be made nullable")
+            }
+            PointAttributeDataType::Nullable(_) => {
+                panic!(
+                    "PointAttributeDataType::nullable: {} is already nullable",
+                    datatype
+                )
+            }
+            _ => PointAttributeDataType::Nullable(
+                NullableValueType::try_from(datatype)
+                    .expect("All other datatypes are valid value types of nullable types"),
+            ),
+        }
+    }
+
+    /// Returns `true` if the associated `PointAttributeDataType` is nullable
+    pub fn is_nullable(&self) -> bool {
+        matches!(self, PointAttributeDataType::Nullable(_))
+    }
+
+    /// Returns the type of the scalar components of the associated `PointAttributeDataType`. For scalar types, this is the
+    /// type itself, for vectors and matrices it is the type of their components. `Bool`, `ByteArray` and nullable types have
+    /// no scalar type
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// assert_eq!(Some(PointAttributeScalarType::U16), PointAttributeDataType::Vec3u16.scalar_type());
+    /// assert_eq!(None, PointAttributeDataType::ByteArray(16).scalar_type());
+    /// ```
+    pub fn scalar_type(&self) -> Option<PointAttributeScalarType> {
+        match self.normalized() {
+            PointAttributeDataType::U8 => Some(PointAttributeScalarType::U8),
+            PointAttributeDataType::I8 => Some(PointAttributeScalarType::I8),
+            PointAttributeDataType::U16 => Some(PointAttributeScalarType::U16),
+            PointAttributeDataType::I16 => Some(PointAttributeScalarType::I16),
+            PointAttributeDataType::U32 => Some(PointAttributeScalarType::U32),
+            PointAttributeDataType::I32 => Some(PointAttributeScalarType::I32),
+            PointAttributeDataType::U64 => Some(PointAttributeScalarType::U64),
+            PointAttributeDataType::I64 => Some(PointAttributeScalarType::I64),
+            PointAttributeDataType::F32 => Some(PointAttributeScalarType::F32),
+            PointAttributeDataType::F64 => Some(PointAttributeScalarType::F64),
+            PointAttributeDataType::Vector(scalar_type, _) => Some(scalar_type),
+            PointAttributeDataType::Matrix(scalar_type, _, _) => Some(scalar_type),
+            _ => None,
+        }
+    }
+
     /// Size of the associated `PointAttributeDataType`
     pub fn size(&self) -> u64 {
         match self {
@@ -89,6 +325,30 @@ impl PointAttributeDataType {
             PointAttributeDataType::Vec3f32 => 12,
             PointAttributeDataType::Vec3f64 => 24,
             PointAttributeDataType::Vec4u8 => 4,
+            PointAttributeDataType::Vector(scalar_type, components) => {
+                scalar_type.size() * *components as u64
+            }
+            PointAttributeDataType::Matrix(scalar_type, rows, columns) => {
+                scalar_type.size() * *rows as u64 * *columns as u64
+            }
+            PointAttributeDataType::ByteArray(length) => *length as u64,
+            // All value types have a size that is a multiple of their alignment, so this is equal to `size_of::<Nullable<T>>()`
+            PointAttributeDataType::Nullable(value_type) => {
+                let value_type = value_type.datatype();
+                value_type.min_alignment() + value_type.size()
+            }
+        }
+    }
+
+    /// Checks that the associated `PointAttributeDataType` has a size greater than zero. Vectors and matrices without
+    /// components and empty byte arrays can be expressed through the enum variants, but are not valid as attributes
+    fn check_size(&self) -> Result<(), String> {
+        match self {
+            PointAttributeDataType::Nullable(value_type) => value_type.datatype().check_size(),
+            datatype if datatype.size() == 0 => {
+                Err(format!("Datatype {} has a size of zero", datatype))
+            }
+            _ => Ok(()),
         }
     }
 
@@ -111,9 +371,90 @@ impl PointAttributeDataType {
             PointAttributeDataType::Vec3f32 => std::mem::align_of::<Vector3<f32>>(),
             PointAttributeDataType::Vec3f64 => std::mem::align_of::<Vector3<f64>>(),
             PointAttributeDataType::Vec4u8 => std::mem::align_of::<Vector4<u8>>(),
+            // nalgebra stores vectors and matrices as plain arrays of their scalar type
+            PointAttributeDataType::Vector(scalar_type, _) => {
+                return scalar_type.min_alignment();
+            }
+            PointAttributeDataType::Matrix(scalar_type, _, _) => {
+                return scalar_type.min_alignment();
+            }
+            PointAttributeDataType::ByteArray(_) => std::mem::align_of::<u8>(),
+            PointAttributeDataType::Nullable(value_type) => {
+                return value_type.datatype().min_alignment()
+            }
         };
         align as u64
     }
+
+    /// Returns the generic representation of the dedicated vector variants, i.e. `Vector(PointAttributeScalarType::F64, 3)`
+    /// for `Vec3f64`. All other types are returned unchanged
+    pub(crate) fn normalized(&self) -> Self {
+        match self {
+            PointAttributeDataType::Vec3u8 => {
+                PointAttributeDataType::Vector(PointAttributeScalarType::U8, 3)
+            }
+            PointAttributeDataType::Vec3u16 => {
+                PointAttributeDataType::Vector(PointAttributeScalarType::U16, 3)
+            }
+            PointAttributeDataType::Vec3f32 => {
+                PointAttributeDataType::Vector(PointAttributeScalarType::F32, 3)
+            }
+            PointAttributeDataType::Vec3f64 => {
+                PointAttributeDataType::Vector(PointAttributeScalarType::F64, 3)
+            }
+            PointAttributeDataType::Vec4u8 => {
+                PointAttributeDataType::Vector(PointAttributeScalarType::U8, 4)
+            }
+            other => *other,
+        }
+    }
+}
+
+impl PartialEq for PointAttributeDataType {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.normalized(), other.normalized()) {
+            (
+                PointAttributeDataType::Vector(scalar_a, components_a),
+                PointAttributeDataType::Vector(scalar_b, components_b),
+            ) => scalar_a == scalar_b && components_a == components_b,
+            (
+                PointAttributeDataType::Matrix(scalar_a, rows_a, columns_a),
+                PointAttributeDataType::Matrix(scalar_b, rows_b, columns_b),
+            ) => scalar_a == scalar_b && rows_a == rows_b && columns_a == columns_b,
+            (
+                PointAttributeDataType::ByteArray(length_a),
+                PointAttributeDataType::ByteArray(length_b),
+            ) => length_a == length_b,
+            (
+                PointAttributeDataType::Nullable(datatype_a),
+                PointAttributeDataType::Nullable(datatype_b),
+            ) => datatype_a == datatype_b,
+            (a, b) => std::mem::discriminant(&a) == std::mem::discriminant(&b),
+        }
+    }
+}
+
+impl Eq for PointAttributeDataType {}
+
+impl Hash for PointAttributeDataType {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let normalized = self.normalized();
+        std::mem::discriminant(&normalized).hash(state);
+        match normalized {
+            PointAttributeDataType::Vector(scalar_type, components) => {
+                scalar_type.hash(state);
+                components.hash(state);
+            }
+            PointAttributeDataType::Matrix(scalar_type, rows, columns) => {
+                scalar_type.hash(state);
+                rows.hash(state);
+                columns.hash(state);
+            }
+            PointAttributeDataType::ByteArray(length) => length.hash(state),
+            PointAttributeDataType::Nullable(value_type) => value_type.hash(state),
+            _ => {}
+        }
+    }
 }
 
 impl Display for PointAttributeDataType {
@@ -134,97 +475,305 @@ impl Display for PointAttributeDataType {
             PointAttributeDataType::Vec3u16 => write!(f, "Vec3<u16>"),
             PointAttributeDataType::Vec3f32 => write!(f, "Vec3<f32>"),
             PointAttributeDataType::Vec3f64 => write!(f, "Vec3<f64>"),
-            &PointAttributeDataType::Vec4u8 => write!(f, "Vec4<u8>"),
+            PointAttributeDataType::Vec4u8 => write!(f, "Vec4<u8>"),
+            PointAttributeDataType::Vector(scalar_type, components) => {
+                write!(f, "Vec{}<{}>", components, scalar_type)
+            }
+            PointAttributeDataType::Matrix(scalar_type, rows, columns) => {
+                write!(f, "Mat{}x{}<{}>", rows, columns, scalar_type)
+            }
+            PointAttributeDataType::ByteArray(length) => write!(f, "[u8;{}]", length),
+            PointAttributeDataType::Nullable(value_type) => {
+                write!(f, "Nullable<{}>", value_type.datatype())
+            }
+        }
+    }
+}
+
+/// The type of the value of a nullable attribute (see [`PointAttributeDataType::Nullable`]). Any `PointAttributeDataType`
+/// except `Bool` and nullable types themselves can be the value type of a nullable attribute. Use
+/// [`datatype`](Self::datatype) to get the corresponding `PointAttributeDataType`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "PointAttributeDataType", try_from = "PointAttributeDataType")]
+pub enum NullableValueType {
+    /// A scalar value of the given type
+    Scalar(PointAttributeScalarType),
+    /// A vector with the given number of components (see [`PointAttributeDataType::Vector`])
+    Vector(PointAttributeScalarType, u8),
+    /// A matrix with the given number of rows and columns (see [`PointAttributeDataType::Matrix`])
+    Matrix(PointAttributeScalarType, u8, u8),
+    /// A fixed-size array of raw bytes with the given length (see [`PointAttributeDataType::ByteArray`])
+    ByteArray(u32),
+}
+
+impl NullableValueType {
+    /// Returns the `PointAttributeDataType` of the associated value type
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// let gps_time = PointAttributeDataType::nullable(PointAttributeDataType::F64);
+    /// match gps_time {
+    ///     PointAttributeDataType::Nullable(value_type) => assert_eq!(PointAttributeDataType::F64, value_type.datatype()),
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn datatype(&self) -> PointAttributeDataType {
+        match *self {
+            NullableValueType::Scalar(scalar_type) => scalar_type.into(),
+            NullableValueType::Vector(scalar_type, components) => {
+                PointAttributeDataType::vector(scalar_type, components)
+            }
+            NullableValueType::Matrix(scalar_type, rows, columns) => {
+                PointAttributeDataType::Matrix(scalar_type, rows, columns)
+            }
+            NullableValueType::ByteArray(length) => PointAttributeDataType::ByteArray(length),
+        }
+    }
+}
+
+impl TryFrom<PointAttributeDataType> for NullableValueType {
+    type Error = anyhow::Error;
+
+    fn try_from(datatype: PointAttributeDataType) -> Result<Self> {
+        match datatype.normalized() {
+            PointAttributeDataType::Bool => Err(anyhow!("Bool can't be made nullable")),
+            PointAttributeDataType::Nullable(_) => Err(anyhow!("{} is already nullable", datatype)),
+            PointAttributeDataType::Vector(scalar_type, components) => {
+                Ok(NullableValueType::Vector(scalar_type, components))
+            }
+            PointAttributeDataType::Matrix(scalar_type, rows, columns) => {
+                Ok(NullableValueType::Matrix(scalar_type, rows, columns))
+            }
+            PointAttributeDataType::ByteArray(length) => Ok(NullableValueType::ByteArray(length)),
+            scalar => Ok(NullableValueType::Scalar(
+                scalar
+                    .scalar_type()
+                    .expect("All remaining datatypes are scalar types"),
+            )),
         }
     }
 }
 
+impl From<NullableValueType> for PointAttributeDataType {
+    fn from(value_type: NullableValueType) -> Self {
+        value_type.datatype()
+    }
+}
+
 /// Marker trait for all types that can be used as primitive types within a `PointAttributeDefinition`. It provides a mapping
 /// between Rust types and the `PointAttributeDataType` enum.
-pub trait PrimitiveType: Copy + private::Sealed {
+pub trait PrimitiveType: Copy + Send + Sync + private::Sealed {
     /// Returns the corresponding `PointAttributeDataType` for the implementing type
     fn data_type() -> PointAttributeDataType;
 }
 
-impl PrimitiveType for u8 {
-    fn data_type() -> PointAttributeDataType {
-        PointAttributeDataType::U8
-    }
+/// Marker trait for all primitive types that can be used as the components of vector and matrix types. It provides a
+/// mapping between Rust types and the `PointAttributeScalarType` enum.
+pub trait PrimitiveScalar: PrimitiveType + NullableValue + nalgebra::Scalar {
+    /// Returns the corresponding `PointAttributeScalarType` for the implementing type
+    fn scalar_type() -> PointAttributeScalarType;
 }
-impl PrimitiveType for u16 {
-    fn data_type() -> PointAttributeDataType {
-        PointAttributeDataType::U16
-    }
+
+macro_rules! impl_primitive_scalar {
+    ($type:ty, $variant:ident) => {
+        impl PrimitiveType for $type {
+            fn data_type() -> PointAttributeDataType {
+                PointAttributeDataType::$variant
+            }
+        }
+
+        impl NullableValue for $type {
+            type Padding = [u8; std::mem::align_of::<$type>() - 1];
+        }
+
+        impl PrimitiveScalar for $type {
+            fn scalar_type() -> PointAttributeScalarType {
+                PointAttributeScalarType::$variant
+            }
+        }
+    };
 }
-impl PrimitiveType for u32 {
+
+impl_primitive_scalar!(u8, U8);
+impl_primitive_scalar!(u16, U16);
+impl_primitive_scalar!(u32, U32);
+impl_primitive_scalar!(u64, U64);
+impl_primitive_scalar!(i8, I8);
+impl_primitive_scalar!(i16, I16);
+impl_primitive_scalar!(i32, I32);
+impl_primitive_scalar!(i64, I64);
+impl_primitive_scalar!(f32, F32);
+impl_primitive_scalar!(f64, F64);
+
+impl PrimitiveType for bool {
     fn data_type() -> PointAttributeDataType {
-        PointAttributeDataType::U32
+        PointAttributeDataType::Bool
     }
 }
-impl PrimitiveType for u64 {
-    fn data_type() -> PointAttributeDataType {
-        PointAttributeDataType::U64
-    }
+
+macro_rules! impl_primitive_vector {
+    ($type:ident, $components:expr) => {
+        impl<T: PrimitiveScalar> PrimitiveType for $type<T> {
+            fn data_type() -> PointAttributeDataType {
+                PointAttributeDataType::vector(T::scalar_type(), $components)
+            }
+        }
+    };
 }
-impl PrimitiveType for i8 {
-    fn data_type() -> PointAttributeDataType {
-        PointAttributeDataType::I8
-    }
+
+impl_primitive_vector!(Vector2, 2);
+impl_primitive_vector!(Vector3, 3);
+impl_primitive_vector!(Vector4, 4);
+impl_primitive_vector!(Vector5, 5);
+impl_primitive_vector!(Vector6, 6);
+
+macro_rules! impl_primitive_matrix {
+    ($type:ident, $rows:expr, $columns:expr) => {
+        impl<T: PrimitiveScalar> PrimitiveType for $type<T> {
+            fn data_type() -> PointAttributeDataType {
+                PointAttributeDataType::matrix(T::scalar_type(), $rows, $columns)
+            }
+        }
+    };
 }
-impl PrimitiveType for i16 {
+
+impl_primitive_matrix!(Matrix2, 2, 2);
+impl_primitive_matrix!(Matrix3, 3, 3);
+impl_primitive_matrix!(Matrix4, 4, 4);
+
+/// # Panics
+///
+/// `data_type` panics for the empty array `[u8; 0]`
+impl<const N: usize> PrimitiveType for [u8; N] {
     fn data_type() -> PointAttributeDataType {
-        PointAttributeDataType::I16
+        PointAttributeDataType::byte_array(N as u32)
     }
 }
-impl PrimitiveType for i32 {
-    fn data_type() -> PointAttributeDataType {
-        PointAttributeDataType::I32
-    }
+
+/// Marker trait for all primitive types that can be the value of a [`Nullable<T>`], which are all primitive types except
+/// `bool` and `Nullable<T>` itself
+pub trait NullableValue: PrimitiveType {
+    /// Padding between the validity byte and the value of a `Nullable<Self>`, so that the value is aligned
+    #[doc(hidden)]
+    type Padding: Copy + Default + Send + Sync;
 }
-impl PrimitiveType for i64 {
-    fn data_type() -> PointAttributeDataType {
-        PointAttributeDataType::I64
-    }
+
+macro_rules! impl_nullable_value {
+    ($type:ident) => {
+        impl<T: PrimitiveScalar> NullableValue for $type<T> {
+            type Padding = T::Padding;
+        }
+    };
 }
-impl PrimitiveType for f32 {
-    fn data_type() -> PointAttributeDataType {
-        PointAttributeDataType::F32
-    }
+
+impl_nullable_value!(Vector2);
+impl_nullable_value!(Vector3);
+impl_nullable_value!(Vector4);
+impl_nullable_value!(Vector5);
+impl_nullable_value!(Vector6);
+impl_nullable_value!(Matrix2);
+impl_nullable_value!(Matrix3);
+impl_nullable_value!(Matrix4);
+
+impl<const N: usize> NullableValue for [u8; N] {
+    type Padding = [u8; 0];
 }
-impl PrimitiveType for f64 {
-    fn data_type() -> PointAttributeDataType {
-        PointAttributeDataType::F64
+
+/// A value of type `T` that may be absent. In contrast to `Option<T>`, whose memory layout is unspecified, `Nullable<T>`
+/// has the well-defined memory layout of [`PointAttributeDataType::Nullable`]: A validity byte that is `1` if the value is
+/// present and `0` otherwise, zero bytes up to the alignment of `T`, and the value itself, which is zero if absent. This
+/// makes it usable in `PointType`s and with the typed accessors of all point buffers. Use [`get`](Self::get) or the
+/// conversions from and into `Option<T>` to work with the value
+/// ```
+/// # use pasture_core::layout::*;
+/// let gps_time: Nullable<f64> = Some(1.5).into();
+/// assert_eq!(Some(1.5), gps_time.get());
+/// assert_eq!(16, std::mem::size_of::<Nullable<f64>>());
+/// assert!(Nullable::<f64>::none().is_none());
+/// ```
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct Nullable<T: NullableValue> {
+    validity: u8,
+    padding: T::Padding,
+    value: T,
+}
+
+impl<T: NullableValue> Nullable<T> {
+    /// Creates a new `Nullable<T>` with the present `value`
+    pub fn some(value: T) -> Self {
+        Self {
+            validity: 1,
+            padding: Default::default(),
+            value,
+        }
+    }
+
+    /// Creates a new `Nullable<T>` without a value
+    pub fn none() -> Self {
+        Self {
+            validity: 0,
+            padding: Default::default(),
+            // All types that implement `NullableValue` are plain numbers or arrays of numbers, for which zero is valid
+            value: unsafe { std::mem::zeroed() },
+        }
+    }
+
+    /// Returns `true` if the associated `Nullable<T>` contains a value
+    pub fn is_some(&self) -> bool {
+        self.validity != 0
+    }
+
+    /// Returns `true` if the associated `Nullable<T>` contains no value
+    pub fn is_none(&self) -> bool {
+        !self.is_some()
+    }
+
+    /// Returns the value of the associated `Nullable<T>`, or `None` if it is absent
+    pub fn get(&self) -> Option<T> {
+        if self.is_some() {
+            Some(self.value)
+        } else {
+            None
+        }
     }
 }
-impl PrimitiveType for bool {
-    fn data_type() -> PointAttributeDataType {
-        PointAttributeDataType::Bool
+
+impl<T: NullableValue> Default for Nullable<T> {
+    fn default() -> Self {
+        Self::none()
     }
 }
-impl PrimitiveType for Vector3<u8> {
-    fn data_type() -> PointAttributeDataType {
-        PointAttributeDataType::Vec3u8
+
+impl<T: NullableValue> From<Option<T>> for Nullable<T> {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(value) => Self::some(value),
+            None => Self::none(),
+        }
     }
 }
-impl PrimitiveType for Vector3<u16> {
-    fn data_type() -> PointAttributeDataType {
-        PointAttributeDataType::Vec3u16
+
+impl<T: NullableValue> From<Nullable<T>> for Option<T> {
+    fn from(value: Nullable<T>) -> Self {
+        value.get()
     }
 }
-impl PrimitiveType for Vector3<f32> {
-    fn data_type() -> PointAttributeDataType {
-        PointAttributeDataType::Vec3f32
+
+impl<T: NullableValue + PartialEq> PartialEq for Nullable<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
     }
 }
-impl PrimitiveType for Vector3<f64> {
-    fn data_type() -> PointAttributeDataType {
-        PointAttributeDataType::Vec3f64
+
+impl<T: NullableValue + Debug> Debug for Nullable<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.get().fmt(f)
     }
 }
 
-impl PrimitiveType for Vector4<u8> {
+impl<T: NullableValue> PrimitiveType for Nullable<T> {
     fn data_type() -> PointAttributeDataType {
-        PointAttributeDataType::Vec4u8
+        PointAttributeDataType::nullable(T::data_type())
     }
 }
 
@@ -235,45 +784,208 @@ const_assert!(std::mem::size_of::<Vector3<u16>>() == 6);
 const_assert!(std::mem::size_of::<Vector3<f32>>() == 12);
 const_assert!(std::mem::size_of::<Vector3<f64>>() == 24);
 const_assert!(std::mem::size_of::<Vector4<u8>>() == 4);
-
-/// A definition for a single point attribute of a point cloud. Point attributes are things like the position,
-/// GPS time, intensity etc. In Pasture, attributes are identified by a unique name together with the data type
-/// that a single record of the attribute is stored in. Attributes can be grouped into two categories: Built-in
-/// attributes (e.g. POSITION_3D, INTENSITY, GPS_TIME etc.) and custom attributes.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PointAttributeDefinition {
-    name: &'static str,
-    datatype: PointAttributeDataType,
+const_assert!(std::mem::size_of::<Vector2<f32>>() == 8);
+const_assert!(std::mem::size_of::<Vector4<u16>>() == 8);
+const_assert!(std::mem::size_of::<Vector6<i64>>() == 48);
+const_assert!(std::mem::size_of::<Matrix3<f64>>() == 72);
+const_assert!(std::mem::size_of::<Matrix4<u8>>() == 16);
+
+// `Nullable<T>` must not contain any padding besides its explicit padding bytes
+const_assert!(std::mem::size_of::<Nullable<u8>>() == 2);
+const_assert!(std::mem::size_of::<Nullable<u16>>() == 4);
+const_assert!(std::mem::size_of::<Nullable<f32>>() == 8);
+const_assert!(std::mem::size_of::<Nullable<f64>>() == 16);
+const_assert!(std::mem::size_of::<Nullable<Vector3<u16>>>() == 8);
+const_assert!(std::mem::size_of::<Nullable<Vector3<f64>>>() == 32);
+const_assert!(std::mem::size_of::<Nullable<[u8; 5]>>() == 6);
+
+/// Describes how the values of a quantized point attribute map to their actual values. Each component of a quantized
+/// attribute is stored as an integer (or float) `q` that represents the actual value `q * scale + offset`. Scale and
+/// offset can either be the same for all components of an attribute or specified per component
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PointAttributeQuantization {
+    scales: Vec<f64>,
+    offsets: Vec<f64>,
 }
 
-impl PointAttributeDefinition {
-    /// Creates a new custom PointAttributeDefinition with the given name and data type
+impl PointAttributeQuantization {
+    /// Creates a new `PointAttributeQuantization` that uses the same `scale` and `offset` for all components of an attribute
     /// ```
     /// # use pasture_core::layout::*;
-    /// let custom_attribute = PointAttributeDefinition::custom("Custom", PointAttributeDataType::F32);
-    /// # assert_eq!(custom_attribute.name(), "Custom");
-    /// # assert_eq!(custom_attribute.datatype(), PointAttributeDataType::F32);
+    /// // Normalized u16 values in the range [0;1]
+    /// let quantization = PointAttributeQuantization::new(1.0 / 65535.0, 0.0);
+    /// assert_eq!(1.0, quantization.dequantize(0, 65535.0));
     /// ```
-    pub const fn custom(name: &'static str, datatype: PointAttributeDataType) -> Self {
-        Self { name, datatype }
+    pub fn new(scale: f64, offset: f64) -> Self {
+        Self {
+            scales: vec![scale],
+            offsets: vec![offset],
+        }
     }
 
-    /// Returns the name of this PointAttributeDefinition
+    /// Creates a new `PointAttributeQuantization` with a separate scale and offset for each component of an attribute
     /// ```
     /// # use pasture_core::layout::*;
-    /// let custom_attribute = PointAttributeDefinition::custom("Custom", PointAttributeDataType::F32);
-    /// let name = custom_attribute.name();
-    /// # assert_eq!(name, "Custom");
+    /// let quantization = PointAttributeQuantization::per_component(&[0.5, 0.25], &[10.0, 20.0]);
+    /// assert_eq!(11.0, quantization.dequantize(0, 2.0));
+    /// assert_eq!(20.5, quantization.dequantize(1, 2.0));
     /// ```
-    pub fn name(&self) -> &'static str {
-        self.name
+    ///
+    /// # Panics
+    ///
+    /// If `scales` is empty or `scales` and `offsets` have different lengths
+    pub fn per_component(scales: &[f64], offsets: &[f64]) -> Self {
+        if scales.is_empty() || scales.len() != offsets.len() {
+            panic!("PointAttributeQuantization::per_component: scales and offsets must be non-empty and of equal length");
+        }
+        Self {
+            scales: scales.to_vec(),
+            offsets: offsets.to_vec(),
+        }
     }
 
-    /// Returns the datatype of this PointAttributeDefinition
-    /// ```
-    /// # use pasture_core::layout::*;
-    /// let custom_attribute = PointAttributeDefinition::custom("Custom", PointAttributeDataType::F32);
-    /// let datatype = custom_attribute.datatype();
+    /// Returns the scale of the component with the given index
+    pub fn scale(&self, component: usize) -> f64 {
+        self.scales[component % self.scales.len()]
+    }
+
+    /// Returns the offset of the component with the given index
+    pub fn offset(&self, component: usize) -> f64 {
+        self.offsets[component % self.offsets.len()]
+    }
+
+    /// Converts the quantized `value` of the component with the given index into its actual value
+    pub fn dequantize(&self, component: usize, value: f64) -> f64 {
+        value * self.scale(component) + self.offset(component)
+    }
+
+    /// Converts the actual `value` of the component with the given index into its quantized value. The result is not
+    /// rounded, since quantized values can also be stored as floating-point numbers
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// let quantization = PointAttributeQuantization::new(0.5, 100.0);
+    /// assert_eq!(24.5, quantization.quantize(0, 112.25));
+    /// ```
+    pub fn quantize(&self, component: usize, value: f64) -> f64 {
+        (value - self.offset(component)) / self.scale(component)
+    }
+
+    /// Checks that the associated `PointAttributeQuantization` can be used for an attribute with the given `datatype`
+    fn check_datatype(&self, datatype: PointAttributeDataType) -> Result<(), String> {
+        if self.scales.is_empty() || self.scales.len() != self.offsets.len() {
+            return Err(
+                "Quantization must have non-empty scales and offsets of equal length".into(),
+            );
+        }
+        let components = match datatype.scalar_type() {
+            Some(scalar_type) => (datatype.size() / scalar_type.size()) as usize,
+            None => return Err(format!("Datatype {} can't be quantized", datatype)),
+        };
+        if self.scales.len() != 1 && self.scales.len() != components {
+            return Err(format!(
+                "Quantization has {} components, but datatype {} has {} components",
+                self.scales.len(),
+                datatype,
+                components
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl PartialEq for PointAttributeQuantization {
+    fn eq(&self, other: &Self) -> bool {
+        // Compare bitwise, so that quantizations can be used as part of hashed PointAttributeDefinitions
+        let to_bits = |values: &[f64]| values.iter().map(|v| v.to_bits()).collect::<Vec<_>>();
+        to_bits(&self.scales) == to_bits(&other.scales)
+            && to_bits(&self.offsets) == to_bits(&other.offsets)
+    }
+}
+
+impl Eq for PointAttributeQuantization {}
+
+impl Hash for PointAttributeQuantization {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for value in self.scales.iter().chain(self.offsets.iter()) {
+            value.to_bits().hash(state);
+        }
+    }
+}
+
+impl Display for PointAttributeQuantization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "scale {:?} offset {:?}", self.scales, self.offsets)
+    }
+}
+
+/// A definition for a single point attribute of a point cloud. Point attributes are things like the position,
+/// GPS time, intensity etc. In Pasture, attributes are identified by a unique name together with the data type
+/// that a single record of the attribute is stored in. Attributes can be grouped into two categories: Built-in
+/// attributes (e.g. POSITION_3D, INTENSITY, GPS_TIME etc.) and custom attributes.
+///
+/// The name of an attribute is either a `&'static str` (as for all built-in attributes) or an owned `String`, which
+/// allows attributes that are only known at runtime (e.g. from the header of a file) to be modelled without leaking memory.
+///
+/// Attributes can optionally be [quantized](PointAttributeQuantization), in which case the stored values are integers
+/// that have to be scaled and offset to obtain the actual values of the attribute (like the positions in a LAS file).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedPointAttributeDefinition")]
+pub struct PointAttributeDefinition {
+    name: Cow<'static, str>,
+    datatype: PointAttributeDataType,
+    quantization: Option<PointAttributeQuantization>,
+}
+
+impl PointAttributeDefinition {
+    /// Creates a new custom PointAttributeDefinition with the given name and data type
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// let custom_attribute = PointAttributeDefinition::custom("Custom", PointAttributeDataType::F32);
+    /// # assert_eq!(custom_attribute.name(), "Custom");
+    /// # assert_eq!(custom_attribute.datatype(), PointAttributeDataType::F32);
+    /// ```
+    pub const fn custom(name: &'static str, datatype: PointAttributeDataType) -> Self {
+        Self {
+            name: Cow::Borrowed(name),
+            datatype,
+            quantization: None,
+        }
+    }
+
+    /// Creates a new custom PointAttributeDefinition with the given name and data type. In contrast to
+    /// [`custom`](Self::custom), the name does not have to be `'static`, so this can be used for attributes whose
+    /// names are only known at runtime
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// let name_from_file = String::from("Reflectance");
+    /// let custom_attribute = PointAttributeDefinition::custom_owned(name_from_file, PointAttributeDataType::F32);
+    /// # assert_eq!(custom_attribute.name(), "Reflectance");
+    /// # assert_eq!(custom_attribute.datatype(), PointAttributeDataType::F32);
+    /// ```
+    pub fn custom_owned<S: Into<String>>(name: S, datatype: PointAttributeDataType) -> Self {
+        Self {
+            name: Cow::Owned(name.into()),
+            datatype,
+            quantization: None,
+        }
+    }
+
+    /// Returns the name of this PointAttributeDefinition
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// let custom_attribute = PointAttributeDefinition::custom("Custom", PointAttributeDataType::F32);
+    /// let name = custom_attribute.name();
+    /// # assert_eq!(name, "Custom");
+    /// ```
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the datatype of this PointAttributeDefinition
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// let custom_attribute = PointAttributeDefinition::custom("Custom", PointAttributeDataType::F32);
+    /// let datatype = custom_attribute.datatype();
     /// # assert_eq!(datatype, PointAttributeDataType::F32);
     /// ```
     pub fn datatype(&self) -> PointAttributeDataType {
@@ -285,7 +997,13 @@ impl PointAttributeDefinition {
         self.datatype.size()
     }
 
-    /// Returns a new PointAttributeDefinition based on this PointAttributeDefinition, but with a different datatype
+    /// Returns the quantization of this PointAttributeDefinition, or `None` if the attribute is not quantized
+    pub fn quantization(&self) -> Option<&PointAttributeQuantization> {
+        self.quantization.as_ref()
+    }
+
+    /// Returns a new PointAttributeDefinition based on this PointAttributeDefinition, but with a different datatype. The
+    /// new PointAttributeDefinition is not quantized
     /// ```
     /// # use pasture_core::layout::*;
     /// let custom_position_attribute = attributes::POSITION_3D.with_custom_datatype(PointAttributeDataType::Vec3f32);
@@ -294,8 +1012,50 @@ impl PointAttributeDefinition {
     /// ```
     pub fn with_custom_datatype(&self, new_datatype: PointAttributeDataType) -> Self {
         Self {
-            name: self.name,
+            name: self.name.clone(),
             datatype: new_datatype,
+            quantization: None,
+        }
+    }
+
+    /// Returns a new PointAttributeDefinition based on this PointAttributeDefinition, but with a different name
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// let normalized_intensity = attributes::INTENSITY.with_name("NormalizedIntensity");
+    /// # assert_eq!(normalized_intensity.name(), "NormalizedIntensity");
+    /// # assert_eq!(normalized_intensity.datatype(), attributes::INTENSITY.datatype());
+    /// ```
+    pub fn with_name<S: Into<Cow<'static, str>>>(&self, new_name: S) -> Self {
+        Self {
+            name: new_name.into(),
+            datatype: self.datatype,
+            quantization: self.quantization.clone(),
+        }
+    }
+
+    /// Returns a new PointAttributeDefinition based on this PointAttributeDefinition, but with the given `quantization`.
+    /// Values of the new attribute are stored in quantized form, i.e. an actual value `v` is stored as `(v - offset) / scale`
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// // Positions in a LAS file are stored as i32 values with a scale and offset
+    /// let las_position = attributes::POSITION_3D
+    ///     .with_custom_datatype(PointAttributeDataType::vector(PointAttributeScalarType::I32, 3))
+    ///     .with_quantization(PointAttributeQuantization::per_component(&[0.01, 0.01, 0.01], &[100.0, 200.0, 0.0]));
+    /// # assert!(las_position.quantization().is_some());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the datatype of this PointAttributeDefinition has no [scalar type](PointAttributeDataType::scalar_type), or if
+    /// `quantization` has per-component values that don't match the number of components of the datatype
+    pub fn with_quantization(&self, quantization: PointAttributeQuantization) -> Self {
+        if let Err(why) = quantization.check_datatype(self.datatype) {
+            panic!("PointAttributeDefinition::with_quantization: {}", why);
+        }
+        Self {
+            name: self.name.clone(),
+            datatype: self.datatype,
+            quantization: Some(quantization),
         }
     }
 
@@ -312,15 +1072,81 @@ impl PointAttributeDefinition {
     pub fn at_offset_in_type(&self, offset: u64) -> PointAttributeMember {
         PointAttributeMember {
             datatype: self.datatype,
-            name: self.name,
+            name: self.name.clone(),
+            quantization: self.quantization.clone(),
+            offset,
+            bit_field: None,
+        }
+    }
+
+    /// Creates a `PointAttributeMember` from the associated `PointAttributeDefinition` that is stored as a bit field
+    /// within a `PointType`. The bit field starts at bit `bit_offset` of the byte at `offset` and is `bit_width` bits
+    /// wide, see [`PointAttributeBitField`] for the exact bit order. Reading the attribute yields the value of the bit
+    /// field converted to the datatype of the associated `PointAttributeDefinition`
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// // LAS point format 0 stores the return number in the lowest 3 bits of byte 14
+    /// let return_number = attributes::RETURN_NUMBER.at_bit_offset_in_type(14, 0, 3);
+    /// # assert_eq!(return_number.offset(), 14);
+    /// assert_eq!(return_number.bit_field(), Some(PointAttributeBitField::new(0, 3)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the bit field is invalid (see [`PointAttributeBitField::new`]), if the datatype of the associated
+    /// `PointAttributeDefinition` is not an unsigned integer type or `Bool`, or if the datatype can't hold
+    /// `bit_width` bits
+    pub fn at_bit_offset_in_type(
+        &self,
+        offset: u64,
+        bit_offset: u8,
+        bit_width: u8,
+    ) -> PointAttributeMember {
+        let bit_field = PointAttributeBitField::new(bit_offset, bit_width);
+        if let Err(why) = bit_field.check_datatype(self.datatype) {
+            panic!("PointAttributeDefinition::at_bit_offset_in_type: {}", why);
+        }
+        PointAttributeMember {
+            datatype: self.datatype,
+            name: self.name.clone(),
+            quantization: self.quantization.clone(),
             offset,
+            bit_field: Some(bit_field),
         }
     }
 }
 
 impl Display for PointAttributeDefinition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "[{};{}]", self.name, self.datatype)
+        match &self.quantization {
+            Some(quantization) => write!(f, "[{};{};{}]", self.name, self.datatype, quantization),
+            None => write!(f, "[{};{}]", self.name, self.datatype),
+        }
+    }
+}
+
+/// Serialized form of a `PointAttributeDefinition` that has not yet been checked for validity
+#[derive(Deserialize)]
+#[serde(rename = "PointAttributeDefinition")]
+struct UncheckedPointAttributeDefinition {
+    name: Cow<'static, str>,
+    datatype: PointAttributeDataType,
+    quantization: Option<PointAttributeQuantization>,
+}
+
+impl TryFrom<UncheckedPointAttributeDefinition> for PointAttributeDefinition {
+    type Error = String;
+
+    fn try_from(attribute: UncheckedPointAttributeDefinition) -> Result<Self, Self::Error> {
+        attribute.datatype.check_size()?;
+        if let Some(quantization) = &attribute.quantization {
+            quantization.check_datatype(attribute.datatype)?;
+        }
+        Ok(Self {
+            name: attribute.name,
+            datatype: attribute.datatype,
+            quantization: attribute.quantization,
+        })
     }
 }
 
@@ -329,6 +1155,7 @@ impl From<PointAttributeMember> for PointAttributeDefinition {
         Self {
             datatype: attribute.datatype,
             name: attribute.name,
+            quantization: attribute.quantization,
         }
     }
 }
@@ -337,18 +1164,132 @@ impl From<&PointAttributeMember> for PointAttributeDefinition {
     fn from(attribute: &PointAttributeMember) -> Self {
         Self {
             datatype: attribute.datatype,
-            name: attribute.name,
+            name: attribute.name.clone(),
+            quantization: attribute.quantization.clone(),
+        }
+    }
+}
+
+/// Describes the position of a sub-byte bit field within a point record, as it is used by formats such as LAS to pack
+/// multiple small attributes (return number, number of returns, scan direction flag etc.) into a single byte. The bit
+/// field is relative to the byte offset of its [`PointAttributeMember`]. The bytes starting at this offset are interpreted
+/// as a little-endian unsigned integer and bit 0 is its least significant bit, which matches the bit order used by LAS
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PointAttributeBitField {
+    bit_offset: u8,
+    bit_width: u8,
+}
+
+impl PointAttributeBitField {
+    /// Creates a new `PointAttributeBitField` that starts at bit `bit_offset` and spans `bit_width` bits
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// let bit_field = PointAttributeBitField::new(6, 4);
+    /// # assert_eq!(bit_field.bit_offset(), 6);
+    /// # assert_eq!(bit_field.bit_width(), 4);
+    /// // The bit field spans bits 6 and 7 of the first byte and bits 0 and 1 of the second byte
+    /// assert_eq!(bit_field.byte_count(), 2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `bit_offset` is not in the range `[0;7]`, if `bit_width` is zero or if the bit field spans more than 64 bits
+    pub fn new(bit_offset: u8, bit_width: u8) -> Self {
+        let bit_field = Self {
+            bit_offset,
+            bit_width,
+        };
+        if let Err(why) = bit_field.check_bits() {
+            panic!("PointAttributeBitField::new: {}", why);
+        }
+        bit_field
+    }
+
+    /// Returns the offset of the first bit of the associated `PointAttributeBitField`
+    pub fn bit_offset(&self) -> u8 {
+        self.bit_offset
+    }
+
+    /// Returns the number of bits of the associated `PointAttributeBitField`
+    pub fn bit_width(&self) -> u8 {
+        self.bit_width
+    }
+
+    /// Returns the number of bytes that the associated `PointAttributeBitField` touches
+    pub fn byte_count(&self) -> u64 {
+        (self.bit_offset as u64 + self.bit_width as u64).div_ceil(8)
+    }
+
+    fn check_bits(&self) -> Result<(), String> {
+        if self.bit_offset >= 8 {
+            return Err("Bit offset must be smaller than 8, use the byte offset of the attribute for larger offsets".into());
+        }
+        if self.bit_width == 0 || self.bit_offset as u32 + self.bit_width as u32 > 64 {
+            return Err(format!(
+                "Invalid bit width {} for bit offset {}",
+                self.bit_width, self.bit_offset
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks that the associated `PointAttributeBitField` can be read into and written from a value of `datatype`
+    fn check_datatype(&self, datatype: PointAttributeDataType) -> Result<(), String> {
+        let max_bits = match datatype {
+            PointAttributeDataType::Bool => 1,
+            PointAttributeDataType::U8
+            | PointAttributeDataType::U16
+            | PointAttributeDataType::U32
+            | PointAttributeDataType::U64 => datatype.size() * 8,
+            _ => {
+                return Err(format!(
+                    "Datatype {} can't be used for a bit field, only unsigned integers and Bool are supported",
+                    datatype
+                ))
+            }
+        };
+        if self.bit_width as u64 > max_bits {
+            return Err(format!(
+                "Bit field with {} bits does not fit into datatype {}",
+                self.bit_width, datatype
+            ));
         }
+        Ok(())
+    }
+
+    fn mask(&self) -> u64 {
+        u64::MAX >> (64 - self.bit_width as u32)
+    }
+
+    /// Extracts the value of this bit field from `storage`, which has to be `byte_count()` bytes long
+    fn extract(&self, storage: &[u8]) -> u64 {
+        let mut bytes = [0; 8];
+        bytes[..storage.len()].copy_from_slice(storage);
+        (u64::from_le_bytes(bytes) >> self.bit_offset) & self.mask()
+    }
+
+    /// Writes `value` into this bit field in `storage`, leaving all bits outside of the bit field untouched. Bits of
+    /// `value` that don't fit into the bit field are discarded
+    fn insert(&self, value: u64, storage: &mut [u8]) {
+        let mut bytes = [0; 8];
+        bytes[..storage.len()].copy_from_slice(storage);
+        let mask = self.mask() << self.bit_offset;
+        let packed = (u64::from_le_bytes(bytes) & !mask) | ((value << self.bit_offset) & mask);
+        storage.copy_from_slice(&packed.to_le_bytes()[..storage.len()]);
     }
 }
 
 /// A point attribute within a `PointType` structure. This is similar to a `PointAttributeDefinition`, but includes the
-/// offset of the member within the structure
-#[derive(Debug, Clone)]
+/// offset of the member within the structure. Attributes can optionally be stored as a [bit field](PointAttributeBitField)
+/// at this offset
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedPointAttributeMember")]
 pub struct PointAttributeMember {
-    name: &'static str,
+    name: Cow<'static, str>,
     datatype: PointAttributeDataType,
+    quantization: Option<PointAttributeQuantization>,
     offset: u64,
+    bit_field: Option<PointAttributeBitField>,
 }
 
 impl PointAttributeMember {
@@ -362,9 +1303,34 @@ impl PointAttributeMember {
     /// ```
     pub fn custom(name: &'static str, datatype: PointAttributeDataType, offset: u64) -> Self {
         Self {
-            name,
+            name: Cow::Borrowed(name),
+            datatype,
+            quantization: None,
+            offset,
+            bit_field: None,
+        }
+    }
+
+    /// Creates a new custom `PointAttributeMember` with the given name, datatype and byte offset. The name does
+    /// not have to be `'static`, see [`PointAttributeDefinition::custom_owned`]
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// let name_from_file = String::from("Reflectance");
+    /// let custom_attribute = PointAttributeMember::custom_owned(name_from_file, PointAttributeDataType::F32, 8);
+    /// # assert_eq!(custom_attribute.name(), "Reflectance");
+    /// # assert_eq!(custom_attribute.offset(), 8);
+    /// ```
+    pub fn custom_owned<S: Into<String>>(
+        name: S,
+        datatype: PointAttributeDataType,
+        offset: u64,
+    ) -> Self {
+        Self {
+            name: Cow::Owned(name.into()),
             datatype,
+            quantization: None,
             offset,
+            bit_field: None,
         }
     }
 
@@ -375,8 +1341,8 @@ impl PointAttributeMember {
     /// let name = custom_attribute.name();
     /// # assert_eq!(name, "Custom");
     /// ```
-    pub fn name(&self) -> &'static str {
-        self.name
+    pub fn name(&self) -> &str {
+        &self.name
     }
 
     /// Returns the datatype of the associated `PointAttributeMember`
@@ -390,6 +1356,11 @@ impl PointAttributeMember {
         self.datatype
     }
 
+    /// Returns the quantization of the associated `PointAttributeMember`, or `None` if the attribute is not quantized
+    pub fn quantization(&self) -> Option<&PointAttributeQuantization> {
+        self.quantization.as_ref()
+    }
+
     /// Returns the byte offset of the associated `PointAttributeMember`
     /// ```
     /// # use pasture_core::layout::*;
@@ -401,193 +1372,359 @@ impl PointAttributeMember {
         self.offset
     }
 
-    /// Returns the size in bytes of the associated `PointAttributeMember`
+    /// Returns the size in bytes of the associated `PointAttributeMember`. For bit field attributes, this is the size
+    /// of the unpacked value, see [`size_in_point_record`](Self::size_in_point_record) for the number of bytes that
+    /// the attribute occupies within a point record
     pub fn size(&self) -> u64 {
-        match self.datatype {
-            PointAttributeDataType::Bool => 1,
-            PointAttributeDataType::F32 => 4,
-            PointAttributeDataType::F64 => 8,
-            PointAttributeDataType::I8 => 1,
-            PointAttributeDataType::I16 => 2,
-            PointAttributeDataType::I32 => 4,
-            PointAttributeDataType::I64 => 8,
-            PointAttributeDataType::U8 => 1,
-            PointAttributeDataType::U16 => 2,
-            PointAttributeDataType::U32 => 4,
-            PointAttributeDataType::U64 => 8,
-            PointAttributeDataType::Vec3f32 => 3 * 4,
-            PointAttributeDataType::Vec3f64 => 3 * 8,
-            PointAttributeDataType::Vec3u16 => 3 * 2,
-            PointAttributeDataType::Vec3u8 => 3,
-            PointAttributeDataType::Vec4u8 => 4,
+        self.datatype.size()
+    }
+
+    /// Returns the bit field of the associated `PointAttributeMember`, or `None` if the attribute is not stored as
+    /// a bit field
+    pub fn bit_field(&self) -> Option<PointAttributeBitField> {
+        self.bit_field
+    }
+
+    /// Returns the number of bytes that the associated `PointAttributeMember` touches within a point record, starting
+    /// at its offset
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// let intensity = attributes::INTENSITY.at_offset_in_type(0);
+    /// assert_eq!(intensity.size_in_point_record(), 2);
+    /// let return_number = attributes::RETURN_NUMBER.at_bit_offset_in_type(14, 0, 3);
+    /// assert_eq!(return_number.size_in_point_record(), 1);
+    /// ```
+    pub fn size_in_point_record(&self) -> u64 {
+        match self.bit_field {
+            Some(bit_field) => bit_field.byte_count(),
+            None => self.size(),
+        }
+    }
+
+    /// Returns the range of bits that the associated `PointAttributeMember` occupies within a point record
+    fn bit_range_in_point_record(&self) -> Range<u64> {
+        match self.bit_field {
+            Some(bit_field) => {
+                let start = self.offset * 8 + bit_field.bit_offset() as u64;
+                start..start + bit_field.bit_width() as u64
+            }
+            None => self.offset * 8..(self.offset + self.size()) * 8,
+        }
+    }
+
+    /// Reads the value of the associated `PointAttributeMember` from the given `point_record` into `buf`. `point_record`
+    /// is the memory of a single point in the `PointLayout` that contains this attribute and `buf` must be exactly
+    /// [`size`](Self::size) bytes long. Bit field attributes are unpacked into the datatype of this attribute
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// let return_number = attributes::RETURN_NUMBER.at_bit_offset_in_type(1, 3, 3);
+    /// let point_record = [0xFF, 0b0010_1000];
+    /// let mut value = [0; 1];
+    /// return_number.read_from_point_record(&point_record, &mut value);
+    /// assert_eq!(value[0], 5);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `point_record` is too small to contain this attribute, or if `buf.len()` does not match the size of this attribute
+    pub fn read_from_point_record(&self, point_record: &[u8], buf: &mut [u8]) {
+        let start = self.offset as usize;
+        let end = start + self.size_in_point_record() as usize;
+        match self.bit_field {
+            Some(bit_field) => {
+                let value = bit_field.extract(&point_record[start..end]);
+                match self.datatype {
+                    PointAttributeDataType::U8 | PointAttributeDataType::Bool => {
+                        buf.copy_from_slice(&(value as u8).to_ne_bytes())
+                    }
+                    PointAttributeDataType::U16 => {
+                        buf.copy_from_slice(&(value as u16).to_ne_bytes())
+                    }
+                    PointAttributeDataType::U32 => {
+                        buf.copy_from_slice(&(value as u32).to_ne_bytes())
+                    }
+                    PointAttributeDataType::U64 => buf.copy_from_slice(&value.to_ne_bytes()),
+                    _ => unreachable!("Bit fields only support unsigned integers and Bool"),
+                }
+            }
+            None => buf.copy_from_slice(&point_record[start..end]),
+        }
+    }
+
+    /// Writes the value in `buf` into the associated `PointAttributeMember` within the given `point_record`. This is the
+    /// inverse of [`read_from_point_record`](Self::read_from_point_record). For bit field attributes, only the bits of
+    /// the bit field are modified and all other bits within `point_record` are left untouched. Values that are too large
+    /// for the bit field are truncated
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// let return_number = attributes::RETURN_NUMBER.at_bit_offset_in_type(1, 3, 3);
+    /// let mut point_record = [0xFF, 0b1100_0111];
+    /// return_number.write_to_point_record(&[5], &mut point_record);
+    /// assert_eq!(point_record, [0xFF, 0b1110_1111]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `point_record` is too small to contain this attribute, or if `buf.len()` does not match the size of this attribute
+    pub fn write_to_point_record(&self, buf: &[u8], point_record: &mut [u8]) {
+        let start = self.offset as usize;
+        let end = start + self.size_in_point_record() as usize;
+        match self.bit_field {
+            Some(bit_field) => {
+                if buf.len() != self.size() as usize {
+                    panic!("PointAttributeMember::write_to_point_record: Size of buffer does not match the size of the point attribute");
+                }
+                let mut bytes = [0; 8];
+                bytes[..buf.len()].copy_from_slice(buf);
+                let value = match self.datatype {
+                    PointAttributeDataType::U8 | PointAttributeDataType::Bool => bytes[0] as u64,
+                    PointAttributeDataType::U16 => u16::from_ne_bytes([bytes[0], bytes[1]]) as u64,
+                    PointAttributeDataType::U32 => {
+                        u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as u64
+                    }
+                    PointAttributeDataType::U64 => u64::from_ne_bytes(bytes),
+                    _ => unreachable!("Bit fields only support unsigned integers and Bool"),
+                };
+                bit_field.insert(value, &mut point_record[start..end]);
+            }
+            None => point_record[start..end].copy_from_slice(buf),
         }
     }
 }
 
 impl Display for PointAttributeMember {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "[{};{} @ offset {}]",
-            self.name, self.datatype, self.offset
-        )
+        write!(f, "[{};{}", self.name, self.datatype)?;
+        if let Some(quantization) = &self.quantization {
+            write!(f, ";{}", quantization)?;
+        }
+        write!(f, " @ offset {}", self.offset)?;
+        if let Some(bit_field) = self.bit_field {
+            write!(
+                f,
+                ", bits {}..{}",
+                bit_field.bit_offset(),
+                bit_field.bit_offset() + bit_field.bit_width()
+            )?;
+        }
+        write!(f, "]")
     }
 }
 
 impl PartialEq for PointAttributeMember {
     fn eq(&self, other: &Self) -> bool {
-        self.name == other.name && self.datatype == other.datatype
+        self.name == other.name
+            && self.datatype == other.datatype
+            && self.quantization == other.quantization
     }
 }
 
 impl Eq for PointAttributeMember {}
 
+/// Serialized form of a `PointAttributeMember` that has not yet been checked for validity
+#[derive(Deserialize)]
+#[serde(rename = "PointAttributeMember")]
+struct UncheckedPointAttributeMember {
+    name: Cow<'static, str>,
+    datatype: PointAttributeDataType,
+    quantization: Option<PointAttributeQuantization>,
+    offset: u64,
+    bit_field: Option<PointAttributeBitField>,
+}
+
+impl TryFrom<UncheckedPointAttributeMember> for PointAttributeMember {
+    type Error = String;
+
+    fn try_from(attribute: UncheckedPointAttributeMember) -> Result<Self, Self::Error> {
+        attribute.datatype.check_size()?;
+        if let Some(quantization) = &attribute.quantization {
+            quantization.check_datatype(attribute.datatype)?;
+        }
+        if let Some(bit_field) = &attribute.bit_field {
+            bit_field.check_bits()?;
+            bit_field.check_datatype(attribute.datatype)?;
+        }
+        Ok(Self {
+            name: attribute.name,
+            datatype: attribute.datatype,
+            quantization: attribute.quantization,
+            offset: attribute.offset,
+            bit_field: attribute.bit_field,
+        })
+    }
+}
+
 /// Module containing default attribute definitions
 pub mod attributes {
     use super::{PointAttributeDataType, PointAttributeDefinition};
+    use std::borrow::Cow;
 
     /// Attribute definition for a 3D position. Default datatype is Vec3f64
     pub const POSITION_3D: PointAttributeDefinition = PointAttributeDefinition {
-        name: "Position3D",
+        name: Cow::Borrowed("Position3D"),
         datatype: PointAttributeDataType::Vec3f64,
+        quantization: None,
     };
 
     /// Attribute definition for an intensity value. Default datatype is U16
     pub const INTENSITY: PointAttributeDefinition = PointAttributeDefinition {
-        name: "Intensity",
+        name: Cow::Borrowed("Intensity"),
         datatype: PointAttributeDataType::U16,
+        quantization: None,
     };
 
     /// Attribute definition for a return number. Default datatype is U8
     pub const RETURN_NUMBER: PointAttributeDefinition = PointAttributeDefinition {
-        name: "ReturnNumber",
+        name: Cow::Borrowed("ReturnNumber"),
         datatype: PointAttributeDataType::U8,
+        quantization: None,
     };
 
     /// Attribute definition for the number of returns. Default datatype is U8
     pub const NUMBER_OF_RETURNS: PointAttributeDefinition = PointAttributeDefinition {
-        name: "NumberOfReturns",
+        name: Cow::Borrowed("NumberOfReturns"),
         datatype: PointAttributeDataType::U8,
+        quantization: None,
     };
 
     /// Attribute definition for the classification flags. Default datatype is U8
     pub const CLASSIFICATION_FLAGS: PointAttributeDefinition = PointAttributeDefinition {
-        name: "ClassificationFlags",
+        name: Cow::Borrowed("ClassificationFlags"),
         datatype: PointAttributeDataType::U8,
+        quantization: None,
     };
 
     /// Attribute definition for the scanner channel. Default datatype is U8
     pub const SCANNER_CHANNEL: PointAttributeDefinition = PointAttributeDefinition {
-        name: "ScannerChannel",
+        name: Cow::Borrowed("ScannerChannel"),
         datatype: PointAttributeDataType::U8,
+        quantization: None,
     };
 
     /// Attribute definition for a scan direction flag. Default datatype is Bool
     pub const SCAN_DIRECTION_FLAG: PointAttributeDefinition = PointAttributeDefinition {
-        name: "ScanDirectionFlag",
+        name: Cow::Borrowed("ScanDirectionFlag"),
         datatype: PointAttributeDataType::Bool,
+        quantization: None,
     };
 
     /// Attribute definition for an edge of flight line flag. Default datatype is Bool
     pub const EDGE_OF_FLIGHT_LINE: PointAttributeDefinition = PointAttributeDefinition {
-        name: "EdgeOfFlightLine",
+        name: Cow::Borrowed("EdgeOfFlightLine"),
         datatype: PointAttributeDataType::Bool,
+        quantization: None,
     };
 
     /// Attribute definition for a classification. Default datatype is U8
     pub const CLASSIFICATION: PointAttributeDefinition = PointAttributeDefinition {
-        name: "Classification",
+        name: Cow::Borrowed("Classification"),
         datatype: PointAttributeDataType::U8,
+        quantization: None,
     };
 
     /// Attribute definition for a scan angle rank. Default datatype is I8
     pub const SCAN_ANGLE_RANK: PointAttributeDefinition = PointAttributeDefinition {
-        name: "ScanAngleRank",
+        name: Cow::Borrowed("ScanAngleRank"),
         datatype: PointAttributeDataType::I8,
+        quantization: None,
     };
 
     /// Attribute definition for a scan angle with extended precision (like in LAS format 1.4). Default datatype is I16
     pub const SCAN_ANGLE: PointAttributeDefinition = PointAttributeDefinition {
-        name: "ScanAngle",
+        name: Cow::Borrowed("ScanAngle"),
         datatype: PointAttributeDataType::I16,
+        quantization: None,
     };
 
     /// Attribute definition for a user data field. Default datatype is U8
     pub const USER_DATA: PointAttributeDefinition = PointAttributeDefinition {
-        name: "UserData",
+        name: Cow::Borrowed("UserData"),
         datatype: PointAttributeDataType::U8,
+        quantization: None,
     };
 
     /// Attribute definition for a point source ID. Default datatype is U16
     pub const POINT_SOURCE_ID: PointAttributeDefinition = PointAttributeDefinition {
-        name: "PointSourceID",
+        name: Cow::Borrowed("PointSourceID"),
         datatype: PointAttributeDataType::U16,
+        quantization: None,
     };
 
     /// Attribute definition for an RGB color. Default datatype is Vec3u16
     pub const COLOR_RGB: PointAttributeDefinition = PointAttributeDefinition {
-        name: "ColorRGB",
+        name: Cow::Borrowed("ColorRGB"),
         datatype: PointAttributeDataType::Vec3u16,
+        quantization: None,
     };
 
     /// Attribute definition for a GPS timestamp. Default datatype is F64
     pub const GPS_TIME: PointAttributeDefinition = PointAttributeDefinition {
-        name: "GpsTime",
+        name: Cow::Borrowed("GpsTime"),
         datatype: PointAttributeDataType::F64,
+        quantization: None,
     };
 
     /// Attribute definition for near-infrared records (NIR). Default datatype is U16
     /// TODO NIR semantically belongs to the color attributes, so there should be a separate
     /// attribute for 4-channel color that includes NIR!
     pub const NIR: PointAttributeDefinition = PointAttributeDefinition {
-        name: "NIR",
+        name: Cow::Borrowed("NIR"),
         datatype: PointAttributeDataType::U16,
+        quantization: None,
     };
 
     /// Attribute definition for the wave packet descriptor index in the LAS format. Default datatype is U8
     pub const WAVE_PACKET_DESCRIPTOR_INDEX: PointAttributeDefinition = PointAttributeDefinition {
-        name: "WavePacketDescriptorIndex",
+        name: Cow::Borrowed("WavePacketDescriptorIndex"),
         datatype: PointAttributeDataType::U8,
+        quantization: None,
     };
 
     /// Attribute definition for the offset to the waveform data in the LAS format. Default datatype is U64
     pub const WAVEFORM_DATA_OFFSET: PointAttributeDefinition = PointAttributeDefinition {
-        name: "WaveformDataOffset",
+        name: Cow::Borrowed("WaveformDataOffset"),
         datatype: PointAttributeDataType::U64,
+        quantization: None,
     };
 
     /// Attribute definition for the size of a waveform data packet in the LAS format. Default datatype is U32
     pub const WAVEFORM_PACKET_SIZE: PointAttributeDefinition = PointAttributeDefinition {
-        name: "WaveformPacketSize",
+        name: Cow::Borrowed("WaveformPacketSize"),
         datatype: PointAttributeDataType::U32,
+        quantization: None,
     };
 
     /// Attribute definition for the return point waveform location in the LAS format. Default datatype is F32
     pub const RETURN_POINT_WAVEFORM_LOCATION: PointAttributeDefinition = PointAttributeDefinition {
-        name: "ReturnPointWaveformLocation",
+        name: Cow::Borrowed("ReturnPointWaveformLocation"),
         datatype: PointAttributeDataType::F32,
+        quantization: None,
     };
 
     /// Attribute definition for the waveform parameters in the LAS format. Default datatype is Vector3<f32>
     pub const WAVEFORM_PARAMETERS: PointAttributeDefinition = PointAttributeDefinition {
-        name: "WaveformParameters",
+        name: Cow::Borrowed("WaveformParameters"),
         datatype: PointAttributeDataType::Vec3f32,
+        quantization: None,
     };
 
     /// Attribute definition for a point ID. Default datatype is U64
     pub const POINT_ID: PointAttributeDefinition = PointAttributeDefinition {
-        name: "PointID",
+        name: Cow::Borrowed("PointID"),
         datatype: PointAttributeDataType::U64,
+        quantization: None,
     };
 
     /// Attribute definition for a 3D point normal. Default datatype is Vec3f32
     pub const NORMAL: PointAttributeDefinition = PointAttributeDefinition {
-        name: "Normal",
+        name: Cow::Borrowed("Normal"),
         datatype: PointAttributeDataType::Vec3f32,
+        quantization: None,
     };
 }
 
 /// How is a field within the associated in-memory type of a `PointLayout` aligned?
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum FieldAlignment {
     /// Use alignment as if the type is [`#[repr(C)]`](https://doc.rust-lang.org/reference/type-layout.html#reprc-structs)
     Default,
@@ -633,7 +1770,22 @@ pub enum FieldAlignment {
 /// To support the different memory layouts, Pasture buffers store point data as raw binary buffers internally. To work with the data,
 /// you will want to use strongly typed Rust structures. Any type `T` that you want to use for accessing point data in a strongly typed manner
 /// must implement the `PointType` trait and thus provide Pasture with a way of figuring out the attributes and memory layout of this type `T`.
-#[derive(Debug, Clone, PartialEq)]
+///
+/// # Serialization
+///
+/// `PointLayout` implements `Serialize` and `Deserialize`, so that the layout of a point cloud can be stored or sent along with
+/// its data. The serialized form contains all attributes with their offsets, as well as the size and alignment of a single point
+/// entry, so a deserialized `PointLayout` describes exactly the same memory layout as the original one:
+/// ```
+/// # use pasture_core::layout::*;
+/// let layout = PointLayout::from_attributes_packed(&[attributes::INTENSITY, attributes::POSITION_3D], 1);
+/// let json = serde_json::to_string(&layout).unwrap();
+/// let deserialized_layout: PointLayout = serde_json::from_str(&json).unwrap();
+/// assert!(layout.compare_without_offsets(&deserialized_layout));
+/// assert_eq!(layout.at(1).offset(), deserialized_layout.at(1).offset());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(into = "UncheckedPointLayout", try_from = "UncheckedPointLayout")]
 pub struct PointLayout {
     attributes: Vec<PointAttributeMember>,
     memory_layout: Layout,
@@ -695,6 +1847,9 @@ impl PointLayout {
 
     /// Creates a new PointLayout from the given `PointAttributeMember` sequence as well as the given `type_alignment`.
     ///
+    /// Attributes that are stored as [bit fields](PointAttributeBitField) may share bytes with other bit fields, as long
+    /// as their bits don't overlap.
+    ///
     /// #Panics
     ///
     /// If any two attributes within the sequence share the same attribute name, or if there is overlap between any two
@@ -712,50 +1867,27 @@ impl PointLayout {
         attributes: &[PointAttributeMember],
         type_alignment: u64,
     ) -> Self {
-        // Conduct extensive checks for uniqueness and non-overlap. The checks are a bit expensive, however
-        // they are absolutely necessary because this method is dangerous!
-        let unique_names = attributes.iter().map(|a| a.name()).unique();
-        if unique_names.count() != attributes.len() {
-            panic!(
-                "PointLayout::from_attributes_and_offsets: All attributes must have unique names!"
-            );
+        let unaligned_size = Self::check_members(attributes).unwrap_or_else(|why| {
+            panic!("PointLayout::from_attributes_and_offsets: {}", why);
+        });
+
+        Self {
+            attributes: attributes.to_vec(),
+            memory_layout: Layout::from_size_align(
+                unaligned_size.align_to(type_alignment) as usize,
+                type_alignment as usize,
+            )
+            .expect("Could not create memory layout for PointLayout"),
         }
-
-        let mut unaligned_ranges = attributes
-            .iter()
-            .map(|a| (a.offset()..(a.offset() + a.size())))
-            .collect::<Vec<_>>();
-        unaligned_ranges.sort_by(|a, b| a.start.cmp(&b.start));
-        for next_idx in 1..unaligned_ranges.len() {
-            let this_range = &unaligned_ranges[next_idx - 1];
-            let next_range = &unaligned_ranges[next_idx];
-            if this_range.end > next_range.start {
-                panic!("PointLayout::from_attributes_and_offsets: All attributes must span non-overlapping memory regions!")
-            }
-        }
-
-        let unaligned_size = attributes
-            .iter()
-            .max_by(|a, b| a.offset().cmp(&b.offset()))
-            .map(|last_attribute| last_attribute.offset() + last_attribute.size())
-            .unwrap_or(0);
-
-        Self {
-            attributes: attributes.to_vec(),
-            memory_layout: Layout::from_size_align(
-                unaligned_size.align_to(type_alignment) as usize,
-                type_alignment as usize,
-            )
-            .expect("Could not create memory layout for PointLayout"),
-        }
-    }
+    }
 
     /// Adds the given PointAttributeDefinition to this PointLayout. Sets the offset of the new attribute
     /// within the `PointLayout` based on the given `FieldAlignment`
     ///
     /// #Panics
     ///
-    /// If an attribute with the same name is already part of this PointLayout.
+    /// If an attribute with the same name is already part of this PointLayout, or if the datatype of the attribute
+    /// has a size of zero.
     /// ```
     /// # use pasture_core::layout::*;
     /// let mut layout = PointLayout::default();
@@ -778,6 +1910,9 @@ impl PointLayout {
                 old_attribute.name()
             );
         }
+        if let Err(why) = point_attribute.datatype().check_size() {
+            panic!("PointLayout::add_attribute: {}", why);
+        }
 
         let alignment_requirement_of_field = match field_alignment {
             FieldAlignment::Default => point_attribute.datatype().min_alignment(),
@@ -922,6 +2057,56 @@ impl PointLayout {
         self.memory_layout.size() as u64
     }
 
+    /// Returns the alignment in bytes of a single point entry with the associated `PointLayout`
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// let layout = PointLayout::from_attributes(&[attributes::POSITION_3D, attributes::INTENSITY]);
+    /// assert_eq!(8, layout.alignment_of_point_entry());
+    /// ```
+    pub fn alignment_of_point_entry(&self) -> u64 {
+        self.memory_layout.align() as u64
+    }
+
+    /// Returns a copy of the associated `PointLayout` where a single point entry has a size of `size_of_point_entry` bytes.
+    /// The attributes keep their offsets, the additional bytes are padding at the end of each point entry. This is useful
+    /// for point records that store more data than the attributes of the `PointLayout`, like LAS point records with extra bytes
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// let layout = PointLayout::from_attributes_packed(&[attributes::POSITION_3D, attributes::INTENSITY], 1);
+    /// let padded_layout = layout.with_size_of_point_entry(32);
+    /// assert_eq!(32, padded_layout.size_of_point_entry());
+    /// assert_eq!(layout.at(1), padded_layout.at(1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `size_of_point_entry` is smaller than the number of bytes that the attributes of the associated `PointLayout` span,
+    /// or if it is no multiple of the alignment of the associated `PointLayout`
+    pub fn with_size_of_point_entry(&self, size_of_point_entry: u64) -> PointLayout {
+        let unaligned_size = Self::check_members(&self.attributes)
+            .expect("PointLayout::with_size_of_point_entry: Invalid PointLayout");
+        if size_of_point_entry < unaligned_size {
+            panic!(
+                "PointLayout::with_size_of_point_entry: Size {} is smaller than the size of all attributes ({})",
+                size_of_point_entry, unaligned_size
+            );
+        }
+        let memory_layout =
+            Layout::from_size_align(size_of_point_entry as usize, self.memory_layout.align())
+                .expect("PointLayout::with_size_of_point_entry: Invalid size of point entry");
+        if !size_of_point_entry.is_multiple_of(memory_layout.align() as u64) {
+            panic!(
+                "PointLayout::with_size_of_point_entry: Size {} is no multiple of the alignment ({})",
+                size_of_point_entry,
+                memory_layout.align()
+            );
+        }
+        Self {
+            attributes: self.attributes.clone(),
+            memory_layout,
+        }
+    }
+
     /// Returns the index of the given attribute within the associated `PointLayout`, or `None` if the attribute is not
     /// part of the `PointLayout`. The index depends on the order in which the attributes have been added to the associated
     /// `PointLayout`, but does not necessarily reflect the order of the attributes in memory.
@@ -973,6 +2158,229 @@ impl PointLayout {
             .map(|member| member.offset())
     }
 
+    /// Creates a new `PointLayout` that contains only the given `attributes` of this `PointLayout`, in the order in which
+    /// they appear in `attributes`. This can also be used to reorder the attributes of a `PointLayout`. Attributes in the new
+    /// `PointLayout` are aligned using [`FieldAlignment::Default`], use [`PointLayout::repacked`] for a different alignment.
+    /// Returns the new `PointLayout` together with the [mapping](PointLayoutMapping) from this `PointLayout` into the new one
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// let layout = PointLayout::from_attributes(&[attributes::POSITION_3D, attributes::INTENSITY, attributes::CLASSIFICATION]);
+    /// let (subset, mapping) = layout.subset(&[attributes::CLASSIFICATION, attributes::POSITION_3D]);
+    /// assert_eq!(subset, PointLayout::from_attributes(&[attributes::CLASSIFICATION, attributes::POSITION_3D]));
+    /// # assert_eq!(2, mapping.attributes().count());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If any of the `attributes` is not part of this `PointLayout`, or if `attributes` contains an attribute more than once
+    pub fn subset(
+        &self,
+        attributes: &[PointAttributeDefinition],
+    ) -> (PointLayout, PointLayoutMapping) {
+        let members = attributes
+            .iter()
+            .map(|attribute| {
+                self.get_attribute(attribute).unwrap_or_else(|| {
+                    panic!(
+                        "PointLayout::subset: Attribute {} is not part of this PointLayout",
+                        attribute
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+        Self::derive_from_members(
+            members.into_iter().map(|member| (member, member.into())),
+            FieldAlignment::Default,
+        )
+    }
+
+    /// Creates a new `PointLayout` that contains all attributes of this `PointLayout`, followed by all attributes of `other`
+    /// that are not part of this `PointLayout`. Attributes are identified by their name, if both layouts contain an attribute
+    /// with the same name but different datatypes, the datatype from this `PointLayout` is used and the mapping from `other`
+    /// converts the attribute into this datatype. Attributes in the new `PointLayout` are aligned using [`FieldAlignment::Default`].
+    /// Returns the new `PointLayout` together with the [mappings](PointLayoutMapping) from this `PointLayout` and from `other`
+    /// into the new one
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// let layout_a = PointLayout::from_attributes(&[attributes::POSITION_3D, attributes::INTENSITY]);
+    /// let layout_b = PointLayout::from_attributes(&[attributes::POSITION_3D, attributes::GPS_TIME]);
+    /// let (union, mapping_a, mapping_b) = layout_a.union(&layout_b);
+    /// assert_eq!(union, PointLayout::from_attributes(&[attributes::POSITION_3D, attributes::INTENSITY, attributes::GPS_TIME]));
+    /// # assert_eq!(2, mapping_a.attributes().count());
+    /// # assert_eq!(2, mapping_b.attributes().count());
+    /// ```
+    pub fn union(
+        &self,
+        other: &PointLayout,
+    ) -> (PointLayout, PointLayoutMapping, PointLayoutMapping) {
+        let attributes = self
+            .attributes()
+            .chain(
+                other
+                    .attributes()
+                    .filter(|attribute| !self.has_attribute_with_name(attribute.name())),
+            )
+            .map(PointAttributeDefinition::from)
+            .collect::<Vec<_>>();
+        let union = Self::from_attributes(&attributes);
+        let self_mapping = PointLayoutMapping::by_name(self, &union);
+        let other_mapping = PointLayoutMapping::by_name(other, &union);
+        (union, self_mapping, other_mapping)
+    }
+
+    /// Creates a new `PointLayout` where `attribute` is renamed to `new_name`. All other properties of the `PointLayout`,
+    /// including the offsets of all attributes, stay the same. Returns the new `PointLayout` together with the
+    /// [mapping](PointLayoutMapping) from this `PointLayout` into the new one
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// let layout = PointLayout::from_attributes(&[attributes::POSITION_3D, attributes::INTENSITY]);
+    /// let (renamed_layout, mapping) = layout.with_renamed_attribute(&attributes::INTENSITY, "Reflectance");
+    /// assert!(renamed_layout.has_attribute_with_name("Reflectance"));
+    /// assert_eq!(layout.size_of_point_entry(), renamed_layout.size_of_point_entry());
+    /// # assert!(!renamed_layout.has_attribute(&attributes::INTENSITY));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `attribute` is not part of this `PointLayout`, or if this `PointLayout` already contains a different attribute
+    /// with the name `new_name`
+    pub fn with_renamed_attribute<S: Into<Cow<'static, str>>>(
+        &self,
+        attribute: &PointAttributeDefinition,
+        new_name: S,
+    ) -> (PointLayout, PointLayoutMapping) {
+        let new_name = new_name.into();
+        let index = self.index_of(attribute).unwrap_or_else(|| {
+            panic!(
+                "PointLayout::with_renamed_attribute: Attribute {} is not part of this PointLayout",
+                attribute
+            )
+        });
+        if new_name != attribute.name() && self.has_attribute_with_name(&new_name) {
+            panic!(
+                "PointLayout::with_renamed_attribute: An attribute with name {} is already present in this PointLayout",
+                new_name
+            );
+        }
+
+        let mut renamed_layout = self.clone();
+        renamed_layout.attributes[index].name = new_name;
+        let mut mapping = PointLayoutMapping::default();
+        for (source, target) in self.attributes().zip(renamed_layout.attributes()) {
+            mapping.add_attribute(source, target);
+        }
+        (renamed_layout, mapping)
+    }
+
+    /// Creates a new `PointLayout` where `attribute` has the datatype `new_datatype` (see
+    /// [`PointAttributeDefinition::with_custom_datatype`]). Attributes in the new `PointLayout` are aligned using
+    /// [`FieldAlignment::Default`]. Returns the new `PointLayout` together with the [mapping](PointLayoutMapping)
+    /// from this `PointLayout` into the new one
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// let layout = PointLayout::from_attributes(&[attributes::POSITION_3D, attributes::INTENSITY]);
+    /// let (f32_layout, mapping) = layout.with_attribute_datatype(&attributes::POSITION_3D, PointAttributeDataType::Vec3f32);
+    /// assert!(f32_layout.has_attribute(&attributes::POSITION_3D.with_custom_datatype(PointAttributeDataType::Vec3f32)));
+    /// assert_eq!(16, f32_layout.size_of_point_entry());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `attribute` is not part of this `PointLayout`
+    pub fn with_attribute_datatype(
+        &self,
+        attribute: &PointAttributeDefinition,
+        new_datatype: PointAttributeDataType,
+    ) -> (PointLayout, PointLayoutMapping) {
+        let index = self.index_of(attribute).unwrap_or_else(|| {
+            panic!(
+                "PointLayout::with_attribute_datatype: Attribute {} is not part of this PointLayout",
+                attribute
+            )
+        });
+        Self::derive_from_members(
+            self.attributes().enumerate().map(|(idx, member)| {
+                let definition = PointAttributeDefinition::from(member);
+                if idx == index {
+                    (member, definition.with_custom_datatype(new_datatype))
+                } else {
+                    (member, definition)
+                }
+            }),
+            FieldAlignment::Default,
+        )
+    }
+
+    /// Creates a new `PointLayout` with the same attributes as this `PointLayout`, aligned using the given `field_alignment`.
+    /// Attributes that are stored as [bit fields](PointAttributeBitField) in this `PointLayout` are stored as regular
+    /// attributes in the new `PointLayout`. Returns the new `PointLayout` together with the [mapping](PointLayoutMapping)
+    /// from this `PointLayout` into the new one
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// let layout = PointLayout::from_attributes(&[attributes::INTENSITY, attributes::POSITION_3D]);
+    /// let (packed_layout, mapping) = layout.repacked(FieldAlignment::Packed(1));
+    /// assert_eq!(2, packed_layout.at(1).offset());
+    /// assert_eq!(26, packed_layout.size_of_point_entry());
+    /// ```
+    pub fn repacked(&self, field_alignment: FieldAlignment) -> (PointLayout, PointLayoutMapping) {
+        Self::derive_from_members(
+            self.attributes().map(|member| (member, member.into())),
+            field_alignment,
+        )
+    }
+
+    /// Creates a new `PointLayout` by adding the attribute definitions in `attributes` with the given `field_alignment`, and
+    /// a mapping from the associated members into the new `PointLayout`
+    fn derive_from_members<'a>(
+        attributes: impl Iterator<Item = (&'a PointAttributeMember, PointAttributeDefinition)>,
+        field_alignment: FieldAlignment,
+    ) -> (PointLayout, PointLayoutMapping) {
+        let mut layout = Self::default();
+        let mut source_members = vec![];
+        for (source_member, target_attribute) in attributes {
+            layout.add_attribute(target_attribute, field_alignment);
+            source_members.push(source_member);
+        }
+        let mut mapping = PointLayoutMapping::default();
+        for (source, target) in source_members.into_iter().zip(layout.attributes()) {
+            mapping.add_attribute(source, target);
+        }
+        (layout, mapping)
+    }
+
+    /// Checks that the given `attributes` have unique names and don't overlap. Returns the size of the point record that
+    /// the `attributes` span, without trailing padding
+    fn check_members(attributes: &[PointAttributeMember]) -> Result<u64, String> {
+        // Conduct extensive checks for uniqueness and non-overlap. The checks are a bit expensive, however
+        // they are absolutely necessary because creating a PointLayout from members is dangerous!
+        let unique_names = attributes.iter().map(|a| a.name()).unique();
+        if unique_names.count() != attributes.len() {
+            return Err("All attributes must have unique names!".into());
+        }
+        for attribute in attributes {
+            attribute.datatype().check_size()?;
+        }
+
+        let mut unaligned_ranges = attributes
+            .iter()
+            .map(|a| a.bit_range_in_point_record())
+            .collect::<Vec<_>>();
+        unaligned_ranges.sort_by(|a, b| a.start.cmp(&b.start));
+        for next_idx in 1..unaligned_ranges.len() {
+            let this_range = &unaligned_ranges[next_idx - 1];
+            let next_range = &unaligned_ranges[next_idx];
+            if this_range.end > next_range.start {
+                return Err("All attributes must span non-overlapping memory regions!".into());
+            }
+        }
+
+        Ok(attributes
+            .iter()
+            .map(|attribute| attribute.offset() + attribute.size_in_point_record())
+            .max()
+            .unwrap_or(0))
+    }
+
     /// Returns the offset of the next field that could be added to this `PointLayout`, without any alignment
     /// requirements
     fn packed_offset_of_next_field(&self) -> u64 {
@@ -982,8 +2390,103 @@ impl PointLayout {
             // If there are previous attributes, the offset to this attribute is equal to the offset
             // to the previous attribute plus the previous attribute's size
             let last_attribute = self.attributes.last().unwrap();
-            last_attribute.offset() + last_attribute.size()
+            last_attribute.offset() + last_attribute.size_in_point_record()
+        }
+    }
+}
+
+/// Describes which attributes of a source `PointLayout` correspond to which attributes of a target `PointLayout`. A mapping
+/// is a sequence of pairs of source and target attributes, and the attributes within each pair may have different names,
+/// datatypes and offsets. Mappings are returned by all methods that derive a new `PointLayout` from existing ones (such as
+/// [`PointLayout::subset`] or [`PointLayout::with_renamed_attribute`]) and can be used to convert points between the layouts
+/// using [`RawPointConverter::from_mapping`](crate::layout::conversion::RawPointConverter::from_mapping)
+/// ```
+/// # use pasture_core::layout::*;
+/// let layout = PointLayout::from_attributes(&[attributes::POSITION_3D, attributes::INTENSITY]);
+/// let (renamed_layout, mapping) = layout.with_renamed_attribute(&attributes::INTENSITY, "Reflectance");
+/// let (source, target) = mapping.attributes().nth(1).unwrap();
+/// assert_eq!(source.name(), "Intensity");
+/// assert_eq!(target.name(), "Reflectance");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PointLayoutMapping {
+    attributes: Vec<(PointAttributeMember, PointAttributeMember)>,
+}
+
+impl PointLayoutMapping {
+    /// Creates a new `PointLayoutMapping` that maps each attribute of `source_layout` to the attribute with the same name in
+    /// `target_layout`. Attributes that are only part of one of the two layouts are not mapped
+    /// ```
+    /// # use pasture_core::layout::*;
+    /// let source_layout = PointLayout::from_attributes(&[attributes::POSITION_3D, attributes::INTENSITY]);
+    /// let target_layout = PointLayout::from_attributes(&[attributes::GPS_TIME, attributes::POSITION_3D]);
+    /// let mapping = PointLayoutMapping::by_name(&source_layout, &target_layout);
+    /// assert_eq!(1, mapping.attributes().count());
+    /// ```
+    pub fn by_name(source_layout: &PointLayout, target_layout: &PointLayout) -> Self {
+        let mut mapping = Self::default();
+        for source_attribute in source_layout.attributes() {
+            if let Some(target_attribute) =
+                target_layout.get_attribute_by_name(source_attribute.name())
+            {
+                mapping.add_attribute(source_attribute, target_attribute);
+            }
+        }
+        mapping
+    }
+
+    /// Adds a mapping from the `source` attribute to the `target` attribute to the associated `PointLayoutMapping`
+    pub fn add_attribute(&mut self, source: &PointAttributeMember, target: &PointAttributeMember) {
+        self.attributes.push((source.clone(), target.clone()));
+    }
+
+    /// Returns an iterator over all pairs of source and target attributes of the associated `PointLayoutMapping`
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = (&PointAttributeMember, &PointAttributeMember)> + '_ {
+        self.attributes
+            .iter()
+            .map(|(source, target)| (source, target))
+    }
+}
+
+/// Serialized form of a `PointLayout` that has not yet been checked for validity
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "PointLayout")]
+struct UncheckedPointLayout {
+    attributes: Vec<PointAttributeMember>,
+    size: u64,
+    alignment: u64,
+}
+
+impl From<PointLayout> for UncheckedPointLayout {
+    fn from(layout: PointLayout) -> Self {
+        Self {
+            size: layout.memory_layout.size() as u64,
+            alignment: layout.memory_layout.align() as u64,
+            attributes: layout.attributes,
+        }
+    }
+}
+
+impl TryFrom<UncheckedPointLayout> for PointLayout {
+    type Error = String;
+
+    fn try_from(layout: UncheckedPointLayout) -> Result<Self, Self::Error> {
+        let unaligned_size = Self::check_members(&layout.attributes)?;
+        let memory_layout =
+            Layout::from_size_align(layout.size as usize, layout.alignment as usize)
+                .map_err(|why| why.to_string())?;
+        if layout.size < unaligned_size || !layout.size.is_multiple_of(layout.alignment) {
+            return Err(format!(
+                "Invalid size {} for point records with {} bytes of attributes and an alignment of {}",
+                layout.size, unaligned_size, layout.alignment
+            ));
         }
+        Ok(Self {
+            attributes: layout.attributes,
+            memory_layout,
+        })
     }
 }
 
@@ -1017,7 +2520,10 @@ impl Default for PointLayout {
 #[cfg(test)]
 mod tests {
     use crate::layout::{
-        attributes::{COLOR_RGB, INTENSITY, POSITION_3D},
+        attributes::{
+            COLOR_RGB, EDGE_OF_FLIGHT_LINE, INTENSITY, NUMBER_OF_RETURNS, POINT_SOURCE_ID,
+            POSITION_3D, RETURN_NUMBER, SCAN_ANGLE, SCAN_DIRECTION_FLAG,
+        },
         PointType,
     };
 
@@ -1048,4 +2554,633 @@ mod tests {
 
         assert_eq!(expected_layout_1, TestPoint1::layout());
     }
+
+    #[derive(Debug, PointType, Copy, Clone, PartialEq)]
+    #[repr(C)]
+    struct TestPointWithCompoundTypes {
+        #[pasture(attribute = "TextureCoordinates")]
+        texture_coordinates: Vector2<f32>,
+        #[pasture(attribute = "ColorRGBNIR")]
+        color: Vector4<u16>,
+        #[pasture(BUILTIN_POSITION_3D)]
+        position: Vector3<f64>,
+        #[pasture(attribute = "Covariance")]
+        covariance: Matrix3<f64>,
+        #[pasture(attribute = "Raw")]
+        raw: [u8; 5],
+    }
+
+    #[test]
+    fn test_derive_point_type_with_compound_types() {
+        let expected_layout = PointLayout::from_attributes(&[
+            PointAttributeDefinition::custom(
+                "TextureCoordinates",
+                PointAttributeDataType::Vector(PointAttributeScalarType::F32, 2),
+            ),
+            PointAttributeDefinition::custom(
+                "ColorRGBNIR",
+                PointAttributeDataType::Vector(PointAttributeScalarType::U16, 4),
+            ),
+            POSITION_3D,
+            PointAttributeDefinition::custom(
+                "Covariance",
+                PointAttributeDataType::Matrix(PointAttributeScalarType::F64, 3, 3),
+            ),
+            PointAttributeDefinition::custom("Raw", PointAttributeDataType::ByteArray(5)),
+        ]);
+
+        let layout = TestPointWithCompoundTypes::layout();
+        assert_eq!(expected_layout, layout);
+        assert_eq!(
+            std::mem::size_of::<TestPointWithCompoundTypes>() as u64,
+            layout.size_of_point_entry()
+        );
+        assert_eq!(Some(16), layout.offset_of(&POSITION_3D));
+    }
+
+    #[test]
+    fn test_compound_datatypes() {
+        assert_eq!(
+            PointAttributeDataType::Vec3f64,
+            PointAttributeDataType::Vector(PointAttributeScalarType::F64, 3)
+        );
+        assert_eq!(
+            PointAttributeDataType::Vec3f64,
+            <Vector3<f64> as PrimitiveType>::data_type()
+        );
+        assert_ne!(
+            PointAttributeDataType::Vector(PointAttributeScalarType::F64, 3),
+            PointAttributeDataType::Vector(PointAttributeScalarType::F32, 3)
+        );
+        assert_ne!(
+            PointAttributeDataType::Vector(PointAttributeScalarType::F64, 4),
+            PointAttributeDataType::Matrix(PointAttributeScalarType::F64, 2, 2)
+        );
+
+        let mut hash_set = std::collections::HashSet::new();
+        hash_set.insert(PointAttributeDataType::Vec4u8);
+        assert!(hash_set.contains(&PointAttributeDataType::Vector(
+            PointAttributeScalarType::U8,
+            4
+        )));
+
+        assert_eq!(
+            PointAttributeDataType::Matrix(PointAttributeScalarType::F32, 4, 4),
+            <Matrix4<f32> as PrimitiveType>::data_type()
+        );
+        assert_eq!(
+            PointAttributeDataType::ByteArray(16),
+            <[u8; 16] as PrimitiveType>::data_type()
+        );
+        assert_eq!(
+            64,
+            PointAttributeDataType::Matrix(PointAttributeScalarType::F32, 4, 4).size()
+        );
+        assert_eq!(
+            4,
+            PointAttributeDataType::Matrix(PointAttributeScalarType::F32, 4, 4).min_alignment()
+        );
+        assert_eq!(
+            "Vec2<i16>",
+            format!("{}", <Vector2<i16> as PrimitiveType>::data_type())
+        );
+        assert_eq!(
+            "Mat3x3<f64>",
+            format!("{}", <Matrix3<f64> as PrimitiveType>::data_type())
+        );
+    }
+
+    #[derive(Debug, PointType, Copy, Clone, PartialEq)]
+    #[repr(C)]
+    struct TestPointWithNullableTypes {
+        #[pasture(BUILTIN_INTENSITY)]
+        intensity: u16,
+        #[pasture(BUILTIN_GPS_TIME)]
+        gps_time: Nullable<f64>,
+        #[pasture(BUILTIN_COLOR_RGB)]
+        color: Nullable<Vector3<u16>>,
+        #[pasture(attribute = "Raw")]
+        raw: Nullable<[u8; 3]>,
+    }
+
+    #[test]
+    fn test_derive_point_type_with_nullable_types() {
+        let expected_layout = PointLayout::from_attributes(&[
+            INTENSITY,
+            attributes::GPS_TIME.with_custom_datatype(PointAttributeDataType::nullable(
+                PointAttributeDataType::F64,
+            )),
+            COLOR_RGB.with_custom_datatype(PointAttributeDataType::nullable(
+                PointAttributeDataType::Vec3u16,
+            )),
+            PointAttributeDefinition::custom(
+                "Raw",
+                PointAttributeDataType::nullable(PointAttributeDataType::ByteArray(3)),
+            ),
+        ]);
+
+        let layout = TestPointWithNullableTypes::layout();
+        assert_eq!(expected_layout, layout);
+        assert_eq!(
+            std::mem::size_of::<TestPointWithNullableTypes>() as u64,
+            layout.size_of_point_entry()
+        );
+    }
+
+    fn assert_nullable_layout<T: NullableValue + PartialEq + std::fmt::Debug>(value: T) {
+        let datatype = <Nullable<T> as PrimitiveType>::data_type();
+        assert_eq!(std::mem::size_of::<Nullable<T>>() as u64, datatype.size());
+        assert_eq!(
+            std::mem::align_of::<Nullable<T>>() as u64,
+            datatype.min_alignment()
+        );
+
+        let value_offset = datatype.min_alignment() as usize;
+        let some = Nullable::some(value);
+        let some_bytes = unsafe { crate::util::view_raw_bytes(&some) };
+        assert_eq!(1, some_bytes[0]);
+        assert!(some_bytes[1..value_offset].iter().all(|&byte| byte == 0));
+        assert_eq!(value, unsafe {
+            (some_bytes[value_offset..].as_ptr() as *const T).read_unaligned()
+        });
+        assert_eq!(Some(value), some.get());
+
+        let none = Nullable::<T>::none();
+        let none_bytes = unsafe { crate::util::view_raw_bytes(&none) };
+        assert!(none_bytes.iter().all(|&byte| byte == 0));
+        assert_eq!(None, none.get());
+    }
+
+    #[test]
+    fn test_nullable_layout() {
+        assert_nullable_layout(42_u8);
+        assert_nullable_layout(-42_i16);
+        assert_nullable_layout(42_u32);
+        assert_nullable_layout(-42_i64);
+        assert_nullable_layout(1.5_f32);
+        assert_nullable_layout(1.5_f64);
+        assert_nullable_layout(Vector3::new(1_u8, 2, 3));
+        assert_nullable_layout(Vector3::new(1_u16, 2, 3));
+        assert_nullable_layout(Vector4::new(1_u16, 2, 3, 4));
+        assert_nullable_layout(Vector2::new(1.5_f32, 2.5));
+        assert_nullable_layout(Vector3::new(1.5, 2.5, 3.5));
+        assert_nullable_layout(Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0));
+        assert_nullable_layout([1_u8, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Bool can't be made nullable")]
+    fn test_nullable_bool() {
+        PointAttributeDataType::nullable(PointAttributeDataType::Bool);
+    }
+
+    #[test]
+    fn test_layout_with_runtime_attribute_names() {
+        let names_from_file = vec!["Reflectance".to_owned(), "Deviation".to_owned()];
+        let attributes = names_from_file
+            .iter()
+            .map(|name| {
+                PointAttributeDefinition::custom_owned(name.as_str(), PointAttributeDataType::F32)
+            })
+            .collect::<Vec<_>>();
+        drop(names_from_file);
+
+        let mut layout = PointLayout::from_attributes(&attributes);
+        layout.add_attribute(INTENSITY, FieldAlignment::Default);
+
+        assert_eq!(3, layout.attributes().count());
+        assert!(layout.has_attribute_with_name("Reflectance"));
+        assert!(layout.has_attribute(&PointAttributeDefinition::custom(
+            "Deviation",
+            PointAttributeDataType::F32
+        )));
+        assert_eq!(
+            Some(4),
+            layout
+                .get_attribute_by_name("Deviation")
+                .map(|attribute| attribute.offset())
+        );
+        assert!(layout.has_attribute(&INTENSITY));
+    }
+
+    #[test]
+    fn test_layout_with_bit_fields() {
+        let layout = PointLayout::from_members_and_alignment(
+            &[
+                INTENSITY.at_offset_in_type(0),
+                RETURN_NUMBER.at_bit_offset_in_type(2, 0, 3),
+                NUMBER_OF_RETURNS.at_bit_offset_in_type(2, 3, 3),
+                SCAN_DIRECTION_FLAG.at_bit_offset_in_type(2, 6, 1),
+                EDGE_OF_FLIGHT_LINE.at_bit_offset_in_type(2, 7, 1),
+                POINT_SOURCE_ID
+                    .with_custom_datatype(PointAttributeDataType::U16)
+                    .at_bit_offset_in_type(3, 2, 10),
+            ],
+            1,
+        );
+        assert_eq!(5, layout.size_of_point_entry());
+
+        let mut point_record = [0; 5];
+        let return_number = layout.get_attribute(&RETURN_NUMBER).unwrap();
+        let edge_of_flight_line = layout.get_attribute(&EDGE_OF_FLIGHT_LINE).unwrap();
+        let point_source_id = layout.get_attribute(&POINT_SOURCE_ID).unwrap();
+        return_number.write_to_point_record(&[5], &mut point_record);
+        edge_of_flight_line.write_to_point_record(&[1], &mut point_record);
+        point_source_id.write_to_point_record(&1001_u16.to_ne_bytes(), &mut point_record);
+        assert_eq!([0, 0, 0b1000_0101, 0b1010_0100, 0b0000_1111], point_record);
+
+        let mut value = [0; 2];
+        point_source_id.read_from_point_record(&point_record, &mut value);
+        assert_eq!(1001, u16::from_ne_bytes(value));
+
+        let mut flag = [0; 1];
+        layout
+            .get_attribute(&SCAN_DIRECTION_FLAG)
+            .unwrap()
+            .read_from_point_record(&point_record, &mut flag);
+        assert_eq!([0], flag);
+    }
+
+    #[test]
+    #[should_panic(expected = "non-overlapping")]
+    fn test_overlapping_bit_fields() {
+        PointLayout::from_members_and_alignment(
+            &[
+                RETURN_NUMBER.at_bit_offset_in_type(0, 0, 4),
+                NUMBER_OF_RETURNS.at_bit_offset_in_type(0, 3, 3),
+            ],
+            1,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "can't be used for a bit field")]
+    fn test_bit_field_with_invalid_datatype() {
+        SCAN_ANGLE.at_bit_offset_in_type(0, 0, 4);
+    }
+
+    #[test]
+    fn test_quantized_attributes() {
+        let quantization = PointAttributeQuantization::per_component(&[0.5, 0.25], &[1.0, 2.0]);
+        let texture_coordinates = PointAttributeDefinition::custom(
+            "TextureCoordinates",
+            PointAttributeDataType::vector(PointAttributeScalarType::U16, 2),
+        );
+        let quantized_texture_coordinates =
+            texture_coordinates.with_quantization(quantization.clone());
+        assert_ne!(texture_coordinates, quantized_texture_coordinates);
+        assert_eq!(
+            Some(&quantization),
+            quantized_texture_coordinates.quantization()
+        );
+
+        // Layouts keep the quantization of their attributes, but attribute lookup only depends on name and datatype
+        let layout = PointLayout::from_attributes(&[quantized_texture_coordinates.clone()]);
+        let member = layout.get_attribute(&texture_coordinates).unwrap();
+        assert_eq!(Some(&quantization), member.quantization());
+        assert_eq!(
+            quantized_texture_coordinates,
+            PointAttributeDefinition::from(member)
+        );
+        assert_eq!(
+            None,
+            quantized_texture_coordinates
+                .with_custom_datatype(PointAttributeDataType::F32)
+                .quantization()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "has 2 components")]
+    fn test_quantization_with_wrong_number_of_components() {
+        INTENSITY.with_quantization(PointAttributeQuantization::per_component(
+            &[1.0, 2.0],
+            &[0.0, 0.0],
+        ));
+    }
+
+    #[test]
+    fn test_layout_subset_and_reorder() {
+        let layout = PointLayout::from_attributes(&[POSITION_3D, INTENSITY, POINT_SOURCE_ID]);
+        let (subset, mapping) = layout.subset(&[POINT_SOURCE_ID, POSITION_3D]);
+        assert_eq!(
+            PointLayout::from_attributes(&[POINT_SOURCE_ID, POSITION_3D]),
+            subset
+        );
+        let offsets = mapping
+            .attributes()
+            .map(|(source, target)| (source.name(), source.offset(), target.offset()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![("PointSourceID", 26, 0), ("Position3D", 0, 8)],
+            offsets
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "is not part of this PointLayout")]
+    fn test_layout_subset_with_unknown_attribute() {
+        PointLayout::from_attributes(&[POSITION_3D]).subset(&[INTENSITY]);
+    }
+
+    #[test]
+    fn test_layout_union() {
+        let layout_a = PointLayout::from_attributes(&[INTENSITY, POSITION_3D]);
+        let layout_b = PointLayout::from_attributes(&[
+            POSITION_3D.with_custom_datatype(PointAttributeDataType::Vec3f32),
+            COLOR_RGB,
+        ]);
+        let (union, mapping_a, mapping_b) = layout_a.union(&layout_b);
+        assert_eq!(
+            PointLayout::from_attributes(&[INTENSITY, POSITION_3D, COLOR_RGB]),
+            union
+        );
+        assert_eq!(PointLayoutMapping::by_name(&layout_a, &union), mapping_a);
+        let targets_b = mapping_b
+            .attributes()
+            .map(|(_, target)| target.clone().into())
+            .collect::<Vec<PointAttributeDefinition>>();
+        assert_eq!(vec![POSITION_3D, COLOR_RGB], targets_b);
+    }
+
+    #[test]
+    fn test_layout_rename_change_datatype_and_repack() {
+        let layout = PointLayout::from_members_and_alignment(
+            &[
+                INTENSITY.at_offset_in_type(0),
+                RETURN_NUMBER.at_bit_offset_in_type(2, 0, 4),
+                POSITION_3D.at_offset_in_type(3),
+            ],
+            1,
+        );
+
+        let (renamed_layout, mapping) =
+            layout.with_renamed_attribute(&RETURN_NUMBER, format!("Return{}", "Index"));
+        assert_eq!(
+            Some(
+                &RETURN_NUMBER
+                    .with_name("ReturnIndex")
+                    .at_bit_offset_in_type(2, 0, 4)
+            ),
+            renamed_layout.get_attribute_by_name("ReturnIndex")
+        );
+        assert_eq!(
+            Some(PointAttributeBitField::new(0, 4)),
+            renamed_layout.at(1).bit_field()
+        );
+        assert_eq!(
+            layout.size_of_point_entry(),
+            renamed_layout.size_of_point_entry()
+        );
+        assert_eq!(3, mapping.attributes().count());
+
+        let (changed_layout, _) =
+            layout.with_attribute_datatype(&INTENSITY, PointAttributeDataType::F64);
+        assert_eq!(
+            PointLayout::from_attributes(&[
+                INTENSITY.with_custom_datatype(PointAttributeDataType::F64),
+                RETURN_NUMBER,
+                POSITION_3D
+            ]),
+            changed_layout
+        );
+        assert_eq!(
+            vec![0, 8, 16],
+            changed_layout
+                .attributes()
+                .map(|a| a.offset())
+                .collect::<Vec<_>>()
+        );
+
+        let (repacked_layout, _) = layout.repacked(FieldAlignment::Default);
+        assert_eq!(None, repacked_layout.at(1).bit_field());
+        assert_eq!(
+            vec![0, 2, 8],
+            repacked_layout
+                .attributes()
+                .map(|a| a.offset())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(32, repacked_layout.size_of_point_entry());
+    }
+
+    #[test]
+    #[should_panic(expected = "is already present")]
+    fn test_layout_rename_to_existing_name() {
+        PointLayout::from_attributes(&[POSITION_3D, INTENSITY])
+            .with_renamed_attribute(&INTENSITY, "Position3D");
+    }
+
+    #[test]
+    fn test_layout_with_size_of_point_entry() {
+        let layout = PointLayout::from_attributes(&[POSITION_3D, INTENSITY]);
+        let padded_layout = layout.with_size_of_point_entry(40);
+        assert_eq!(40, padded_layout.size_of_point_entry());
+        assert_eq!(8, padded_layout.alignment_of_point_entry());
+        assert!(layout.compare_without_offsets(&padded_layout));
+        assert_ne!(layout, padded_layout);
+
+        // New attributes are placed after the last attribute, so they can use the padding bytes
+        let mut extended_layout = padded_layout.clone();
+        extended_layout.add_attribute(attributes::CLASSIFICATION, FieldAlignment::Default);
+        assert_eq!(26, extended_layout.at(2).offset());
+        assert_eq!(40, extended_layout.size_of_point_entry());
+    }
+
+    #[test]
+    #[should_panic(expected = "no multiple of the alignment")]
+    fn test_layout_with_misaligned_size_of_point_entry() {
+        PointLayout::from_attributes(&[POSITION_3D, INTENSITY]).with_size_of_point_entry(36);
+    }
+
+    fn layout_with_all_kinds_of_attributes() -> PointLayout {
+        PointLayout::from_members_and_alignment(
+            &[
+                POSITION_3D
+                    .with_custom_datatype(PointAttributeDataType::vector(
+                        PointAttributeScalarType::I32,
+                        3,
+                    ))
+                    .with_quantization(PointAttributeQuantization::per_component(
+                        &[0.01, 0.01, 0.001],
+                        &[100.0, 200.0, 0.0],
+                    ))
+                    .at_offset_in_type(0),
+                INTENSITY.at_offset_in_type(12),
+                RETURN_NUMBER.at_bit_offset_in_type(14, 0, 4),
+                NUMBER_OF_RETURNS.at_bit_offset_in_type(14, 4, 4),
+                PointAttributeMember::custom_owned(
+                    format!("Extra{}", 1),
+                    PointAttributeDataType::ByteArray(3),
+                    15,
+                ),
+                PointAttributeDefinition::custom(
+                    "Transform",
+                    PointAttributeDataType::Matrix(PointAttributeScalarType::F32, 3, 4),
+                )
+                .at_offset_in_type(20),
+                PointAttributeDefinition::custom(
+                    "MaybeTime",
+                    PointAttributeDataType::nullable(PointAttributeDataType::F64),
+                )
+                .at_offset_in_type(72),
+            ],
+            8,
+        )
+    }
+
+    fn assert_layouts_match(expected: &PointLayout, actual: &PointLayout) {
+        assert!(expected.compare_without_offsets(actual));
+        assert_eq!(expected, actual);
+        assert_eq!(expected.size_of_point_entry(), actual.size_of_point_entry());
+        for (expected_member, actual_member) in expected.attributes().zip(actual.attributes()) {
+            assert_eq!(expected_member.offset(), actual_member.offset());
+            assert_eq!(expected_member.bit_field(), actual_member.bit_field());
+        }
+    }
+
+    #[test]
+    fn test_serialize_layout_as_json() {
+        let layout = layout_with_all_kinds_of_attributes();
+        let json = serde_json::to_string(&layout).unwrap();
+        let deserialized_layout: PointLayout = serde_json::from_str(&json).unwrap();
+        assert_layouts_match(&layout, &deserialized_layout);
+
+        let packed_layout = PointLayout::from_attributes_packed(&[INTENSITY, POSITION_3D], 1);
+        let json = serde_json::to_string(&packed_layout).unwrap();
+        let deserialized_layout: PointLayout = serde_json::from_str(&json).unwrap();
+        assert_layouts_match(&packed_layout, &deserialized_layout);
+    }
+
+    #[test]
+    fn test_serialize_layout_as_binary() {
+        let layout = layout_with_all_kinds_of_attributes();
+        let bytes = bincode::serialize(&layout).unwrap();
+        let deserialized_layout: PointLayout = bincode::deserialize(&bytes).unwrap();
+        assert_layouts_match(&layout, &deserialized_layout);
+    }
+
+    #[test]
+    fn test_serialize_datatypes_and_members() {
+        for datatype in layout_with_all_kinds_of_attributes()
+            .attributes()
+            .map(|a| a.datatype())
+            .chain(std::iter::once(PointAttributeDataType::Vec3u16))
+        {
+            let json = serde_json::to_string(&datatype).unwrap();
+            assert_eq!(
+                datatype,
+                serde_json::from_str::<PointAttributeDataType>(&json).unwrap()
+            );
+        }
+
+        let member = RETURN_NUMBER.at_bit_offset_in_type(14, 0, 4);
+        let bytes = bincode::serialize(&member).unwrap();
+        let deserialized_member: PointAttributeMember = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(member, deserialized_member);
+        assert_eq!(member.offset(), deserialized_member.offset());
+        assert_eq!(member.bit_field(), deserialized_member.bit_field());
+    }
+
+    #[test]
+    fn test_deserialize_invalid_layouts() {
+        let mut json =
+            serde_json::to_value(&PointLayout::from_attributes(&[POSITION_3D, INTENSITY])).unwrap();
+        json["attributes"][1]["offset"] = 20.into();
+        assert!(serde_json::from_value::<PointLayout>(json.clone()).is_err());
+
+        json["attributes"][1]["offset"] = 24.into();
+        json["size"] = 30.into();
+        assert!(serde_json::from_value::<PointLayout>(json.clone()).is_err());
+
+        json["size"] = 32.into();
+        json["attributes"][1]["datatype"] = serde_json::json!({ "Nullable": "Bool" });
+        assert!(serde_json::from_value::<PointLayout>(json.clone()).is_err());
+
+        json["attributes"][1]["datatype"] = "U8".into();
+        json["attributes"][1]["bit_field"] = serde_json::json!({ "bit_offset": 4, "bit_width": 9 });
+        assert!(serde_json::from_value::<PointLayout>(json.clone()).is_err());
+
+        json["attributes"][1]["bit_field"] = serde_json::Value::Null;
+        assert!(serde_json::from_value::<PointLayout>(json.clone()).is_ok());
+
+        for (datatype, empty_datatype) in [
+            (
+                serde_json::json!({ "ByteArray": 2 }),
+                serde_json::json!({ "ByteArray": 0 }),
+            ),
+            (
+                serde_json::json!({ "Vector": ["U16", 2] }),
+                serde_json::json!({ "Vector": ["U16", 0] }),
+            ),
+            (
+                serde_json::json!({ "Matrix": ["F32", 1, 2] }),
+                serde_json::json!({ "Matrix": ["F32", 1, 0] }),
+            ),
+            (
+                serde_json::json!({ "Nullable": { "ByteArray": 2 } }),
+                serde_json::json!({ "Nullable": { "ByteArray": 0 } }),
+            ),
+        ] {
+            let mut json = json.clone();
+            json["attributes"][1]["datatype"] = datatype.clone();
+            assert!(serde_json::from_value::<PointLayout>(json.clone()).is_ok());
+            json["attributes"][1]["datatype"] = empty_datatype.clone();
+            assert!(serde_json::from_value::<PointLayout>(json.clone()).is_err());
+            assert!(
+                serde_json::from_value::<PointAttributeMember>(json["attributes"][1].clone())
+                    .is_err()
+            );
+
+            let mut attribute =
+                serde_json::json!({ "name": "Custom", "datatype": datatype, "quantization": null });
+            assert!(serde_json::from_value::<PointAttributeDefinition>(attribute.clone()).is_ok());
+            attribute["datatype"] = empty_datatype;
+            assert!(serde_json::from_value::<PointAttributeDefinition>(attribute).is_err());
+        }
+    }
+
+    #[test]
+    fn test_zero_size_datatypes_are_rejected() {
+        let empty_datatypes = [
+            PointAttributeDataType::ByteArray(0),
+            PointAttributeDataType::Vector(PointAttributeScalarType::F64, 0),
+            PointAttributeDataType::Matrix(PointAttributeScalarType::U8, 0, 2),
+            PointAttributeDataType::Matrix(PointAttributeScalarType::U8, 2, 0),
+        ];
+        for datatype in empty_datatypes.iter() {
+            assert!(datatype.check_size().is_err());
+            let add_attribute = std::panic::catch_unwind(|| {
+                PointLayout::from_attributes(&[
+                    PointAttributeDefinition::custom("Empty", *datatype),
+                    INTENSITY,
+                ])
+            });
+            assert!(add_attribute.is_err());
+            let from_members = std::panic::catch_unwind(|| {
+                PointLayout::from_members_and_alignment(
+                    &[PointAttributeMember::custom("Empty", *datatype, 0)],
+                    1,
+                )
+            });
+            assert!(from_members.is_err());
+            let nullable = std::panic::catch_unwind(|| PointAttributeDataType::nullable(*datatype));
+            assert!(nullable.is_err());
+        }
+
+        assert!(std::panic::catch_unwind(|| <[u8; 0] as PrimitiveType>::data_type()).is_err());
+        assert!(std::panic::catch_unwind(|| PointAttributeDataType::byte_array(0)).is_err());
+        assert!(std::panic::catch_unwind(|| {
+            PointAttributeDataType::vector(PointAttributeScalarType::U16, 0)
+        })
+        .is_err());
+        assert!(std::panic::catch_unwind(|| {
+            PointAttributeDataType::matrix(PointAttributeScalarType::F32, 0, 3)
+        })
+        .is_err());
+        assert_eq!(
+            PointAttributeDataType::Matrix(PointAttributeScalarType::F32, 2, 3),
+            PointAttributeDataType::matrix(PointAttributeScalarType::F32, 2, 3)
+        );
+    }
 }
@@ -1,14 +1,12 @@
 extern crate proc_macro;
-use std::collections::HashSet;
-
 //use anyhow::{anyhow, bail, Result};
 use layout::{get_struct_member_layout, StructMemberLayout};
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::DeriveInput;
 use syn::{
-    parse_macro_input, Attribute, Data, Error, Field, Fields, GenericArgument, Ident, Lit,
-    NestedMeta, PathArguments, Result, Type, TypePath,
+    parse_macro_input, Attribute, Data, Error, Expr, ExprLit, Field, Fields, GenericArgument,
+    Ident, Lit, NestedMeta, PathArguments, Result, Type, TypeArray, TypePath,
 };
 
 mod layout;
@@ -25,11 +23,10 @@ enum PasturePrimitiveType {
     F32,
     F64,
     Bool,
-    Vec3u8,
-    Vec3u16,
-    Vec3f32,
-    Vec3f64,
-    Vec4u8,
+    Vector(Box<PasturePrimitiveType>, u8),
+    Matrix(Box<PasturePrimitiveType>, u8, u8),
+    ByteArray(u32),
+    Nullable(Box<PasturePrimitiveType>),
 }
 
 impl PasturePrimitiveType {
@@ -46,11 +43,10 @@ impl PasturePrimitiveType {
             PasturePrimitiveType::F32 => 4,
             PasturePrimitiveType::F64 => 8,
             PasturePrimitiveType::Bool => 1,
-            PasturePrimitiveType::Vec3u8 => 1,
-            PasturePrimitiveType::Vec3u16 => 2,
-            PasturePrimitiveType::Vec3f32 => 4,
-            PasturePrimitiveType::Vec3f64 => 8,
-            &PasturePrimitiveType::Vec4u8 => 1,
+            PasturePrimitiveType::Vector(scalar, _) => scalar.min_alignment(),
+            PasturePrimitiveType::Matrix(scalar, _, _) => scalar.min_alignment(),
+            PasturePrimitiveType::ByteArray(_) => 1,
+            PasturePrimitiveType::Nullable(value_type) => value_type.min_alignment(),
         }
     }
 
@@ -67,11 +63,15 @@ impl PasturePrimitiveType {
             PasturePrimitiveType::F32 => 4,
             PasturePrimitiveType::F64 => 8,
             PasturePrimitiveType::Bool => 1,
-            PasturePrimitiveType::Vec3u8 => 3,
-            PasturePrimitiveType::Vec3u16 => 6,
-            PasturePrimitiveType::Vec3f32 => 12,
-            PasturePrimitiveType::Vec3f64 => 24,
-            &PasturePrimitiveType::Vec4u8 => 4,
+            PasturePrimitiveType::Vector(scalar, components) => scalar.size() * *components as u64,
+            PasturePrimitiveType::Matrix(scalar, rows, columns) => {
+                scalar.size() * *rows as u64 * *columns as u64
+            }
+            PasturePrimitiveType::ByteArray(length) => *length as u64,
+            // Nullable<T> stores a validity byte and padding up to the alignment of T in front of the value
+            PasturePrimitiveType::Nullable(value_type) => {
+                value_type.min_alignment() + value_type.size()
+            }
         }
     }
 
@@ -90,21 +90,53 @@ impl PasturePrimitiveType {
             PasturePrimitiveType::Bool => {
                 quote! {pasture_core::layout::PointAttributeDataType::Bool}
             }
-            PasturePrimitiveType::Vec3u8 => {
-                quote! {pasture_core::layout::PointAttributeDataType::Vec3u8}
+            PasturePrimitiveType::Vector(scalar, components) => {
+                let scalar = scalar.as_scalar_token_stream();
+                quote! {pasture_core::layout::PointAttributeDataType::vector(#scalar, #components)}
+            }
+            PasturePrimitiveType::Matrix(scalar, rows, columns) => {
+                let scalar = scalar.as_scalar_token_stream();
+                quote! {pasture_core::layout::PointAttributeDataType::Matrix(#scalar, #rows, #columns)}
+            }
+            PasturePrimitiveType::ByteArray(length) => {
+                quote! {pasture_core::layout::PointAttributeDataType::ByteArray(#length)}
+            }
+            PasturePrimitiveType::Nullable(value_type) => {
+                let value_type = value_type.as_token_stream();
+                quote! {pasture_core::layout::PointAttributeDataType::nullable(#value_type)}
+            }
+        }
+    }
+
+    fn as_scalar_token_stream(&self) -> quote::__private::TokenStream {
+        match self {
+            PasturePrimitiveType::U8 => quote! {pasture_core::layout::PointAttributeScalarType::U8},
+            PasturePrimitiveType::I8 => quote! {pasture_core::layout::PointAttributeScalarType::I8},
+            PasturePrimitiveType::U16 => {
+                quote! {pasture_core::layout::PointAttributeScalarType::U16}
+            }
+            PasturePrimitiveType::I16 => {
+                quote! {pasture_core::layout::PointAttributeScalarType::I16}
+            }
+            PasturePrimitiveType::U32 => {
+                quote! {pasture_core::layout::PointAttributeScalarType::U32}
+            }
+            PasturePrimitiveType::I32 => {
+                quote! {pasture_core::layout::PointAttributeScalarType::I32}
             }
-            PasturePrimitiveType::Vec3u16 => {
-                quote! {pasture_core::layout::PointAttributeDataType::Vec3u16}
+            PasturePrimitiveType::U64 => {
+                quote! {pasture_core::layout::PointAttributeScalarType::U64}
             }
-            PasturePrimitiveType::Vec3f32 => {
-                quote! {pasture_core::layout::PointAttributeDataType::Vec3f32}
+            PasturePrimitiveType::I64 => {
+                quote! {pasture_core::layout::PointAttributeScalarType::I64}
             }
-            PasturePrimitiveType::Vec3f64 => {
-                quote! {pasture_core::layout::PointAttributeDataType::Vec3f64}
+            PasturePrimitiveType::F32 => {
+                quote! {pasture_core::layout::PointAttributeScalarType::F32}
             }
-            PasturePrimitiveType::Vec4u8 => {
-                quote! {pasture_core::layout::PointAttributeDataType::Vec4u8}
+            PasturePrimitiveType::F64 => {
+                quote! {pasture_core::layout::PointAttributeScalarType::F64}
             }
+            _ => unreachable!("Only scalar types are valid components of vector and matrix types"),
         }
     }
 }
@@ -131,17 +163,24 @@ fn get_primitive_type_for_ident_type(ident: &Ident) -> Result<PasturePrimitiveTy
 }
 
 fn get_primitive_type_for_non_ident_type(type_path: &TypePath) -> Result<PasturePrimitiveType> {
-    // Path should have an ident (Vector3, Vector4, ...), as well as one generic argument
-    let valid_idents: HashSet<_> = ["Vector3", "Vector4"].iter().collect();
-
+    // Path should have an ident (Vector3, Matrix3, Nullable, ...), as well as one generic argument
     let path_segment = type_path
         .path
         .segments
         .first()
         .ok_or_else(|| Error::new_spanned(&type_path.path, "Invalid type"))?;
-    if !valid_idents.contains(&path_segment.ident.to_string().as_str()) {
-        return Err(Error::new_spanned(&path_segment.ident, "Invalid type"));
-    }
+    let path_ident = path_segment.ident.to_string();
+    let make_compound_type = |scalar_type: PasturePrimitiveType| match path_ident.as_str() {
+        "Vector2" => Ok(PasturePrimitiveType::Vector(Box::new(scalar_type), 2)),
+        "Vector3" => Ok(PasturePrimitiveType::Vector(Box::new(scalar_type), 3)),
+        "Vector4" => Ok(PasturePrimitiveType::Vector(Box::new(scalar_type), 4)),
+        "Vector5" => Ok(PasturePrimitiveType::Vector(Box::new(scalar_type), 5)),
+        "Vector6" => Ok(PasturePrimitiveType::Vector(Box::new(scalar_type), 6)),
+        "Matrix2" => Ok(PasturePrimitiveType::Matrix(Box::new(scalar_type), 2, 2)),
+        "Matrix3" => Ok(PasturePrimitiveType::Matrix(Box::new(scalar_type), 3, 3)),
+        "Matrix4" => Ok(PasturePrimitiveType::Matrix(Box::new(scalar_type), 4, 4)),
+        _ => Err(Error::new_spanned(&path_segment.ident, "Invalid type")),
+    };
 
     let path_arg = match &path_segment.arguments {
         PathArguments::AngleBracketed(arg) => arg,
@@ -158,6 +197,16 @@ fn get_primitive_type_for_non_ident_type(type_path: &TypePath) -> Result<Pasture
         _ => return Err(Error::new_spanned(first_generic_arg, "Invalid type")),
     };
 
+    if path_ident == "Nullable" {
+        return match type_to_primitive_type(type_arg)? {
+            PasturePrimitiveType::Bool | PasturePrimitiveType::Nullable(_) => Err(Error::new_spanned(
+                type_arg,
+                "Nullable<T> is supported for all Pasture primitive types except bool and Nullable<T>",
+            )),
+            value_type => Ok(PasturePrimitiveType::Nullable(Box::new(value_type))),
+        };
+    }
+
     let type_path = match type_arg {
         Type::Path(p) => p,
         _ => return Err(Error::new_spanned(type_arg, "Invalid type")),
@@ -165,33 +214,51 @@ fn get_primitive_type_for_non_ident_type(type_path: &TypePath) -> Result<Pasture
 
     match type_path.path.get_ident() {
         Some(ident) => {
-            // Not ALL primitive types are supported as generic arguments for Vector3
+            // Vectors and matrices are supported for all primitive types except bool
             let type_name = ident.to_string();
-            match path_segment.ident.to_string().as_str() {
-                "Vector3" => match type_name.as_str() {
-                    "u8" => Ok(PasturePrimitiveType::Vec3u8),
-                    "u16" => Ok(PasturePrimitiveType::Vec3u16),
-                    "f32" => Ok(PasturePrimitiveType::Vec3f32),
-                    "f64" => Ok(PasturePrimitiveType::Vec3f64),
-                    _ => Err(Error::new_spanned(
-                        ident,
-                        format!("Vector3<{}> is no valid Pasture primitive type. Vector3 is supported, but only for generic argument(s) u8, u16, f32 or f64", type_name),
-                    ))
-                },
-                "Vector4" => match type_name.as_str() {
-                    "u8" => Ok(PasturePrimitiveType::Vec4u8),
-                    _ => Err(Error::new_spanned(
-                        ident,
-                        format!("Vector4<{}> is no valid Pasture primitive type. Vector4 is supported, but only for generic argument(s) u8", type_name),
-                    ))
-                },
-                _ => Err(Error::new_spanned(ident, format!("Invalid type"))),
+            match get_primitive_type_for_ident_type(ident)? {
+                PasturePrimitiveType::Bool => Err(Error::new_spanned(
+                    ident,
+                    format!("{}<{}> is no valid Pasture primitive type. {} is supported, but only for generic argument(s) u8, u16, u32, u64, i8, i16, i32, i64, f32 or f64", path_ident, type_name, path_ident),
+                )),
+                scalar_type => make_compound_type(scalar_type),
             }
         }
         None => Err(Error::new_spanned(&type_path.path, "Invalid type")),
     }
 }
 
+fn type_array_to_primitive_type(type_array: &TypeArray) -> Result<PasturePrimitiveType> {
+    // Only raw byte arrays ([u8; N]) with a literal length are supported
+    let is_byte_array = match type_array.elem.as_ref() {
+        Type::Path(type_path) => type_path.path.is_ident("u8"),
+        _ => false,
+    };
+    if !is_byte_array {
+        return Err(Error::new_spanned(
+            &type_array.elem,
+            "Arrays in a struct with #[derive(PointType)] are only supported for the u8 type",
+        ));
+    }
+
+    match &type_array.len {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(length),
+            ..
+        }) => match length.base10_parse()? {
+            0 => Err(Error::new_spanned(
+                length,
+                "Arrays in a struct with #[derive(PointType)] must not be empty",
+            )),
+            length => Ok(PasturePrimitiveType::ByteArray(length)),
+        },
+        bad => Err(Error::new_spanned(
+            bad,
+            "Arrays in a struct with #[derive(PointType)] must have a literal length",
+        )),
+    }
+}
+
 fn type_path_to_primitive_type(type_path: &TypePath) -> Result<PasturePrimitiveType> {
     if type_path.qself.is_some() {
         return Err(Error::new_spanned(
@@ -212,6 +279,14 @@ fn type_path_to_primitive_type(type_path: &TypePath) -> Result<PasturePrimitiveT
     // Ok(gen)
 }
 
+fn type_to_primitive_type(ty: &Type) -> Result<PasturePrimitiveType> {
+    match ty {
+        Type::Path(type_path) => type_path_to_primitive_type(type_path),
+        Type::Array(type_array) => type_array_to_primitive_type(type_array),
+        bad => Err(Error::new_spanned(bad, "Invalid type in PointType struct")),
+    }
+}
+
 fn get_attribute_name_from_field(field: &Field) -> Result<String> {
     if field.attrs.len() != 1 {
         return Err(Error::new_spanned(
@@ -313,20 +388,14 @@ struct FieldLayoutDescription {
 fn get_field_layout_descriptions(fields: &Fields) -> Result<Vec<FieldLayoutDescription>> {
     fields
         .iter()
-        .map(|field| match field.ty {
-            Type::Path(ref type_path) => {
-                let primitive_type = type_path_to_primitive_type(type_path)?;
-                let attribute_name = get_attribute_name_from_field(field)?;
-
-                Ok(FieldLayoutDescription {
-                    attribute_name,
-                    primitive_type,
-                })
-            }
-            ref bad => Err(Error::new_spanned(
-                bad,
-                format!("Invalid type in PointType struct"),
-            )),
+        .map(|field| {
+            let primitive_type = type_to_primitive_type(&field.ty)?;
+            let attribute_name = get_attribute_name_from_field(field)?;
+
+            Ok(FieldLayoutDescription {
+                attribute_name,
+                primitive_type,
+            })
         })
         .collect::<Result<Vec<FieldLayoutDescription>>>()
 }
@@ -390,6 +459,7 @@ fn calculate_offsets_and_alignment(
 /// Any that that wants to implement `PointType` using this `derive` macro must fulfill the following requirements:
 /// - It must be at least one of `#[repr(C)]` and `#[repr(packed)]`
 /// - All its members may only be [Pasture primitive types](pasture_core::layout::PointAttributeDataType)
+/// - Members can be optional by wrapping them in [`Nullable<T>`](pasture_core::layout::Nullable), which is supported for all primitive types except `bool` (see [Nullable](pasture_core::layout::PointAttributeDataType::Nullable))
 /// - Each member must contain an attribute `#[pasture(X)]`, where `X` is either one of the builtin attributes explained below, or `attribute = "name"` for a custom attribute named `name`
 /// - No two members may share the same attribute name
 ///
@@ -5,8 +5,8 @@ use pasture_core::layout::attributes;
 use pasture_core::meta::Metadata;
 use pasture_core::{containers::PointBufferWriteable, layout::PointLayout};
 use pasture_core::{
-    containers::{InterleavedVecPointStorage, PointBuffer},
-    layout::PointAttributeDefinition,
+    containers::{InterleavedPointView, InterleavedVecPointStorage, PointBuffer},
+    layout::{conversion::RawPointConverter, PointAttributeDefinition},
 };
 use std::collections::HashSet;
 use std::io::{BufRead, Read};
@@ -289,6 +289,44 @@ impl<T: Read + BufRead> RawAsciiReader<T> {
         Ok(())
     }
 
+    /// Pushes the `points_chunk` in the given `layout` into `point_buffer`, converting it into the layout of `point_buffer`
+    /// using `converter` if necessary
+    fn push_chunk(
+        point_buffer: &mut dyn PointBufferWriteable,
+        points_chunk: &[u8],
+        layout: &PointLayout,
+        converter: Option<&RawPointConverter>,
+        converted_chunk: &mut Vec<u8>,
+    ) {
+        match converter {
+            Some(converter) => {
+                let source_point_size = layout.size_of_point_entry() as usize;
+                let num_points = points_chunk.len() / source_point_size;
+                let target_layout = point_buffer.point_layout().clone();
+                let target_point_size = target_layout.size_of_point_entry() as usize;
+                converted_chunk.clear();
+                converted_chunk.resize(num_points * target_point_size, 0);
+                unsafe {
+                    converter.convert_batch(
+                        points_chunk,
+                        source_point_size,
+                        converted_chunk,
+                        target_point_size,
+                        num_points,
+                    );
+                }
+                point_buffer.push(&InterleavedPointView::from_raw_slice(
+                    converted_chunk,
+                    target_layout,
+                ));
+            }
+            None => point_buffer.push(&InterleavedPointView::from_raw_slice(
+                points_chunk,
+                layout.clone(),
+            )),
+        }
+    }
+
     fn parse_string<V: FromStr>(value_str: &str) -> Result<V, anyhow::Error> {
         value_str.parse::<V>().map_err(|_| {
             anyhow::anyhow!(
@@ -324,18 +362,53 @@ impl<T: Read + BufRead> PointReader for RawAsciiReader<T> {
         point_buffer: &mut dyn PointBufferWriteable,
         count: usize,
     ) -> Result<usize> {
-        let layout = point_buffer.point_layout().clone();
-        let mut temp_point = UntypedPointBuffer::new(&layout);
+        // Points are parsed in the default layout and collected in chunks. If the layout of `point_buffer` is different,
+        // each chunk is converted into this layout at once
+        let chunk_size = 50_000;
+        let target_layout = point_buffer.point_layout().clone();
+        let converter = if target_layout != self.point_layout {
+            Some(RawPointConverter::try_from_to(
+                &self.point_layout,
+                &target_layout,
+            )?)
+        } else {
+            None
+        };
+        let point_size = self.point_layout.size_of_point_entry() as usize;
+        let mut points_chunk: Vec<u8> = Vec::with_capacity(point_size * chunk_size);
+        let mut converted_chunk: Vec<u8> = vec![];
+        let mut temp_point = UntypedPointBuffer::new(&self.point_layout);
+        let mut points_read = 0;
         //read line by line
         for (index, line) in (&mut self.reader).lines().take(count).enumerate() {
             let line = line?;
             //parse the line in an untypedpoint
             Self::parse_point(&mut temp_point, &line, &self.delimiter, &self.parse_layout)
                 .with_context(|| format!("ReadError in line {}.", index))?;
-            //put it in the buffer
-            point_buffer.push(&temp_point.get_interleaved_point_view());
+            points_chunk.extend_from_slice(temp_point.get_cursor().into_inner());
+            points_read += 1;
+
+            if points_chunk.len() == point_size * chunk_size {
+                Self::push_chunk(
+                    point_buffer,
+                    &points_chunk,
+                    &self.point_layout,
+                    converter.as_ref(),
+                    &mut converted_chunk,
+                );
+                points_chunk.clear();
+            }
+        }
+        if !points_chunk.is_empty() {
+            Self::push_chunk(
+                point_buffer,
+                &points_chunk,
+                &self.point_layout,
+                converter.as_ref(),
+                &mut converted_chunk,
+            );
         }
-        Ok(count)
+        Ok(points_read)
     }
     fn get_default_point_layout(&self) -> &PointLayout {
         &self.point_layout
@@ -2,7 +2,10 @@ use anyhow::{anyhow, Result};
 use las::point::Format;
 use pasture_core::{
     layout::attributes,
-    layout::{PointLayout, PointType},
+    layout::{
+        PointAttributeDataType, PointAttributeDefinition, PointAttributeScalarType, PointLayout,
+        PointType,
+    },
 };
 
 use super::{
@@ -42,6 +45,106 @@ pub fn point_layout_from_las_point_format(format: &Format) -> Result<PointLayout
     }
 }
 
+/// Returns a `PointLayout` that exactly mirrors the binary layout of the point records of the given LAS point format, as
+/// they are stored inside a LAS file. In contrast to [`point_layout_from_las_point_format`], the attributes that LAS stores
+/// as packed bits (return number, number of returns, scan direction flag etc.) are described through bit fields, and
+/// `POSITION_3D` uses the raw `Vector3<i32>` coordinates without applying the scale and offset from the LAS header. Extra
+/// bytes are described by a single `LASExtraBytes` attribute with a byte array datatype. This layout can be used to view
+/// raw LAS point records through an `InterleavedPointView` without unpacking them first:
+/// ```
+/// # use pasture_io::las::*;
+/// # use pasture_core::containers::*;
+/// # use pasture_core::layout::*;
+/// let format = las::point::Format::new(0).unwrap();
+/// let layout = raw_point_layout_from_las_point_format(&format).unwrap();
+/// assert_eq!(layout.size_of_point_entry(), format.len() as u64);
+///
+/// // A single raw point record in LAS format 0, with return number 2 and 3 returns
+/// let mut point_record = vec![0; 20];
+/// point_record[14] = 0b0001_1010;
+/// let view = InterleavedPointView::from_raw_slice(&point_record, layout);
+/// assert_eq!(2, view.get_attribute::<u8>(&attributes::RETURN_NUMBER, 0));
+/// assert_eq!(3, view.get_attribute::<u8>(&attributes::NUMBER_OF_RETURNS, 0));
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if `format` is an invalid LAS point format
+pub fn raw_point_layout_from_las_point_format(format: &Format) -> Result<PointLayout> {
+    // Only used for validating the format
+    format.to_u8()?;
+
+    let raw_position = attributes::POSITION_3D.with_custom_datatype(
+        PointAttributeDataType::vector(PointAttributeScalarType::I32, 3),
+    );
+    let mut members = vec![
+        raw_position.at_offset_in_type(0),
+        attributes::INTENSITY.at_offset_in_type(12),
+    ];
+    let mut offset = if format.is_extended {
+        members.extend_from_slice(&[
+            attributes::RETURN_NUMBER.at_bit_offset_in_type(14, 0, 4),
+            attributes::NUMBER_OF_RETURNS.at_bit_offset_in_type(14, 4, 4),
+            attributes::CLASSIFICATION_FLAGS.at_bit_offset_in_type(15, 0, 4),
+            attributes::SCANNER_CHANNEL.at_bit_offset_in_type(15, 4, 2),
+            attributes::SCAN_DIRECTION_FLAG.at_bit_offset_in_type(15, 6, 1),
+            attributes::EDGE_OF_FLIGHT_LINE.at_bit_offset_in_type(15, 7, 1),
+            attributes::CLASSIFICATION.at_offset_in_type(16),
+            attributes::USER_DATA.at_offset_in_type(17),
+            attributes::SCAN_ANGLE.at_offset_in_type(18),
+            attributes::POINT_SOURCE_ID.at_offset_in_type(20),
+            attributes::GPS_TIME.at_offset_in_type(22),
+        ]);
+        30
+    } else {
+        members.extend_from_slice(&[
+            attributes::RETURN_NUMBER.at_bit_offset_in_type(14, 0, 3),
+            attributes::NUMBER_OF_RETURNS.at_bit_offset_in_type(14, 3, 3),
+            attributes::SCAN_DIRECTION_FLAG.at_bit_offset_in_type(14, 6, 1),
+            attributes::EDGE_OF_FLIGHT_LINE.at_bit_offset_in_type(14, 7, 1),
+            attributes::CLASSIFICATION.at_bit_offset_in_type(15, 0, 5),
+            attributes::CLASSIFICATION_FLAGS.at_bit_offset_in_type(15, 5, 3),
+            attributes::SCAN_ANGLE_RANK.at_offset_in_type(16),
+            attributes::USER_DATA.at_offset_in_type(17),
+            attributes::POINT_SOURCE_ID.at_offset_in_type(18),
+        ]);
+        if format.has_gps_time {
+            members.push(attributes::GPS_TIME.at_offset_in_type(20));
+            28
+        } else {
+            20
+        }
+    };
+
+    if format.has_color {
+        members.push(attributes::COLOR_RGB.at_offset_in_type(offset));
+        offset += 6;
+    }
+    if format.has_nir {
+        members.push(attributes::NIR.at_offset_in_type(offset));
+        offset += 2;
+    }
+    if format.has_waveform {
+        members.extend_from_slice(&[
+            attributes::WAVE_PACKET_DESCRIPTOR_INDEX.at_offset_in_type(offset),
+            attributes::WAVEFORM_DATA_OFFSET.at_offset_in_type(offset + 1),
+            attributes::WAVEFORM_PACKET_SIZE.at_offset_in_type(offset + 9),
+            attributes::RETURN_POINT_WAVEFORM_LOCATION.at_offset_in_type(offset + 13),
+            attributes::WAVEFORM_PARAMETERS.at_offset_in_type(offset + 17),
+        ]);
+        offset += 29;
+    }
+    if format.extra_bytes > 0 {
+        let extra_bytes = PointAttributeDefinition::custom(
+            "LASExtraBytes",
+            PointAttributeDataType::ByteArray(format.extra_bytes as u32),
+        );
+        members.push(extra_bytes.at_offset_in_type(offset));
+    }
+
+    Ok(PointLayout::from_members_and_alignment(&members, 1))
+}
+
 /// Returns the best matching LAS point format for the given `PointLayout`. This method tries to match as many attributes
 /// as possible in the given `PointLayout` to attributes that are supported by the LAS format (v1.4) natively. Attributes
 /// that do not have a corresponding LAS attribute are ignored. If no matching attributes are found, LAS point format 0 is
@@ -81,3 +184,109 @@ pub fn las_point_format_from_point_layout(point_layout: &PointLayout) -> Format
 
     format
 }
+
+#[cfg(test)]
+mod tests {
+    use las_rs::{Read, Reader};
+    use pasture_core::{
+        containers::{InterleavedPointView, PointBufferExt},
+        nalgebra::Vector3,
+    };
+
+    use super::*;
+    use crate::las::*;
+
+    #[test]
+    fn test_view_raw_las_point_records() -> Result<()> {
+        for format in 0..=10 {
+            let path = get_test_las_path(format);
+            let header = Reader::from_path(&path)?.header().clone().into_raw()?;
+            let las_format = Format::new(format)?;
+
+            let layout = raw_point_layout_from_las_point_format(&las_format)?;
+            assert_eq!(
+                header.point_data_record_length as u64,
+                layout.size_of_point_entry()
+            );
+
+            let file_bytes = std::fs::read(&path)?;
+            let point_data_start = header.offset_to_point_data as usize;
+            let point_data_end = point_data_start
+                + test_data_point_count() * header.point_data_record_length as usize;
+            let view = InterleavedPointView::from_raw_slice(
+                &file_bytes[point_data_start..point_data_end],
+                layout,
+            );
+
+            let raw_position_attribute = attributes::POSITION_3D.with_custom_datatype(
+                PointAttributeDataType::vector(PointAttributeScalarType::I32, 3),
+            );
+            let positions = view
+                .iter_attribute::<Vector3<i32>>(&raw_position_attribute)
+                .map(|raw_position| {
+                    Vector3::new(
+                        raw_position.x as f64 * header.x_scale_factor + header.x_offset,
+                        raw_position.y as f64 * header.y_scale_factor + header.y_offset,
+                        raw_position.z as f64 * header.z_scale_factor + header.z_offset,
+                    )
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(test_data_positions(), positions);
+
+            let intensities = view
+                .iter_attribute::<u16>(&attributes::INTENSITY)
+                .collect::<Vec<_>>();
+            assert_eq!(test_data_intensities(), intensities);
+
+            let return_numbers = view
+                .iter_attribute::<u8>(&attributes::RETURN_NUMBER)
+                .collect::<Vec<_>>();
+            let number_of_returns = view
+                .iter_attribute::<u8>(&attributes::NUMBER_OF_RETURNS)
+                .collect::<Vec<_>>();
+            if format >= 6 {
+                assert_eq!(test_data_return_numbers_extended(), return_numbers);
+                assert_eq!(test_data_number_of_returns_extended(), number_of_returns);
+            } else {
+                assert_eq!(test_data_return_numbers(), return_numbers);
+                assert_eq!(test_data_number_of_returns(), number_of_returns);
+            }
+
+            let scan_direction_flags = view
+                .iter_attribute::<bool>(&attributes::SCAN_DIRECTION_FLAG)
+                .collect::<Vec<_>>();
+            assert_eq!(test_data_scan_direction_flags(), scan_direction_flags);
+
+            let edge_of_flight_lines = view
+                .iter_attribute::<bool>(&attributes::EDGE_OF_FLIGHT_LINE)
+                .collect::<Vec<_>>();
+            assert_eq!(test_data_edge_of_flight_lines(), edge_of_flight_lines);
+
+            let classifications = view
+                .iter_attribute::<u8>(&attributes::CLASSIFICATION)
+                .collect::<Vec<_>>();
+            assert_eq!(test_data_classifications(), classifications);
+
+            let point_source_ids = view
+                .iter_attribute::<u16>(&attributes::POINT_SOURCE_ID)
+                .collect::<Vec<_>>();
+            assert_eq!(test_data_point_source_ids(), point_source_ids);
+
+            if format_has_gps_times(format) {
+                let gps_times = view
+                    .iter_attribute::<f64>(&attributes::GPS_TIME)
+                    .collect::<Vec<_>>();
+                assert_eq!(test_data_gps_times(), gps_times);
+            }
+
+            if format_has_colors(format) {
+                let colors = view
+                    .iter_attribute::<Vector3<u16>>(&attributes::COLOR_RGB)
+                    .collect::<Vec<_>>();
+                assert_eq!(test_data_colors(), colors);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -422,47 +422,3 @@ pub(crate) enum BitAttributes {
     Regular(BitAttributesRegular),
     Extended(BitAttributesExtended),
 }
-
-impl BitAttributes {
-    pub fn return_number(&self) -> u8 {
-        match &self {
-            Self::Regular(attr) => attr.return_number,
-            Self::Extended(attr) => attr.return_number,
-        }
-    }
-
-    pub fn number_of_returns(&self) -> u8 {
-        match &self {
-            Self::Regular(attr) => attr.number_of_returns,
-            Self::Extended(attr) => attr.number_of_returns,
-        }
-    }
-
-    pub fn classification_flags_or_default(&self) -> u8 {
-        match &self {
-            Self::Regular(_) => Default::default(),
-            Self::Extended(attr) => attr.classification_flags,
-        }
-    }
-
-    pub fn scanner_channel_or_default(&self) -> u8 {
-        match &self {
-            Self::Regular(_) => Default::default(),
-            Self::Extended(attr) => attr.scanner_channel,
-        }
-    }
-
-    pub fn scan_direction_flag(&self) -> u8 {
-        match &self {
-            Self::Regular(attr) => attr.scan_direction_flag,
-            Self::Extended(attr) => attr.scan_direction_flag,
-        }
-    }
-
-    pub fn edge_of_flight_line(&self) -> u8 {
-        match &self {
-            Self::Regular(attr) => attr.edge_of_flight_line,
-            Self::Extended(attr) => attr.edge_of_flight_line,
-        }
-    }
-}
@@ -8,22 +8,15 @@ use laz::{
     las::laszip::{LASZIP_RECORD_ID, LASZIP_USER_ID},
     LasZipDecompressor,
 };
-use pasture_core::layout::PointAttributeDefinition;
 use pasture_core::{
     containers::InterleavedPointView,
     containers::{InterleavedVecPointStorage, PointBuffer, PointBufferWriteable},
-    layout::attributes,
-    layout::conversion::get_converter_for_attributes,
-    layout::{conversion::AttributeConversionFn, PointLayout},
+    layout::{conversion::RawPointConverter, PointLayout},
     meta::Metadata,
     nalgebra::Vector3,
-    util::view_raw_bytes,
 };
 
-use super::{
-    map_laz_err, point_layout_from_las_point_format, BitAttributes, BitAttributesExtended,
-    BitAttributesRegular, LASMetadata,
-};
+use super::{map_laz_err, point_layout_from_las_point_format, LASMetadata};
 use crate::base::{PointReader, SeekToPoint};
 
 /// Is the given VLR the LASzip VLR? Function taken from the `las` crate because it is not exported there
@@ -197,404 +190,6 @@ impl<T: Read + Seek> RawLASReader<T> {
         Ok(())
     }
 
-    fn read_chunk_custom_layout(
-        &mut self,
-        source_data: &mut [u8],
-        chunk_buffer: &mut [u8],
-        num_points_in_chunk: usize,
-        target_layout: &PointLayout,
-    ) -> Result<()> {
-        //let mut buffer_cursor = Cursor::new(chunk_buffer);
-
-        let source_format = Format::new(self.metadata.point_format())?;
-
-        // This probably works best by introducing a type that stores all information needed for reading and writing a single
-        // attribute:
-        //   - does the source format of the LAS file have this attribute?
-        //   - does the target layout have this attribute?
-        //   - if the target layout has the attribute, we may need an attribute converter
-        //   - if the target layout has the attribute, we need the byte offset of the attribute to the start of the point record within the point layout
-        //
-        // With this information, we can build a bunch of these objects and execute the I/O operations with them, should be more readable
-
-        fn get_attribute_parser(
-            default_attribute: &PointAttributeDefinition,
-            source_layout: &PointLayout,
-            target_layout: &PointLayout,
-        ) -> Option<(usize, usize, Option<AttributeConversionFn>)> {
-            target_layout
-                .get_attribute_by_name(default_attribute.name())
-                .map_or(None, |target_attribute| {
-                    let converter = source_layout
-                        .get_attribute_by_name(default_attribute.name())
-                        .and_then(|source_attribute| {
-                            get_converter_for_attributes(
-                                &source_attribute.into(),
-                                &target_attribute.into(),
-                            )
-                        })
-                        .or_else(|| {
-                            // If the source_layout does not contain the desired attribute, we still might need a converter
-                            // because in this case a default attribute is read (e.g. Vector3<u16> for COLOR_RGB), and this
-                            // default attribute might have a different data type from the target attribute
-                            get_converter_for_attributes(
-                                default_attribute,
-                                &target_attribute.into(),
-                            )
-                        });
-                    let offset_of_attribute = target_attribute.offset() as usize;
-                    let size_of_attribute = target_attribute.size() as usize;
-                    Some((offset_of_attribute, size_of_attribute, converter))
-                })
-        }
-
-        let target_position_parser =
-            get_attribute_parser(&attributes::POSITION_3D, &self.layout, target_layout);
-        let target_intensity_parser =
-            get_attribute_parser(&attributes::INTENSITY, &self.layout, target_layout);
-        let target_return_number_parser =
-            get_attribute_parser(&attributes::RETURN_NUMBER, &self.layout, target_layout);
-        let target_number_of_returns_parser =
-            get_attribute_parser(&attributes::NUMBER_OF_RETURNS, &self.layout, target_layout);
-        let target_classification_flags_parser = get_attribute_parser(
-            &attributes::CLASSIFICATION_FLAGS,
-            &self.layout,
-            target_layout,
-        );
-        let target_scanner_channel_parser =
-            get_attribute_parser(&attributes::SCANNER_CHANNEL, &self.layout, target_layout);
-        let target_scan_direction_flag_parser = get_attribute_parser(
-            &attributes::SCAN_DIRECTION_FLAG,
-            &self.layout,
-            target_layout,
-        );
-        let target_eof_parser = get_attribute_parser(
-            &attributes::EDGE_OF_FLIGHT_LINE,
-            &self.layout,
-            target_layout,
-        );
-        let target_classification_parser =
-            get_attribute_parser(&attributes::CLASSIFICATION, &self.layout, target_layout);
-        let target_scan_angle_rank_parser =
-            get_attribute_parser(&attributes::SCAN_ANGLE_RANK, &self.layout, target_layout);
-        let target_user_data_parser =
-            get_attribute_parser(&attributes::USER_DATA, &self.layout, target_layout);
-        let target_point_source_id_parser =
-            get_attribute_parser(&attributes::POINT_SOURCE_ID, &self.layout, target_layout);
-        let target_gps_time_parser =
-            get_attribute_parser(&attributes::GPS_TIME, &self.layout, target_layout);
-        let target_color_parser =
-            get_attribute_parser(&attributes::COLOR_RGB, &self.layout, target_layout);
-        let target_nir_parser = get_attribute_parser(&attributes::NIR, &self.layout, target_layout);
-        let target_wave_packet_index_parser = get_attribute_parser(
-            &attributes::WAVE_PACKET_DESCRIPTOR_INDEX,
-            &self.layout,
-            target_layout,
-        );
-        let target_waveform_byte_offset_parser = get_attribute_parser(
-            &attributes::WAVEFORM_DATA_OFFSET,
-            &self.layout,
-            target_layout,
-        );
-        let target_waveform_packet_size_parser = get_attribute_parser(
-            &attributes::WAVEFORM_PACKET_SIZE,
-            &self.layout,
-            target_layout,
-        );
-        let target_waveform_return_point_parser = get_attribute_parser(
-            &attributes::RETURN_POINT_WAVEFORM_LOCATION,
-            &self.layout,
-            target_layout,
-        );
-        let target_waveform_parameters_parser = get_attribute_parser(
-            &attributes::WAVEFORM_PARAMETERS,
-            &self.layout,
-            target_layout,
-        );
-
-        let target_point_size = target_layout.size_of_point_entry() as usize;
-
-        fn run_parser<T: Read + Seek, U>(
-            decoder_fn: impl Fn(&mut T) -> Result<U>,
-            maybe_parser: Option<(usize, usize, Option<AttributeConversionFn>)>,
-            start_of_target_point_in_chunk: usize,
-            size_of_attribute: Option<usize>,
-            reader: &mut T,
-            chunk_buffer: &mut [u8],
-        ) -> Result<()> {
-            if let Some((offset, size, maybe_converter)) = maybe_parser {
-                let source_data = decoder_fn(reader)?;
-                let source_slice = unsafe { view_raw_bytes(&source_data) };
-
-                let pos_start = start_of_target_point_in_chunk + offset;
-                let pos_end = pos_start + size;
-                let target_slice = &mut chunk_buffer[pos_start..pos_end];
-
-                if let Some(converter) = maybe_converter {
-                    unsafe {
-                        converter(source_slice, target_slice);
-                    }
-                } else {
-                    target_slice.copy_from_slice(source_slice);
-                }
-            } else if let Some(bytes_to_skip) = size_of_attribute {
-                reader.seek(SeekFrom::Current(bytes_to_skip as i64))?;
-            }
-
-            Ok(())
-        }
-
-        let point_offsets = self.point_offsets.clone();
-        let point_scales = self.point_scales.clone();
-
-        let mut source_reader = Cursor::new(source_data);
-
-        for point_index in 0..num_points_in_chunk {
-            // Point size might be larger than what the format indicates due to extra bytes. Extra bytes are not
-            // supported by pasture at the moment, so we skip over them
-            let start_of_source_point = point_index as u64 * self.size_of_point_in_file;
-            source_reader.seek(SeekFrom::Start(start_of_source_point))?;
-
-            let start_of_target_point_in_chunk = point_index * target_point_size;
-
-            run_parser(
-                |reader| {
-                    Self::read_next_world_space_position(reader, &point_scales, &point_offsets)
-                },
-                target_position_parser,
-                start_of_target_point_in_chunk,
-                Some(12),
-                &mut source_reader,
-                chunk_buffer,
-            )?;
-
-            run_parser(
-                |buf| Ok(buf.read_u16::<LittleEndian>()?),
-                target_intensity_parser,
-                start_of_target_point_in_chunk,
-                Some(2),
-                &mut source_reader,
-                chunk_buffer,
-            )?;
-
-            let bit_attributes =
-                Self::read_next_bit_attributes(&mut source_reader, &source_format)?;
-            run_parser(
-                |_| Ok(bit_attributes.return_number()),
-                target_return_number_parser,
-                start_of_target_point_in_chunk,
-                None,
-                &mut source_reader,
-                chunk_buffer,
-            )?;
-            run_parser(
-                |_| Ok(bit_attributes.number_of_returns()),
-                target_number_of_returns_parser,
-                start_of_target_point_in_chunk,
-                None,
-                &mut source_reader,
-                chunk_buffer,
-            )?;
-            run_parser(
-                |_| Ok(bit_attributes.classification_flags_or_default()),
-                target_classification_flags_parser,
-                start_of_target_point_in_chunk,
-                None,
-                &mut source_reader,
-                chunk_buffer,
-            )?;
-            run_parser(
-                |_| Ok(bit_attributes.scanner_channel_or_default()),
-                target_scanner_channel_parser,
-                start_of_target_point_in_chunk,
-                None,
-                &mut source_reader,
-                chunk_buffer,
-            )?;
-            run_parser(
-                |_| Ok(bit_attributes.scan_direction_flag()),
-                target_scan_direction_flag_parser,
-                start_of_target_point_in_chunk,
-                None,
-                &mut source_reader,
-                chunk_buffer,
-            )?;
-            run_parser(
-                |_| Ok(bit_attributes.edge_of_flight_line()),
-                target_eof_parser,
-                start_of_target_point_in_chunk,
-                None,
-                &mut source_reader,
-                chunk_buffer,
-            )?;
-
-            run_parser(
-                |buf| Ok(buf.read_u8()?),
-                target_classification_parser,
-                start_of_target_point_in_chunk,
-                Some(1),
-                &mut source_reader,
-                chunk_buffer,
-            )?;
-
-            if source_format.is_extended {
-                // Extended LAS format has user data before scan angle
-                run_parser(
-                    |buf| Ok(buf.read_u8()?),
-                    target_user_data_parser,
-                    start_of_target_point_in_chunk,
-                    Some(1),
-                    &mut source_reader,
-                    chunk_buffer,
-                )?;
-
-                run_parser(
-                    |buf| Ok(buf.read_i16::<LittleEndian>()?),
-                    target_scan_angle_rank_parser,
-                    start_of_target_point_in_chunk,
-                    Some(2),
-                    &mut source_reader,
-                    chunk_buffer,
-                )?;
-            } else {
-                // Regular formats have scan angle rank before user data
-                run_parser(
-                    |buf| Ok(buf.read_i8()?),
-                    target_scan_angle_rank_parser,
-                    start_of_target_point_in_chunk,
-                    Some(1),
-                    &mut source_reader,
-                    chunk_buffer,
-                )?;
-
-                run_parser(
-                    |buf| Ok(buf.read_u8()?),
-                    target_user_data_parser,
-                    start_of_target_point_in_chunk,
-                    Some(1),
-                    &mut source_reader,
-                    chunk_buffer,
-                )?;
-            }
-
-            run_parser(
-                |buf| Ok(buf.read_u16::<LittleEndian>()?),
-                target_point_source_id_parser,
-                start_of_target_point_in_chunk,
-                Some(2),
-                &mut source_reader,
-                chunk_buffer,
-            )?;
-
-            let gps_bytes_in_current_format = if source_format.has_gps_time {
-                Some(8)
-            } else {
-                None
-            };
-            run_parser(
-                |buf| Ok(buf.read_f64::<LittleEndian>()?),
-                target_gps_time_parser,
-                start_of_target_point_in_chunk,
-                gps_bytes_in_current_format,
-                &mut source_reader,
-                chunk_buffer,
-            )?;
-
-            let color_bytes_in_current_format = if source_format.has_color {
-                Some(6)
-            } else {
-                None
-            };
-            run_parser(
-                |reader| Self::read_next_color_or_default(reader, &source_format),
-                target_color_parser,
-                start_of_target_point_in_chunk,
-                color_bytes_in_current_format,
-                &mut source_reader,
-                chunk_buffer,
-            )?;
-
-            let nir_bytes_in_current_format = if source_format.has_nir { Some(2) } else { None };
-            run_parser(
-                |buf| Ok(buf.read_u16::<LittleEndian>()?),
-                target_nir_parser,
-                start_of_target_point_in_chunk,
-                nir_bytes_in_current_format,
-                &mut source_reader,
-                chunk_buffer,
-            )?;
-
-            let wave_packet_index_bytes_in_current_format = if source_format.has_waveform {
-                Some(1)
-            } else {
-                None
-            };
-            run_parser(
-                |buf| Ok(buf.read_u8()?),
-                target_wave_packet_index_parser,
-                start_of_target_point_in_chunk,
-                wave_packet_index_bytes_in_current_format,
-                &mut source_reader,
-                chunk_buffer,
-            )?;
-            let waveform_data_offset_bytes_in_current_format = if source_format.has_waveform {
-                Some(8)
-            } else {
-                None
-            };
-            run_parser(
-                |buf| Ok(buf.read_u64::<LittleEndian>()?),
-                target_waveform_byte_offset_parser,
-                start_of_target_point_in_chunk,
-                waveform_data_offset_bytes_in_current_format,
-                &mut source_reader,
-                chunk_buffer,
-            )?;
-            let waveform_packet_bytes_in_current_format = if source_format.has_waveform {
-                Some(4)
-            } else {
-                None
-            };
-            run_parser(
-                |buf| Ok(buf.read_u32::<LittleEndian>()?),
-                target_waveform_packet_size_parser,
-                start_of_target_point_in_chunk,
-                waveform_packet_bytes_in_current_format,
-                &mut source_reader,
-                chunk_buffer,
-            )?;
-            let waveform_location_bytes_in_current_format = if source_format.has_waveform {
-                Some(4)
-            } else {
-                None
-            };
-            run_parser(
-                |buf| Ok(buf.read_f32::<LittleEndian>()?),
-                target_waveform_return_point_parser,
-                start_of_target_point_in_chunk,
-                waveform_location_bytes_in_current_format,
-                &mut source_reader,
-                chunk_buffer,
-            )?;
-
-            let waveform_params_bytes_in_current_format = if source_format.has_waveform {
-                Some(12)
-            } else {
-                None
-            };
-            run_parser(
-                |reader| Self::read_next_waveform_parameters(reader, &source_format),
-                target_waveform_parameters_parser,
-                start_of_target_point_in_chunk,
-                waveform_params_bytes_in_current_format,
-                &mut source_reader,
-                chunk_buffer,
-            )?;
-        }
-
-        Ok(())
-    }
-
     fn read_into_default_layout(
         &mut self,
         point_buffer: &mut dyn PointBufferWriteable,
@@ -642,34 +237,36 @@ impl<T: Read + Seek> RawLASReader<T> {
         }
 
         // Read in interleaved chunks, even if the `point_buffer` is not interleaved. `push_points_interleaved` will
-        // handle the memory transpose in this case
+        // handle the memory transpose in this case. Each chunk is read in the default layout and then converted into
+        // the target layout, which converts each attribute for the whole chunk at once
         let chunk_size = 50_000;
-        let point_size = point_buffer.point_layout().size_of_point_entry() as usize;
-        let chunk_bytes = point_size * chunk_size;
+        let target_layout = point_buffer.point_layout().clone();
+        let default_point_size = self.layout.size_of_point_entry() as usize;
+        let point_size = target_layout.size_of_point_entry() as usize;
         let num_chunks = (num_points_to_read + chunk_size - 1) / chunk_size;
-        let mut points_chunk: Vec<u8> = vec![0; chunk_bytes];
-
-        let las_buffer_bytes = self.size_of_point_in_file as usize * chunk_size;
-        let mut buffer: Vec<u8> = vec![0; las_buffer_bytes];
+        let mut default_layout_chunk: Vec<u8> = vec![0; default_point_size * chunk_size];
+        let mut points_chunk: Vec<u8> = vec![0; point_size * chunk_size];
+        let converter = RawPointConverter::try_from_to(&self.layout, &target_layout)?;
 
         for chunk_index in 0..num_chunks {
             let points_in_chunk =
                 std::cmp::min(chunk_size, num_points_to_read - (chunk_index * chunk_size));
             let bytes_in_chunk = points_in_chunk * point_size;
-            let chunk_size_in_file = points_in_chunk * self.size_of_point_in_file as usize;
-
-            self.reader.read_exact(&mut buffer[0..chunk_size_in_file])?;
 
-            self.read_chunk_custom_layout(
-                &mut buffer[0..chunk_size_in_file],
-                &mut points_chunk[..],
-                points_in_chunk,
-                point_buffer.point_layout(),
-            )?;
+            self.read_chunk_default_layout(&mut default_layout_chunk[..], points_in_chunk)?;
+            unsafe {
+                converter.convert_batch(
+                    &default_layout_chunk,
+                    default_point_size,
+                    &mut points_chunk,
+                    point_size,
+                    points_in_chunk,
+                );
+            }
 
             point_buffer.push(&InterleavedPointView::from_raw_slice(
                 &points_chunk[0..bytes_in_chunk],
-                point_buffer.point_layout().clone(),
+                target_layout.clone(),
             ));
         }
 
@@ -677,77 +274,6 @@ impl<T: Read + Seek> RawLASReader<T> {
 
         Ok(num_points_to_read)
     }
-
-    /// Read the next position, converted into world space of the current LAS file
-    fn read_next_world_space_position<U: Read>(
-        reader: &mut U,
-        point_scales: &Vector3<f64>,
-        point_offsets: &Vector3<f64>,
-    ) -> Result<Vector3<f64>> {
-        let local_x = reader.read_i32::<LittleEndian>()?;
-        let local_y = reader.read_i32::<LittleEndian>()?;
-        let local_z = reader.read_i32::<LittleEndian>()?;
-        let global_x = (local_x as f64 * point_scales.x) + point_offsets.x;
-        let global_y = (local_y as f64 * point_scales.y) + point_offsets.y;
-        let global_z = (local_z as f64 * point_scales.z) + point_offsets.z;
-        Ok(Vector3::new(global_x, global_y, global_z))
-    }
-
-    /// Read the next bit flag attributes from the current LAS file
-    fn read_next_bit_attributes<U: Read>(
-        reader: &mut U,
-        las_format: &Format,
-    ) -> Result<BitAttributes> {
-        if las_format.is_extended {
-            let low_byte = reader.read_u8()?;
-            let high_byte = reader.read_u8()?;
-
-            Ok(BitAttributes::Extended(BitAttributesExtended {
-                return_number: low_byte & 0b1111,
-                number_of_returns: (low_byte >> 4) & 0b1111,
-                classification_flags: high_byte & 0b1111,
-                scanner_channel: (high_byte >> 4) & 0b11,
-                scan_direction_flag: (high_byte >> 6) & 0b1,
-                edge_of_flight_line: (high_byte >> 7) & 0b1,
-            }))
-        } else {
-            let byte = reader.read_u8()?;
-            Ok(BitAttributes::Regular(BitAttributesRegular {
-                return_number: byte & 0b111,
-                number_of_returns: (byte >> 3) & 0b111,
-                scan_direction_flag: (byte >> 6) & 0b1,
-                edge_of_flight_line: (byte >> 7) & 0b1,
-            }))
-        }
-    }
-
-    fn read_next_color_or_default<U: Read>(
-        reader: &mut U,
-        las_format: &Format,
-    ) -> Result<Vector3<u16>> {
-        if !las_format.has_color {
-            Ok(Default::default())
-        } else {
-            let r = reader.read_u16::<LittleEndian>()?;
-            let g = reader.read_u16::<LittleEndian>()?;
-            let b = reader.read_u16::<LittleEndian>()?;
-            Ok(Vector3::new(r, g, b))
-        }
-    }
-
-    fn read_next_waveform_parameters<U: Read>(
-        reader: &mut U,
-        las_format: &Format,
-    ) -> Result<Vector3<f32>> {
-        if !las_format.has_waveform {
-            Ok(Default::default())
-        } else {
-            let px = reader.read_f32::<LittleEndian>()?;
-            let py = reader.read_f32::<LittleEndian>()?;
-            let pz = reader.read_f32::<LittleEndian>()?;
-            Ok(Vector3::new(px, py, pz))
-        }
-    }
 }
 
 impl<T: Read + Seek> LASReaderBase for RawLASReader<T> {
@@ -1033,395 +559,6 @@ impl<'a, T: Read + Seek + Send + 'a> RawLAZReader<'a, T> {
         Ok(())
     }
 
-    fn read_chunk_custom_layout(
-        &mut self,
-        chunk_buffer: &mut [u8],
-        decompression_buffer: &mut [u8],
-        num_points_in_chunk: usize,
-        target_layout: &PointLayout,
-    ) -> Result<()> {
-        // HACK Not happy with how large this function is... But there are so many special
-        // cases, I don't know how to clean it up at the moment. Maybe revise in future?
-        let source_format = Format::new(self.metadata.point_format())?;
-
-        fn get_attribute_parser(
-            default_attribute: &PointAttributeDefinition,
-            source_layout: &PointLayout,
-            target_layout: &PointLayout,
-        ) -> Option<(usize, usize, Option<AttributeConversionFn>)> {
-            target_layout
-                .get_attribute_by_name(default_attribute.name())
-                .map_or(None, |target_attribute| {
-                    let converter = source_layout
-                        .get_attribute_by_name(default_attribute.name())
-                        .and_then(|source_attribute| {
-                            get_converter_for_attributes(
-                                &source_attribute.into(),
-                                &target_attribute.into(),
-                            )
-                        })
-                        .or_else(|| {
-                            // If the source_layout does not contain the desired attribute, we still might need a converter
-                            // because in this case a default attribute is read (e.g. Vector3<u16> for COLOR_RGB), and this
-                            // default attribute might have a different data type from the target attribute
-                            get_converter_for_attributes(
-                                default_attribute,
-                                &target_attribute.into(),
-                            )
-                        });
-                    let offset_of_attribute = target_attribute.offset() as usize;
-                    let size_of_attribute = target_attribute.size() as usize;
-                    Some((offset_of_attribute, size_of_attribute, converter))
-                })
-        }
-
-        let target_position_parser =
-            get_attribute_parser(&attributes::POSITION_3D, &self.layout, target_layout);
-        let target_intensity_parser =
-            get_attribute_parser(&attributes::INTENSITY, &self.layout, target_layout);
-        let target_return_number_parser =
-            get_attribute_parser(&attributes::RETURN_NUMBER, &self.layout, target_layout);
-        let target_number_of_returns_parser =
-            get_attribute_parser(&attributes::NUMBER_OF_RETURNS, &self.layout, target_layout);
-        let target_classification_flags_parser = get_attribute_parser(
-            &attributes::CLASSIFICATION_FLAGS,
-            &self.layout,
-            target_layout,
-        );
-        let target_scanner_channel_parser =
-            get_attribute_parser(&attributes::SCANNER_CHANNEL, &self.layout, target_layout);
-        let target_scan_direction_flag_parser = get_attribute_parser(
-            &attributes::SCAN_DIRECTION_FLAG,
-            &self.layout,
-            target_layout,
-        );
-        let target_eof_parser = get_attribute_parser(
-            &attributes::EDGE_OF_FLIGHT_LINE,
-            &self.layout,
-            target_layout,
-        );
-        let target_classification_parser =
-            get_attribute_parser(&attributes::CLASSIFICATION, &self.layout, target_layout);
-        let target_scan_angle_rank_parser =
-            get_attribute_parser(&attributes::SCAN_ANGLE_RANK, &self.layout, target_layout);
-        let target_user_data_parser =
-            get_attribute_parser(&attributes::USER_DATA, &self.layout, target_layout);
-        let target_point_source_id_parser =
-            get_attribute_parser(&attributes::POINT_SOURCE_ID, &self.layout, target_layout);
-        let target_gps_time_parser =
-            get_attribute_parser(&attributes::GPS_TIME, &self.layout, target_layout);
-        let target_color_parser =
-            get_attribute_parser(&attributes::COLOR_RGB, &self.layout, target_layout);
-        let target_nir_parser = get_attribute_parser(&attributes::NIR, &self.layout, target_layout);
-        let target_wave_packet_index_parser = get_attribute_parser(
-            &attributes::WAVE_PACKET_DESCRIPTOR_INDEX,
-            &self.layout,
-            target_layout,
-        );
-        let target_waveform_byte_offset_parser = get_attribute_parser(
-            &attributes::WAVEFORM_DATA_OFFSET,
-            &self.layout,
-            target_layout,
-        );
-        let target_waveform_packet_size_parser = get_attribute_parser(
-            &attributes::WAVEFORM_PACKET_SIZE,
-            &self.layout,
-            target_layout,
-        );
-        let target_waveform_return_point_parser = get_attribute_parser(
-            &attributes::RETURN_POINT_WAVEFORM_LOCATION,
-            &self.layout,
-            target_layout,
-        );
-        let target_waveform_parameters_parser = get_attribute_parser(
-            &attributes::WAVEFORM_PARAMETERS,
-            &self.layout,
-            target_layout,
-        );
-
-        let target_point_size = target_layout.size_of_point_entry() as usize;
-
-        self.reader.decompress_many(
-            &mut decompression_buffer
-                [0..(num_points_in_chunk * self.size_of_point_in_file as usize)],
-        )?;
-        let mut decompressed_data = Cursor::new(decompression_buffer);
-
-        fn run_parser<T>(
-            decoder_fn: impl Fn(&mut Cursor<&mut [u8]>) -> Result<T>,
-            maybe_parser: Option<(usize, usize, Option<AttributeConversionFn>)>,
-            start_of_target_point_in_chunk: usize,
-            size_of_attribute: Option<usize>,
-            decompressed_data: &mut Cursor<&mut [u8]>,
-            chunk_buffer: &mut [u8],
-        ) -> Result<()> {
-            if let Some((offset, size, maybe_converter)) = maybe_parser {
-                let source_data = decoder_fn(decompressed_data)?;
-                let source_slice = unsafe { view_raw_bytes(&source_data) };
-
-                let pos_start = start_of_target_point_in_chunk + offset;
-                let pos_end = pos_start + size;
-                let target_slice = &mut chunk_buffer[pos_start..pos_end];
-
-                if let Some(converter) = maybe_converter {
-                    unsafe {
-                        converter(source_slice, target_slice);
-                    }
-                } else {
-                    target_slice.copy_from_slice(source_slice);
-                }
-            } else if let Some(bytes_to_skip) = size_of_attribute {
-                decompressed_data.seek(SeekFrom::Current(bytes_to_skip as i64))?;
-            }
-
-            Ok(())
-        }
-
-        for point_index in 0..num_points_in_chunk {
-            // Point size might be larger than what the format indicates due to extra bytes. Extra bytes are not
-            // supported by pasture at the moment, so we skip over them
-            let start_of_point_in_decompressed_data =
-                point_index as u64 * self.size_of_point_in_file;
-            decompressed_data.seek(SeekFrom::Start(start_of_point_in_decompressed_data))?;
-
-            let start_of_target_point_in_chunk = point_index * target_point_size;
-
-            run_parser(
-                |buf| self.read_next_world_space_position(buf),
-                target_position_parser,
-                start_of_target_point_in_chunk,
-                Some(12),
-                &mut decompressed_data,
-                chunk_buffer,
-            )?;
-
-            run_parser(
-                |buf| Ok(buf.read_u16::<LittleEndian>()?),
-                target_intensity_parser,
-                start_of_target_point_in_chunk,
-                Some(2),
-                &mut decompressed_data,
-                chunk_buffer,
-            )?;
-
-            let bit_attributes =
-                self.read_next_bit_attributes(&mut decompressed_data, &source_format)?;
-            run_parser(
-                |_| Ok(bit_attributes.return_number()),
-                target_return_number_parser,
-                start_of_target_point_in_chunk,
-                None,
-                &mut decompressed_data,
-                chunk_buffer,
-            )?;
-            run_parser(
-                |_| Ok(bit_attributes.number_of_returns()),
-                target_number_of_returns_parser,
-                start_of_target_point_in_chunk,
-                None,
-                &mut decompressed_data,
-                chunk_buffer,
-            )?;
-            run_parser(
-                |_| Ok(bit_attributes.classification_flags_or_default()),
-                target_classification_flags_parser,
-                start_of_target_point_in_chunk,
-                None,
-                &mut decompressed_data,
-                chunk_buffer,
-            )?;
-            run_parser(
-                |_| Ok(bit_attributes.scanner_channel_or_default()),
-                target_scanner_channel_parser,
-                start_of_target_point_in_chunk,
-                None,
-                &mut decompressed_data,
-                chunk_buffer,
-            )?;
-            run_parser(
-                |_| Ok(bit_attributes.scan_direction_flag()),
-                target_scan_direction_flag_parser,
-                start_of_target_point_in_chunk,
-                None,
-                &mut decompressed_data,
-                chunk_buffer,
-            )?;
-            run_parser(
-                |_| Ok(bit_attributes.edge_of_flight_line()),
-                target_eof_parser,
-                start_of_target_point_in_chunk,
-                None,
-                &mut decompressed_data,
-                chunk_buffer,
-            )?;
-
-            run_parser(
-                |buf| Ok(buf.read_u8()?),
-                target_classification_parser,
-                start_of_target_point_in_chunk,
-                Some(1),
-                &mut decompressed_data,
-                chunk_buffer,
-            )?;
-
-            if source_format.is_extended {
-                // Extended LAS format has user data before scan angle
-                run_parser(
-                    |buf| Ok(buf.read_u8()?),
-                    target_user_data_parser,
-                    start_of_target_point_in_chunk,
-                    Some(1),
-                    &mut decompressed_data,
-                    chunk_buffer,
-                )?;
-
-                run_parser(
-                    |buf| Ok(buf.read_i16::<LittleEndian>()?),
-                    target_scan_angle_rank_parser,
-                    start_of_target_point_in_chunk,
-                    Some(2),
-                    &mut decompressed_data,
-                    chunk_buffer,
-                )?;
-            } else {
-                // Regular formats have scan angle rank before user data
-                run_parser(
-                    |buf| Ok(buf.read_i8()?),
-                    target_scan_angle_rank_parser,
-                    start_of_target_point_in_chunk,
-                    Some(1),
-                    &mut decompressed_data,
-                    chunk_buffer,
-                )?;
-
-                run_parser(
-                    |buf| Ok(buf.read_u8()?),
-                    target_user_data_parser,
-                    start_of_target_point_in_chunk,
-                    Some(1),
-                    &mut decompressed_data,
-                    chunk_buffer,
-                )?;
-            }
-
-            run_parser(
-                |buf| Ok(buf.read_u16::<LittleEndian>()?),
-                target_point_source_id_parser,
-                start_of_target_point_in_chunk,
-                Some(2),
-                &mut decompressed_data,
-                chunk_buffer,
-            )?;
-
-            let gps_bytes_in_current_format = if source_format.has_gps_time {
-                Some(8)
-            } else {
-                None
-            };
-            run_parser(
-                |buf| Ok(buf.read_f64::<LittleEndian>()?),
-                target_gps_time_parser,
-                start_of_target_point_in_chunk,
-                gps_bytes_in_current_format,
-                &mut decompressed_data,
-                chunk_buffer,
-            )?;
-
-            let color_bytes_in_current_format = if source_format.has_color {
-                Some(6)
-            } else {
-                None
-            };
-            run_parser(
-                |buf| Self::read_next_colors_or_default(buf, &source_format),
-                target_color_parser,
-                start_of_target_point_in_chunk,
-                color_bytes_in_current_format,
-                &mut decompressed_data,
-                chunk_buffer,
-            )?;
-
-            let nir_bytes_in_current_format = if source_format.has_nir { Some(2) } else { None };
-            run_parser(
-                |buf| Ok(buf.read_u16::<LittleEndian>()?),
-                target_nir_parser,
-                start_of_target_point_in_chunk,
-                nir_bytes_in_current_format,
-                &mut decompressed_data,
-                chunk_buffer,
-            )?;
-
-            let wave_packet_index_bytes_in_current_format = if source_format.has_waveform {
-                Some(1)
-            } else {
-                None
-            };
-            run_parser(
-                |buf| Ok(buf.read_u8()?),
-                target_wave_packet_index_parser,
-                start_of_target_point_in_chunk,
-                wave_packet_index_bytes_in_current_format,
-                &mut decompressed_data,
-                chunk_buffer,
-            )?;
-            let waveform_data_offset_bytes_in_current_format = if source_format.has_waveform {
-                Some(8)
-            } else {
-                None
-            };
-            run_parser(
-                |buf| Ok(buf.read_u64::<LittleEndian>()?),
-                target_waveform_byte_offset_parser,
-                start_of_target_point_in_chunk,
-                waveform_data_offset_bytes_in_current_format,
-                &mut decompressed_data,
-                chunk_buffer,
-            )?;
-            let waveform_packet_bytes_in_current_format = if source_format.has_waveform {
-                Some(4)
-            } else {
-                None
-            };
-            run_parser(
-                |buf| Ok(buf.read_u32::<LittleEndian>()?),
-                target_waveform_packet_size_parser,
-                start_of_target_point_in_chunk,
-                waveform_packet_bytes_in_current_format,
-                &mut decompressed_data,
-                chunk_buffer,
-            )?;
-            let waveform_location_bytes_in_current_format = if source_format.has_waveform {
-                Some(4)
-            } else {
-                None
-            };
-            run_parser(
-                |buf| Ok(buf.read_f32::<LittleEndian>()?),
-                target_waveform_return_point_parser,
-                start_of_target_point_in_chunk,
-                waveform_location_bytes_in_current_format,
-                &mut decompressed_data,
-                chunk_buffer,
-            )?;
-
-            let waveform_params_bytes_in_current_format = if source_format.has_waveform {
-                Some(12)
-            } else {
-                None
-            };
-            run_parser(
-                |buf| Self::read_next_waveform_parameters_or_default(buf, &source_format),
-                target_waveform_parameters_parser,
-                start_of_target_point_in_chunk,
-                waveform_params_bytes_in_current_format,
-                &mut decompressed_data,
-                chunk_buffer,
-            )?;
-        }
-
-        Ok(())
-    }
-
     fn read_into_default_layout(
         &mut self,
         point_buffer: &mut dyn PointBufferWriteable,
@@ -1476,12 +613,16 @@ impl<'a, T: Read + Seek + Send + 'a> RawLAZReader<'a, T> {
         }
 
         // Read in interleaved chunks, even if the `point_buffer` is not interleaved. `push_points_interleaved` will
-        // handle the memory transpose in this case
+        // handle the memory transpose in this case. Each chunk is read in the default layout and then converted into
+        // the target layout, which converts each attribute for the whole chunk at once
         let chunk_size = 50_000;
-        let point_size = point_buffer.point_layout().size_of_point_entry() as usize;
-        let chunk_bytes = point_size * chunk_size;
+        let target_layout = point_buffer.point_layout().clone();
+        let default_point_size = self.layout.size_of_point_entry() as usize;
+        let point_size = target_layout.size_of_point_entry() as usize;
         let num_chunks = (num_points_to_read + chunk_size - 1) / chunk_size;
-        let mut points_chunk: Vec<u8> = vec![0; chunk_bytes];
+        let mut default_layout_chunk: Vec<u8> = vec![0; default_point_size * chunk_size];
+        let mut points_chunk: Vec<u8> = vec![0; point_size * chunk_size];
+        let converter = RawPointConverter::try_from_to(&self.layout, &target_layout)?;
 
         let decompression_chunk_size = self.size_of_point_in_file as usize * chunk_size;
         let mut decompression_chunk: Vec<u8> = vec![0; decompression_chunk_size];
@@ -1491,16 +632,24 @@ impl<'a, T: Read + Seek + Send + 'a> RawLAZReader<'a, T> {
                 std::cmp::min(chunk_size, num_points_to_read - (chunk_index * chunk_size));
             let bytes_in_chunk = points_in_chunk * point_size;
 
-            self.read_chunk_custom_layout(
-                &mut points_chunk[..],
+            self.read_chunk_default_layout(
+                &mut default_layout_chunk[..],
                 &mut decompression_chunk[..],
                 points_in_chunk,
-                point_buffer.point_layout(),
             )?;
+            unsafe {
+                converter.convert_batch(
+                    &default_layout_chunk,
+                    default_point_size,
+                    &mut points_chunk,
+                    point_size,
+                    points_in_chunk,
+                );
+            }
 
             point_buffer.push(&InterleavedPointView::from_raw_slice(
                 &points_chunk[0..bytes_in_chunk],
-                point_buffer.point_layout().clone(),
+                target_layout.clone(),
             ));
         }
 
@@ -1508,73 +657,6 @@ impl<'a, T: Read + Seek + Send + 'a> RawLAZReader<'a, T> {
 
         Ok(num_points_to_read)
     }
-
-    fn read_next_world_space_position(
-        &self,
-        decompressed_data: &mut Cursor<&mut [u8]>,
-    ) -> Result<Vector3<f64>> {
-        let local_x = decompressed_data.read_i32::<LittleEndian>()?;
-        let local_y = decompressed_data.read_i32::<LittleEndian>()?;
-        let local_z = decompressed_data.read_i32::<LittleEndian>()?;
-        let global_x = (local_x as f64 * self.point_scales.x) + self.point_offsets.x;
-        let global_y = (local_y as f64 * self.point_scales.y) + self.point_offsets.y;
-        let global_z = (local_z as f64 * self.point_scales.z) + self.point_offsets.z;
-        Ok(Vector3::new(global_x, global_y, global_z))
-    }
-
-    fn read_next_bit_attributes(
-        &self,
-        decompressed_data: &mut Cursor<&mut [u8]>,
-        las_format: &Format,
-    ) -> Result<BitAttributes> {
-        if las_format.is_extended {
-            let low_byte = decompressed_data.read_u8()?;
-            let high_byte = decompressed_data.read_u8()?;
-
-            Ok(BitAttributes::Extended(BitAttributesExtended {
-                return_number: low_byte & 0b1111,
-                number_of_returns: (low_byte >> 4) & 0b1111,
-                classification_flags: high_byte & 0b1111,
-                scanner_channel: (high_byte >> 4) & 0b11,
-                scan_direction_flag: (high_byte >> 6) & 0b1,
-                edge_of_flight_line: (high_byte >> 7) & 0b1,
-            }))
-        } else {
-            let byte = decompressed_data.read_u8()?;
-            Ok(BitAttributes::Regular(BitAttributesRegular {
-                return_number: byte & 0b111,
-                number_of_returns: (byte >> 3) & 0b111,
-                scan_direction_flag: (byte >> 6) & 0b1,
-                edge_of_flight_line: (byte >> 7) & 0b1,
-            }))
-        }
-    }
-
-    fn read_next_colors_or_default(
-        decompressed_data: &mut Cursor<&mut [u8]>,
-        las_format: &Format,
-    ) -> Result<Vector3<u16>> {
-        if !las_format.has_color {
-            return Ok(Default::default());
-        }
-        let r = decompressed_data.read_u16::<LittleEndian>()?;
-        let g = decompressed_data.read_u16::<LittleEndian>()?;
-        let b = decompressed_data.read_u16::<LittleEndian>()?;
-        Ok(Vector3::new(r, g, b))
-    }
-
-    fn read_next_waveform_parameters_or_default(
-        decompressed_data: &mut Cursor<&mut [u8]>,
-        las_format: &Format,
-    ) -> Result<Vector3<f32>> {
-        if !las_format.has_waveform {
-            return Ok(Default::default());
-        }
-        let px = decompressed_data.read_f32::<LittleEndian>()?;
-        let py = decompressed_data.read_f32::<LittleEndian>()?;
-        let pz = decompressed_data.read_f32::<LittleEndian>()?;
-        Ok(Vector3::new(px, py, pz))
-    }
 }
 
 impl<'a, T: Read + Seek + Send + 'a> LASReaderBase for RawLAZReader<'a, T> {
@@ -1647,7 +729,7 @@ mod tests {
 
     use las_rs::point::Format;
     use pasture_core::containers::PointBufferExt;
-    use pasture_core::layout::PointAttributeDataType;
+    use pasture_core::layout::{attributes, PointAttributeDataType};
 
     use crate::las::{
         compare_to_reference_data, compare_to_reference_data_range, get_test_las_path,
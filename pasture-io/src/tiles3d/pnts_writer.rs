@@ -67,7 +67,7 @@ pub struct PntsWriter<W: Write + Seek> {
     expected_layout: PointLayout,
     default_layout: PointLayout,
     cached_points: PerAttributeVecPointStorage,
-    attribute_converters: HashMap<&'static str, Option<AttributeConversionFn>>,
+    attribute_converters: HashMap<String, Option<AttributeConversionFn>>,
     rtc_center: Option<Vector3<f64>>,
     requires_flush: bool,
 }
@@ -105,42 +105,42 @@ impl<W: Write + Seek> PntsWriter<W> {
     /// type as per the [3D Tiles standard](https://github.com/CesiumGS/3d-tiles/blob/master/specification/TileFormats/PointCloud/README.md#semantics)
     fn make_compatible_layout(
         point_layout: &PointLayout,
-    ) -> (
-        PointLayout,
-        HashMap<&'static str, Option<AttributeConversionFn>>,
-    ) {
+    ) -> (PointLayout, HashMap<String, Option<AttributeConversionFn>>) {
         let mut compatible_layout = PointLayout::default();
-        let mut conversion_fns: HashMap<&'static str, Option<AttributeConversionFn>> =
-            HashMap::new();
+        let mut conversion_fns: HashMap<String, Option<AttributeConversionFn>> = HashMap::new();
         // TODO Support for other attributes:
         // * Quantized positions
         // * RGB565 colors
         // * Normal oct encoded
         // * Batch ID (and batch table with custom attributes)
 
-        let supported_attributes: HashMap<&'static str, PointAttributeDataType> = vec![
-            (POSITION_3D.name(), PointAttributeDataType::Vec3f32),
-            (COLOR_RGB.name(), PointAttributeDataType::Vec3u8),
-            (COLOR_RGBA.name(), PointAttributeDataType::Vec4u8),
-            (NORMAL.name(), PointAttributeDataType::Vec3f32),
+        let supported_attributes: HashMap<String, PointAttributeDataType> = vec![
+            (
+                POSITION_3D.name().to_owned(),
+                PointAttributeDataType::Vec3f32,
+            ),
+            (COLOR_RGB.name().to_owned(), PointAttributeDataType::Vec3u8),
+            (COLOR_RGBA.name().to_owned(), PointAttributeDataType::Vec4u8),
+            (NORMAL.name().to_owned(), PointAttributeDataType::Vec3f32),
         ]
         .drain(..)
         .collect();
 
         for src_attribute in point_layout.attributes() {
-            if let Some(dst_attribute_datatype) = supported_attributes.get(&src_attribute.name()) {
+            if let Some(dst_attribute_datatype) = supported_attributes.get(src_attribute.name()) {
                 compatible_layout.add_attribute(
-                    PointAttributeDefinition::custom(src_attribute.name(), *dst_attribute_datatype),
+                    PointAttributeDefinition::from(src_attribute)
+                        .with_custom_datatype(*dst_attribute_datatype),
                     FieldAlignment::Default,
                 );
                 let dst_attribute = compatible_layout
                     .get_attribute_by_name(src_attribute.name())
                     .unwrap();
                 if src_attribute.datatype() == dst_attribute.datatype() {
-                    conversion_fns.insert(src_attribute.name(), None);
+                    conversion_fns.insert(src_attribute.name().to_owned(), None);
                 } else {
                     conversion_fns.insert(
-                        src_attribute.name(),
+                        src_attribute.name().to_owned(),
                         get_converter_for_attributes(&src_attribute.into(), &dst_attribute.into()),
                     );
                 }